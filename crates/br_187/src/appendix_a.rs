@@ -3,3 +3,5 @@ pub mod equation_a2;
 pub mod equation_a3;
 pub mod equation_a4;
 pub mod equation_a5;
+pub mod external_flame;
+pub mod radiant_exposure;
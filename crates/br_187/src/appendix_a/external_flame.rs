@@ -0,0 +1,205 @@
+//! External flame projecting from an opening, treated as an additional
+//! emitter above the opening. The flame height comes from CIBSE Guide E
+//! equation 6.57 and the flame geometry follows Law's correlations for
+//! openings without through draught. The flame radiation is added to the
+//! opening emitter result from this appendix to give the combined heat flux
+//! at a receiver opposite the centre of the opening.
+
+use super::{equation_a2, equation_a4};
+use crate::chapter_1::fire_severity;
+use cibse_guide_e::chapter_6::equation_6_57;
+
+/// Height of the flame above the top of the opening (m).
+pub fn flame_height(r: f64, w: f64, h_o: f64) -> f64 {
+    equation_6_57::height_of_flame_aboveopening(r, w, h_o).max(0.0)
+}
+
+/// Horizontal projection of the flame from the façade (m).
+pub fn flame_projection(h_o: f64, w: f64) -> f64 {
+    if h_o <= 1.25 * w {
+        h_o / 3.0
+    } else {
+        0.3 * h_o * (h_o / w).powf(0.54)
+    }
+}
+
+pub fn flame_thickness(h_o: f64) -> f64 {
+    2.0 * h_o / 3.0
+}
+
+pub fn flame_emissivity(d_f: f64) -> f64 {
+    1.0 - (-0.3 * d_f).exp()
+}
+
+/// View factor from the flame to a receiver opposite the centre of the
+/// opening, with the flame face at distance `s` from the receiver. Returns
+/// `None` unless the receiver is in front of the flame face, `s > 0`.
+pub fn flame_view_factor(w: f64, h_o: f64, z_f: f64, s: f64) -> Option<f64> {
+    if s <= 0.0 {
+        return None;
+    }
+
+    let x = equation_a4::x(w / 2.0, s);
+    let upper = equation_a4::phi(x, equation_a4::y(h_o / 2.0 + z_f, s), true);
+    let lower = equation_a4::phi(x, equation_a4::y(h_o / 2.0, s), false);
+
+    Some(2.0 * (upper + lower))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExternalFlame {
+    pub height: f64,
+    pub width: f64,
+    pub projection: f64,
+    pub thickness: f64,
+    pub emissivity: f64,
+    pub temperature: f64,
+}
+
+impl ExternalFlame {
+    /// Builds the flame emitter for an opening of width `w` and height `h_o`
+    /// with a rate of burning `r` (kg/s) and a flame temperature `theta_f` (°C).
+    pub fn from_compartment(r: f64, w: f64, h_o: f64, theta_f: f64) -> Self {
+        let thickness = flame_thickness(h_o);
+
+        ExternalFlame {
+            height: flame_height(r, w, h_o),
+            width: w,
+            projection: flame_projection(h_o, w),
+            thickness,
+            emissivity: flame_emissivity(thickness),
+            temperature: theta_f,
+        }
+    }
+
+    /// Radiation intensity at the flame surface (kW/m²).
+    pub fn intensity(&self, sigma: f64) -> f64 {
+        fire_severity::emitter_intensity(sigma, self.emissivity, self.temperature)
+    }
+
+    /// View factor to a receiver at distance `s` from the façade, opposite
+    /// the centre of an opening of height `h_o`. Returns `None` if the
+    /// receiver is within the flame projection.
+    pub fn view_factor(&self, h_o: f64, s: f64) -> Option<f64> {
+        flame_view_factor(self.width, h_o, self.height, s - self.projection)
+    }
+
+    /// Radiation intensity received from the flame (kW/m²), or `None` if
+    /// the receiver is within the flame projection.
+    pub fn received_intensity(&self, h_o: f64, s: f64, sigma: f64) -> Option<f64> {
+        let phi = self.view_factor(h_o, s)?;
+        Some(equation_a2::radiation_intensity_at_receiver(
+            phi,
+            self.intensity(sigma),
+        ))
+    }
+}
+
+/// Combined radiation intensity at the receiver (kW/m²) from the opening
+/// emitter and the external flame.
+pub fn combined_receiver_intensity(phi_o: f64, i_o: f64, phi_f: f64, i_f: f64) -> f64 {
+    equation_a2::radiation_intensity_at_receiver(phi_o, i_o)
+        + equation_a2::radiation_intensity_at_receiver(phi_f, i_f)
+}
+
+#[cfg(not(coverage))]
+pub fn flame_projection_equation(l_h: String, h_o: String, w: String) -> String {
+    format!(
+        "{} = \\begin{{cases}} {} / 3 & {} \\leq 1.25 {} \\\\ 0.3 \\cdot {} \\left(\\frac{{{}}}{{{}}}\\right)^{{0.54}} & {} > 1.25 {} \\end{{cases}}",
+        l_h, h_o, h_o, w, h_o, h_o, w, h_o, w
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn flame_thickness_equation(d_f: String, h_o: String) -> String {
+    format!("{} = \\frac{{2}}{{3}} \\cdot {}", d_f, h_o)
+}
+
+#[cfg(not(coverage))]
+pub fn flame_emissivity_equation(epsilon_f: String, d_f: String) -> String {
+    format!("{} = 1 - e^{{-0.3 \\cdot {}}}", epsilon_f, d_f)
+}
+
+#[cfg(not(coverage))]
+pub fn combined_receiver_intensity_equation(
+    i_r: String,
+    phi_o: String,
+    i_o: String,
+    phi_f: String,
+    i_f: String,
+) -> String {
+    format!(
+        "{} = {} \\cdot {} + {} \\cdot {}",
+        i_r, phi_o, i_o, phi_f, i_f
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flame_height() {
+        let result = flame_height(0.2, 1.0, 2.1);
+        assert!((result - 2.2775384234923455).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_flame_height_below_opening() {
+        assert_eq!(flame_height(0.01, 2.0, 3.0), 0.0);
+    }
+
+    #[test]
+    fn test_flame_projection_wide_opening() {
+        assert!((flame_projection(1.2, 2.0) - 0.4).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_flame_projection_tall_opening() {
+        let result = flame_projection(2.1, 1.0);
+        assert!((result - 0.9404570547393712).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_flame_emissivity() {
+        let result = flame_emissivity(flame_thickness(2.1));
+        assert!((result - 0.34295318018494325).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_flame_view_factor() {
+        let result = flame_view_factor(1.0, 2.1, 2.2775384234923455, 4.0).unwrap();
+        assert!((result - 0.026962966346080447).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_external_flame_from_compartment() {
+        let flame = ExternalFlame::from_compartment(0.2, 1.0, 2.1, 800.0);
+        assert_eq!(flame.width, 1.0);
+        assert!((flame.projection - 0.9404570547393712).abs() < 1e-12);
+        let phi = flame.view_factor(2.1, 5.0).unwrap();
+        assert!((phi - 0.026499858504445133).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_external_flame_received_intensity() {
+        let flame = ExternalFlame::from_compartment(0.2, 1.0, 2.1, 800.0);
+        let expected = flame.view_factor(2.1, 5.0).unwrap() * flame.intensity(5.67e-11);
+        let result = flame.received_intensity(2.1, 5.0, 5.67e-11).unwrap();
+        assert!((result - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_receiver_within_flame_projection() {
+        let flame = ExternalFlame::from_compartment(0.2, 1.0, 2.1, 800.0);
+        assert_eq!(flame.view_factor(2.1, flame.projection), None);
+        assert_eq!(flame.received_intensity(2.1, 0.5, 5.67e-11), None);
+        assert_eq!(flame_view_factor(1.0, 2.1, 2.0, -1.0), None);
+    }
+
+    #[test]
+    fn test_combined_receiver_intensity() {
+        let result = combined_receiver_intensity(0.05, 84.0, 0.02, 30.0);
+        assert!((result - 4.8).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,261 @@
+//! Time-dependent radiation received from a compartment fire. A compartment
+//! temperature history is converted into the emitter intensity (equation A1)
+//! and scaled by the view factor of equations A3 to A5 (equation A2) at each
+//! time, giving the received heat flux history, the radiant dose and the time
+//! to ignition of the receiver by the flux-time product method. Times are in
+//! s.
+
+use eurocode_1_1_2::fire_curve::FireCurve;
+
+use super::{equation_a2, equation_a3, equation_a4, equation_a5};
+use crate::chapter_1::fire_severity;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Receiver {
+    /// Parallel to the emitter and opposite its centre, equation A3.
+    ParallelCentre,
+    /// Parallel to the emitter and opposite a corner, equation A4.
+    ParallelCorner,
+    /// Perpendicular to the emitter and in line with a corner, equation A5.
+    PerpendicularCorner,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Emitter {
+    /// Width of the emitter (m).
+    pub width: f64,
+    /// Height of the emitter (m).
+    pub height: f64,
+    /// Separation between the emitter and the receiver (m).
+    pub separation: f64,
+    pub receiver: Receiver,
+}
+
+impl Emitter {
+    pub fn new(width: f64, height: f64, separation: f64, receiver: Receiver) -> Self {
+        Emitter {
+            width,
+            height,
+            separation,
+            receiver,
+        }
+    }
+
+    /// View factor from the receiver to the emitter.
+    pub fn view_factor(&self) -> f64 {
+        let (w, h, s) = (self.width, self.height, self.separation);
+        match self.receiver {
+            Receiver::ParallelCentre => {
+                equation_a3::phi(equation_a3::x(w, s), equation_a3::y(h, s), true)
+            }
+            Receiver::ParallelCorner => {
+                equation_a4::phi(equation_a4::x(w, s), equation_a4::y(h, s), true)
+            }
+            Receiver::PerpendicularCorner => {
+                equation_a5::phi(equation_a5::x(w, s), equation_a5::y(h, s), true)
+            }
+        }
+    }
+}
+
+/// Samples a temperature-time curve (°C) at the given times.
+pub fn temperature_history<F: Fn(f64) -> f64>(curve: F, times: &[f64]) -> Vec<f64> {
+    times.iter().map(|t| curve(*t)).collect()
+}
+
+/// Samples a fire curve, which is defined in minutes, at the given times.
+pub fn fire_curve_history(curve: &FireCurve, times: &[f64]) -> Vec<f64> {
+    temperature_history(|t| curve.temperature(t / 60.0), times)
+}
+
+/// Received radiation intensity (kW/m²) from the emitter for each
+/// compartment temperature (°C).
+pub fn received_intensity_history(
+    temperatures: &[f64],
+    emitter: &Emitter,
+    sigma: f64,
+    emissivity: f64,
+) -> Vec<f64> {
+    let phi = emitter.view_factor();
+    temperatures
+        .iter()
+        .map(|theta| {
+            let i_s = fire_severity::emitter_intensity(sigma, emissivity, *theta);
+            equation_a2::radiation_intensity_at_receiver(phi, i_s)
+        })
+        .collect()
+}
+
+/// Cumulative radiant dose (kJ/m²) at each time, integrated with the
+/// trapezoidal rule. The times and intensities must have the same length.
+pub fn cumulative_radiant_dose(times: &[f64], intensities: &[f64]) -> Vec<f64> {
+    assert_eq!(
+        times.len(),
+        intensities.len(),
+        "times and intensities must have the same length"
+    );
+    let mut dose = Vec::with_capacity(times.len());
+    let mut total = 0.0;
+
+    for i in 0..times.len() {
+        if i > 0 {
+            total += 0.5 * (intensities[i] + intensities[i - 1]) * (times[i] - times[i - 1]);
+        }
+        dose.push(total);
+    }
+
+    dose
+}
+
+/// Total radiant dose (kJ/m²) received over the history.
+pub fn radiant_dose(times: &[f64], intensities: &[f64]) -> f64 {
+    cumulative_radiant_dose(times, intensities)
+        .last()
+        .copied()
+        .unwrap_or(0.0)
+}
+
+/// Cumulative flux-time product `Σ (q - q_cr)ⁿ Δt` at each time. Intervals
+/// where the received intensity is below the critical intensity do not
+/// contribute.
+pub fn cumulative_flux_time_product(
+    times: &[f64],
+    intensities: &[f64],
+    q_cr: f64,
+    n: f64,
+) -> Vec<f64> {
+    let excess: Vec<f64> = intensities
+        .iter()
+        .map(|q| (q - q_cr).max(0.0).powf(n))
+        .collect();
+
+    cumulative_radiant_dose(times, &excess)
+}
+
+/// Time at which the flux-time product first reaches `ftp_crit`, linearly
+/// interpolated between samples. Returns `None` if the receiver does not
+/// ignite within the history. `ftp_crit` must be positive.
+pub fn time_to_ignition(
+    times: &[f64],
+    intensities: &[f64],
+    q_cr: f64,
+    n: f64,
+    ftp_crit: f64,
+) -> Option<f64> {
+    assert!(
+        ftp_crit > 0.0,
+        "critical flux-time product must be positive"
+    );
+    let ftp = cumulative_flux_time_product(times, intensities, q_cr, n);
+
+    for i in 1..ftp.len() {
+        if ftp[i] >= ftp_crit {
+            let fraction = (ftp_crit - ftp[i - 1]) / (ftp[i] - ftp[i - 1]);
+            return Some(times[i - 1] + fraction * (times[i] - times[i - 1]));
+        }
+    }
+
+    None
+}
+
+#[cfg(not(coverage))]
+pub fn radiant_dose_equation(d: String, q: String, t: String) -> String {
+    format!("{} = \\int_0^{{{}}} {} \\, dt", d, t, q)
+}
+
+#[cfg(not(coverage))]
+pub fn flux_time_product_equation(ftp: String, q: String, q_cr: String, n: String) -> String {
+    format!(
+        "{} = \\sum \\left({} - {}\\right)^{{{}}} \\Delta t",
+        ftp, q, q_cr, n
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_temperature_history() {
+        let result = temperature_history(|t| 20.0 + t, &[0.0, 10.0, 20.0]);
+        assert_eq!(result, vec![20.0, 30.0, 40.0]);
+    }
+
+    #[test]
+    fn test_received_intensity_history() {
+        let emitter = Emitter::new(5.0, 3.0, 10.0, Receiver::ParallelCentre);
+        let phi = equation_a3::phi(0.25, 0.15, true);
+        let result = received_intensity_history(&[1000.0, 1000.0], &emitter, 5.67e-11, 1.0);
+
+        assert_eq!(result.len(), 2);
+        assert!((result[0] - phi * 148.9006773989847).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_view_factors() {
+        let parallel = Emitter::new(4.0, 2.0, 5.0, Receiver::ParallelCorner);
+        let perpendicular = Emitter::new(4.0, 2.0, 5.0, Receiver::PerpendicularCorner);
+
+        let expected = equation_a4::phi(0.8, 0.4, true);
+        assert!((parallel.view_factor() - expected).abs() < 1e-12);
+        let expected = equation_a5::phi(0.8, 0.4, true);
+        assert!((perpendicular.view_factor() - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_fire_curve_history() {
+        let result = fire_curve_history(&FireCurve::Standard, &[0.0, 600.0]);
+        let expected = 20.0 + 345.0 * 81.0_f64.log10();
+        assert!((result[0] - 20.0).abs() < 1e-9);
+        assert!((result[1] - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_radiant_dose_mismatched_lengths() {
+        radiant_dose(&[0.0, 60.0], &[10.0]);
+    }
+
+    #[test]
+    fn test_radiant_dose_constant() {
+        let times = [0.0, 60.0, 120.0];
+        let intensities = [10.0, 10.0, 10.0];
+        assert!((radiant_dose(&times, &intensities) - 1200.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_radiant_dose_empty() {
+        assert_eq!(radiant_dose(&[], &[]), 0.0);
+    }
+
+    #[test]
+    fn test_cumulative_radiant_dose_ramp() {
+        let times = [0.0, 10.0, 20.0];
+        let intensities = [0.0, 10.0, 20.0];
+        let result = cumulative_radiant_dose(&times, &intensities);
+        assert_eq!(result, vec![0.0, 50.0, 200.0]);
+    }
+
+    #[test]
+    fn test_flux_time_product_below_critical() {
+        let times = [0.0, 60.0];
+        let intensities = [5.0, 5.0];
+        let result = cumulative_flux_time_product(&times, &intensities, 10.0, 1.5);
+        assert_eq!(result, vec![0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_time_to_ignition() {
+        let times = [0.0, 100.0, 200.0];
+        let intensities = [20.0, 20.0, 20.0];
+        let result = time_to_ignition(&times, &intensities, 10.0, 1.0, 1500.0).unwrap();
+        assert!((result - 150.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_time_to_ignition_none() {
+        let times = [0.0, 100.0];
+        let intensities = [12.0, 12.0];
+        assert!(time_to_ignition(&times, &intensities, 10.0, 1.0, 1000.0).is_none());
+    }
+}
@@ -1 +1,3 @@
 pub mod equation_1;
+pub mod fire_severity;
+pub mod purpose_group;
@@ -0,0 +1,147 @@
+//! Compartment fire severity inputs for the emitter. The ventilation factor
+//! from equation 1 sets the maximum compartment temperature (Law), which is
+//! moderated by the fire load and converted into an emitter radiation
+//! intensity for use with appendix A.
+
+use super::purpose_group::FireLoad;
+use crate::appendix_a::equation_a1;
+
+pub fn maximum_temperature(omega: f64) -> f64 {
+    6000.0 * (1.0 - (-0.1 * omega).exp()) / omega.sqrt()
+}
+
+pub fn fire_load_factor(l: f64, a_v: f64, a_t: f64) -> f64 {
+    l / (a_v * a_t).sqrt()
+}
+
+pub fn temperature(theta_max: f64, psi: f64) -> f64 {
+    theta_max * (1.0 - (-0.05 * psi).exp())
+}
+
+/// Emitter radiation intensity (kW/m²) for a compartment temperature in °C.
+pub fn emitter_intensity(sigma: f64, emissivity: f64, theta: f64) -> f64 {
+    equation_a1::radiation_intensity(sigma, emissivity, theta + 273.0)
+}
+
+/// Compartment temperature (°C) that produces the given emitter intensity.
+pub fn emitter_temperature(sigma: f64, emissivity: f64, i_s: f64) -> f64 {
+    (i_s / (sigma * emissivity)).powf(0.25) - 273.0
+}
+
+/// Emitter radiation intensity (kW/m²) for a compartment described by its
+/// ventilation factor and fire load factor.
+pub fn emitter_intensity_from_ventilation(
+    omega: f64,
+    psi: f64,
+    sigma: f64,
+    emissivity: f64,
+) -> f64 {
+    let theta = temperature(maximum_temperature(omega), psi);
+    emitter_intensity(sigma, emissivity, theta)
+}
+
+/// Compartment temperature (°C) equivalent to the emitter intensity of a
+/// fire load classification.
+pub fn fire_load_temperature(fire_load: FireLoad, sigma: f64, emissivity: f64) -> f64 {
+    emitter_temperature(sigma, emissivity, fire_load.radiation_intensity())
+}
+
+#[cfg(not(coverage))]
+pub fn maximum_temperature_equation(theta_max: String, omega: String) -> String {
+    format!(
+        "{} = 6000 \\cdot \\frac{{1 - e^{{-0.1 \\cdot {}}}}}{{\\sqrt{{{}}}}}",
+        theta_max, omega, omega
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn fire_load_factor_equation(psi: String, l: String, a_v: String, a_t: String) -> String {
+    format!(
+        "{} = \\frac{{{}}}{{\\sqrt{{{} \\cdot {}}}}}",
+        psi, l, a_v, a_t
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn temperature_equation(theta: String, theta_max: String, psi: String) -> String {
+    format!(
+        "{} = {} \\cdot \\left(1 - e^{{-0.05 \\cdot {}}}\\right)",
+        theta, theta_max, psi
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn emitter_intensity_equation(
+    i_s: String,
+    sigma: String,
+    emissivity: String,
+    theta: String,
+) -> String {
+    format!(
+        "{} = {} \\cdot {} \\cdot ({} + 273)^4",
+        i_s, sigma, emissivity, theta
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn emitter_temperature_equation(
+    theta: String,
+    sigma: String,
+    emissivity: String,
+    i_s: String,
+) -> String {
+    format!(
+        "{} = \\left(\\frac{{{}}}{{{} \\cdot {}}}\\right)^{{1/4}} - 273",
+        theta, i_s, sigma, emissivity
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_maximum_temperature() {
+        let result = maximum_temperature(14.386997);
+        assert!((result - 1206.5806071046927).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_fire_load_factor() {
+        let result = fire_load_factor(500.0, 1.785, 37.215);
+        assert!((result - 61.3467955734053).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_temperature() {
+        let result = temperature(1206.5806071046927, 61.3467955734053);
+        assert!((result - 1150.4205422319083).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_emitter_intensity() {
+        let result = emitter_intensity(5.67e-11, 1.0, 1000.0);
+        assert!((result - 148.9006773989847).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_emitter_temperature_round_trip() {
+        let theta = emitter_temperature(5.67e-11, 1.0, 148.9006773989847);
+        assert!((theta - 1000.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_emitter_intensity_from_ventilation() {
+        let psi = fire_load_factor(500.0, 1.785, 37.215);
+        let result = emitter_intensity_from_ventilation(14.386997, psi, 5.67e-11, 1.0);
+        assert!((result - 232.76408760005484).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_fire_load_temperature() {
+        let normal = fire_load_temperature(FireLoad::Normal, 5.67e-11, 1.0);
+        let high = fire_load_temperature(FireLoad::High, 5.67e-11, 1.0);
+        assert!((normal - 830.2503065489577).abs() < 1e-9);
+        assert!((high - 1038.9931141769537).abs() < 1e-9);
+    }
+}
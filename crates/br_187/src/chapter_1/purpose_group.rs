@@ -0,0 +1,76 @@
+//! Fire load classification of a compartment by its purpose group and the
+//! emitter radiation intensity that BR 187 assigns to each classification.
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PurposeGroup {
+    Residential,
+    Office,
+    AssemblyAndRecreation,
+    Shop,
+    Commercial,
+    Industrial,
+    Storage,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FireLoad {
+    Normal,
+    High,
+}
+
+impl PurposeGroup {
+    pub fn fire_load(&self) -> FireLoad {
+        match self {
+            PurposeGroup::Residential
+            | PurposeGroup::Office
+            | PurposeGroup::AssemblyAndRecreation => FireLoad::Normal,
+            PurposeGroup::Shop
+            | PurposeGroup::Commercial
+            | PurposeGroup::Industrial
+            | PurposeGroup::Storage => FireLoad::High,
+        }
+    }
+}
+
+impl FireLoad {
+    /// Radiation intensity at the emitter (kW/m²) used for unprotected areas.
+    pub fn radiation_intensity(&self) -> f64 {
+        match self {
+            FireLoad::Normal => 84.0,
+            FireLoad::High => 168.0,
+        }
+    }
+}
+
+pub fn radiation_intensity(purpose_group: PurposeGroup) -> f64 {
+    purpose_group.fire_load().radiation_intensity()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fire_load_normal() {
+        assert_eq!(PurposeGroup::Residential.fire_load(), FireLoad::Normal);
+        assert_eq!(PurposeGroup::Office.fire_load(), FireLoad::Normal);
+        assert_eq!(
+            PurposeGroup::AssemblyAndRecreation.fire_load(),
+            FireLoad::Normal
+        );
+    }
+
+    #[test]
+    fn test_fire_load_high() {
+        assert_eq!(PurposeGroup::Shop.fire_load(), FireLoad::High);
+        assert_eq!(PurposeGroup::Commercial.fire_load(), FireLoad::High);
+        assert_eq!(PurposeGroup::Industrial.fire_load(), FireLoad::High);
+        assert_eq!(PurposeGroup::Storage.fire_load(), FireLoad::High);
+    }
+
+    #[test]
+    fn test_radiation_intensity() {
+        assert_eq!(radiation_intensity(PurposeGroup::Office), 84.0);
+        assert_eq!(radiation_intensity(PurposeGroup::Storage), 168.0);
+    }
+}
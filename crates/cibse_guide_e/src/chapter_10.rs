@@ -7,3 +7,4 @@ pub mod equation_10_3;
 pub mod equation_10_4;
 pub mod equation_10_7;
 pub mod equation_10_8;
+pub mod smoke_reservoir;
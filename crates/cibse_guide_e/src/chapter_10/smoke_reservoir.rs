@@ -0,0 +1,271 @@
+//! Smoke reservoir design for natural or mechanical extract. The plume
+//! entering the reservoir follows the large fire correlation
+//! `M = 0.19 P Y^3/2`, the layer is heated adiabatically by the convective
+//! heat release, and natural vents are sized from the buoyancy head of the
+//! layer with the replacement air inlets. Extract points are limited by
+//! plugholing (equation 10.1) and spaced by equation 10.2. Heat release rates
+//! are in kW, lengths in m and temperatures in K.
+
+use super::equation_10_1::max_volumetric_flow_rate;
+use super::equation_10_2::min_separation_dist;
+use super::equation_10_3::volumetric_flow_rate;
+
+const G: f64 = 9.81;
+/// Specific heat of the smoke (kJ/kg·K).
+const C_P: f64 = 1.0;
+/// Entrainment coefficient of the large fire plume (kg/s·m^5/2).
+const ENTRAINMENT: f64 = 0.19;
+/// Largest reservoir area (m²).
+pub const MAX_AREA: f64 = 2000.0;
+/// Largest reservoir length (m).
+pub const MAX_LENGTH: f64 = 60.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Extract {
+    /// Natural vents with replacement air inlets of `inlet_ratio` times the
+    /// aerodynamic free area of the vents.
+    Natural {
+        inlet_ratio: f64,
+    },
+    Mechanical,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Violation {
+    /// The convective heat release, fire perimeter or clear height is not
+    /// positive, so no heated plume enters the reservoir.
+    NoPlume,
+    /// The clear height is not below the ceiling.
+    NoSmokeLayer,
+    /// The reservoir is larger than `MAX_AREA`.
+    AreaTooLarge,
+    /// The reservoir is longer than `MAX_LENGTH`.
+    LengthTooLarge,
+    /// The extract points cannot be spaced at the minimum separation within
+    /// the reservoir.
+    ExtractSpacing,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Reservoir {
+    pub width: f64,
+    pub length: f64,
+    /// Height of the ceiling above the floor.
+    pub height: f64,
+    /// Ambient temperature (K).
+    pub t_0: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReservoirDesign {
+    /// Mass flow of smoke into and out of the reservoir (kg/s).
+    pub mass_flow: f64,
+    /// Smoke layer temperature (K).
+    pub layer_temperature: f64,
+    /// Smoke layer depth (m).
+    pub layer_depth: f64,
+    /// Volumetric extract at the layer temperature (m³/s).
+    pub volumetric_flow: f64,
+    /// Total aerodynamic free area of the natural vents (m²), `None` for
+    /// mechanical extract.
+    pub vent_area: Option<f64>,
+    /// Largest flow through one extract point without plugholing (m³/s).
+    pub max_flow_per_point: f64,
+    /// Smallest number of extract points that avoids plugholing.
+    pub extract_points: usize,
+    /// Minimum separation between extract points (m).
+    pub min_separation: f64,
+    pub violations: Vec<Violation>,
+}
+
+impl Reservoir {
+    /// Reservoir at 20 °C.
+    pub fn new(width: f64, length: f64, height: f64) -> Self {
+        Reservoir {
+            width,
+            length,
+            height,
+            t_0: 293.15,
+        }
+    }
+
+    /// Design for a fire of convective heat release `q_c` and perimeter
+    /// `perimeter` (m) on the floor, holding the layer at `clear_height`
+    /// above it. `gamma` is 1.0 for extract points away from walls and 0.5
+    /// for points next to a wall.
+    pub fn design(
+        &self,
+        q_c: f64,
+        perimeter: f64,
+        clear_height: f64,
+        extract: Extract,
+        gamma: f64,
+    ) -> ReservoirDesign {
+        let no_plume = q_c <= 0.0 || perimeter <= 0.0 || clear_height <= 0.0;
+        let mass_flow = ENTRAINMENT * perimeter.max(0.0) * clear_height.max(0.0).powf(1.5);
+        let theta = if no_plume {
+            0.0
+        } else {
+            q_c / (mass_flow * C_P)
+        };
+        let layer_temperature = self.t_0 + theta;
+        let rho_0 = 353.0 / self.t_0;
+        let volumetric_flow = volumetric_flow_rate(mass_flow, layer_temperature, rho_0, self.t_0);
+        let layer_depth = self.height - clear_height;
+
+        let mut violations = vec![];
+        if self.width * self.length > MAX_AREA {
+            violations.push(Violation::AreaTooLarge);
+        }
+        if self.width.max(self.length) > MAX_LENGTH {
+            violations.push(Violation::LengthTooLarge);
+        }
+        if layer_depth <= 0.0 {
+            violations.insert(0, Violation::NoSmokeLayer);
+        }
+        if no_plume {
+            violations.insert(0, Violation::NoPlume);
+        }
+        if no_plume || layer_depth <= 0.0 {
+            return ReservoirDesign {
+                mass_flow,
+                layer_temperature,
+                layer_depth: layer_depth.max(0.0),
+                volumetric_flow,
+                vent_area: None,
+                max_flow_per_point: 0.0,
+                extract_points: 0,
+                min_separation: 0.0,
+                violations,
+            };
+        }
+
+        let vent_area = match extract {
+            Extract::Natural { inlet_ratio } => {
+                let t_1 = layer_temperature;
+                let head = (2.0 * G * layer_depth * theta * self.t_0).sqrt();
+                let temperatures = (t_1.powi(2) + t_1 * self.t_0 / inlet_ratio.powi(2)).sqrt();
+                Some(mass_flow * temperatures / (rho_0 * head))
+            }
+            Extract::Mechanical => None,
+        };
+
+        let max_flow_per_point =
+            max_volumetric_flow_rate(gamma, layer_depth, layer_temperature, self.t_0);
+        let extract_points = (volumetric_flow / max_flow_per_point).ceil().max(1.0) as usize;
+        let min_separation = min_separation_dist(volumetric_flow / extract_points as f64);
+        if extract_points as f64 * min_separation.powi(2) > self.width * self.length {
+            violations.push(Violation::ExtractSpacing);
+        }
+
+        ReservoirDesign {
+            mass_flow,
+            layer_temperature,
+            layer_depth,
+            volumetric_flow,
+            vent_area,
+            max_flow_per_point,
+            extract_points,
+            min_separation,
+            violations,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layer_properties() {
+        // 2.5 MW convective shop fire with a 12 m perimeter and 3 m clear.
+        let reservoir = Reservoir::new(30.0, 40.0, 5.0);
+        let design = reservoir.design(2500.0, 12.0, 3.0, Extract::Mechanical, 1.0);
+
+        let m = 0.19 * 12.0 * 3.0_f64.powf(1.5);
+        assert!((design.mass_flow - m).abs() < 1e-9);
+        assert!((design.layer_temperature - (293.15 + 2500.0 / m)).abs() < 1e-9);
+        assert_eq!(design.layer_depth, 2.0);
+        let v = m * design.layer_temperature / 353.0;
+        assert!((design.volumetric_flow - v).abs() < 1e-9);
+        assert_eq!(design.vent_area, None);
+        assert!(design.violations.is_empty());
+    }
+
+    #[test]
+    fn test_extract_points_avoid_plugholing() {
+        let reservoir = Reservoir::new(30.0, 40.0, 4.0);
+        let design = reservoir.design(2500.0, 12.0, 3.0, Extract::Mechanical, 0.5);
+
+        let n = design.extract_points as f64;
+        assert!(n * design.max_flow_per_point >= design.volumetric_flow);
+        assert!((n - 1.0) * design.max_flow_per_point < design.volumetric_flow);
+        let expected = 0.9 * (design.volumetric_flow / n).sqrt();
+        assert!((design.min_separation - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_natural_vent_area() {
+        let reservoir = Reservoir::new(30.0, 40.0, 5.0);
+        let design = reservoir.design(
+            2500.0,
+            12.0,
+            3.0,
+            Extract::Natural { inlet_ratio: 2.0 },
+            1.0,
+        );
+
+        let (m, t_1, t_0) = (design.mass_flow, design.layer_temperature, 293.15);
+        let rho_0 = 353.0 / t_0;
+        let expected = m * (t_1 * t_1 + t_1 * t_0 / 4.0).sqrt()
+            / (rho_0 * (2.0 * 9.81 * 2.0 * (t_1 - t_0) * t_0).sqrt());
+        assert!((design.vent_area.unwrap() - expected).abs() < 1e-9);
+
+        // Larger inlets reduce the vent area required.
+        let generous = reservoir.design(
+            2500.0,
+            12.0,
+            3.0,
+            Extract::Natural { inlet_ratio: 10.0 },
+            1.0,
+        );
+        assert!(generous.vent_area.unwrap() < design.vent_area.unwrap());
+    }
+
+    #[test]
+    fn test_violations() {
+        let large = Reservoir::new(40.0, 70.0, 5.0);
+        let design = large.design(2500.0, 12.0, 3.0, Extract::Mechanical, 1.0);
+        assert_eq!(
+            design.violations,
+            vec![Violation::AreaTooLarge, Violation::LengthTooLarge]
+        );
+
+        let low = Reservoir::new(30.0, 40.0, 3.0);
+        let design = low.design(2500.0, 12.0, 3.0, Extract::Mechanical, 1.0);
+        assert_eq!(design.violations, vec![Violation::NoSmokeLayer]);
+        assert_eq!(design.extract_points, 0);
+
+        let normal = Reservoir::new(30.0, 40.0, 5.0);
+        for (q_c, perimeter, clear_height) in
+            [(0.0, 12.0, 3.0), (2500.0, 0.0, 3.0), (2500.0, 12.0, 0.0)]
+        {
+            let design = normal.design(
+                q_c,
+                perimeter,
+                clear_height,
+                Extract::Natural { inlet_ratio: 2.0 },
+                1.0,
+            );
+            assert_eq!(design.violations, vec![Violation::NoPlume]);
+            assert_eq!(design.layer_temperature, 293.15);
+            assert!(design.volumetric_flow.is_finite());
+            assert_eq!(design.vent_area, None);
+            assert_eq!(design.extract_points, 0);
+        }
+
+        let small = Reservoir::new(3.0, 3.0, 3.5);
+        let design = small.design(2500.0, 12.0, 3.0, Extract::Mechanical, 0.5);
+        assert!(design.violations.contains(&Violation::ExtractSpacing));
+    }
+}
@@ -0,0 +1,12 @@
+pub mod equation_a1;
+pub mod equation_a10;
+pub mod equation_a11;
+pub mod equation_a2;
+pub mod equation_a3;
+pub mod equation_a4;
+pub mod equation_a5;
+pub mod equation_a6;
+pub mod equation_a7;
+pub mod equation_a8;
+pub mod equation_a9;
+pub mod parametric_curve;
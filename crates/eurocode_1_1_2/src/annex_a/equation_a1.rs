@@ -0,0 +1,33 @@
+pub fn heating_phase_temperature(t_star: f64) -> f64 {
+    20.0 + 1325.0
+        * (1.0
+            - 0.324 * (-0.2 * t_star).exp()
+            - 0.204 * (-1.7 * t_star).exp()
+            - 0.472 * (-19.0 * t_star).exp())
+}
+
+#[cfg(not(coverage))]
+pub fn heating_phase_temperature_equation(theta_g: String, t_star: String) -> String {
+    format!(
+        "{} = 20 + 1325 \\cdot \\left( 1 - 0.324 \\cdot e^{{-0.2 \\cdot {}}} - 0.204 \\cdot e^{{-1.7 \\cdot {}}} - 0.472 \\cdot e^{{-19 \\cdot {}}} \\right)",
+        theta_g, t_star, t_star, t_star
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_heating_phase_temperature() {
+        let result = heating_phase_temperature(1.0);
+        let expected = 944.1395276481792;
+        assert!((result - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_heating_phase_temperature_start() {
+        let result = heating_phase_temperature(0.0);
+        assert!((result - 20.0).abs() < 1e-9);
+    }
+}
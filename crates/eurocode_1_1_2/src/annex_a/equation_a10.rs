@@ -0,0 +1,37 @@
+/// Factor applied to `gamma_lim` for enclosures with a large opening factor,
+/// a low fire load and a low thermal absorptivity. Returns one where the
+/// conditions for its use are not met.
+pub fn gamma_lim_factor(o: f64, q_td: f64, b: f64) -> f64 {
+    if o > 0.04 && q_td < 75.0 && b < 1160.0 {
+        1.0 + ((o - 0.04) / 0.04) * ((q_td - 75.0) / 75.0) * ((1160.0 - b) / 1160.0)
+    } else {
+        1.0
+    }
+}
+
+#[cfg(not(coverage))]
+pub fn gamma_lim_factor_equation(k: String, o: String, q_td: String, b: String) -> String {
+    format!(
+        "{} = 1 + \\left( \\frac{{{} - 0.04}}{{0.04}} \\right) \\left( \\frac{{{} - 75}}{{75}} \\right) \\left( \\frac{{1160 - {}}}{{1160}} \\right)",
+        k, o, q_td, b
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gamma_lim_factor_applies() {
+        let result = gamma_lim_factor(0.1, 60.0, 800.0);
+        let expected = 1.0 + 1.5 * (-0.2) * (360.0 / 1160.0);
+        assert!((result - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_gamma_lim_factor_not_applicable() {
+        assert_eq!(gamma_lim_factor(0.03, 60.0, 800.0), 1.0);
+        assert_eq!(gamma_lim_factor(0.1, 80.0, 800.0), 1.0);
+        assert_eq!(gamma_lim_factor(0.1, 60.0, 1200.0), 1.0);
+    }
+}
@@ -0,0 +1,83 @@
+/// Gas temperature (°C) in the cooling phase, for the maximum temperature
+/// `theta_max` (°C), fictitious time `t_star` (h), fictitious time of
+/// maximum temperature `t_star_max` (h) and factor `x`.
+pub fn cooling_phase_temperature(theta_max: f64, t_star: f64, t_star_max: f64, x: f64) -> f64 {
+    let rate = if t_star_max <= 0.5 {
+        625.0
+    } else if t_star_max < 2.0 {
+        250.0 * (3.0 - t_star_max)
+    } else {
+        250.0
+    };
+
+    theta_max - rate * (t_star - t_star_max * x)
+}
+
+/// Factor `x` for the cooling phase: one for a ventilation controlled fire,
+/// `t_lim · Γ / t*_max` for a fuel controlled fire.
+pub fn cooling_phase_factor(t_max: f64, t_lim: f64, gamma: f64, t_star_max: f64) -> f64 {
+    if t_max > t_lim {
+        1.0
+    } else {
+        t_lim * gamma / t_star_max
+    }
+}
+
+#[cfg(not(coverage))]
+pub fn cooling_phase_temperature_equation(
+    theta_g: String,
+    theta_max: String,
+    t_star: String,
+    t_star_max: String,
+    x: String,
+) -> String {
+    format!(
+        "{} = \\begin{{cases}} {} - 625 \\left( {} - {} \\cdot {} \\right) & {} \\leq 0.5 \\\\ {} - 250 \\left( 3 - {} \\right) \\left( {} - {} \\cdot {} \\right) & 0.5 < {} < 2 \\\\ {} - 250 \\left( {} - {} \\cdot {} \\right) & {} \\geq 2 \\end{{cases}}",
+        theta_g,
+        theta_max,
+        t_star,
+        t_star_max,
+        x,
+        t_star_max,
+        theta_max,
+        t_star_max,
+        t_star,
+        t_star_max,
+        x,
+        t_star_max,
+        theta_max,
+        t_star,
+        t_star_max,
+        x,
+        t_star_max
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cooling_short_fire() {
+        let result = cooling_phase_temperature(800.0, 0.6, 0.4, 1.0);
+        assert!((result - 675.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cooling_medium_fire() {
+        let result = cooling_phase_temperature(1109.1197057406018, 4.0369, 3.0276, 1.0);
+        let expected = 1109.1197057406018 - 250.0 * (4.0369 - 3.0276);
+        assert!((result - expected).abs() < 1e-9);
+
+        let result = cooling_phase_temperature(900.0, 1.5, 1.0, 1.0);
+        assert!((result - 650.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cooling_phase_factor() {
+        assert_eq!(cooling_phase_factor(1.0, 1.0 / 3.0, 3.0, 3.0), 1.0);
+
+        let result = cooling_phase_factor(1.0 / 3.0, 1.0 / 3.0, 13.140625, 1.576875);
+        assert!((result - 2.7777777777777772).abs() < 1e-9);
+    }
+}
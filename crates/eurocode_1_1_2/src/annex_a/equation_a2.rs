@@ -0,0 +1,62 @@
+/// Fictitious time (h) for a time `t` (h).
+pub fn fictitious_time(t: f64, gamma: f64) -> f64 {
+    t * gamma
+}
+
+pub fn gamma(o: f64, b: f64) -> f64 {
+    (o / b).powi(2) / (0.04_f64 / 1160.0).powi(2)
+}
+
+/// Opening factor (m^1/2) for vertical openings of total area `a_v` (m²)
+/// and weighted average height `h_eq` (m) in an enclosure of total area
+/// `a_t` (m²).
+pub fn opening_factor(a_v: f64, h_eq: f64, a_t: f64) -> f64 {
+    a_v * h_eq.sqrt() / a_t
+}
+
+#[cfg(not(coverage))]
+pub fn fictitious_time_equation(t_star: String, t: String, gamma: String) -> String {
+    format!("{} = {} \\cdot {}", t_star, t, gamma)
+}
+
+#[cfg(not(coverage))]
+pub fn gamma_equation(gamma: String, o: String, b: String) -> String {
+    format!(
+        "{} = \\frac{{\\left[ {} / {} \\right]^2}}{{\\left( 0.04 / 1160 \\right)^2}}",
+        gamma, o, b
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn opening_factor_equation(o: String, a_v: String, h_eq: String, a_t: String) -> String {
+    format!("{} = \\frac{{{} \\sqrt{{{}}}}}{{{}}}", o, a_v, h_eq, a_t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fictitious_time() {
+        let result = fictitious_time(0.5, 3.0);
+        assert!((result - 1.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_gamma_reference_compartment() {
+        let result = gamma(0.04, 1160.0);
+        assert!((result - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_gamma() {
+        let result = gamma(0.06, 1000.0);
+        assert!((result - 3.0276).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_opening_factor() {
+        let result = opening_factor(3.0, 2.0, 100.0);
+        assert!((result - 0.042426406871192854).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,19 @@
+pub fn thermal_absorptivity(rho: f64, c: f64, lambda: f64) -> f64 {
+    (rho * c * lambda).sqrt()
+}
+
+#[cfg(not(coverage))]
+pub fn thermal_absorptivity_equation(b: String, rho: String, c: String, lambda: String) -> String {
+    format!("{} = \\sqrt{{{} \\cdot {} \\cdot {}}}", b, rho, c, lambda)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thermal_absorptivity() {
+        let result = thermal_absorptivity(2300.0, 1000.0, 1.6);
+        assert!((result - 1918.3326093250878).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,50 @@
+/// Thermal absorptivity (J/m²s^1/2K) of an enclosure bounded by surfaces of
+/// absorptivity `b` and area `a` (m²), excluding openings `a_v` (m²).
+/// Returns `None` unless there is one area per surface and the openings are
+/// smaller than the total area `a_t` (m²).
+pub fn thermal_absorptivity_enclosure(b: &[f64], a: &[f64], a_t: f64, a_v: f64) -> Option<f64> {
+    if b.len() != a.len() || a_t <= a_v {
+        return None;
+    }
+    let sum: f64 = b.iter().zip(a.iter()).map(|(b_j, a_j)| b_j * a_j).sum();
+
+    Some(sum / (a_t - a_v))
+}
+
+#[cfg(not(coverage))]
+pub fn thermal_absorptivity_enclosure_equation(
+    b: String,
+    b_j: String,
+    a_j: String,
+    a_t: String,
+    a_v: String,
+) -> String {
+    format!(
+        "{} = \\frac{{\\sum_j {} \\cdot {}}}{{{} - {}}}",
+        b, b_j, a_j, a_t, a_v
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thermal_absorptivity_enclosure() {
+        let result =
+            thermal_absorptivity_enclosure(&[1500.0, 500.0], &[60.0, 40.0], 110.0, 10.0).unwrap();
+        assert!((result - 1100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_invalid_surfaces() {
+        assert_eq!(
+            thermal_absorptivity_enclosure(&[1500.0, 500.0], &[60.0], 110.0, 10.0),
+            None
+        );
+        assert_eq!(
+            thermal_absorptivity_enclosure(&[1500.0], &[60.0], 10.0, 10.0),
+            None
+        );
+    }
+}
@@ -0,0 +1,72 @@
+/// Limiting thickness (m) of the exposed layer for a fire of duration
+/// `t_max` (h).
+pub fn limiting_thickness(t_max: f64, lambda: f64, c: f64, rho: f64) -> f64 {
+    (3600.0 * t_max * lambda / (c * rho)).sqrt()
+}
+
+/// Thermal absorptivity of a surface with an exposed layer (thickness `s_1`,
+/// absorptivity `b_1`) in front of a second layer of absorptivity `b_2`.
+pub fn thermal_absorptivity_layered(b_1: f64, b_2: f64, s_1: f64, s_lim: f64) -> f64 {
+    if b_1 < b_2 || s_1 > s_lim {
+        b_1
+    } else {
+        (s_1 / s_lim) * b_1 + (1.0 - s_1 / s_lim) * b_2
+    }
+}
+
+#[cfg(not(coverage))]
+pub fn limiting_thickness_equation(
+    s_lim: String,
+    t_max: String,
+    lambda: String,
+    c: String,
+    rho: String,
+) -> String {
+    format!(
+        "{} = \\sqrt{{\\frac{{3600 \\cdot {} \\cdot {}}}{{{} \\cdot {}}}}}",
+        s_lim, t_max, lambda, c, rho
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn thermal_absorptivity_layered_equation(
+    b: String,
+    b_1: String,
+    b_2: String,
+    s_1: String,
+    s_lim: String,
+) -> String {
+    format!(
+        "{} = \\frac{{{}}}{{{}}} \\cdot {} + \\left(1 - \\frac{{{}}}{{{}}}\\right) \\cdot {}",
+        b, s_1, s_lim, b_1, s_1, s_lim, b_2
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_limiting_thickness() {
+        let result = limiting_thickness(0.5, 0.2, 1000.0, 900.0);
+        assert!((result - 0.02).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_layered_lower_absorptivity_exposed() {
+        let result = thermal_absorptivity_layered(400.0, 1500.0, 0.0125, 0.02);
+        assert_eq!(result, 400.0);
+    }
+
+    #[test]
+    fn test_layered_thick_exposed_layer() {
+        let result = thermal_absorptivity_layered(1500.0, 400.0, 0.03, 0.02);
+        assert_eq!(result, 1500.0);
+    }
+
+    #[test]
+    fn test_layered_thin_exposed_layer() {
+        let result = thermal_absorptivity_layered(1500.0, 400.0, 0.01, 0.02);
+        assert!((result - 950.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,19 @@
+pub fn fictitious_time_max(t_max: f64, gamma: f64) -> f64 {
+    t_max * gamma
+}
+
+#[cfg(not(coverage))]
+pub fn fictitious_time_max_equation(t_star_max: String, t_max: String, gamma: String) -> String {
+    format!("{} = {} \\cdot {}", t_star_max, t_max, gamma)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fictitious_time_max() {
+        let result = fictitious_time_max(1.0, 3.0276);
+        assert!((result - 3.0276).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,82 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FireGrowthRate {
+    Slow,
+    Medium,
+    Fast,
+}
+
+impl FireGrowthRate {
+    /// Time (h) for maximum gas temperature in a fuel controlled fire.
+    pub fn t_lim(&self) -> f64 {
+        match self {
+            FireGrowthRate::Slow => 25.0 / 60.0,
+            FireGrowthRate::Medium => 20.0 / 60.0,
+            FireGrowthRate::Fast => 15.0 / 60.0,
+        }
+    }
+}
+
+/// Time (h) for maximum gas temperature for a design fire load density
+/// `q_td` (MJ/m²) related to the total enclosure area.
+pub fn time_max_temperature(q_td: f64, o: f64, t_lim: f64) -> f64 {
+    (0.2e-3 * q_td / o).max(t_lim)
+}
+
+/// Design fire load density (MJ/m²) related to the total enclosure area.
+pub fn fire_load_density_total_area(q_fd: f64, a_f: f64, a_t: f64) -> f64 {
+    q_fd * a_f / a_t
+}
+
+#[cfg(not(coverage))]
+pub fn time_max_temperature_equation(
+    t_max: String,
+    q_td: String,
+    o: String,
+    t_lim: String,
+) -> String {
+    format!(
+        "{} = \\max \\left( 0.2 \\cdot 10^{{-3}} \\cdot \\frac{{{}}}{{{}}}, {} \\right)",
+        t_max, q_td, o, t_lim
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn fire_load_density_total_area_equation(
+    q_td: String,
+    q_fd: String,
+    a_f: String,
+    a_t: String,
+) -> String {
+    format!("{} = {} \\cdot \\frac{{{}}}{{{}}}", q_td, q_fd, a_f, a_t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_t_lim() {
+        assert!((FireGrowthRate::Slow.t_lim() - 25.0 / 60.0).abs() < 1e-12);
+        assert!((FireGrowthRate::Medium.t_lim() - 20.0 / 60.0).abs() < 1e-12);
+        assert!((FireGrowthRate::Fast.t_lim() - 0.25).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_time_max_temperature_ventilation_controlled() {
+        let result = time_max_temperature(300.0, 0.06, FireGrowthRate::Medium.t_lim());
+        assert!((result - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_time_max_temperature_fuel_controlled() {
+        let t_lim = FireGrowthRate::Medium.t_lim();
+        let result = time_max_temperature(60.0, 0.1, t_lim);
+        assert_eq!(result, t_lim);
+    }
+
+    #[test]
+    fn test_fire_load_density_total_area() {
+        let result = fire_load_density_total_area(600.0, 50.0, 200.0);
+        assert!((result - 150.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,24 @@
+/// Fictitious time (h) in the heating phase of a fuel controlled fire.
+pub fn fictitious_time_fuel_controlled(t: f64, gamma_lim: f64) -> f64 {
+    t * gamma_lim
+}
+
+#[cfg(not(coverage))]
+pub fn fictitious_time_fuel_controlled_equation(
+    t_star: String,
+    t: String,
+    gamma_lim: String,
+) -> String {
+    format!("{} = {} \\cdot {}", t_star, t, gamma_lim)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fictitious_time_fuel_controlled() {
+        let result = fictitious_time_fuel_controlled(0.25, 0.4);
+        assert!((result - 0.1).abs() < 1e-12);
+    }
+}
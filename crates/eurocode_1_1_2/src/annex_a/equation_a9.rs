@@ -0,0 +1,42 @@
+use super::equation_a2;
+
+pub fn opening_factor_lim(q_td: f64, t_lim: f64) -> f64 {
+    0.1e-3 * q_td / t_lim
+}
+
+pub fn gamma_lim(o_lim: f64, b: f64) -> f64 {
+    equation_a2::gamma(o_lim, b)
+}
+
+#[cfg(not(coverage))]
+pub fn opening_factor_lim_equation(o_lim: String, q_td: String, t_lim: String) -> String {
+    format!(
+        "{} = 0.1 \\cdot 10^{{-3}} \\cdot \\frac{{{}}}{{{}}}",
+        o_lim, q_td, t_lim
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn gamma_lim_equation(gamma_lim: String, o_lim: String, b: String) -> String {
+    format!(
+        "{} = \\frac{{\\left[ {} / {} \\right]^2}}{{\\left( 0.04 / 1160 \\right)^2}}",
+        gamma_lim, o_lim, b
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_opening_factor_lim() {
+        let result = opening_factor_lim(60.0, 20.0 / 60.0);
+        assert!((result - 0.018).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_gamma_lim() {
+        let result = gamma_lim(0.04, 1160.0);
+        assert!((result - 1.0).abs() < 1e-12);
+    }
+}
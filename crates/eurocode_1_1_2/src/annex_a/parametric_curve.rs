@@ -0,0 +1,173 @@
+//! Parametric temperature-time curve combining the heating and cooling
+//! phases of this annex. Times are in minutes and temperatures in °C, as for
+//! the nominal curves in section 3.
+
+use super::equation_a7::FireGrowthRate;
+use super::{
+    equation_a1, equation_a2, equation_a6, equation_a7, equation_a9, equation_a10, equation_a11,
+};
+
+/// Range of the opening factor (m^1/2) for which the curves are valid.
+pub const OPENING_FACTOR_LIMITS: (f64, f64) = (0.02, 0.20);
+/// Range of the thermal absorptivity (J/m²s^1/2K) for which the curves are
+/// valid.
+pub const ABSORPTIVITY_LIMITS: (f64, f64) = (100.0, 2200.0);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParametricFire {
+    pub opening_factor: f64,
+    pub thermal_absorptivity: f64,
+    pub fire_load_density: f64,
+    pub growth_rate: FireGrowthRate,
+    pub gamma: f64,
+    pub gamma_lim: f64,
+    pub t_max: f64,
+    pub fuel_controlled: bool,
+}
+
+impl ParametricFire {
+    /// Builds the curve for an opening factor `o` (m^1/2), thermal
+    /// absorptivity `b` (J/m²s^1/2K) and design fire load density `q_td`
+    /// (MJ/m²) related to the total enclosure area.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `o` or `b` is not positive.
+    pub fn new(o: f64, b: f64, q_td: f64, growth_rate: FireGrowthRate) -> Self {
+        assert!(
+            o > 0.0 && b > 0.0,
+            "the opening factor and thermal absorptivity must be positive"
+        );
+        let t_lim = growth_rate.t_lim();
+        let t_max = equation_a7::time_max_temperature(q_td, o, t_lim);
+        let o_lim = equation_a9::opening_factor_lim(q_td, t_lim);
+        let k = equation_a10::gamma_lim_factor(o, q_td, b);
+
+        ParametricFire {
+            opening_factor: o,
+            thermal_absorptivity: b,
+            fire_load_density: q_td,
+            growth_rate,
+            gamma: equation_a2::gamma(o, b),
+            gamma_lim: k * equation_a9::gamma_lim(o_lim, b),
+            t_max,
+            fuel_controlled: t_lim >= t_max,
+        }
+    }
+
+    /// Whether the opening factor and thermal absorptivity are within the
+    /// limits of A(7), outside which the curves are extrapolated.
+    pub fn within_limits(&self) -> bool {
+        let (o_min, o_max) = OPENING_FACTOR_LIMITS;
+        let (b_min, b_max) = ABSORPTIVITY_LIMITS;
+        (o_min..=o_max).contains(&self.opening_factor)
+            && (b_min..=b_max).contains(&self.thermal_absorptivity)
+    }
+
+    /// Gamma used for the heating phase.
+    pub fn heating_gamma(&self) -> f64 {
+        if self.fuel_controlled {
+            self.gamma_lim
+        } else {
+            self.gamma
+        }
+    }
+
+    /// Maximum gas temperature (°C).
+    pub fn maximum_temperature(&self) -> f64 {
+        equation_a1::heating_phase_temperature(equation_a6::fictitious_time_max(
+            self.t_max,
+            self.heating_gamma(),
+        ))
+    }
+
+    /// Gas temperature (°C) at time `t` (min).
+    pub fn temperature(&self, t: f64) -> f64 {
+        let t = t / 60.0;
+
+        if t <= self.t_max {
+            return equation_a1::heating_phase_temperature(equation_a2::fictitious_time(
+                t,
+                self.heating_gamma(),
+            ));
+        }
+
+        let t_lim = self.growth_rate.t_lim();
+        let t_star_max = equation_a6::fictitious_time_max(
+            0.2e-3 * self.fire_load_density / self.opening_factor,
+            self.gamma,
+        );
+        let x = equation_a11::cooling_phase_factor(self.t_max, t_lim, self.gamma, t_star_max);
+
+        equation_a11::cooling_phase_temperature(
+            self.maximum_temperature(),
+            equation_a2::fictitious_time(t, self.gamma),
+            t_star_max,
+            x,
+        )
+        .max(20.0)
+    }
+
+    pub fn temperature_history(&self, times: &[f64]) -> Vec<f64> {
+        times.iter().map(|&t| self.temperature(t)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ventilation_controlled() {
+        let fire = ParametricFire::new(0.06, 1000.0, 300.0, FireGrowthRate::Medium);
+        assert!(!fire.fuel_controlled);
+        assert!((fire.gamma - 3.0276).abs() < 1e-9);
+        assert!((fire.t_max - 1.0).abs() < 1e-12);
+        assert!((fire.maximum_temperature() - 1109.1197057406018).abs() < 1e-9);
+        assert!((fire.temperature(30.0) - 1007.2271967866603).abs() < 1e-9);
+        assert!((fire.temperature(80.0) - 856.8197057406022).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_fuel_controlled() {
+        let fire = ParametricFire::new(0.1, 800.0, 60.0, FireGrowthRate::Medium);
+        assert!(fire.fuel_controlled);
+        assert!((fire.gamma - 13.140624999999998).abs() < 1e-9);
+        assert!((fire.gamma_lim - 0.38611687499999997).abs() < 1e-9);
+        assert!((fire.maximum_temperature() - 655.2118009416021).abs() < 1e-9);
+        assert!((fire.temperature(10.0) - 494.7621116651838).abs() < 1e-9);
+        assert_eq!(fire.temperature(40.0), 20.0);
+    }
+
+    #[test]
+    fn test_high_absorptivity() {
+        let fire = ParametricFire::new(0.1, 1500.0, 200.0, FireGrowthRate::Medium);
+        assert!(!fire.fuel_controlled);
+        assert!((fire.gamma_lim - 1.3456000000000001).abs() < 1e-9);
+        assert!((fire.maximum_temperature() - 1005.3740047158278).abs() < 1e-9);
+        assert!((fire.temperature(25.0) - 981.9367536870211).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_temperature_history() {
+        let fire = ParametricFire::new(0.06, 1000.0, 300.0, FireGrowthRate::Medium);
+        let result = fire.temperature_history(&[0.0, 30.0, 300.0]);
+        assert!((result[0] - 20.0).abs() < 1e-9);
+        assert!((result[1] - 1007.2271967866603).abs() < 1e-9);
+        assert_eq!(result[2], 20.0);
+    }
+
+    #[test]
+    fn test_within_limits() {
+        let fire = ParametricFire::new(0.06, 1000.0, 300.0, FireGrowthRate::Medium);
+        assert!(fire.within_limits());
+        assert!(!ParametricFire::new(0.3, 1000.0, 300.0, FireGrowthRate::Medium).within_limits());
+        assert!(!ParametricFire::new(0.06, 50.0, 300.0, FireGrowthRate::Medium).within_limits());
+    }
+
+    #[test]
+    #[should_panic(expected = "the opening factor and thermal absorptivity must be positive")]
+    fn test_zero_opening_factor() {
+        ParametricFire::new(0.0, 1000.0, 300.0, FireGrowthRate::Medium);
+    }
+}
@@ -0,0 +1,6 @@
+pub mod compartment_temperature;
+pub mod external_flame;
+pub mod flame_emissivity;
+pub mod forced_draught;
+pub mod no_forced_draught;
+pub mod rate_of_burning;
@@ -0,0 +1,65 @@
+//! Temperature (K) of the fire compartment behind the opening.
+
+pub fn fire_load_ratio(a_f: f64, q_fd: f64, a_v: f64, a_t: f64) -> f64 {
+    a_f * q_fd / (a_v * a_t).sqrt()
+}
+
+/// Compartment temperature for an opening factor `o` (m^1/2), fire load
+/// ratio `omega` and ambient temperature `t_0` (K).
+pub fn compartment_temperature(o: f64, omega: f64, t_0: f64) -> f64 {
+    6000.0 * (1.0 - (-0.1 / o).exp()) * o.sqrt() * (1.0 - (-0.00286 * omega).exp()) + t_0
+}
+
+/// Internal gas density (kg/m³) at the compartment temperature `t_f` (K).
+pub fn gas_density(t_f: f64) -> f64 {
+    353.0 / t_f
+}
+
+#[cfg(not(coverage))]
+pub fn fire_load_ratio_equation(
+    omega: String,
+    a_f: String,
+    q_fd: String,
+    a_v: String,
+    a_t: String,
+) -> String {
+    format!(
+        "{} = \\frac{{{} \\cdot {}}}{{\\sqrt{{{} \\cdot {}}}}}",
+        omega, a_f, q_fd, a_v, a_t
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn compartment_temperature_equation(
+    t_f: String,
+    o: String,
+    omega: String,
+    t_0: String,
+) -> String {
+    format!(
+        "{} = 6000 \\left( 1 - e^{{-0.1 / {}}} \\right) {}^{{1/2}} \\left( 1 - e^{{-0.00286 \\cdot {}}} \\right) + {}",
+        t_f, o, o, omega, t_0
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fire_load_ratio() {
+        let result = fire_load_ratio(100.0, 500.0, 6.0, 400.0);
+        assert!((result - 1020.6207261596576).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_compartment_temperature() {
+        let result = compartment_temperature(0.021213203435596427, 1020.6207261596576, 293.0);
+        assert!((result - 1112.290774787864).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_gas_density() {
+        assert!((gas_density(1000.0) - 0.353).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,274 @@
+//! External flame from a window of a fire compartment, for assessing
+//! members outside the building. The flame temperature along the axis is
+//! converted to °C to give the net radiative heat flux of section 3.
+
+use super::{
+    compartment_temperature, flame_emissivity, forced_draught, no_forced_draught, rate_of_burning,
+};
+use crate::annex_a::equation_a2;
+use crate::section_3::equation_3_3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Compartment {
+    pub a_f: f64,
+    pub a_t: f64,
+    pub q_fd: f64,
+    pub w_t: f64,
+    pub h_eq: f64,
+    pub d_over_w: f64,
+    pub t_0: f64,
+}
+
+impl Compartment {
+    /// Free burning fire duration (s).
+    pub fn tau_f(&self) -> f64 {
+        1200.0
+    }
+
+    /// Window area (m²).
+    pub fn a_v(&self) -> f64 {
+        self.w_t * self.h_eq
+    }
+
+    pub fn opening_factor(&self) -> f64 {
+        equation_a2::opening_factor(self.a_v(), self.h_eq, self.a_t)
+    }
+
+    /// Compartment temperature (K).
+    pub fn temperature(&self) -> f64 {
+        compartment_temperature::compartment_temperature(
+            self.opening_factor(),
+            compartment_temperature::fire_load_ratio(self.a_f, self.q_fd, self.a_v(), self.a_t),
+            self.t_0,
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Draught {
+    NoForced,
+    Forced { u: f64 },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExternalFlame {
+    pub compartment: Compartment,
+    pub draught: Draught,
+    pub rate_of_burning: f64,
+    pub height: f64,
+    pub width: f64,
+    pub projection: f64,
+    pub length: f64,
+    pub thickness: f64,
+    pub emissivity: f64,
+    pub window_temperature: f64,
+}
+
+impl ExternalFlame {
+    /// Flame without forced draught. `wall_above` is true where there is a
+    /// wall above the window and `isolated` where the distance to other
+    /// windows is more than four times the window width. Returns `None` if
+    /// the flame is too long for the rate of burning.
+    pub fn no_forced_draught(
+        compartment: Compartment,
+        wall_above: bool,
+        isolated: bool,
+    ) -> Option<Self> {
+        let c = compartment;
+        let q = rate_of_burning::no_forced_draught(
+            rate_of_burning::fuel_controlled(c.a_f, c.q_fd, c.tau_f()),
+            rate_of_burning::ventilation_controlled(
+                c.opening_factor(),
+                c.a_v(),
+                c.h_eq,
+                c.d_over_w,
+            ),
+        );
+        let rho_g = compartment_temperature::gas_density(c.temperature());
+        let height = no_forced_draught::flame_height(q, c.a_v(), rho_g, c.h_eq, 9.81);
+        let projection = no_forced_draught::horizontal_projection(c.h_eq, c.w_t, isolated);
+        let length = no_forced_draught::flame_length(height, projection, c.h_eq, c.w_t, wall_above);
+        let thickness = no_forced_draught::flame_thickness(c.h_eq);
+        let window_temperature = no_forced_draught::window_temperature(length, c.w_t, q, c.t_0)?;
+
+        Some(ExternalFlame {
+            compartment,
+            draught: Draught::NoForced,
+            rate_of_burning: q,
+            height,
+            width: c.w_t,
+            projection,
+            length,
+            thickness,
+            emissivity: flame_emissivity::flame_emissivity(thickness),
+            window_temperature,
+        })
+    }
+
+    /// Flame with forced draught from a wind speed `u` (m/s). Returns `None`
+    /// if the flame is too long for the rate of burning.
+    pub fn forced_draught(compartment: Compartment, u: f64) -> Option<Self> {
+        let c = compartment;
+        let q = rate_of_burning::fuel_controlled(c.a_f, c.q_fd, c.tau_f());
+        let height = forced_draught::flame_height(u, q, c.a_v(), c.h_eq);
+        let projection = forced_draught::horizontal_projection(u, c.h_eq, height);
+        let length = forced_draught::flame_length(height, projection);
+        let thickness = forced_draught::flame_thickness(c.h_eq);
+        let window_temperature = forced_draught::window_temperature(length, c.a_v(), q, c.t_0)?;
+
+        Some(ExternalFlame {
+            compartment,
+            draught: Draught::Forced { u },
+            rate_of_burning: q,
+            height: height.max(0.0),
+            width: forced_draught::flame_width(c.w_t, projection),
+            projection,
+            length,
+            thickness,
+            emissivity: flame_emissivity::flame_emissivity(thickness),
+            window_temperature,
+        })
+    }
+
+    /// Flame temperature (K) at distance `l_x` (m) along the axis. Returns
+    /// `None` past the validity limit of the axis temperature.
+    pub fn axis_temperature(&self, l_x: f64) -> Option<f64> {
+        let c = self.compartment;
+        match self.draught {
+            Draught::NoForced => no_forced_draught::axis_temperature(
+                self.window_temperature,
+                l_x,
+                c.w_t,
+                self.rate_of_burning,
+                c.t_0,
+            ),
+            Draught::Forced { .. } => forced_draught::axis_temperature(
+                self.window_temperature,
+                l_x,
+                c.a_v(),
+                self.rate_of_burning,
+                c.t_0,
+            ),
+        }
+    }
+
+    /// Convective heat transfer coefficient (W/m²K) for a member of
+    /// characteristic size `d_eq` (m).
+    pub fn convective_coefficient(&self, d_eq: f64) -> f64 {
+        let a_v = self.compartment.a_v();
+        match self.draught {
+            Draught::NoForced => {
+                no_forced_draught::convective_coefficient(d_eq, self.rate_of_burning, a_v)
+            }
+            Draught::Forced { u } => {
+                forced_draught::convective_coefficient(d_eq, self.rate_of_burning, a_v, u)
+            }
+        }
+    }
+
+    /// Net radiative heat flux (W/m²) to a member with surface temperature
+    /// `theta_m` (°C), opposite the flame at distance `l_x` (m) along the
+    /// axis. Returns `None` past the validity limit of the axis temperature.
+    pub fn net_radiative_heat_flux(
+        &self,
+        phi: f64,
+        epsilon_m: f64,
+        sigma: f64,
+        theta_m: f64,
+        l_x: f64,
+    ) -> Option<f64> {
+        Some(equation_3_3::net_radiative_heat_flux_surface(
+            phi,
+            epsilon_m,
+            self.emissivity,
+            sigma,
+            self.axis_temperature(l_x)? - 273.0,
+            theta_m,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compartment() -> Compartment {
+        Compartment {
+            a_f: 200.0,
+            a_t: 600.0,
+            q_fd: 400.0,
+            w_t: 10.0,
+            h_eq: 2.5,
+            d_over_w: 1.0,
+            t_0: 293.0,
+        }
+    }
+
+    #[test]
+    fn test_compartment() {
+        let c = compartment();
+        assert!((c.opening_factor() - 0.06588078458684124).abs() < 1e-12);
+        assert!((c.temperature() - 1309.8227147967186).abs() < 1e-9);
+    }
+
+    fn no_forced_compartment() -> Compartment {
+        Compartment {
+            q_fd: 600.0,
+            w_t: 3.0,
+            ..compartment()
+        }
+    }
+
+    #[test]
+    fn test_no_forced_draught() {
+        let flame = ExternalFlame::no_forced_draught(no_forced_compartment(), true, true).unwrap();
+        assert!((flame.rate_of_burning - 31.31093149594085).abs() < 1e-9);
+        assert!((flame.height - 8.958229457677408).abs() < 1e-9);
+        assert!((flame.length - 10.208229457677408).abs() < 1e-9);
+        assert!((flame.emissivity - 0.3934693402873666).abs() < 1e-12);
+        assert!((flame.window_temperature - 1259.8018750550389).abs() < 1e-9);
+        assert!((flame.axis_temperature(1.0).unwrap() - 1216.0330827382668).abs() < 1e-9);
+        assert_eq!(flame.axis_temperature(10.5), None);
+    }
+
+    #[test]
+    fn test_no_forced_draught_long_flame() {
+        assert_eq!(
+            ExternalFlame::no_forced_draught(compartment(), true, true),
+            None
+        );
+    }
+
+    #[test]
+    fn test_no_forced_draught_radiative_heat_flux() {
+        let flame = ExternalFlame::no_forced_draught(no_forced_compartment(), true, true).unwrap();
+        let result = flame
+            .net_radiative_heat_flux(1.0, 0.7, 5.67e-8, 20.0, 1.0)
+            .unwrap();
+        assert!((result - 34033.55480338944).abs() < 1e-6);
+        assert_eq!(
+            flame.net_radiative_heat_flux(1.0, 0.7, 5.67e-8, 20.0, 10.5),
+            None
+        );
+    }
+
+    #[test]
+    fn test_forced_draught() {
+        let c = Compartment {
+            a_f: 100.0,
+            q_fd: 500.0,
+            w_t: 3.0,
+            h_eq: 2.0,
+            a_t: 400.0,
+            ..compartment()
+        };
+        let flame = ExternalFlame::forced_draught(c, 6.0).unwrap();
+        assert!((flame.rate_of_burning - 41.666666666666664).abs() < 1e-12);
+        assert!((flame.width - 7.915119847411901).abs() < 1e-9);
+        assert!((flame.length - 15.087003142983).abs() < 1e-9);
+        assert!((flame.window_temperature - 1030.4888209696953).abs() < 1e-9);
+        assert!((flame.axis_temperature(1.0).unwrap() - 1016.0731799766827).abs() < 1e-9);
+        assert_eq!(flame.axis_temperature(20.0), None);
+        assert!((flame.convective_coefficient(2.0) - 17.4358661828558).abs() < 1e-9);
+    }
+}
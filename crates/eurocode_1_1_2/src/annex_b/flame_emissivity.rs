@@ -0,0 +1,20 @@
+/// Emissivity of the flame for a flame thickness `d_f` (m).
+pub fn flame_emissivity(d_f: f64) -> f64 {
+    1.0 - (-0.3 * d_f).exp()
+}
+
+#[cfg(not(coverage))]
+pub fn flame_emissivity_equation(epsilon_f: String, d_f: String) -> String {
+    format!("{} = 1 - e^{{-0.3 \\cdot {}}}", epsilon_f, d_f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flame_emissivity() {
+        let result = flame_emissivity(2.0);
+        assert!((result - 0.4511883639059736).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,139 @@
+//! Flame geometry and temperature for an opening with forced draught, for
+//! a wind speed `u` (m/s). Rates of burning are in MW and temperatures in K.
+
+/// Flame height above the top of the window (m).
+pub fn flame_height(u: f64, q: f64, a_v: f64, h_eq: f64) -> f64 {
+    1.366 * (1.0 / u).powf(0.43) * q / a_v.sqrt() - h_eq
+}
+
+/// Horizontal projection of the flame (m) from the façade.
+pub fn horizontal_projection(u: f64, h_eq: f64, l_l: f64) -> f64 {
+    0.605 * (u.powi(2) / h_eq).powf(0.22) * (l_l + h_eq)
+}
+
+pub fn flame_width(w_t: f64, l_h: f64) -> f64 {
+    w_t + 0.4 * l_h
+}
+
+/// Flame length along the axis (m).
+pub fn flame_length(l_l: f64, l_h: f64) -> f64 {
+    if l_l > 0.0 {
+        (l_l.powi(2) + l_h.powi(2)).sqrt()
+    } else {
+        l_h
+    }
+}
+
+pub fn flame_thickness(h_eq: f64) -> f64 {
+    h_eq
+}
+
+/// Flame temperature at the window. Returns `None` outside the validity
+/// limit `L_f √A_v / Q < 1`.
+pub fn window_temperature(l_f: f64, a_v: f64, q: f64, t_0: f64) -> Option<f64> {
+    let ratio = l_f * a_v.sqrt() / q;
+    (ratio < 1.0).then(|| 520.0 / (1.0 - 0.3325 * ratio) + t_0)
+}
+
+/// Flame temperature at distance `l_x` (m) along the flame axis from the
+/// window. Returns `None` outside the validity limit `L_x √A_v / Q < 1`.
+pub fn axis_temperature(t_w: f64, l_x: f64, a_v: f64, q: f64, t_0: f64) -> Option<f64> {
+    let ratio = l_x * a_v.sqrt() / q;
+    (ratio < 1.0).then_some((t_w - t_0) * (1.0 - 0.3325 * ratio) + t_0)
+}
+
+/// Convective heat transfer coefficient (W/m²K) for a member of
+/// characteristic size `d_eq` (m).
+pub fn convective_coefficient(d_eq: f64, q: f64, a_v: f64, u: f64) -> f64 {
+    9.8 * (1.0 / d_eq).powf(0.4) * (q / (17.5 * a_v) + u / 1.6).powf(0.6)
+}
+
+#[cfg(not(coverage))]
+pub fn flame_height_equation(
+    l_l: String,
+    u: String,
+    q: String,
+    a_v: String,
+    h_eq: String,
+) -> String {
+    format!(
+        "{} = 1.366 \\left( \\frac{{1}}{{{}}} \\right)^{{0.43}} \\frac{{{}}}{{\\sqrt{{{}}}}} - {}",
+        l_l, u, q, a_v, h_eq
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn horizontal_projection_equation(l_h: String, u: String, h_eq: String, l_l: String) -> String {
+    format!(
+        "{} = 0.605 \\left( \\frac{{{}^2}}{{{}}} \\right)^{{0.22}} \\left( {} + {} \\right)",
+        l_h, u, h_eq, l_l, h_eq
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn window_temperature_equation(
+    t_w: String,
+    l_f: String,
+    a_v: String,
+    q: String,
+    t_0: String,
+) -> String {
+    format!(
+        "{} = \\frac{{520}}{{1 - 0.3325 \\cdot {} \\sqrt{{{}}} / {}}} + {}",
+        t_w, l_f, a_v, q, t_0
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn convective_coefficient_equation(
+    alpha_c: String,
+    d_eq: String,
+    q: String,
+    a_v: String,
+    u: String,
+) -> String {
+    format!(
+        "{} = 9.8 \\left( 1 / {} \\right)^{{0.4}} \\left( \\frac{{{}}}{{17.5 \\cdot {}}} + \\frac{{{}}}{{1.6}} \\right)^{{0.6}}",
+        alpha_c, d_eq, q, a_v, u
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const Q: f64 = 41.666666666666664;
+
+    #[test]
+    fn test_flame_geometry() {
+        let l_l = flame_height(6.0, Q, 6.0, 2.0);
+        let l_h = horizontal_projection(6.0, 2.0, l_l);
+        assert!((l_l - 8.753721743992038).abs() < 1e-9);
+        assert!((l_h - 12.287799618529753).abs() < 1e-9);
+        assert!((flame_width(3.0, l_h) - 7.915119847411901).abs() < 1e-9);
+        assert!((flame_length(l_l, l_h) - 15.087003142983).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_flame_length_below_window() {
+        assert_eq!(flame_length(-0.5, 1.2), 1.2);
+    }
+
+    #[test]
+    fn test_temperatures() {
+        let t_w = window_temperature(15.087003142983, 6.0, Q, 293.0).unwrap();
+        assert!((t_w - 1030.4888209696953).abs() < 1e-9);
+        assert_eq!(window_temperature(60.0, 6.0, Q, 293.0), None);
+        assert_eq!(window_temperature(20.0, 6.0, Q, 293.0), None);
+
+        let t_z = axis_temperature(t_w, 1.0, 6.0, Q, 293.0).unwrap();
+        assert!((t_z - 1016.0731799766827).abs() < 1e-9);
+        assert_eq!(axis_temperature(t_w, 20.0, 6.0, Q, 293.0), None);
+    }
+
+    #[test]
+    fn test_convective_coefficient() {
+        let result = convective_coefficient(2.0, Q, 6.0, 6.0);
+        assert!((result - 17.4358661828558).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,171 @@
+//! Flame geometry and temperature for an opening without forced draught.
+//! Rates of burning are in MW and temperatures in K.
+
+/// Flame height above the top of the window (m) for a rate of burning `q`,
+/// window area `a_v` (m²), internal gas density `rho_g` (kg/m³) and window
+/// height `h_eq` (m).
+pub fn flame_height(q: f64, a_v: f64, rho_g: f64, h_eq: f64, g: f64) -> f64 {
+    (h_eq * (2.37 * (q / (a_v * rho_g * (h_eq * g).sqrt())).powf(2.0 / 3.0) - 1.0)).max(0.0)
+}
+
+/// Horizontal projection of the flame (m) from the façade. `isolated` is
+/// true where the distance to other windows is more than four times the
+/// window width `w_t` (m).
+pub fn horizontal_projection(h_eq: f64, w_t: f64, isolated: bool) -> f64 {
+    if h_eq <= 1.25 * w_t {
+        h_eq / 3.0
+    } else if isolated {
+        0.3 * h_eq * (h_eq / w_t).powf(0.54)
+    } else {
+        0.454 * h_eq * (h_eq / (2.0 * w_t)).powf(0.54)
+    }
+}
+
+/// Flame length along the axis (m) for a flame height `l_l` (m) and
+/// horizontal projection `l_h` (m).
+pub fn flame_length(l_l: f64, l_h: f64, h_eq: f64, w_t: f64, wall_above: bool) -> f64 {
+    if wall_above && h_eq <= 1.25 * w_t {
+        l_l + h_eq / 2.0
+    } else {
+        (l_l.powi(2) + (l_h - h_eq / 3.0).powi(2)).sqrt() + h_eq / 2.0
+    }
+}
+
+pub fn flame_thickness(h_eq: f64) -> f64 {
+    2.0 * h_eq / 3.0
+}
+
+/// Flame temperature at the window. Returns `None` outside the validity
+/// limit `L_f w_t / Q < 1`.
+pub fn window_temperature(l_f: f64, w_t: f64, q: f64, t_0: f64) -> Option<f64> {
+    let ratio = l_f * w_t / q;
+    (ratio < 1.0).then(|| 520.0 / (1.0 - 0.4725 * ratio) + t_0)
+}
+
+/// Flame temperature at distance `l_x` (m) along the flame axis from the
+/// window. Returns `None` outside the validity limit `L_x w_t / Q < 1`.
+pub fn axis_temperature(t_w: f64, l_x: f64, w_t: f64, q: f64, t_0: f64) -> Option<f64> {
+    let ratio = l_x * w_t / q;
+    (ratio < 1.0).then_some((t_w - t_0) * (1.0 - 0.4725 * ratio) + t_0)
+}
+
+/// Convective heat transfer coefficient (W/m²K) for a member of
+/// characteristic size `d_eq` (m).
+pub fn convective_coefficient(d_eq: f64, q: f64, a_v: f64) -> f64 {
+    4.67 * (1.0 / d_eq).powf(0.4) * (q / a_v).powf(0.6)
+}
+
+#[cfg(not(coverage))]
+pub fn flame_height_equation(
+    l_l: String,
+    q: String,
+    a_v: String,
+    rho_g: String,
+    h_eq: String,
+    g: String,
+) -> String {
+    format!(
+        "{} = \\max \\left[ 0; {} \\left( 2.37 \\left( \\frac{{{}}}{{{} \\cdot {} \\sqrt{{{} \\cdot {}}}}} \\right)^{{2/3}} - 1 \\right) \\right]",
+        l_l, h_eq, q, a_v, rho_g, h_eq, g
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn window_temperature_equation(
+    t_w: String,
+    l_f: String,
+    w_t: String,
+    q: String,
+    t_0: String,
+) -> String {
+    format!(
+        "{} = \\frac{{520}}{{1 - 0.4725 \\left( {} \\cdot {} / {} \\right)}} + {}",
+        t_w, l_f, w_t, q, t_0
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn axis_temperature_equation(
+    t_z: String,
+    t_w: String,
+    l_x: String,
+    w_t: String,
+    q: String,
+    t_0: String,
+) -> String {
+    format!(
+        "{} = \\left( {} - {} \\right) \\left( 1 - 0.4725 \\left( {} \\cdot {} / {} \\right) \\right) + {}",
+        t_z, t_w, t_0, l_x, w_t, q, t_0
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn convective_coefficient_equation(
+    alpha_c: String,
+    d_eq: String,
+    q: String,
+    a_v: String,
+) -> String {
+    format!(
+        "{} = 4.67 \\left( 1 / {} \\right)^{{0.4}} \\left( {} / {} \\right)^{{0.6}}",
+        alpha_c, d_eq, q, a_v
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flame_height() {
+        let result = flame_height(21.831359813028595, 6.0, 0.45, 2.0, 9.81);
+        assert!((result - 5.079838585240209).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_flame_height_below_window() {
+        assert_eq!(flame_height(0.1, 6.0, 0.45, 2.0, 9.81), 0.0);
+    }
+
+    #[test]
+    fn test_horizontal_projection() {
+        assert!((horizontal_projection(2.0, 3.0, true) - 2.0 / 3.0).abs() < 1e-12);
+        assert!((horizontal_projection(3.0, 2.0, true) - 1.120293430734709).abs() < 1e-12);
+        assert!((horizontal_projection(3.0, 2.0, false) - 1.1660312500058767).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_flame_length() {
+        let result = flame_length(5.079838585240209, 2.0 / 3.0, 2.0, 3.0, true);
+        assert!((result - 6.079838585240209).abs() < 1e-12);
+
+        let result = flame_length(4.0, 2.0, 3.0, 2.0, false);
+        assert!((result - (17.0_f64.sqrt() + 1.5)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_window_temperature() {
+        let result = window_temperature(6.079838585240209, 3.0, 21.831359813028595, 293.0);
+        assert!((result.unwrap() - 1152.1648413254893).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_window_temperature_long_flame() {
+        assert_eq!(window_temperature(40.0, 3.0, 21.0, 293.0), None);
+        assert_eq!(window_temperature(1.0, 1.0, 0.4, 293.0), None);
+        assert_eq!(window_temperature(8.0, 3.0, 21.0, 293.0), None);
+    }
+
+    #[test]
+    fn test_axis_temperature() {
+        let result = axis_temperature(1152.1648413254893, 1.0, 3.0, 21.831359813028595, 293.0);
+        assert!((result.unwrap() - 1096.379669306471).abs() < 1e-9);
+        assert_eq!(axis_temperature(1152.0, 8.0, 3.0, 21.8, 293.0), None);
+    }
+
+    #[test]
+    fn test_convective_coefficient() {
+        let result = convective_coefficient(2.0, 21.831359813028595, 6.0);
+        assert!((result - 7.681793078768578).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,62 @@
+//! Rate of burning (MW) of the fire compartment feeding the external flame.
+
+/// Fuel controlled rate of burning for a floor area `a_f` (m²), design fire
+/// load density `q_fd` (MJ/m²) and free burning fire duration `tau_f` (s).
+pub fn fuel_controlled(a_f: f64, q_fd: f64, tau_f: f64) -> f64 {
+    a_f * q_fd / tau_f
+}
+
+/// Ventilation controlled rate of burning for an opening factor `o`
+/// (m^1/2), window area `a_v` (m²), window height `h_eq` (m) and ratio of
+/// compartment depth to width `d_over_w`.
+pub fn ventilation_controlled(o: f64, a_v: f64, h_eq: f64, d_over_w: f64) -> f64 {
+    3.15 * (1.0 - (-0.036 / o).exp()) * a_v * (h_eq / d_over_w).sqrt()
+}
+
+/// Rate of burning without forced draught, the lower of the fuel and
+/// ventilation controlled rates.
+pub fn no_forced_draught(fuel_controlled: f64, ventilation_controlled: f64) -> f64 {
+    fuel_controlled.min(ventilation_controlled)
+}
+
+#[cfg(not(coverage))]
+pub fn fuel_controlled_equation(q: String, a_f: String, q_fd: String, tau_f: String) -> String {
+    format!("{} = \\frac{{{} \\cdot {}}}{{{}}}", q, a_f, q_fd, tau_f)
+}
+
+#[cfg(not(coverage))]
+pub fn ventilation_controlled_equation(
+    q: String,
+    o: String,
+    a_v: String,
+    h_eq: String,
+    d_over_w: String,
+) -> String {
+    format!(
+        "{} = 3.15 \\left( 1 - e^{{-0.036 / {}}} \\right) {} \\left( \\frac{{{}}}{{{}}} \\right)^{{1/2}}",
+        q, o, a_v, h_eq, d_over_w
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuel_controlled() {
+        let result = fuel_controlled(100.0, 500.0, 1200.0);
+        assert!((result - 41.666666666666664).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_ventilation_controlled() {
+        let result = ventilation_controlled(0.021213203435596427, 6.0, 2.0, 1.0);
+        assert!((result - 21.831359813028595).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_no_forced_draught() {
+        let result = no_forced_draught(41.666666666666664, 21.831359813028595);
+        assert_eq!(result, 21.831359813028595);
+    }
+}
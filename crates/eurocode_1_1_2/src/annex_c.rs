@@ -0,0 +1,10 @@
+pub mod equation_c1;
+pub mod equation_c2;
+pub mod equation_c3;
+pub mod equation_c4;
+pub mod equation_c5;
+pub mod equation_c6;
+pub mod equation_c7;
+pub mod equation_c8;
+pub mod equation_c9;
+pub mod localised_fire;
@@ -0,0 +1,21 @@
+/// Flame length (m) of a localised fire of diameter `d` (m) and rate of
+/// heat release `q` (W).
+pub fn flame_length(d: f64, q: f64) -> f64 {
+    -1.02 * d + 0.0148 * q.powf(0.4)
+}
+
+#[cfg(not(coverage))]
+pub fn flame_length_equation(l_f: String, d: String, q: String) -> String {
+    format!("{} = -1.02 \\cdot {} + 0.0148 \\cdot {}^{{0.4}}", l_f, d, q)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flame_length() {
+        let result = flame_length(2.0, 2.0e6);
+        assert!((result - 2.8653919456631822).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,46 @@
+/// Temperature (°C) in the plume along the vertical flame axis at height
+/// `z` (m), for the convective part of the rate of heat release `q_c` (W)
+/// and virtual origin `z_0` (m), limited to 900 °C.
+pub fn plume_temperature(q_c: f64, z: f64, z_0: f64) -> f64 {
+    (20.0 + 0.25 * q_c.powf(2.0 / 3.0) * (z - z_0).powf(-5.0 / 3.0)).min(900.0)
+}
+
+/// Convective part (W) of the rate of heat release `q` (W).
+pub fn convective_heat_release(q: f64) -> f64 {
+    0.8 * q
+}
+
+#[cfg(not(coverage))]
+pub fn plume_temperature_equation(theta_z: String, q_c: String, z: String, z_0: String) -> String {
+    format!(
+        "{} = 20 + 0.25 \\cdot {}^{{2/3}} \\cdot \\left( {} - {} \\right)^{{-5/3}} \\leq 900",
+        theta_z, q_c, z, z_0
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn convective_heat_release_equation(q_c: String, q: String) -> String {
+    format!("{} = 0.8 \\cdot {}", q_c, q)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plume_temperature() {
+        let result = plume_temperature(1.6e6, 5.0, -0.30322609491384656);
+        assert!((result - 232.05723390132715).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_plume_temperature_limited() {
+        let result = plume_temperature(1.6e6, 0.5, -0.30322609491384656);
+        assert_eq!(result, 900.0);
+    }
+
+    #[test]
+    fn test_convective_heat_release() {
+        assert!((convective_heat_release(2.0e6) - 1.6e6).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,23 @@
+/// Vertical position of the virtual origin (m).
+pub fn virtual_origin(d: f64, q: f64) -> f64 {
+    -1.02 * d + 0.00524 * q.powf(0.4)
+}
+
+#[cfg(not(coverage))]
+pub fn virtual_origin_equation(z_0: String, d: String, q: String) -> String {
+    format!(
+        "{} = -1.02 \\cdot {} + 0.00524 \\cdot {}^{{0.4}}",
+        z_0, d, q
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_virtual_origin() {
+        let result = virtual_origin(2.0, 2.0e6);
+        assert!((result - -0.30322609491384656).abs() < 1e-9);
+    }
+}
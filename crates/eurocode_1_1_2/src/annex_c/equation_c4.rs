@@ -0,0 +1,24 @@
+/// Horizontal flame length (m) along the ceiling for a distance `h` (m)
+/// between the fire source and the ceiling.
+pub fn horizontal_flame_length(h: f64, q_h_star: f64) -> f64 {
+    2.9 * h * q_h_star.powf(0.33) - h
+}
+
+#[cfg(not(coverage))]
+pub fn horizontal_flame_length_equation(l_h: String, h: String, q_h_star: String) -> String {
+    format!(
+        "{} = 2.9 \\cdot {} \\cdot {}^{{0.33}} - {}",
+        l_h, h, q_h_star, h
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_horizontal_flame_length() {
+        let result = horizontal_flame_length(4.0, 0.22522522522522523);
+        assert!((result - 3.0928636147367916).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,28 @@
+/// Non-dimensional rate of heat release related to the ceiling height
+/// `h` (m).
+pub fn nondimensional_heat_release_ceiling(q: f64, h: f64) -> f64 {
+    q / (1.11e6 * h.powf(2.5))
+}
+
+#[cfg(not(coverage))]
+pub fn nondimensional_heat_release_ceiling_equation(
+    q_h_star: String,
+    q: String,
+    h: String,
+) -> String {
+    format!(
+        "{} = \\frac{{{}}}{{1.11 \\cdot 10^6 \\cdot {}^{{2.5}}}}",
+        q_h_star, q, h
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nondimensional_heat_release_ceiling() {
+        let result = nondimensional_heat_release_ceiling(8.0e6, 4.0);
+        assert!((result - 0.22522522522522523).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,41 @@
+/// Heat flux (W/m²) received by the fire exposed surface at the ceiling
+/// level for the parameter `y`.
+pub fn heat_flux(y: f64) -> f64 {
+    if y <= 0.30 {
+        100000.0
+    } else if y < 1.0 {
+        136300.0 - 121000.0 * y
+    } else {
+        15000.0 * y.powf(-3.7)
+    }
+}
+
+#[cfg(not(coverage))]
+pub fn heat_flux_equation(h: String, y: String) -> String {
+    format!(
+        "{} = \\begin{{cases}} 100000 & {} \\leq 0.30 \\\\ 136300 - 121000 \\cdot {} & 0.30 < {} < 1.0 \\\\ 15000 \\cdot {}^{{-3.7}} & {} \\geq 1.0 \\end{{cases}}",
+        h, y, y, y, y, y
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_heat_flux_near_axis() {
+        assert_eq!(heat_flux(0.2), 100000.0);
+    }
+
+    #[test]
+    fn test_heat_flux_within_flame() {
+        let result = heat_flux(0.6170382481608995);
+        assert!((result - 61638.37197253117).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_heat_flux_beyond_flame() {
+        let result = heat_flux(1.2361437108369369);
+        assert!((result - 6845.987370698142).abs() < 1e-6);
+    }
+}
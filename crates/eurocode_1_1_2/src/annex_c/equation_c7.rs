@@ -0,0 +1,31 @@
+/// Parameter `y` for a point at horizontal distance `r` (m) from the flame
+/// axis, with ceiling height `h` (m), horizontal flame length `l_h` (m) and
+/// virtual origin `z_prime` (m).
+pub fn parameter_y(r: f64, h: f64, z_prime: f64, l_h: f64) -> f64 {
+    (r + h + z_prime) / (l_h + h + z_prime)
+}
+
+#[cfg(not(coverage))]
+pub fn parameter_y_equation(
+    y: String,
+    r: String,
+    h: String,
+    z_prime: String,
+    l_h: String,
+) -> String {
+    format!(
+        "{} = \\frac{{{} + {} + {}}}{{{} + {} + {}}}",
+        y, r, h, z_prime, l_h, h, z_prime
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parameter_y() {
+        let result = parameter_y(2.0, 4.0, 0.983304827369778, 3.0928636147367916);
+        assert!((result - 0.8646804332313145).abs() < 1e-12);
+    }
+}
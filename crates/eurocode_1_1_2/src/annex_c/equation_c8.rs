@@ -0,0 +1,57 @@
+use crate::section_3::{equation_3_2, equation_3_3};
+
+/// Net heat flux (W/m²) received by the fire exposed surface at the
+/// ceiling level, for a member surface temperature `theta_m` (°C).
+pub fn net_heat_flux(
+    h: f64,
+    alpha_c: f64,
+    theta_m: f64,
+    phi: f64,
+    epsilon_m: f64,
+    epsilon_f: f64,
+    sigma: f64,
+) -> f64 {
+    h + equation_3_2::net_convective_heat_flux_surface(alpha_c, 20.0, theta_m)
+        + equation_3_3::net_radiative_heat_flux_surface(
+            phi, epsilon_m, epsilon_f, sigma, 20.0, theta_m,
+        )
+}
+
+/// Total heat flux (W/m²) from several localised fires, limited to
+/// 100 kW/m².
+pub fn total_heat_flux(h: &[f64]) -> f64 {
+    h.iter().sum::<f64>().min(100000.0)
+}
+
+#[cfg(not(coverage))]
+pub fn net_heat_flux_equation(
+    h_net: String,
+    h: String,
+    alpha_c: String,
+    theta_m: String,
+    phi: String,
+    epsilon_m: String,
+    epsilon_f: String,
+) -> String {
+    format!(
+        "{} = {} - {} \\cdot \\left( {} - 20 \\right) - {} \\cdot {} \\cdot {} \\cdot \\sigma \\cdot \\left[ \\left( {} + 273 \\right)^4 - 293^4 \\right]",
+        h_net, h, alpha_c, theta_m, phi, epsilon_m, epsilon_f, theta_m
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_net_heat_flux() {
+        let result = net_heat_flux(50000.0, 25.0, 200.0, 1.0, 0.7, 1.0, 5.67e-8);
+        assert!((result - 43805.8476449384).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_total_heat_flux() {
+        assert!((total_heat_flux(&[30000.0, 20000.0]) - 50000.0).abs() < 1e-9);
+        assert_eq!(total_heat_flux(&[80000.0, 60000.0]), 100000.0);
+    }
+}
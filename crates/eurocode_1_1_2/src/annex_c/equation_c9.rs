@@ -0,0 +1,58 @@
+/// Non-dimensional rate of heat release related to the fire diameter
+/// `d` (m).
+pub fn nondimensional_heat_release_diameter(q: f64, d: f64) -> f64 {
+    q / (1.11e6 * d.powf(2.5))
+}
+
+/// Vertical position of the virtual heat source (m).
+pub fn virtual_heat_source(d: f64, q_d_star: f64) -> f64 {
+    if q_d_star < 1.0 {
+        2.4 * d * (q_d_star.powf(2.0 / 5.0) - q_d_star.powf(2.0 / 3.0))
+    } else {
+        2.4 * d * (1.0 - q_d_star.powf(2.0 / 5.0))
+    }
+}
+
+#[cfg(not(coverage))]
+pub fn nondimensional_heat_release_diameter_equation(
+    q_d_star: String,
+    q: String,
+    d: String,
+) -> String {
+    format!(
+        "{} = \\frac{{{}}}{{1.11 \\cdot 10^6 \\cdot {}^{{2.5}}}}",
+        q_d_star, q, d
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn virtual_heat_source_equation(z_prime: String, d: String, q_d_star: String) -> String {
+    format!(
+        "{} = \\begin{{cases}} 2.4 \\cdot {} \\left( {}^{{2/5}} - {}^{{2/3}} \\right) & {} < 1.0 \\\\ 2.4 \\cdot {} \\left( 1.0 - {}^{{2/5}} \\right) & {} \\geq 1.0 \\end{{cases}}",
+        z_prime, d, q_d_star, q_d_star, q_d_star, d, q_d_star, q_d_star
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nondimensional_heat_release_diameter() {
+        let result = nondimensional_heat_release_diameter(8.0e6, 3.0);
+        assert!((result - 0.4623425579096102).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_virtual_heat_source() {
+        let result = virtual_heat_source(3.0, 0.4623425579096102);
+        assert!((result - 0.983304827369778).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_virtual_heat_source_large_fire() {
+        let result = virtual_heat_source(1.0, 2.0);
+        let expected = 2.4 * (1.0 - 2.0_f64.powf(0.4));
+        assert!((result - expected).abs() < 1e-12);
+    }
+}
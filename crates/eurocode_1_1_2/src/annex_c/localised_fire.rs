@@ -0,0 +1,72 @@
+//! Localised fire of diameter `d` (m) and rate of heat release `q` (W). The
+//! plume temperature (Heskestad) applies while the flame does not impact the
+//! ceiling, otherwise the heat flux at the ceiling follows Hasemi.
+
+use super::{
+    equation_c1, equation_c2, equation_c3, equation_c4, equation_c5, equation_c6, equation_c7,
+    equation_c9,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LocalisedFire {
+    pub d: f64,
+    pub q: f64,
+}
+
+impl LocalisedFire {
+    pub fn new(d: f64, q: f64) -> Self {
+        LocalisedFire { d, q }
+    }
+
+    pub fn flame_length(&self) -> f64 {
+        equation_c1::flame_length(self.d, self.q)
+    }
+
+    /// Whether the flame impacts a ceiling at height `h` (m) above the fire.
+    pub fn impacts_ceiling(&self, h: f64) -> bool {
+        self.flame_length() >= h
+    }
+
+    /// Plume temperature (°C) on the flame axis at height `z` (m).
+    pub fn plume_temperature(&self, z: f64) -> f64 {
+        equation_c2::plume_temperature(
+            equation_c2::convective_heat_release(self.q),
+            z,
+            equation_c3::virtual_origin(self.d, self.q),
+        )
+    }
+
+    /// Heat flux (W/m²) at the ceiling at height `h` (m), at horizontal
+    /// distance `r` (m) from the flame axis.
+    pub fn ceiling_heat_flux(&self, r: f64, h: f64) -> f64 {
+        let q_h_star = equation_c5::nondimensional_heat_release_ceiling(self.q, h);
+        let q_d_star = equation_c9::nondimensional_heat_release_diameter(self.q, self.d);
+        let l_h = equation_c4::horizontal_flame_length(h, q_h_star);
+        let z_prime = equation_c9::virtual_heat_source(self.d, q_d_star);
+
+        equation_c6::heat_flux(equation_c7::parameter_y(r, h, z_prime, l_h))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flame_not_impacting_ceiling() {
+        let fire = LocalisedFire::new(2.0, 2.0e6);
+        assert!(!fire.impacts_ceiling(5.0));
+        assert!((fire.flame_length() - 2.8653919456631822).abs() < 1e-9);
+        assert!((fire.plume_temperature(5.0) - 232.05723390132715).abs() < 1e-9);
+        assert!((fire.plume_temperature(10.0) - 90.10214071081697).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_flame_impacting_ceiling() {
+        let fire = LocalisedFire::new(3.0, 8.0e6);
+        assert!(fire.impacts_ceiling(4.0));
+        assert!((fire.ceiling_heat_flux(0.0, 4.0) - 61638.37197253117).abs() < 1e-6);
+        assert!((fire.ceiling_heat_flux(2.0, 4.0) - 31673.667579010944).abs() < 1e-6);
+        assert!((fire.ceiling_heat_flux(10.0, 4.0) - 1524.063091892349).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,9 @@
+pub mod equation_e1;
+pub mod equation_e2;
+pub mod equation_e3;
+pub mod equation_e4;
+pub mod equation_e5;
+pub mod equation_e6;
+pub mod heat_release_curve;
+pub mod occupancy;
+pub mod partial_factors;
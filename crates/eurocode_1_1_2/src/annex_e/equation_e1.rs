@@ -0,0 +1,64 @@
+/// Design fire load density (MJ/m²) from the characteristic fire load
+/// density `q_fk` (MJ/m²), combustion factor `m` and the partial factors for
+/// compartment size, occupancy and active fire fighting measures.
+pub fn design_fire_load_density(
+    q_fk: f64,
+    m: f64,
+    delta_q1: f64,
+    delta_q2: f64,
+    delta_n: f64,
+) -> f64 {
+    q_fk * m * delta_q1 * delta_q2 * delta_n
+}
+
+/// Combustion factor for mainly cellulosic fire loads.
+pub fn combustion_factor_cellulosic() -> f64 {
+    0.8
+}
+
+/// Product of the factors `delta_n_i` for active fire fighting measures.
+pub fn active_measures_factor(delta_n_i: &[f64]) -> f64 {
+    delta_n_i.iter().product()
+}
+
+#[cfg(not(coverage))]
+pub fn design_fire_load_density_equation(
+    q_fd: String,
+    q_fk: String,
+    m: String,
+    delta_q1: String,
+    delta_q2: String,
+    delta_n: String,
+) -> String {
+    format!(
+        "{} = {} \\cdot {} \\cdot {} \\cdot {} \\cdot {}",
+        q_fd, q_fk, m, delta_q1, delta_q2, delta_n
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn active_measures_factor_equation(delta_n: String, delta_n_i: String) -> String {
+    format!("{} = \\prod_{{i=1}}^{{10}} {}", delta_n, delta_n_i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_design_fire_load_density() {
+        let result = design_fire_load_density(511.0, 0.8, 1.5, 1.0, 0.61);
+        assert!((result - 374.05199999999996).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_active_measures_factor() {
+        let result = active_measures_factor(&[0.61, 0.87, 0.73]);
+        assert!((result - 0.387411).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_active_measures_factor_none() {
+        assert_eq!(active_measures_factor(&[]), 1.0);
+    }
+}
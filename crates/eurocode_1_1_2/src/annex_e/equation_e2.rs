@@ -0,0 +1,21 @@
+/// Characteristic fire load density (MJ/m²) for a characteristic fire load
+/// `q_fik` (MJ) over a floor area `a` (m²).
+pub fn characteristic_fire_load_density(q_fik: f64, a: f64) -> f64 {
+    q_fik / a
+}
+
+#[cfg(not(coverage))]
+pub fn characteristic_fire_load_density_equation(q_fk: String, q_fik: String, a: String) -> String {
+    format!("{} = \\frac{{{}}}{{{}}}", q_fk, q_fik, a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_characteristic_fire_load_density() {
+        let result = characteristic_fire_load_density(12000.0, 25.0);
+        assert!((result - 480.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,31 @@
+/// Characteristic fire load (MJ) from the mass `m_k` (kg), net calorific
+/// value `h_u` (MJ/kg) and protection factor `psi` of each combustible
+/// material.
+pub fn characteristic_fire_load(m_k: &[f64], h_u: &[f64], psi: &[f64]) -> f64 {
+    m_k.iter()
+        .zip(h_u.iter())
+        .zip(psi.iter())
+        .map(|((m, h), p)| m * h * p)
+        .sum()
+}
+
+#[cfg(not(coverage))]
+pub fn characteristic_fire_load_equation(
+    q_fik: String,
+    m_k: String,
+    h_u: String,
+    psi: String,
+) -> String {
+    format!("{} = \\sum_i {} \\cdot {} \\cdot {}", q_fik, m_k, h_u, psi)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_characteristic_fire_load() {
+        let result = characteristic_fire_load(&[500.0, 100.0], &[17.5, 40.0], &[1.0, 0.5]);
+        assert!((result - 10750.0).abs() < 1e-9);
+    }
+}
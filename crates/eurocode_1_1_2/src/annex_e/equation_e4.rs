@@ -0,0 +1,29 @@
+/// Net calorific value (MJ/kg) of a material with moisture content `u`
+/// (% of dry weight) and net calorific value when dry `h_u0` (MJ/kg).
+pub fn net_calorific_value(h_u0: f64, u: f64) -> f64 {
+    h_u0 * (1.0 - 0.01 * u) - 0.025 * u
+}
+
+#[cfg(not(coverage))]
+pub fn net_calorific_value_equation(h_u: String, h_u0: String, u: String) -> String {
+    format!(
+        "{} = {} \\cdot \\left( 1 - 0.01 \\cdot {} \\right) - 0.025 \\cdot {}",
+        h_u, h_u0, u, u
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_net_calorific_value() {
+        let result = net_calorific_value(17.5, 12.0);
+        assert!((result - 15.1).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_net_calorific_value_dry() {
+        assert_eq!(net_calorific_value(17.5, 0.0), 17.5);
+    }
+}
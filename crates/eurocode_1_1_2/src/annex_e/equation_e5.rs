@@ -0,0 +1,24 @@
+/// Rate of heat release (W) in the growing phase at time `t` (s), for the
+/// time `t_alpha` (s) needed to reach 1 MW.
+pub fn growing_phase_heat_release(t: f64, t_alpha: f64) -> f64 {
+    1.0e6 * (t / t_alpha).powi(2)
+}
+
+#[cfg(not(coverage))]
+pub fn growing_phase_heat_release_equation(q: String, t: String, t_alpha: String) -> String {
+    format!(
+        "{} = 10^6 \\cdot \\left( \\frac{{{}}}{{{}}} \\right)^2",
+        q, t, t_alpha
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_growing_phase_heat_release() {
+        let result = growing_phase_heat_release(600.0, 300.0);
+        assert!((result - 4.0e6).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,48 @@
+/// Maximum rate of heat release (W) for a ventilation controlled fire, with
+/// net calorific value of wood `h_u` (MJ/kg), vertical opening area `a_v`
+/// (m²) and weighted average height of openings `h_eq` (m).
+pub fn ventilation_controlled_heat_release(m: f64, h_u: f64, a_v: f64, h_eq: f64) -> f64 {
+    0.10 * m * h_u * a_v * h_eq.sqrt() * 1.0e6
+}
+
+/// Maximum rate of heat release (W) for a fuel controlled fire, with a
+/// rate of heat release density `rhr_f` (W/m²) over a fire area `a_fi` (m²).
+pub fn fuel_controlled_heat_release(rhr_f: f64, a_fi: f64) -> f64 {
+    rhr_f * a_fi
+}
+
+#[cfg(not(coverage))]
+pub fn ventilation_controlled_heat_release_equation(
+    q_max: String,
+    m: String,
+    h_u: String,
+    a_v: String,
+    h_eq: String,
+) -> String {
+    format!(
+        "{} = 0.10 \\cdot {} \\cdot {} \\cdot {} \\cdot \\sqrt{{{}}}",
+        q_max, m, h_u, a_v, h_eq
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn fuel_controlled_heat_release_equation(q_max: String, rhr_f: String, a_fi: String) -> String {
+    format!("{} = {} \\cdot {}", q_max, rhr_f, a_fi)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ventilation_controlled_heat_release() {
+        let result = ventilation_controlled_heat_release(0.8, 17.5, 4.0, 2.25);
+        assert!((result - 8.4e6).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_fuel_controlled_heat_release() {
+        let result = fuel_controlled_heat_release(250.0e3, 20.0);
+        assert!((result - 5.0e6).abs() < 1e-6);
+    }
+}
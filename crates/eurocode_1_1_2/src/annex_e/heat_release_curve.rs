@@ -0,0 +1,113 @@
+//! Design rate of heat release with a growing phase, a steady phase limited
+//! by fuel or ventilation and a linear decay phase starting once 70% of the
+//! fire load has been consumed. Times are in seconds and rates in W.
+
+use super::equation_e5;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeatReleaseCurve {
+    pub t_alpha: f64,
+    pub q_peak: f64,
+    pub t_growth: f64,
+    pub t_decay: f64,
+    pub t_end: f64,
+}
+
+impl HeatReleaseCurve {
+    /// Builds the curve for a growth time `t_alpha` (s), maximum rate of heat
+    /// release `q_max` (W) and design fire load density `q_fd` (MJ/m²) over
+    /// a fire area `a_fi` (m²).
+    pub fn new(t_alpha: f64, q_max: f64, q_fd: f64, a_fi: f64) -> Self {
+        let energy = q_fd * a_fi * 1.0e6;
+        let t_max = t_alpha * (q_max / 1.0e6).sqrt();
+        let growth_energy = 1.0e6 * t_max.powi(3) / (3.0 * t_alpha.powi(2));
+
+        let (t_growth, t_decay, q_peak) = if growth_energy >= 0.7 * energy {
+            let t = (0.7 * energy * 3.0 * t_alpha.powi(2) / 1.0e6).cbrt();
+            (t, t, equation_e5::growing_phase_heat_release(t, t_alpha))
+        } else {
+            (t_max, t_max + (0.7 * energy - growth_energy) / q_max, q_max)
+        };
+
+        HeatReleaseCurve {
+            t_alpha,
+            q_peak,
+            t_growth,
+            t_decay,
+            t_end: t_decay + 2.0 * 0.3 * energy / q_peak,
+        }
+    }
+
+    /// Rate of heat release (W) at time `t` (s).
+    pub fn rate_of_heat_release(&self, t: f64) -> f64 {
+        if t <= self.t_growth {
+            equation_e5::growing_phase_heat_release(t, self.t_alpha)
+        } else if t <= self.t_decay {
+            self.q_peak
+        } else if t <= self.t_end {
+            self.q_peak * (self.t_end - t) / (self.t_end - self.t_decay)
+        } else {
+            0.0
+        }
+    }
+
+    pub fn rate_of_heat_release_history(&self, times: &[f64]) -> Vec<f64> {
+        times
+            .iter()
+            .map(|&t| self.rate_of_heat_release(t))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn released_energy(curve: &HeatReleaseCurve) -> f64 {
+        let dt = 0.01;
+        let steps = (curve.t_end / dt).ceil() as usize;
+        (0..steps)
+            .map(|i| {
+                let t = i as f64 * dt;
+                0.5 * (curve.rate_of_heat_release(t) + curve.rate_of_heat_release(t + dt)) * dt
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_limited_by_maximum_rate() {
+        let curve = HeatReleaseCurve::new(300.0, 4.0e6, 300.0, 20.0);
+        assert!((curve.t_growth - 600.0).abs() < 1e-9);
+        assert!((curve.t_decay - 1450.0).abs() < 1e-9);
+        assert!((curve.t_end - 2350.0).abs() < 1e-9);
+        assert!((curve.rate_of_heat_release(300.0) - 1.0e6).abs() < 1e-6);
+        assert!((curve.rate_of_heat_release(1000.0) - 4.0e6).abs() < 1e-6);
+        assert!((curve.rate_of_heat_release(1900.0) - 2.0e6).abs() < 1e-6);
+        assert_eq!(curve.rate_of_heat_release(3000.0), 0.0);
+    }
+
+    #[test]
+    fn test_decay_before_maximum_rate() {
+        let curve = HeatReleaseCurve::new(300.0, 4.0e6, 50.0, 20.0);
+        assert_eq!(curve.t_growth, curve.t_decay);
+        assert!(curve.q_peak < 4.0e6);
+        assert!((curve.t_decay - 573.8793548317166).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_released_energy() {
+        let curve = HeatReleaseCurve::new(300.0, 4.0e6, 300.0, 20.0);
+        assert!((released_energy(&curve) / 6.0e9 - 1.0).abs() < 1e-6);
+
+        let curve = HeatReleaseCurve::new(300.0, 4.0e6, 50.0, 20.0);
+        assert!((released_energy(&curve) / 1.0e9 - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_rate_of_heat_release_history() {
+        let curve = HeatReleaseCurve::new(300.0, 4.0e6, 300.0, 20.0);
+        let result = curve.rate_of_heat_release_history(&[0.0, 1000.0]);
+        assert_eq!(result[0], 0.0);
+        assert!((result[1] - 4.0e6).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,113 @@
+//! Characteristic fire load densities and rates of heat release for
+//! typical occupancies. The fire load densities are the 80% fractile values
+//! assuming a Gumbel distribution.
+
+use crate::annex_a::equation_a7::FireGrowthRate;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Occupancy {
+    Dwelling,
+    Hospital,
+    Hotel,
+    Library,
+    Office,
+    Classroom,
+    ShoppingCentre,
+    Theatre,
+    Transport,
+}
+
+impl Occupancy {
+    /// Average fire load density (MJ/m²).
+    pub fn mean_fire_load_density(&self) -> f64 {
+        match self {
+            Occupancy::Dwelling => 780.0,
+            Occupancy::Hospital => 230.0,
+            Occupancy::Hotel => 310.0,
+            Occupancy::Library => 1500.0,
+            Occupancy::Office => 420.0,
+            Occupancy::Classroom => 285.0,
+            Occupancy::ShoppingCentre => 600.0,
+            Occupancy::Theatre => 300.0,
+            Occupancy::Transport => 100.0,
+        }
+    }
+
+    /// Characteristic (80% fractile) fire load density (MJ/m²).
+    pub fn characteristic_fire_load_density(&self) -> f64 {
+        match self {
+            Occupancy::Dwelling => 948.0,
+            Occupancy::Hospital => 280.0,
+            Occupancy::Hotel => 377.0,
+            Occupancy::Library => 1824.0,
+            Occupancy::Office => 511.0,
+            Occupancy::Classroom => 347.0,
+            Occupancy::ShoppingCentre => 730.0,
+            Occupancy::Theatre => 365.0,
+            Occupancy::Transport => 122.0,
+        }
+    }
+
+    pub fn growth_rate(&self) -> FireGrowthRate {
+        match self {
+            Occupancy::Transport => FireGrowthRate::Slow,
+            Occupancy::Library | Occupancy::ShoppingCentre | Occupancy::Theatre => {
+                FireGrowthRate::Fast
+            }
+            _ => FireGrowthRate::Medium,
+        }
+    }
+
+    /// Maximum rate of heat release density (W/m²).
+    pub fn rate_of_heat_release_density(&self) -> f64 {
+        match self {
+            Occupancy::Library | Occupancy::Theatre => 500.0e3,
+            _ => 250.0e3,
+        }
+    }
+}
+
+/// Time (s) needed to reach a rate of heat release of 1 MW.
+pub fn t_alpha(growth_rate: FireGrowthRate) -> f64 {
+    match growth_rate {
+        FireGrowthRate::Slow => 600.0,
+        FireGrowthRate::Medium => 300.0,
+        FireGrowthRate::Fast => 150.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_office() {
+        let occupancy = Occupancy::Office;
+        assert_eq!(occupancy.mean_fire_load_density(), 420.0);
+        assert_eq!(occupancy.characteristic_fire_load_density(), 511.0);
+        assert_eq!(occupancy.growth_rate(), FireGrowthRate::Medium);
+        assert_eq!(occupancy.rate_of_heat_release_density(), 250.0e3);
+    }
+
+    #[test]
+    fn test_library() {
+        let occupancy = Occupancy::Library;
+        assert_eq!(occupancy.characteristic_fire_load_density(), 1824.0);
+        assert_eq!(occupancy.growth_rate(), FireGrowthRate::Fast);
+        assert_eq!(occupancy.rate_of_heat_release_density(), 500.0e3);
+    }
+
+    #[test]
+    fn test_transport() {
+        let occupancy = Occupancy::Transport;
+        assert_eq!(occupancy.characteristic_fire_load_density(), 122.0);
+        assert_eq!(occupancy.growth_rate(), FireGrowthRate::Slow);
+    }
+
+    #[test]
+    fn test_t_alpha() {
+        assert_eq!(t_alpha(FireGrowthRate::Slow), 600.0);
+        assert_eq!(t_alpha(FireGrowthRate::Medium), 300.0);
+        assert_eq!(t_alpha(FireGrowthRate::Fast), 150.0);
+    }
+}
@@ -0,0 +1,204 @@
+//! Factors taking into account the fire activation risk due to the size of
+//! the compartment and the type of occupancy, and the active fire fighting
+//! measures provided.
+
+const COMPARTMENT_AREAS: [f64; 5] = [25.0, 250.0, 2500.0, 5000.0, 10000.0];
+const DELTA_Q1: [f64; 5] = [1.10, 1.50, 1.90, 2.00, 2.13];
+
+/// Factor for the activation risk due to the compartment floor area `a_f`
+/// (m²), interpolated linearly between the tabulated areas.
+pub fn delta_q1(a_f: f64) -> f64 {
+    if a_f <= COMPARTMENT_AREAS[0] {
+        return DELTA_Q1[0];
+    }
+
+    for i in 1..COMPARTMENT_AREAS.len() {
+        if a_f <= COMPARTMENT_AREAS[i] {
+            let ratio = (a_f - COMPARTMENT_AREAS[i - 1])
+                / (COMPARTMENT_AREAS[i] - COMPARTMENT_AREAS[i - 1]);
+            return DELTA_Q1[i - 1] + ratio * (DELTA_Q1[i] - DELTA_Q1[i - 1]);
+        }
+    }
+
+    DELTA_Q1[DELTA_Q1.len() - 1]
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ActivationRisk {
+    ArtGallery,
+    Office,
+    MachineryManufacture,
+    ChemicalLaboratory,
+    FireworksManufacture,
+}
+
+impl ActivationRisk {
+    /// Factor for the activation risk due to the type of occupancy.
+    pub fn delta_q2(&self) -> f64 {
+        match self {
+            ActivationRisk::ArtGallery => 0.78,
+            ActivationRisk::Office => 1.00,
+            ActivationRisk::MachineryManufacture => 1.22,
+            ActivationRisk::ChemicalLaboratory => 1.44,
+            ActivationRisk::FireworksManufacture => 1.66,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FireDetection {
+    None,
+    Heat,
+    Smoke,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FireBrigade {
+    None,
+    Work,
+    OffSite,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SafeAccessRoutes {
+    Enhanced,
+    Normal,
+    Inadequate,
+}
+
+impl SafeAccessRoutes {
+    /// Factor `delta_n8` for the access routes provided.
+    pub fn delta_n8(&self) -> f64 {
+        match self {
+            SafeAccessRoutes::Enhanced => 0.9,
+            SafeAccessRoutes::Normal => 1.0,
+            SafeAccessRoutes::Inadequate => 1.5,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ActiveMeasures {
+    pub sprinklers: bool,
+    pub independent_water_supplies: u32,
+    pub detection: FireDetection,
+    pub alarm_transmission: bool,
+    pub fire_brigade: FireBrigade,
+    pub safe_access_routes: SafeAccessRoutes,
+    pub fire_fighting_devices: bool,
+    pub smoke_exhaust: bool,
+}
+
+impl ActiveMeasures {
+    /// Factors `delta_n1` to `delta_n10` for the measures provided.
+    pub fn factors(&self) -> [f64; 10] {
+        let provided = |present: bool, factor: f64| if present { factor } else { 1.0 };
+        let normal = |present: bool| if present { 1.0 } else { 1.5 };
+
+        [
+            provided(self.sprinklers, 0.61),
+            match self.independent_water_supplies {
+                0 => 1.0,
+                1 => 0.87,
+                _ => 0.7,
+            },
+            provided(self.detection == FireDetection::Heat, 0.87),
+            provided(self.detection == FireDetection::Smoke, 0.73),
+            provided(self.alarm_transmission, 0.87),
+            provided(self.fire_brigade == FireBrigade::Work, 0.61),
+            provided(self.fire_brigade == FireBrigade::OffSite, 0.78),
+            self.safe_access_routes.delta_n8(),
+            normal(self.fire_fighting_devices),
+            normal(self.smoke_exhaust),
+        ]
+    }
+
+    pub fn delta_n(&self) -> f64 {
+        super::equation_e1::active_measures_factor(&self.factors())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn normal_measures() -> ActiveMeasures {
+        ActiveMeasures {
+            sprinklers: false,
+            independent_water_supplies: 0,
+            detection: FireDetection::None,
+            alarm_transmission: false,
+            fire_brigade: FireBrigade::None,
+            safe_access_routes: SafeAccessRoutes::Normal,
+            fire_fighting_devices: true,
+            smoke_exhaust: true,
+        }
+    }
+
+    #[test]
+    fn test_delta_q1_tabulated() {
+        assert_eq!(delta_q1(25.0), 1.10);
+        assert_eq!(delta_q1(250.0), 1.50);
+        assert_eq!(delta_q1(10000.0), 2.13);
+    }
+
+    #[test]
+    fn test_delta_q1_interpolated() {
+        assert!((delta_q1(1375.0) - 1.7).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_delta_q1_outside_table() {
+        assert_eq!(delta_q1(10.0), 1.10);
+        assert_eq!(delta_q1(20000.0), 2.13);
+    }
+
+    #[test]
+    fn test_delta_q2() {
+        assert_eq!(ActivationRisk::Office.delta_q2(), 1.00);
+        assert_eq!(ActivationRisk::FireworksManufacture.delta_q2(), 1.66);
+    }
+
+    #[test]
+    fn test_delta_n_normal_measures() {
+        assert_eq!(normal_measures().delta_n(), 1.0);
+    }
+
+    #[test]
+    fn test_delta_n_sprinklered() {
+        let measures = ActiveMeasures {
+            sprinklers: true,
+            independent_water_supplies: 1,
+            detection: FireDetection::Smoke,
+            alarm_transmission: true,
+            fire_brigade: FireBrigade::OffSite,
+            ..normal_measures()
+        };
+        let expected = 0.61 * 0.87 * 0.73 * 0.87 * 0.78;
+        assert!((measures.delta_n() - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_delta_n_missing_normal_measures() {
+        let measures = ActiveMeasures {
+            smoke_exhaust: false,
+            ..normal_measures()
+        };
+        assert_eq!(measures.delta_n(), 1.5);
+    }
+
+    #[test]
+    fn test_delta_n_safe_access_routes() {
+        for (routes, expected) in [
+            (SafeAccessRoutes::Enhanced, 0.9),
+            (SafeAccessRoutes::Normal, 1.0),
+            (SafeAccessRoutes::Inadequate, 1.5),
+        ] {
+            let measures = ActiveMeasures {
+                safe_access_routes: routes,
+                ..normal_measures()
+            };
+            assert_eq!(measures.delta_n(), expected);
+        }
+    }
+}
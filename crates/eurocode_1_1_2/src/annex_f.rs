@@ -0,0 +1,4 @@
+pub mod conversion_factors;
+pub mod equation_f1;
+pub mod equation_f2;
+pub mod equation_f3;
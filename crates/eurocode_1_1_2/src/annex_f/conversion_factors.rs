@@ -0,0 +1,51 @@
+//! Conversion factor `k_b` for the enclosure linings and correction factor
+//! `k_c` for the member material.
+
+/// Conversion factor (min·m²/MJ) for a thermal absorptivity `b`
+/// (J/m²s^1/2K) of the enclosure.
+pub fn k_b(b: f64) -> f64 {
+    if b > 2500.0 {
+        0.04
+    } else if b >= 720.0 {
+        0.055
+    } else {
+        0.07
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MemberMaterial {
+    ReinforcedConcrete,
+    ProtectedSteel,
+    UnprotectedSteel,
+}
+
+impl MemberMaterial {
+    /// Correction factor for an opening factor `o` (m^1/2).
+    pub fn k_c(&self, o: f64) -> f64 {
+        match self {
+            MemberMaterial::ReinforcedConcrete | MemberMaterial::ProtectedSteel => 1.0,
+            MemberMaterial::UnprotectedSteel => 13.7 * o,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_k_b() {
+        assert_eq!(k_b(3000.0), 0.04);
+        assert_eq!(k_b(2500.0), 0.055);
+        assert_eq!(k_b(720.0), 0.055);
+        assert_eq!(k_b(500.0), 0.07);
+    }
+
+    #[test]
+    fn test_k_c() {
+        assert_eq!(MemberMaterial::ReinforcedConcrete.k_c(0.04), 1.0);
+        assert_eq!(MemberMaterial::ProtectedSteel.k_c(0.04), 1.0);
+        assert!((MemberMaterial::UnprotectedSteel.k_c(0.04) - 0.548).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,57 @@
+use crate::section_3::equation_3_4;
+
+/// Equivalent time of standard fire exposure (min) for a design fire load
+/// density `q_fd` (MJ/m²), conversion factor `k_b` (min·m²/MJ), ventilation
+/// factor `w_f` and correction factor `k_c`.
+pub fn equivalent_time(q_fd: f64, k_b: f64, w_f: f64, k_c: f64) -> f64 {
+    q_fd * k_b * w_f * k_c
+}
+
+/// Standard temperature-time curve temperature (°C) reached at the
+/// equivalent time `t_ed` (min).
+pub fn equivalent_temperature(t_ed: f64) -> f64 {
+    equation_3_4::standard_temp_time_curve(t_ed)
+}
+
+/// Whether the design fire resistance `t_fid` (min) of a member is at least
+/// the equivalent time `t_ed` (min).
+pub fn fire_resistance_sufficient(t_fid: f64, t_ed: f64) -> bool {
+    t_fid >= t_ed
+}
+
+#[cfg(not(coverage))]
+pub fn equivalent_time_equation(
+    t_ed: String,
+    q_fd: String,
+    k_b: String,
+    w_f: String,
+    k_c: String,
+) -> String {
+    format!(
+        "{} = \\left( {} \\cdot {} \\cdot {} \\right) \\cdot {}",
+        t_ed, q_fd, k_b, w_f, k_c
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_equivalent_time() {
+        let result = equivalent_time(600.0, 0.055, 1.0972311319798012, 1.0);
+        assert!((result - 36.20862735533344).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_equivalent_temperature() {
+        let result = equivalent_temperature(10.0);
+        assert!((result - 678.42733151313).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_fire_resistance_sufficient() {
+        assert!(fire_resistance_sufficient(60.0, 36.2));
+        assert!(!fire_resistance_sufficient(30.0, 36.2));
+    }
+}
@@ -0,0 +1,83 @@
+/// Ventilation factor for a compartment of height `h` (m), with the ratio
+/// of vertical openings `alpha_v` and horizontal openings `alpha_h` to the
+/// floor area. The result is at least 0.5.
+pub fn ventilation_factor(h: f64, alpha_v: f64, alpha_h: f64) -> f64 {
+    let b_v = factor_b_v(alpha_v);
+
+    ((6.0 / h).powf(0.3) * (0.62 + 90.0 * (0.4 - alpha_v).powi(4) / (1.0 + b_v * alpha_h))).max(0.5)
+}
+
+/// Ratio of the area of vertical openings `a_v` (m²) to the floor area
+/// `a_f` (m²), limited to between 0.025 and 0.25.
+pub fn vertical_opening_ratio(a_v: f64, a_f: f64) -> f64 {
+    (a_v / a_f).clamp(0.025, 0.25)
+}
+
+pub fn horizontal_opening_ratio(a_h: f64, a_f: f64) -> f64 {
+    a_h / a_f
+}
+
+pub fn factor_b_v(alpha_v: f64) -> f64 {
+    (12.5 * (1.0 + 10.0 * alpha_v - alpha_v.powi(2))).max(10.0)
+}
+
+#[cfg(not(coverage))]
+pub fn ventilation_factor_equation(
+    w_f: String,
+    h: String,
+    alpha_v: String,
+    alpha_h: String,
+    b_v: String,
+) -> String {
+    format!(
+        "{} = \\left( \\frac{{6.0}}{{{}}} \\right)^{{0.3}} \\left[ 0.62 + \\frac{{90 \\left( 0.4 - {} \\right)^4}}{{1 + {} \\cdot {}}} \\right] \\geq 0.5",
+        w_f, h, alpha_v, b_v, alpha_h
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn factor_b_v_equation(b_v: String, alpha_v: String) -> String {
+    format!(
+        "{} = 12.5 \\left( 1 + 10 \\cdot {} - {}^2 \\right) \\geq 10.0",
+        b_v, alpha_v, alpha_v
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ventilation_factor() {
+        let result = ventilation_factor(4.0, 0.15, 0.0);
+        assert!((result - 1.0972311319798012).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_ventilation_factor_with_roof_openings() {
+        let result = ventilation_factor(4.0, 0.15, 0.05);
+        assert!((result - 0.8559909678421054).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_ventilation_factor_minimum() {
+        assert_eq!(ventilation_factor(20.0, 0.25, 1.0), 0.5);
+    }
+
+    #[test]
+    fn test_vertical_opening_ratio() {
+        assert!((vertical_opening_ratio(30.0, 200.0) - 0.15).abs() < 1e-12);
+        assert_eq!(vertical_opening_ratio(1.0, 200.0), 0.025);
+        assert_eq!(vertical_opening_ratio(80.0, 200.0), 0.25);
+    }
+
+    #[test]
+    fn test_horizontal_opening_ratio() {
+        assert!((horizontal_opening_ratio(10.0, 200.0) - 0.05).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_factor_b_v() {
+        assert!((factor_b_v(0.15) - 30.96875).abs() < 1e-12);
+    }
+}
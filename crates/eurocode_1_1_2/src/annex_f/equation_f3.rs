@@ -0,0 +1,29 @@
+/// Ventilation factor for small compartments (floor area below 100 m²)
+/// without openings in the roof.
+pub fn ventilation_factor_small_compartment(o: f64, a_f: f64, a_t: f64) -> f64 {
+    o.powf(-0.5) * a_f / a_t
+}
+
+#[cfg(not(coverage))]
+pub fn ventilation_factor_small_compartment_equation(
+    w_f: String,
+    o: String,
+    a_f: String,
+    a_t: String,
+) -> String {
+    format!(
+        "{} = {}^{{-1/2}} \\cdot \\frac{{{}}}{{{}}}",
+        w_f, o, a_f, a_t
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ventilation_factor_small_compartment() {
+        let result = ventilation_factor_small_compartment(0.04, 50.0, 180.0);
+        assert!((result - 1.3888888888888888).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod boundary;
+pub mod material;
+pub mod wall;
@@ -0,0 +1,78 @@
+//! Boundary conditions at the surfaces of a wall or slab, using the net
+//! heat flux of section 3.
+
+use crate::section_3::{equation_3_1, equation_3_2, equation_3_3};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Boundary {
+    /// Convective and radiative exchange with the gas, taking the
+    /// configuration and flame emissivity factors as 1.0.
+    Exposed { alpha_c: f64, epsilon_m: f64 },
+    /// No heat flow through the surface, such as a plane of symmetry.
+    Adiabatic,
+}
+
+impl Boundary {
+    /// Surface exposed to the standard temperature-time curve.
+    pub fn fire_exposed() -> Self {
+        Boundary::Exposed {
+            alpha_c: 25.0,
+            epsilon_m: 0.8,
+        }
+    }
+
+    /// Unexposed surface with radiation considered separately.
+    pub fn unexposed() -> Self {
+        Boundary::Exposed {
+            alpha_c: 4.0,
+            epsilon_m: 0.8,
+        }
+    }
+
+    /// Net heat flux (W/m²) into a surface at `theta_m` from gas at
+    /// `theta_g` (°C).
+    pub fn heat_flux(&self, theta_g: f64, theta_m: f64) -> f64 {
+        match *self {
+            Boundary::Exposed { alpha_c, epsilon_m } => equation_3_1::net_heat_flux_surface(
+                equation_3_2::net_convective_heat_flux_surface(alpha_c, theta_g, theta_m),
+                equation_3_3::net_radiative_heat_flux_surface(
+                    1.0, epsilon_m, 1.0, 5.67e-8, theta_g, theta_m,
+                ),
+            ),
+            Boundary::Adiabatic => 0.0,
+        }
+    }
+
+    /// Linearised heat transfer coefficient (W/m²K) at surface temperature
+    /// `theta_m`, used to limit the time step.
+    pub fn heat_transfer_coefficient(&self, theta_g: f64, theta_m: f64) -> f64 {
+        match *self {
+            Boundary::Exposed { alpha_c, epsilon_m } => {
+                let t = theta_g.max(theta_m) + 273.0;
+                alpha_c + 4.0 * epsilon_m * 5.67e-8 * t.powi(3)
+            }
+            Boundary::Adiabatic => 0.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fire_exposed_heat_flux() {
+        let result = Boundary::fire_exposed().heat_flux(800.0, 200.0);
+        let expected = 15000.0 + 0.8 * 5.67e-8 * (1073.0_f64.powi(4) - 473.0_f64.powi(4));
+        assert!((result - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_adiabatic() {
+        assert_eq!(Boundary::Adiabatic.heat_flux(800.0, 200.0), 0.0);
+        assert_eq!(
+            Boundary::Adiabatic.heat_transfer_coefficient(800.0, 200.0),
+            0.0
+        );
+    }
+}
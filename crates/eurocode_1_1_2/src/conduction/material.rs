@@ -0,0 +1,191 @@
+//! Temperature dependent thermal properties of wall and slab materials.
+//! Concrete follows EN 1992-1-2 clause 3.3 with the lower limit of thermal
+//! conductivity. Gypsum plasterboard and mineral wool use typical effective
+//! values, the gypsum specific heat including the dehydration peak.
+
+const GYPSUM_CONDUCTIVITY: [(f64, f64); 6] = [
+    (20.0, 0.25),
+    (70.0, 0.25),
+    (200.0, 0.12),
+    (730.0, 0.12),
+    (1000.0, 0.27),
+    (1200.0, 0.35),
+];
+const GYPSUM_SPECIFIC_HEAT: [(f64, f64); 4] = [
+    (20.0, 950.0),
+    (100.0, 950.0),
+    (125.0, 10000.0),
+    (150.0, 950.0),
+];
+const GYPSUM_DENSITY: [(f64, f64); 3] = [(20.0, 700.0), (100.0, 700.0), (150.0, 560.0)];
+
+const MINERAL_WOOL_CONDUCTIVITY: [(f64, f64); 7] = [
+    (20.0, 0.035),
+    (200.0, 0.06),
+    (400.0, 0.10),
+    (600.0, 0.16),
+    (800.0, 0.24),
+    (1000.0, 0.33),
+    (1200.0, 0.44),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Material {
+    /// Normal weight concrete with moisture content `moisture` (% of weight)
+    /// and density `rho_20` (kg/m³) at 20 °C.
+    Concrete {
+        moisture: f64,
+        rho_20: f64,
+    },
+    Gypsum,
+    /// Mineral wool of density `rho` (kg/m³).
+    MineralWool {
+        rho: f64,
+    },
+    /// Constant thermal conductivity (W/mK), density (kg/m³) and specific
+    /// heat (J/kgK).
+    Constant {
+        lambda: f64,
+        rho: f64,
+        c: f64,
+    },
+}
+
+impl Material {
+    pub fn concrete() -> Self {
+        Material::Concrete {
+            moisture: 1.5,
+            rho_20: 2300.0,
+        }
+    }
+
+    /// Thermal conductivity (W/mK) at temperature `theta` (°C).
+    pub fn conductivity(&self, theta: f64) -> f64 {
+        match *self {
+            Material::Concrete { .. } => {
+                let t = theta.clamp(20.0, 1200.0) / 100.0;
+                1.36 - 0.136 * t + 0.0057 * t.powi(2)
+            }
+            Material::Gypsum => interpolate(&GYPSUM_CONDUCTIVITY, theta),
+            Material::MineralWool { .. } => interpolate(&MINERAL_WOOL_CONDUCTIVITY, theta),
+            Material::Constant { lambda, .. } => lambda,
+        }
+    }
+
+    /// Density (kg/m³) at temperature `theta` (°C).
+    pub fn density(&self, theta: f64) -> f64 {
+        match *self {
+            Material::Concrete { rho_20, .. } => {
+                rho_20
+                    * if theta <= 115.0 {
+                        1.0
+                    } else if theta <= 200.0 {
+                        1.0 - 0.02 * (theta - 115.0) / 85.0
+                    } else if theta <= 400.0 {
+                        0.98 - 0.03 * (theta - 200.0) / 200.0
+                    } else {
+                        0.95 - 0.07 * (theta.min(1200.0) - 400.0) / 800.0
+                    }
+            }
+            Material::Gypsum => interpolate(&GYPSUM_DENSITY, theta),
+            Material::MineralWool { rho } => rho,
+            Material::Constant { rho, .. } => rho,
+        }
+    }
+
+    /// Specific heat (J/kgK) at temperature `theta` (°C).
+    pub fn specific_heat(&self, theta: f64) -> f64 {
+        match *self {
+            Material::Concrete { moisture, .. } => {
+                let peak = concrete_peak_specific_heat(moisture);
+                if theta <= 100.0 {
+                    900.0
+                } else if theta <= 115.0 {
+                    peak
+                } else if theta <= 200.0 {
+                    peak + (1000.0 - peak) * (theta - 115.0) / 85.0
+                } else if theta <= 400.0 {
+                    1000.0 + (theta - 200.0) / 2.0
+                } else {
+                    1100.0
+                }
+            }
+            Material::Gypsum => interpolate(&GYPSUM_SPECIFIC_HEAT, theta),
+            Material::MineralWool { .. } => 840.0,
+            Material::Constant { c, .. } => c,
+        }
+    }
+
+    /// Volumetric heat capacity (J/m³K) at temperature `theta` (°C).
+    pub fn heat_capacity(&self, theta: f64) -> f64 {
+        self.density(theta) * self.specific_heat(theta)
+    }
+}
+
+/// Peak specific heat of concrete (J/kgK) between 100 °C and 115 °C,
+/// interpolated between the values for 0 %, 1.5 % and 3 % moisture.
+pub fn concrete_peak_specific_heat(moisture: f64) -> f64 {
+    interpolate(&[(0.0, 900.0), (1.5, 1470.0), (3.0, 2020.0)], moisture)
+}
+
+/// Linear interpolation in a table of increasing `x`, held constant beyond
+/// the first and last entries.
+fn interpolate(table: &[(f64, f64)], x: f64) -> f64 {
+    if x <= table[0].0 {
+        return table[0].1;
+    }
+
+    for i in 1..table.len() {
+        if x <= table[i].0 {
+            let (x0, y0) = table[i - 1];
+            let (x1, y1) = table[i];
+            return y0 + (x - x0) / (x1 - x0) * (y1 - y0);
+        }
+    }
+
+    table[table.len() - 1].1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_concrete_conductivity() {
+        let concrete = Material::concrete();
+        assert!((concrete.conductivity(20.0) - 1.333028).abs() < 1e-9);
+        assert!((concrete.conductivity(500.0) - 0.8225).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_concrete_density() {
+        let concrete = Material::concrete();
+        assert_eq!(concrete.density(100.0), 2300.0);
+        assert!((concrete.density(300.0) - 2219.5).abs() < 1e-9);
+        assert!((concrete.density(1200.0) - 2024.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_concrete_specific_heat() {
+        let concrete = Material::concrete();
+        assert_eq!(concrete.specific_heat(50.0), 900.0);
+        assert!((concrete.specific_heat(110.0) - 1470.0).abs() < 1e-9);
+        assert!((concrete.specific_heat(300.0) - 1050.0).abs() < 1e-9);
+        assert!((concrete_peak_specific_heat(3.0) - 2020.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_gypsum() {
+        let gypsum = Material::Gypsum;
+        assert!((gypsum.conductivity(135.0) - 0.185).abs() < 1e-9);
+        assert_eq!(gypsum.specific_heat(125.0), 10000.0);
+        assert_eq!(gypsum.density(500.0), 560.0);
+    }
+
+    #[test]
+    fn test_mineral_wool() {
+        let wool = Material::MineralWool { rho: 100.0 };
+        assert!((wool.conductivity(300.0) - 0.08).abs() < 1e-9);
+        assert!((wool.heat_capacity(300.0) - 84000.0).abs() < 1e-9);
+    }
+}
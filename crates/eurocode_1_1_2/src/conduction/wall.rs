@@ -0,0 +1,385 @@
+//! One-dimensional explicit finite difference conduction through a layered
+//! wall or slab, heated from the exposed face by a gas temperature curve.
+//! Times are in seconds and temperatures in °C; the nominal curves of
+//! section 3 take minutes, so wrap them as `|t| curve(t / 60.0)`.
+
+use super::boundary::Boundary;
+use super::material::Material;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Layer {
+    pub material: Material,
+    /// Thickness (m).
+    pub thickness: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Wall {
+    /// Layers from the exposed to the unexposed face.
+    pub layers: Vec<Layer>,
+    pub exposed: Boundary,
+    pub unexposed: Boundary,
+    /// Ambient and initial temperature (°C).
+    pub ambient: f64,
+    /// Number of elements in each layer.
+    pub elements: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TemperatureProfiles {
+    /// Node distances from the exposed face (m).
+    pub positions: Vec<f64>,
+    /// Output times (s).
+    pub times: Vec<f64>,
+    /// Node temperatures (°C) at each output time.
+    pub temperatures: Vec<Vec<f64>>,
+}
+
+impl TemperatureProfiles {
+    /// Temperature history (°C) of the unexposed face.
+    pub fn unexposed_temperature(&self) -> Vec<f64> {
+        self.temperatures
+            .iter()
+            .map(|profile| profile[profile.len() - 1])
+            .collect()
+    }
+
+    /// Temperature (°C) at depth `x` (m) from the exposed face at output
+    /// `step`, interpolated between nodes.
+    pub fn temperature_at(&self, step: usize, x: f64) -> f64 {
+        let profile = &self.temperatures[step];
+        if self.positions.len() < 2 {
+            return profile[0];
+        }
+
+        let i = self
+            .positions
+            .windows(2)
+            .position(|w| x <= w[1])
+            .unwrap_or(self.positions.len() - 2);
+        let ratio =
+            ((x - self.positions[i]) / (self.positions[i + 1] - self.positions[i])).clamp(0.0, 1.0);
+
+        profile[i] + ratio * (profile[i + 1] - profile[i])
+    }
+
+    /// Depth (m) from the exposed face at which the temperature falls to
+    /// `theta` (°C) at output `step`, interpolated between nodes. Returns
+    /// zero if the exposed face is below `theta` and the full thickness if
+    /// the whole profile is above it.
+    pub fn isotherm_depth(&self, step: usize, theta: f64) -> f64 {
+        let profile = &self.temperatures[step];
+        if profile[0] <= theta {
+            return 0.0;
+        }
+
+        (1..profile.len())
+            .find(|&i| profile[i] <= theta)
+            .map(|i| {
+                let ratio = (profile[i - 1] - theta) / (profile[i - 1] - profile[i]);
+                self.positions[i - 1] + ratio * (self.positions[i] - self.positions[i - 1])
+            })
+            .unwrap_or(self.positions[self.positions.len() - 1])
+    }
+
+    /// Time (s) at which the unexposed face temperature first rises by
+    /// `rise` (K) above its initial value, such as 140 K for the average
+    /// rise of the insulation criterion, or `None` if not reached.
+    pub fn insulation_time(&self, rise: f64) -> Option<f64> {
+        let unexposed = self.unexposed_temperature();
+        let limit = unexposed[0] + rise;
+
+        (1..unexposed.len()).find_map(|i| {
+            (unexposed[i] >= limit).then(|| {
+                let ratio = (limit - unexposed[i - 1]) / (unexposed[i] - unexposed[i - 1]);
+                self.times[i - 1] + ratio * (self.times[i] - self.times[i - 1])
+            })
+        })
+    }
+}
+
+impl Wall {
+    /// Wall exposed to fire on one face with 20 °C ambient on the other.
+    /// There must be at least one layer, every layer must have a positive
+    /// thickness and `elements` must be at least one.
+    pub fn new(layers: Vec<Layer>, elements: usize) -> Self {
+        let wall = Wall {
+            layers,
+            exposed: Boundary::fire_exposed(),
+            unexposed: Boundary::unexposed(),
+            ambient: 20.0,
+            elements,
+        };
+        wall.check();
+        wall
+    }
+
+    fn check(&self) {
+        assert!(!self.layers.is_empty(), "the wall must have a layer");
+        assert!(self.elements > 0, "each layer must have an element");
+        assert!(
+            self.layers.iter().all(|layer| layer.thickness > 0.0),
+            "each layer must have a positive thickness"
+        );
+    }
+
+    /// Element materials and widths (m) from the exposed face.
+    fn mesh(&self) -> Vec<(Material, f64)> {
+        self.layers
+            .iter()
+            .flat_map(|layer| {
+                let dx = layer.thickness / self.elements as f64;
+                std::iter::repeat_n((layer.material, dx), self.elements)
+            })
+            .collect()
+    }
+
+    /// Node distances from the exposed face (m).
+    pub fn positions(&self) -> Vec<f64> {
+        let mut x = 0.0;
+        let mut positions = vec![x];
+        for (_, dx) in self.mesh() {
+            x += dx;
+            positions.push(x);
+        }
+        positions
+    }
+
+    /// Temperature profiles at times 0, `delta_t`, 2 `delta_t`, ... up to
+    /// `t_end` (s) for the gas temperature curve `gas` at the exposed face.
+    /// Each output step is divided into stable explicit sub-steps. `delta_t`
+    /// must be positive.
+    pub fn solve<F: Fn(f64) -> f64>(
+        &self,
+        gas: F,
+        delta_t: f64,
+        t_end: f64,
+    ) -> TemperatureProfiles {
+        self.check();
+        assert!(delta_t > 0.0, "the time step must be positive");
+
+        let mesh = self.mesh();
+        let n = mesh.len() + 1;
+        let steps = (t_end.max(0.0) / delta_t).round() as usize;
+
+        let mut theta = vec![self.ambient; n];
+        let mut times = vec![0.0];
+        let mut temperatures = vec![theta.clone()];
+        let mut t = 0.0;
+
+        for step in 1..=steps {
+            let t_out = step as f64 * delta_t;
+
+            while t < t_out - 1e-9 {
+                let theta_g = gas(t);
+                let (capacity, conductance) = self.coefficients(&mesh, &theta);
+
+                let mut dt_stable = f64::INFINITY;
+                for j in 0..n {
+                    let mut g = 0.0;
+                    if j > 0 {
+                        g += conductance[j - 1];
+                    }
+                    if j < n - 1 {
+                        g += conductance[j];
+                    }
+                    if j == 0 {
+                        g += self.exposed.heat_transfer_coefficient(theta_g, theta[0]);
+                    }
+                    if j == n - 1 {
+                        g += self
+                            .unexposed
+                            .heat_transfer_coefficient(self.ambient, theta[n - 1]);
+                    }
+                    dt_stable = dt_stable.min(0.5 * capacity[j] / g);
+                }
+                let dt = dt_stable.min(t_out - t);
+
+                let mut flux = vec![0.0; n];
+                for e in 0..n - 1 {
+                    let q = conductance[e] * (theta[e] - theta[e + 1]);
+                    flux[e] -= q;
+                    flux[e + 1] += q;
+                }
+                flux[0] += self.exposed.heat_flux(theta_g, theta[0]);
+                flux[n - 1] += self.unexposed.heat_flux(self.ambient, theta[n - 1]);
+
+                for j in 0..n {
+                    theta[j] += flux[j] * dt / capacity[j];
+                }
+                t += dt;
+            }
+
+            times.push(t_out);
+            temperatures.push(theta.clone());
+        }
+
+        TemperatureProfiles {
+            positions: self.positions(),
+            times,
+            temperatures,
+        }
+    }
+
+    /// Node heat capacities (J/m²K) and element conductances (W/m²K).
+    fn coefficients(&self, mesh: &[(Material, f64)], theta: &[f64]) -> (Vec<f64>, Vec<f64>) {
+        let mut capacity = vec![0.0; theta.len()];
+        let mut conductance = Vec::with_capacity(mesh.len());
+
+        for (e, (material, dx)) in mesh.iter().enumerate() {
+            capacity[e] += material.heat_capacity(theta[e]) * dx / 2.0;
+            capacity[e + 1] += material.heat_capacity(theta[e + 1]) * dx / 2.0;
+            conductance.push(material.conductivity((theta[e] + theta[e + 1]) / 2.0) / dx);
+        }
+
+        (capacity, conductance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::section_3::equation_3_4::standard_temp_time_curve;
+
+    fn standard(t: f64) -> f64 {
+        standard_temp_time_curve(t / 60.0)
+    }
+
+    #[test]
+    fn test_steady_state_constant_material() {
+        let layer = Layer {
+            material: Material::Constant {
+                lambda: 1.0,
+                rho: 1000.0,
+                c: 1000.0,
+            },
+            thickness: 0.1,
+        };
+        let mut wall = Wall::new(vec![layer], 10);
+        wall.exposed = Boundary::Exposed {
+            alpha_c: 100.0,
+            epsilon_m: 0.0,
+        };
+        wall.unexposed = Boundary::Exposed {
+            alpha_c: 100.0,
+            epsilon_m: 0.0,
+        };
+        let profiles = wall.solve(|_| 120.0, 3600.0, 36000.0);
+        let last = profiles.temperatures.len() - 1;
+        assert!((profiles.temperature_at(last, 0.05) - 70.0).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_concrete_slab() {
+        let wall = Wall::new(
+            vec![Layer {
+                material: Material::concrete(),
+                thickness: 0.2,
+            }],
+            20,
+        );
+        let profiles = wall.solve(standard, 60.0, 7200.0);
+        assert_eq!(profiles.positions.len(), 21);
+        assert_eq!(profiles.times.len(), 121);
+        assert!((profiles.temperature_at(120, 0.03) - 571.4267510909162).abs() < 1e-6);
+        assert!((profiles.unexposed_temperature()[120] - 39.67517122656845).abs() < 1e-6);
+        let depth = profiles.isotherm_depth(120, 500.0);
+        assert!((profiles.temperature_at(120, depth) - 500.0).abs() < 1e-9);
+        assert_eq!(profiles.isotherm_depth(0, 500.0), 0.0);
+    }
+
+    #[test]
+    fn test_insulated_wall() {
+        let layers = vec![
+            Layer {
+                material: Material::Gypsum,
+                thickness: 0.0125,
+            },
+            Layer {
+                material: Material::MineralWool { rho: 100.0 },
+                thickness: 0.05,
+            },
+            Layer {
+                material: Material::Gypsum,
+                thickness: 0.0125,
+            },
+        ];
+        let profiles = Wall::new(layers, 5).solve(standard, 30.0, 7200.0);
+        let time = profiles.insulation_time(140.0);
+        assert!((time.unwrap() - 6683.531001266998).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_adiabatic_symmetry() {
+        let mut wall = Wall::new(
+            vec![Layer {
+                material: Material::concrete(),
+                thickness: 0.1,
+            }],
+            10,
+        );
+        wall.unexposed = Boundary::Adiabatic;
+        let profiles = wall.solve(standard, 60.0, 3600.0);
+        let profile = &profiles.temperatures[60];
+        assert!(profile.windows(2).all(|w| w[0] >= w[1]));
+    }
+
+    #[test]
+    #[should_panic(expected = "each layer must have an element")]
+    fn test_no_elements() {
+        Wall::new(
+            vec![Layer {
+                material: Material::concrete(),
+                thickness: 0.1,
+            }],
+            0,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "each layer must have a positive thickness")]
+    fn test_zero_thickness_layer() {
+        Wall::new(
+            vec![
+                Layer {
+                    material: Material::concrete(),
+                    thickness: 0.1,
+                },
+                Layer {
+                    material: Material::Gypsum,
+                    thickness: 0.0,
+                },
+            ],
+            5,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the wall must have a layer")]
+    fn test_no_layers() {
+        Wall::new(vec![], 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "the time step must be positive")]
+    fn test_zero_time_step() {
+        let wall = Wall::new(
+            vec![Layer {
+                material: Material::concrete(),
+                thickness: 0.1,
+            }],
+            5,
+        );
+        wall.solve(standard, 0.0, 3600.0);
+    }
+
+    #[test]
+    fn test_temperature_at_single_node() {
+        let profiles = TemperatureProfiles {
+            positions: vec![0.0],
+            times: vec![0.0],
+            temperatures: vec![vec![20.0]],
+        };
+        assert_eq!(profiles.temperature_at(0, 0.05), 20.0);
+    }
+}
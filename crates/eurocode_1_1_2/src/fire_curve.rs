@@ -0,0 +1,356 @@
+//! Temperature-time curves for fire exposure with sampling, inversion and
+//! severity comparison. Times are in minutes and temperatures in °C.
+
+use crate::annex_a::parametric_curve::ParametricFire;
+use crate::section_3::{equation_3_4, equation_3_5, equation_3_6};
+
+/// Integration step (min) used for inversion and severity.
+const STEP: f64 = 0.1;
+
+/// Published control points of the ASTM E119 curve in °F.
+const ASTM_E119: [(f64, f64); 61] = [
+    (0.0, 68.0),
+    (5.0, 1000.0),
+    (10.0, 1300.0),
+    (15.0, 1399.0),
+    (20.0, 1462.0),
+    (25.0, 1510.0),
+    (30.0, 1550.0),
+    (35.0, 1584.0),
+    (40.0, 1613.0),
+    (45.0, 1638.0),
+    (50.0, 1661.0),
+    (55.0, 1681.0),
+    (60.0, 1700.0),
+    (65.0, 1718.0),
+    (70.0, 1735.0),
+    (75.0, 1750.0),
+    (80.0, 1765.0),
+    (85.0, 1779.0),
+    (90.0, 1792.0),
+    (95.0, 1804.0),
+    (100.0, 1815.0),
+    (105.0, 1826.0),
+    (110.0, 1835.0),
+    (115.0, 1843.0),
+    (120.0, 1850.0),
+    (130.0, 1862.0),
+    (140.0, 1875.0),
+    (150.0, 1888.0),
+    (160.0, 1900.0),
+    (170.0, 1912.0),
+    (180.0, 1925.0),
+    (190.0, 1938.0),
+    (200.0, 1950.0),
+    (210.0, 1962.0),
+    (220.0, 1975.0),
+    (230.0, 1988.0),
+    (240.0, 2000.0),
+    (250.0, 2012.0),
+    (260.0, 2025.0),
+    (270.0, 2038.0),
+    (280.0, 2050.0),
+    (290.0, 2062.0),
+    (300.0, 2075.0),
+    (310.0, 2088.0),
+    (320.0, 2100.0),
+    (330.0, 2112.0),
+    (340.0, 2125.0),
+    (350.0, 2138.0),
+    (360.0, 2150.0),
+    (370.0, 2162.0),
+    (380.0, 2175.0),
+    (390.0, 2188.0),
+    (400.0, 2200.0),
+    (410.0, 2212.0),
+    (420.0, 2225.0),
+    (430.0, 2238.0),
+    (440.0, 2250.0),
+    (450.0, 2262.0),
+    (460.0, 2275.0),
+    (470.0, 2288.0),
+    (480.0, 2300.0),
+];
+
+/// Tabulated values of the ISO 834 standard curve.
+const ISO_834: [(f64, f64); 11] = [
+    (0.0, 20.0),
+    (5.0, 576.0),
+    (10.0, 678.0),
+    (15.0, 739.0),
+    (30.0, 842.0),
+    (60.0, 945.0),
+    (90.0, 1006.0),
+    (120.0, 1049.0),
+    (180.0, 1110.0),
+    (240.0, 1153.0),
+    (360.0, 1214.0),
+];
+
+/// Rijkswaterstaat tunnel curve.
+const RWS: [(f64, f64); 8] = [
+    (0.0, 20.0),
+    (3.0, 890.0),
+    (5.0, 1140.0),
+    (10.0, 1200.0),
+    (30.0, 1300.0),
+    (60.0, 1350.0),
+    (90.0, 1300.0),
+    (120.0, 1200.0),
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FireCurve {
+    /// Standard temperature-time curve, equation 3.4.
+    Standard,
+    /// External fire curve, equation 3.5.
+    External,
+    /// Hydrocarbon curve, equation 3.6.
+    Hydrocarbon,
+    AstmE119,
+    Iso834Tabulated,
+    Rws,
+    /// Parametric curve of annex A.
+    Parametric(ParametricFire),
+    /// User-defined curve through `(time, temperature)` points in increasing
+    /// time, constant beyond the last point. Build it with
+    /// [`FireCurve::piecewise_linear`] to check the points.
+    PiecewiseLinear(Vec<(f64, f64)>),
+}
+
+impl FireCurve {
+    /// Piecewise linear curve through `points`, or `None` if there are no
+    /// points or the times are not in increasing order.
+    pub fn piecewise_linear(points: Vec<(f64, f64)>) -> Option<Self> {
+        let sorted = points.windows(2).all(|w| w[0].0 <= w[1].0);
+        (!points.is_empty() && sorted).then_some(FireCurve::PiecewiseLinear(points))
+    }
+
+    /// Gas temperature (°C) at time `t` (min).
+    pub fn temperature(&self, t: f64) -> f64 {
+        match self {
+            FireCurve::Standard => equation_3_4::standard_temp_time_curve(t),
+            FireCurve::External => equation_3_5::external_temp_time_curve(t),
+            FireCurve::Hydrocarbon => equation_3_6::hydrocarbon_temp_time_curve(t),
+            FireCurve::AstmE119 => (interpolate(&ASTM_E119, t) - 32.0) * 5.0 / 9.0,
+            FireCurve::Iso834Tabulated => interpolate(&ISO_834, t),
+            FireCurve::Rws => interpolate(&RWS, t),
+            FireCurve::Parametric(fire) => fire.temperature(t),
+            FireCurve::PiecewiseLinear(points) => interpolate(points, t),
+        }
+    }
+
+    /// Temperatures (°C) at the given times (min).
+    pub fn temperatures(&self, times: &[f64]) -> Vec<f64> {
+        times.iter().map(|&t| self.temperature(t)).collect()
+    }
+
+    /// `(time, temperature)` pairs at times 0, `step`, 2 `step`, ... up to
+    /// `t_end` (min). `step` must be positive.
+    pub fn sample(&self, step: f64, t_end: f64) -> Vec<(f64, f64)> {
+        assert!(step > 0.0, "the time step must be positive");
+        let steps = (t_end / step).round() as usize;
+        (0..=steps)
+            .map(|i| {
+                let t = i as f64 * step;
+                (t, self.temperature(t))
+            })
+            .collect()
+    }
+
+    /// Sampled curve as comma separated values with a header row.
+    pub fn to_csv(&self, step: f64, t_end: f64) -> String {
+        let mut csv = String::from("time_min,temperature_c\n");
+        for (t, theta) in self.sample(step, t_end) {
+            csv.push_str(&format!("{},{}\n", t, theta));
+        }
+        csv
+    }
+
+    /// First time (min) at which the curve reaches `theta` (°C), or `None`
+    /// if it is not reached by `t_end` (min).
+    pub fn time_to_temperature(&self, theta: f64, t_end: f64) -> Option<f64> {
+        if self.temperature(0.0) >= theta {
+            return Some(0.0);
+        }
+
+        let steps = (t_end / STEP).ceil() as usize;
+        let i = (1..=steps).find(|&i| self.temperature((i as f64 * STEP).min(t_end)) >= theta)?;
+
+        let mut lower = (i - 1) as f64 * STEP;
+        let mut upper = (i as f64 * STEP).min(t_end);
+        for _ in 0..60 {
+            let mid = 0.5 * (lower + upper);
+            if self.temperature(mid) >= theta {
+                upper = mid;
+            } else {
+                lower = mid;
+            }
+        }
+
+        Some(upper)
+    }
+
+    /// Area (°C·min) between the curve and `theta_base` (°C) up to `t_end`
+    /// (min), counting only temperatures above the base, as used by Ingberg
+    /// to compare fire severity.
+    pub fn severity(&self, t_end: f64, theta_base: f64) -> f64 {
+        let steps = (t_end / STEP).ceil().max(1.0) as usize;
+        let dt = t_end / steps as f64;
+
+        (0..steps)
+            .map(|i| {
+                let a = (self.temperature(i as f64 * dt) - theta_base).max(0.0);
+                let b = (self.temperature((i + 1) as f64 * dt) - theta_base).max(0.0);
+                0.5 * (a + b) * dt
+            })
+            .sum()
+    }
+
+    /// Duration (min) of the standard curve with the same severity above
+    /// `theta_base` (°C) as this curve up to `t_end` (min).
+    pub fn equivalent_standard_time(&self, t_end: f64, theta_base: f64) -> f64 {
+        let target = self.severity(t_end, theta_base);
+        let standard = FireCurve::Standard;
+
+        let mut upper = t_end.max(1.0);
+        while standard.severity(upper, theta_base) < target {
+            upper *= 2.0;
+        }
+
+        let mut lower = 0.0;
+        for _ in 0..40 {
+            let mid = 0.5 * (lower + upper);
+            if standard.severity(mid, theta_base) < target {
+                lower = mid;
+            } else {
+                upper = mid;
+            }
+        }
+
+        0.5 * (lower + upper)
+    }
+}
+
+/// Linear interpolation between `(time, temperature)` points, constant
+/// beyond the first and last points. An empty table gives NaN.
+fn interpolate(points: &[(f64, f64)], t: f64) -> f64 {
+    let Some(&(t_first, theta_first)) = points.first() else {
+        return f64::NAN;
+    };
+    if t <= t_first {
+        return theta_first;
+    }
+
+    for i in 1..points.len() {
+        if t <= points[i].0 {
+            let (t0, theta0) = points[i - 1];
+            let (t1, theta1) = points[i];
+            return theta0 + (t - t0) / (t1 - t0) * (theta1 - theta0);
+        }
+    }
+
+    points[points.len() - 1].1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::annex_a::equation_a7::FireGrowthRate;
+
+    #[test]
+    fn test_nominal_curves() {
+        assert!((FireCurve::Standard.temperature(10.0) - 678.42733151313).abs() < 1e-6);
+        assert_eq!(
+            FireCurve::External.temperature(10.0),
+            equation_3_5::external_temp_time_curve(10.0)
+        );
+        assert_eq!(
+            FireCurve::Hydrocarbon.temperature(10.0),
+            equation_3_6::hydrocarbon_temp_time_curve(10.0)
+        );
+    }
+
+    #[test]
+    fn test_tabulated_curves() {
+        assert!((FireCurve::AstmE119.temperature(0.0) - 20.0).abs() < 1e-9);
+        assert!((FireCurve::AstmE119.temperature(45.0) - 892.2222222222222).abs() < 1e-9);
+        assert!((FireCurve::AstmE119.temperature(125.0) - 1013.3333333333334).abs() < 1e-9);
+        assert!((FireCurve::AstmE119.temperature(480.0) - 1260.0).abs() < 1e-9);
+        assert_eq!(FireCurve::Iso834Tabulated.temperature(60.0), 945.0);
+        assert_eq!(FireCurve::Rws.temperature(4.0), 1015.0);
+        assert_eq!(FireCurve::Rws.temperature(180.0), 1200.0);
+    }
+
+    #[test]
+    fn test_piecewise_linear() {
+        let curve = FireCurve::PiecewiseLinear(vec![(0.0, 20.0), (10.0, 820.0), (40.0, 820.0)]);
+        assert_eq!(curve.temperature(5.0), 420.0);
+        assert_eq!(curve.temperature(60.0), 820.0);
+    }
+
+    #[test]
+    fn test_piecewise_linear_points() {
+        let curve = FireCurve::piecewise_linear(vec![(0.0, 20.0), (10.0, 820.0)]);
+        assert_eq!(
+            curve,
+            Some(FireCurve::PiecewiseLinear(vec![(0.0, 20.0), (10.0, 820.0)]))
+        );
+        assert_eq!(FireCurve::piecewise_linear(vec![]), None);
+        assert_eq!(
+            FireCurve::piecewise_linear(vec![(10.0, 820.0), (0.0, 20.0)]),
+            None
+        );
+        assert!(FireCurve::PiecewiseLinear(vec![]).temperature(5.0).is_nan());
+    }
+
+    #[test]
+    fn test_parametric() {
+        let fire = ParametricFire::new(0.06, 1000.0, 300.0, FireGrowthRate::Medium);
+        let curve = FireCurve::Parametric(fire);
+        assert_eq!(curve.temperature(30.0), fire.temperature(30.0));
+    }
+
+    #[test]
+    fn test_sample_and_csv() {
+        let curve = FireCurve::PiecewiseLinear(vec![(0.0, 20.0), (10.0, 820.0)]);
+        let samples = curve.sample(5.0, 10.0);
+        assert_eq!(samples, vec![(0.0, 20.0), (5.0, 420.0), (10.0, 820.0)]);
+        assert_eq!(
+            curve.to_csv(5.0, 10.0),
+            "time_min,temperature_c\n0,20\n5,420\n10,820\n"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the time step must be positive")]
+    fn test_sample_zero_step() {
+        FireCurve::Standard.sample(0.0, 60.0);
+    }
+
+    #[test]
+    fn test_time_to_temperature() {
+        let time = FireCurve::Standard.time_to_temperature(678.42733151313, 60.0);
+        assert!((time.unwrap() - 10.0).abs() < 1e-6);
+        assert_eq!(FireCurve::Standard.time_to_temperature(1200.0, 60.0), None);
+        let rws = FireCurve::Rws.time_to_temperature(1015.0, 120.0);
+        assert!((rws.unwrap() - 4.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_severity() {
+        let curve = FireCurve::PiecewiseLinear(vec![(0.0, 20.0), (10.0, 820.0), (40.0, 820.0)]);
+        let result = curve.severity(40.0, 20.0);
+        assert!((result - 28000.0).abs() < 1e-6);
+        let above_base = curve.severity(40.0, 420.0);
+        assert!((above_base - 13000.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_equivalent_standard_time() {
+        let result = FireCurve::Standard.equivalent_standard_time(60.0, 20.0);
+        assert!((result - 60.0).abs() < 1e-6);
+        let hydrocarbon = FireCurve::Hydrocarbon.equivalent_standard_time(60.0, 20.0);
+        assert!(hydrocarbon > 60.0);
+    }
+}
@@ -1 +1,8 @@
+pub mod annex_a;
+pub mod annex_b;
+pub mod annex_c;
+pub mod annex_e;
+pub mod annex_f;
+pub mod conduction;
+pub mod fire_curve;
 pub mod section_3;
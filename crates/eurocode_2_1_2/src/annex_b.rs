@@ -0,0 +1 @@
+pub mod isotherm_method;
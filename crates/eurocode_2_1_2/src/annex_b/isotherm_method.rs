@@ -0,0 +1,309 @@
+//! 500 °C isotherm method for the sagging moment resistance of slabs and
+//! beams heated from below. Temperatures come from the one-dimensional
+//! conduction solver of EN 1991-1-2; beam bar temperatures combine the
+//! bottom and side profiles with a product solution and corner effects on
+//! the isotherm are neglected. Dimensions are in mm, areas in mm², strengths
+//! in MPa and times in minutes, with the material partial factors taken as
+//! 1.0.
+
+use eurocode_1_1_2::conduction::boundary::Boundary;
+use eurocode_1_1_2::conduction::material::Material;
+use eurocode_1_1_2::conduction::wall::{Layer, TemperatureProfiles, Wall};
+use eurocode_1_1_2::fire_curve::FireCurve;
+
+use crate::section_3::reinforcement_strength::{Manufacture, k_s};
+
+/// Width (mm) of the strip used for slabs.
+const SLAB_WIDTH: f64 = 1000.0;
+/// Target element size (mm) of the conduction mesh.
+const ELEMENT_SIZE: f64 = 5.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Member {
+    /// Slab of depth `h` heated from below, analysed per metre width.
+    Slab { h: f64 },
+    /// Beam of width `b` and depth `h` heated from below and both sides.
+    Beam { b: f64, h: f64 },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bar {
+    /// Cross-sectional area (mm²), or area per metre width for slabs.
+    pub area: f64,
+    /// Axis distance from the bottom face (mm).
+    pub axis_bottom: f64,
+    /// Axis distance from the nearest side face (mm), ignored for slabs.
+    pub axis_side: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IsothermMethod {
+    pub member: Member,
+    /// Tension reinforcement.
+    pub bars: Vec<Bar>,
+    /// Characteristic compressive strength of the concrete (MPa).
+    pub f_ck: f64,
+    /// Characteristic yield strength of the reinforcement (MPa).
+    pub f_yk: f64,
+    pub manufacture: Manufacture,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReducedSection {
+    /// Depth of the 500 °C isotherm from the bottom face (mm).
+    pub a_500_bottom: f64,
+    /// Depth of the 500 °C isotherm from each side face (mm), zero for slabs.
+    pub a_500_side: f64,
+    /// Width of the reduced cross-section (mm).
+    pub b_fi: f64,
+    /// Depth of the reduced cross-section (mm).
+    pub h_fi: f64,
+    /// Temperature of each bar (°C).
+    pub bar_temperatures: Vec<f64>,
+    /// Depth of the compression zone (mm), not more than `h_fi`.
+    pub x: f64,
+    /// The reinforcement force exceeds the compression the reduced section
+    /// can carry, so the force is limited to that of a compression zone of
+    /// depth `h_fi`.
+    pub over_reinforced: bool,
+    /// Sagging moment resistance (kNm), or kNm/m for slabs.
+    pub moment_resistance: f64,
+}
+
+impl IsothermMethod {
+    /// Reduced cross-section and moment resistance after `duration`
+    /// minutes of exposure to `curve`.
+    pub fn analyse(&self, curve: &FireCurve, duration: f64) -> ReducedSection {
+        let (b, h) = match self.member {
+            Member::Slab { h } => (SLAB_WIDTH, h),
+            Member::Beam { b, h } => (b, h),
+        };
+        let theta_g = curve.temperature(duration);
+
+        let bottom = profiles(h, Boundary::unexposed(), curve, duration);
+        let step = bottom.times.len() - 1;
+        let a_500_bottom = bottom.isotherm_depth(step, 500.0) * 1000.0;
+
+        let (a_500_side, side) = match self.member {
+            Member::Slab { .. } => (0.0, None),
+            Member::Beam { b, .. } => {
+                let side = profiles(b / 2.0, Boundary::Adiabatic, curve, duration);
+                (side.isotherm_depth(step, 500.0) * 1000.0, Some(side))
+            }
+        };
+
+        let bar_temperatures: Vec<f64> = self
+            .bars
+            .iter()
+            .map(|bar| {
+                let theta_y = bottom.temperature_at(step, bar.axis_bottom / 1000.0);
+                match &side {
+                    None => theta_y,
+                    Some(side) => {
+                        let theta_x = side.temperature_at(step, bar.axis_side / 1000.0);
+                        if theta_g <= 20.0 {
+                            return theta_x.max(theta_y);
+                        }
+                        let r_x = (theta_x - 20.0) / (theta_g - 20.0);
+                        let r_y = (theta_y - 20.0) / (theta_g - 20.0);
+                        20.0 + (theta_g - 20.0) * (1.0 - (1.0 - r_x) * (1.0 - r_y))
+                    }
+                }
+            })
+            .collect();
+
+        let b_fi = (b - 2.0 * a_500_side).max(0.0);
+        let h_fi = (h - a_500_bottom).max(0.0);
+
+        let forces: Vec<f64> = self
+            .bars
+            .iter()
+            .zip(&bar_temperatures)
+            .map(|(bar, &theta)| bar.area * k_s(theta, self.manufacture) * self.f_yk)
+            .collect();
+        let total: f64 = forces.iter().sum();
+
+        let (x, over_reinforced, moment_resistance) = if b_fi > 0.0 && h_fi > 0.0 {
+            let compression = 0.8 * self.f_ck * b_fi * h_fi;
+            let over_reinforced = total > compression;
+            let scale = if over_reinforced {
+                compression / total
+            } else {
+                1.0
+            };
+            let x = scale * total / (0.8 * self.f_ck * b_fi);
+            let moment: f64 = self
+                .bars
+                .iter()
+                .zip(&forces)
+                .map(|(bar, force)| scale * force * (h - bar.axis_bottom - 0.4 * x))
+                .sum();
+            (x, over_reinforced, moment / 1e6)
+        } else {
+            (0.0, false, 0.0)
+        };
+
+        ReducedSection {
+            a_500_bottom,
+            a_500_side,
+            b_fi,
+            h_fi,
+            bar_temperatures,
+            x,
+            over_reinforced,
+            moment_resistance,
+        }
+    }
+}
+
+/// Concrete temperature profiles through `thickness` (mm) heated on one
+/// face, output every minute up to `duration`.
+fn profiles(
+    thickness: f64,
+    unexposed: Boundary,
+    curve: &FireCurve,
+    duration: f64,
+) -> TemperatureProfiles {
+    let mut wall = Wall::new(
+        vec![Layer {
+            material: Material::concrete(),
+            thickness: thickness / 1000.0,
+        }],
+        (thickness / ELEMENT_SIZE).ceil().max(1.0) as usize,
+    );
+    wall.unexposed = unexposed;
+
+    wall.solve(|t| curve.temperature(t / 60.0), 60.0, duration * 60.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slab() -> IsothermMethod {
+        IsothermMethod {
+            member: Member::Slab { h: 200.0 },
+            bars: vec![Bar {
+                area: 524.0,
+                axis_bottom: 30.0,
+                axis_side: 0.0,
+            }],
+            f_ck: 30.0,
+            f_yk: 500.0,
+            manufacture: Manufacture::HotRolled,
+        }
+    }
+
+    #[test]
+    fn test_slab_ambient() {
+        let result = slab().analyse(&FireCurve::Standard, 0.0);
+
+        assert_eq!(result.a_500_bottom, 0.0);
+        assert_eq!(result.h_fi, 200.0);
+        assert_eq!(result.bar_temperatures, vec![20.0]);
+
+        let x = 524.0 * 500.0 / (0.8 * 30.0 * 1000.0);
+        let expected = 524.0 * 500.0 * (170.0 - 0.4 * x) / 1e6;
+        assert!((result.x - x).abs() < 1e-9);
+        assert!((result.moment_resistance - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_slab_standard_fire() {
+        let r60 = slab().analyse(&FireCurve::Standard, 60.0);
+        assert!((r60.a_500_bottom - 21.63905344527609).abs() < 1e-6);
+        assert!((r60.bar_temperatures[0] - 397.2514412368486).abs() < 1e-6);
+        // Below 400 °C the bar keeps its full strength, F = 524 × 500 N.
+        let x = 262000.0 / (0.8 * 30.0 * 1000.0);
+        assert!((r60.x - x).abs() < 1e-9);
+        assert!((r60.moment_resistance - 262000.0 * (170.0 - 0.4 * x) / 1e6).abs() < 1e-9);
+
+        let r120 = slab().analyse(&FireCurve::Standard, 120.0);
+        assert!((r120.a_500_bottom - 36.99476495757081).abs() < 1e-6);
+        assert!((r120.bar_temperatures[0] - 572.073442152762).abs() < 1e-6);
+        // k_s between 0.625 at 550 °C and 0.47 at 600 °C.
+        let k_s = 0.625 - (572.073442152762 - 550.0) / 50.0 * 0.155;
+        let force = 262000.0 * k_s;
+        let x = force / (0.8 * 30.0 * 1000.0);
+        assert!((r120.x - x).abs() < 1e-6);
+        assert!((r120.moment_resistance - force * (170.0 - 0.4 * x) / 1e6).abs() < 1e-6);
+        assert!(!r120.over_reinforced);
+    }
+
+    #[test]
+    fn test_beam_corner_bar_hotter_than_slab() {
+        let bar = Bar {
+            area: 314.0,
+            axis_bottom: 40.0,
+            axis_side: 40.0,
+        };
+        let beam = IsothermMethod {
+            member: Member::Beam { b: 300.0, h: 500.0 },
+            bars: vec![bar, bar],
+            f_ck: 30.0,
+            f_yk: 500.0,
+            manufacture: Manufacture::HotRolled,
+        };
+        let result = beam.analyse(&FireCurve::Standard, 90.0);
+        let slab_bar = IsothermMethod {
+            member: Member::Slab { h: 500.0 },
+            bars: vec![bar],
+            ..beam.clone()
+        }
+        .analyse(&FireCurve::Standard, 90.0);
+
+        assert!((result.a_500_side - 29.88020839963569).abs() < 1e-6);
+        assert!((result.b_fi - (300.0 - 2.0 * result.a_500_side)).abs() < 1e-9);
+        assert!((result.bar_temperatures[0] - 632.4282059019067).abs() < 1e-6);
+        assert!(result.bar_temperatures[0] > slab_bar.bar_temperatures[0]);
+
+        // k_s between 0.47 at 600 °C and 0.23 at 700 °C.
+        let k_s = 0.47 - (632.4282059019067 - 600.0) / 100.0 * 0.24;
+        let force = 2.0 * 314.0 * 500.0 * k_s;
+        let x = force / (0.8 * 30.0 * result.b_fi);
+        assert!((result.x - x).abs() < 1e-6);
+        assert!((result.moment_resistance - force * (460.0 - 0.4 * x) / 1e6).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_beam_ambient() {
+        let bar = Bar {
+            area: 314.0,
+            axis_bottom: 40.0,
+            axis_side: 40.0,
+        };
+        let beam = IsothermMethod {
+            member: Member::Beam { b: 300.0, h: 500.0 },
+            bars: vec![bar, bar],
+            f_ck: 30.0,
+            f_yk: 500.0,
+            manufacture: Manufacture::HotRolled,
+        };
+        let result = beam.analyse(&FireCurve::Standard, 0.0);
+
+        assert_eq!(result.bar_temperatures, vec![20.0, 20.0]);
+        let x = 314000.0 / (0.8 * 30.0 * 300.0);
+        assert!((result.x - x).abs() < 1e-9);
+        assert!((result.moment_resistance - 314000.0 * (460.0 - 0.4 * x) / 1e6).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_over_reinforced() {
+        let method = IsothermMethod {
+            member: Member::Slab { h: 100.0 },
+            bars: vec![Bar {
+                area: 20000.0,
+                axis_bottom: 30.0,
+                axis_side: 0.0,
+            }],
+            f_ck: 20.0,
+            ..slab()
+        };
+        let result = method.analyse(&FireCurve::Standard, 0.0);
+
+        // The concrete carries at most 0.8 × 20 × 1000 × 100 N.
+        assert!(result.over_reinforced);
+        assert!((result.x - 100.0).abs() < 1e-9);
+        assert!((result.moment_resistance - 1.6e6 * (70.0 - 40.0) / 1e6).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,2 @@
+pub mod annex_b;
+pub mod section_3;
@@ -0,0 +1,2 @@
+pub mod concrete_strength;
+pub mod reinforcement_strength;
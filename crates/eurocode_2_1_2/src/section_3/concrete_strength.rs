@@ -0,0 +1,61 @@
+//! Reduction of the characteristic compressive strength of normal weight
+//! concrete at elevated temperatures.
+
+const TEMPERATURES: [f64; 13] = [
+    20.0, 100.0, 200.0, 300.0, 400.0, 500.0, 600.0, 700.0, 800.0, 900.0, 1000.0, 1100.0, 1200.0,
+];
+const SILICEOUS: [f64; 13] = [
+    1.00, 1.00, 0.95, 0.85, 0.75, 0.60, 0.45, 0.30, 0.15, 0.08, 0.04, 0.01, 0.00,
+];
+const CALCAREOUS: [f64; 13] = [
+    1.00, 1.00, 0.97, 0.91, 0.85, 0.74, 0.60, 0.43, 0.27, 0.15, 0.06, 0.02, 0.00,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Aggregate {
+    Siliceous,
+    Calcareous,
+}
+
+/// Reduction factor for the compressive strength of concrete at
+/// temperature `theta` (°C), interpolated linearly between tabulated values.
+pub fn k_c(theta: f64, aggregate: Aggregate) -> f64 {
+    let values = match aggregate {
+        Aggregate::Siliceous => &SILICEOUS,
+        Aggregate::Calcareous => &CALCAREOUS,
+    };
+
+    interpolate(&TEMPERATURES, values, theta)
+}
+
+pub(crate) fn interpolate(xs: &[f64], ys: &[f64], x: f64) -> f64 {
+    if x <= xs[0] {
+        return ys[0];
+    }
+
+    for i in 1..xs.len() {
+        if x <= xs[i] {
+            let ratio = (x - xs[i - 1]) / (xs[i] - xs[i - 1]);
+            return ys[i - 1] + ratio * (ys[i] - ys[i - 1]);
+        }
+    }
+
+    ys[ys.len() - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_k_c_siliceous() {
+        assert_eq!(k_c(20.0, Aggregate::Siliceous), 1.0);
+        assert!((k_c(550.0, Aggregate::Siliceous) - 0.525).abs() < 1e-9);
+        assert_eq!(k_c(1300.0, Aggregate::Siliceous), 0.0);
+    }
+
+    #[test]
+    fn test_k_c_calcareous() {
+        assert!((k_c(500.0, Aggregate::Calcareous) - 0.74).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,48 @@
+//! Reduction of the characteristic strength of reinforcing steel at
+//! elevated temperatures, for tension reinforcement with strains of at
+//! least 2 %.
+
+use super::concrete_strength::interpolate;
+
+const TEMPERATURES: [f64; 13] = [
+    20.0, 100.0, 200.0, 300.0, 400.0, 500.0, 600.0, 700.0, 800.0, 900.0, 1000.0, 1100.0, 1200.0,
+];
+const HOT_ROLLED: [f64; 13] = [
+    1.00, 1.00, 1.00, 1.00, 1.00, 0.78, 0.47, 0.23, 0.11, 0.06, 0.04, 0.02, 0.00,
+];
+const COLD_WORKED: [f64; 13] = [
+    1.00, 1.00, 1.00, 1.00, 0.94, 0.67, 0.40, 0.12, 0.11, 0.08, 0.05, 0.03, 0.00,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Manufacture {
+    HotRolled,
+    ColdWorked,
+}
+
+/// Reduction factor for the yield strength of class N reinforcement at
+/// temperature `theta` (°C), interpolated linearly between tabulated values.
+pub fn k_s(theta: f64, manufacture: Manufacture) -> f64 {
+    let values = match manufacture {
+        Manufacture::HotRolled => &HOT_ROLLED,
+        Manufacture::ColdWorked => &COLD_WORKED,
+    };
+
+    interpolate(&TEMPERATURES, values, theta)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_k_s_hot_rolled() {
+        assert_eq!(k_s(350.0, Manufacture::HotRolled), 1.0);
+        assert!((k_s(550.0, Manufacture::HotRolled) - 0.625).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_k_s_cold_worked() {
+        assert!((k_s(500.0, Manufacture::ColdWorked) - 0.67).abs() < 1e-9);
+    }
+}
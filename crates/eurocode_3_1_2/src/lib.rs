@@ -0,0 +1,2 @@
+pub mod section_3;
+pub mod section_4;
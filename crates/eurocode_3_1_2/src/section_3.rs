@@ -0,0 +1,2 @@
+pub mod equation_3_2;
+pub mod unit_mass;
@@ -0,0 +1,46 @@
+/// Specific heat of steel (J/kgK) at steel temperature `theta_a` (°C).
+pub fn specific_heat(theta_a: f64) -> f64 {
+    if theta_a < 600.0 {
+        425.0 + 7.73e-1 * theta_a - 1.69e-3 * theta_a.powi(2) + 2.22e-6 * theta_a.powi(3)
+    } else if theta_a < 735.0 {
+        666.0 + 13002.0 / (738.0 - theta_a)
+    } else if theta_a < 900.0 {
+        545.0 + 17820.0 / (theta_a - 731.0)
+    } else {
+        650.0
+    }
+}
+
+#[cfg(not(coverage))]
+pub fn specific_heat_equation(c_a: String, theta_a: String) -> String {
+    format!(
+        "{c} = \\begin{{cases}} 425 + 7.73 \\times 10^{{-1}} {t} - 1.69 \\times 10^{{-3}} {t}^2 + 2.22 \\times 10^{{-6}} {t}^3 & 20 \\leq {t} < 600 \\\\ 666 + \\frac{{13002}}{{738 - {t}}} & 600 \\leq {t} < 735 \\\\ 545 + \\frac{{17820}}{{{t} - 731}} & 735 \\leq {t} < 900 \\\\ 650 & 900 \\leq {t} \\leq 1200 \\end{{cases}}",
+        c = c_a,
+        t = theta_a,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_specific_heat_ambient() {
+        let result = specific_heat(20.0);
+        let expected = 439.80176;
+        assert!((result - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_specific_heat_peak() {
+        let result = specific_heat(735.0);
+        let expected = 545.0 + 17820.0 / 4.0;
+        assert!((result - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_specific_heat_ranges() {
+        assert!((specific_heat(700.0) - 1008.1578947368421).abs() < 1e-9);
+        assert_eq!(specific_heat(1000.0), 650.0);
+    }
+}
@@ -0,0 +1,14 @@
+/// Unit mass of steel (kg/m³), independent of the steel temperature.
+pub fn unit_mass() -> f64 {
+    7850.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unit_mass() {
+        assert_eq!(unit_mass(), 7850.0);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod equation_4_22;
+pub mod equation_4_25;
+pub mod equation_4_26;
+pub mod equation_4_27;
+pub mod member_temperature;
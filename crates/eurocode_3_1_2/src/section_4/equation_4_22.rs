@@ -0,0 +1,24 @@
+/// Critical temperature of steel (°C) for a degree of utilisation `mu_0`.
+pub fn critical_temperature(mu_0: f64) -> f64 {
+    39.19 * (1.0 / (0.9674 * mu_0.powf(3.833)) - 1.0).ln() + 482.0
+}
+
+#[cfg(not(coverage))]
+pub fn critical_temperature_equation(theta_a_cr: String, mu_0: String) -> String {
+    format!(
+        "{} = 39.19 \\ln \\left[ \\frac{{1}}{{0.9674 \\, {}^{{3.833}}}} - 1 \\right] + 482",
+        theta_a_cr, mu_0
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_critical_temperature() {
+        let result = critical_temperature(0.6);
+        let expected = 554.2792987902315;
+        assert!((result - expected).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,40 @@
+/// Increase of temperature (°C) of an unprotected steel member during the
+/// time interval `delta_t` (s).
+pub fn temperature_increase_unprotected(
+    k_sh: f64,
+    a_m_v: f64,
+    c_a: f64,
+    rho_a: f64,
+    h_net: f64,
+    delta_t: f64,
+) -> f64 {
+    k_sh * a_m_v / (c_a * rho_a) * h_net * delta_t
+}
+
+#[cfg(not(coverage))]
+pub fn temperature_increase_unprotected_equation(
+    delta_theta_a: String,
+    k_sh: String,
+    a_m_v: String,
+    c_a: String,
+    rho_a: String,
+    h_net: String,
+    delta_t: String,
+) -> String {
+    format!(
+        "{} = {} \\frac{{{}}}{{{} \\, {}}} {} \\, {}",
+        delta_theta_a, k_sh, a_m_v, c_a, rho_a, h_net, delta_t
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_temperature_increase_unprotected() {
+        let result = temperature_increase_unprotected(1.0, 200.0, 600.0, 7850.0, 50000.0, 5.0);
+        let expected = 10.615711252653927;
+        assert!((result - expected).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,37 @@
+/// Correction factor for the shadow effect of I-sections under nominal fire
+/// actions, from the box value `a_m_v_b` and section factor `a_m_v` (1/m).
+pub fn shadow_factor_i_section(a_m_v_b: f64, a_m_v: f64) -> f64 {
+    0.9 * a_m_v_b / a_m_v
+}
+
+/// Correction factor for the shadow effect of all other cases.
+pub fn shadow_factor(a_m_v_b: f64, a_m_v: f64) -> f64 {
+    a_m_v_b / a_m_v
+}
+
+#[cfg(not(coverage))]
+pub fn shadow_factor_i_section_equation(k_sh: String, a_m_v_b: String, a_m_v: String) -> String {
+    format!("{} = 0.9 \\frac{{{}}}{{{}}}", k_sh, a_m_v_b, a_m_v)
+}
+
+#[cfg(not(coverage))]
+pub fn shadow_factor_equation(k_sh: String, a_m_v_b: String, a_m_v: String) -> String {
+    format!("{} = \\frac{{{}}}{{{}}}", k_sh, a_m_v_b, a_m_v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shadow_factor_i_section() {
+        let result = shadow_factor_i_section(140.0, 180.0);
+        assert!((result - 0.7).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_shadow_factor() {
+        let result = shadow_factor(140.0, 200.0);
+        assert!((result - 0.7).abs() < 1e-9);
+    }
+}
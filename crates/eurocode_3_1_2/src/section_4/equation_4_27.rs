@@ -0,0 +1,103 @@
+/// Increase of temperature (°C) of an insulated steel member during the time
+/// interval `delta_t` (s), using the heat transfer factor and the `phi`
+/// factor below. The result is not negative while the gas temperature
+/// increases.
+pub fn temperature_increase_protected(
+    k_p: f64,
+    phi: f64,
+    theta_g: f64,
+    theta_a: f64,
+    delta_t: f64,
+    delta_theta_g: f64,
+) -> f64 {
+    let delta_theta_a = k_p * (theta_g - theta_a) / (1.0 + phi / 3.0) * delta_t
+        - ((phi / 10.0).exp() - 1.0) * delta_theta_g;
+
+    if delta_theta_g > 0.0 {
+        delta_theta_a.max(0.0)
+    } else {
+        delta_theta_a
+    }
+}
+
+/// Heat transfer factor (1/s) for protection of thermal conductivity
+/// `lambda_p` (W/mK) and thickness `d_p` (m), with section factor `a_p_v`
+/// (1/m).
+pub fn heat_transfer_factor(lambda_p: f64, a_p_v: f64, d_p: f64, c_a: f64, rho_a: f64) -> f64 {
+    lambda_p * a_p_v / (d_p * c_a * rho_a)
+}
+
+/// Ratio of the heat stored in the protection to that stored in the steel,
+/// for protection specific heat `c_p` (J/kgK) and density `rho_p` (kg/m³).
+pub fn phi_factor(c_p: f64, rho_p: f64, d_p: f64, a_p_v: f64, c_a: f64, rho_a: f64) -> f64 {
+    c_p * rho_p / (c_a * rho_a) * d_p * a_p_v
+}
+
+#[cfg(not(coverage))]
+pub fn temperature_increase_protected_equation(
+    delta_theta_a: String,
+    lambda_p: String,
+    a_p_v: String,
+    d_p: String,
+    c_a: String,
+    rho_a: String,
+    phi: String,
+) -> String {
+    format!(
+        "{dta} = \\frac{{{l} {a} / {d}}}{{{c} {r}}} \\frac{{\\theta_{{g,t}} - \\theta_{{a,t}}}}{{1 + {p} / 3}} \\Delta t - \\left( e^{{{p} / 10}} - 1 \\right) \\Delta \\theta_{{g,t}}",
+        dta = delta_theta_a,
+        l = lambda_p,
+        a = a_p_v,
+        d = d_p,
+        c = c_a,
+        r = rho_a,
+        p = phi,
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn phi_factor_equation(
+    phi: String,
+    c_p: String,
+    rho_p: String,
+    d_p: String,
+    a_p_v: String,
+    c_a: String,
+) -> String {
+    format!(
+        "{} = \\frac{{{} {}}}{{{} \\rho_a}} {} \\, {}",
+        phi, c_p, rho_p, c_a, d_p, a_p_v
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_heat_transfer_factor() {
+        let result = heat_transfer_factor(0.12, 150.0, 0.02, 600.0, 7850.0);
+        let expected = 1.910828025477707e-4;
+        assert!((result - expected).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_phi_factor() {
+        let result = phi_factor(1200.0, 300.0, 0.02, 150.0, 600.0, 7850.0);
+        let expected = 0.22929936305732485;
+        assert!((result - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_temperature_increase_protected() {
+        let result = temperature_increase_protected(2e-4, 0.3, 800.0, 200.0, 30.0, 5.0);
+        let expected = 3.120454602959688;
+        assert!((result - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_temperature_increase_protected_not_negative() {
+        let result = temperature_increase_protected(2e-4, 1.5, 400.0, 390.0, 30.0, 10.0);
+        assert_eq!(result, 0.0);
+    }
+}
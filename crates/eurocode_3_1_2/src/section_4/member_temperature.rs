@@ -0,0 +1,276 @@
+//! Incremental temperature of steel members driven by a gas temperature
+//! curve. Times are in seconds and temperatures in °C; nominal curves from
+//! EN 1991-1-2 take minutes, so wrap them as `|t| curve(t / 60.0)`.
+
+use eurocode_1_1_2::section_3::{equation_3_1, equation_3_2, equation_3_3};
+
+use super::{equation_4_25, equation_4_27};
+use crate::section_3::{equation_3_2 as specific_heat, unit_mass};
+
+/// Largest time step (s) for unprotected members, 4.2.5.1(4).
+pub const MAX_STEP_UNPROTECTED: f64 = 5.0;
+/// Largest time step (s) for protected members, 4.2.5.2(3).
+pub const MAX_STEP_PROTECTED: f64 = 30.0;
+
+/// Properties of fire protection material.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Insulation {
+    /// Thermal conductivity (W/mK).
+    pub lambda_p: f64,
+    /// Specific heat (J/kgK).
+    pub c_p: f64,
+    /// Density (kg/m³).
+    pub rho_p: f64,
+    /// Thickness (m).
+    pub d_p: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Protection {
+    Unprotected {
+        k_sh: f64,
+        alpha_c: f64,
+        epsilon_m: f64,
+    },
+    Protected(Insulation),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SteelMember {
+    /// Section factor Am/V, or Ap/V for protected members (1/m).
+    pub section_factor: f64,
+    pub protection: Protection,
+    /// Initial steel temperature (°C).
+    pub theta_0: f64,
+}
+
+impl SteelMember {
+    /// Unprotected member with shadow factor `k_sh`, using the convective
+    /// coefficient of the standard curve and a steel emissivity of 0.7.
+    pub fn unprotected(a_m_v: f64, k_sh: f64) -> Self {
+        SteelMember {
+            section_factor: a_m_v,
+            protection: Protection::Unprotected {
+                k_sh,
+                alpha_c: 25.0,
+                epsilon_m: 0.7,
+            },
+            theta_0: 20.0,
+        }
+    }
+
+    pub fn protected(a_p_v: f64, insulation: Insulation) -> Self {
+        SteelMember {
+            section_factor: a_p_v,
+            protection: Protection::Protected(insulation),
+            theta_0: 20.0,
+        }
+    }
+
+    /// Largest time step (s) allowed for the member.
+    pub fn max_time_step(&self) -> f64 {
+        match self.protection {
+            Protection::Unprotected { .. } => MAX_STEP_UNPROTECTED,
+            Protection::Protected(_) => MAX_STEP_PROTECTED,
+        }
+    }
+
+    /// Steel temperature increase over a step `delta_t` (s) from steel
+    /// temperature `theta_a` at gas temperature `theta_g`, the gas
+    /// temperature rising by `delta_theta_g` over the step.
+    pub fn temperature_increase(
+        &self,
+        theta_a: f64,
+        theta_g: f64,
+        delta_theta_g: f64,
+        delta_t: f64,
+    ) -> f64 {
+        let c_a = specific_heat::specific_heat(theta_a);
+        let rho_a = unit_mass::unit_mass();
+
+        match self.protection {
+            Protection::Unprotected {
+                k_sh,
+                alpha_c,
+                epsilon_m,
+            } => {
+                let h_net_c =
+                    equation_3_2::net_convective_heat_flux_surface(alpha_c, theta_g, theta_a);
+                let h_net_r = equation_3_3::net_radiative_heat_flux_surface(
+                    1.0, epsilon_m, 1.0, 5.67e-8, theta_g, theta_a,
+                );
+                let h_net = equation_3_1::net_heat_flux_surface(h_net_c, h_net_r);
+
+                equation_4_25::temperature_increase_unprotected(
+                    k_sh,
+                    self.section_factor,
+                    c_a,
+                    rho_a,
+                    h_net,
+                    delta_t,
+                )
+            }
+            Protection::Protected(p) => {
+                let k_p = equation_4_27::heat_transfer_factor(
+                    p.lambda_p,
+                    self.section_factor,
+                    p.d_p,
+                    c_a,
+                    rho_a,
+                );
+                let phi = equation_4_27::phi_factor(
+                    p.c_p,
+                    p.rho_p,
+                    p.d_p,
+                    self.section_factor,
+                    c_a,
+                    rho_a,
+                );
+
+                equation_4_27::temperature_increase_protected(
+                    k_p,
+                    phi,
+                    theta_g,
+                    theta_a,
+                    delta_t,
+                    delta_theta_g,
+                )
+            }
+        }
+    }
+
+    /// Steel temperatures at times 0, `delta_t`, 2 `delta_t`, ... up to
+    /// `t_end` (s) for the gas temperature curve `gas`. Output steps longer
+    /// than [`max_time_step`](Self::max_time_step) are split into equal
+    /// sub-steps within the limit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `delta_t` is not positive.
+    pub fn temperature_history<F: Fn(f64) -> f64>(
+        &self,
+        gas: F,
+        delta_t: f64,
+        t_end: f64,
+    ) -> Vec<f64> {
+        assert!(delta_t > 0.0, "the time step must be positive");
+        let steps = (t_end / delta_t).round() as usize;
+        let sub_steps = (delta_t / self.max_time_step()).ceil() as usize;
+        let h = delta_t / sub_steps as f64;
+        let mut theta_a = self.theta_0;
+        let mut history = Vec::with_capacity(steps + 1);
+        history.push(theta_a);
+
+        for i in 0..steps {
+            for j in 0..sub_steps {
+                let t = i as f64 * delta_t + j as f64 * h;
+                let theta_g = gas(t);
+                let delta_theta_g = gas(t + h) - theta_g;
+                theta_a += self.temperature_increase(theta_a, theta_g, delta_theta_g, h);
+            }
+            history.push(theta_a);
+        }
+
+        history
+    }
+
+    /// Time (s) at which the steel first reaches `theta_cr`, interpolated
+    /// within the step, or `None` if it is not reached by `t_end`.
+    pub fn time_to_temperature<F: Fn(f64) -> f64>(
+        &self,
+        gas: F,
+        theta_cr: f64,
+        delta_t: f64,
+        t_end: f64,
+    ) -> Option<f64> {
+        time_to_temperature(
+            &self.temperature_history(gas, delta_t, t_end),
+            theta_cr,
+            delta_t,
+        )
+    }
+}
+
+/// Time (s) at which a temperature history sampled every `delta_t` (s) first
+/// reaches `theta_cr`.
+pub fn time_to_temperature(history: &[f64], theta_cr: f64, delta_t: f64) -> Option<f64> {
+    if history.first().is_some_and(|&theta| theta >= theta_cr) {
+        return Some(0.0);
+    }
+
+    history.windows(2).enumerate().find_map(|(i, w)| {
+        (w[1] >= theta_cr).then(|| (i as f64 + (theta_cr - w[0]) / (w[1] - w[0])) * delta_t)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use eurocode_1_1_2::section_3::equation_3_4::standard_temp_time_curve;
+
+    fn standard(t: f64) -> f64 {
+        standard_temp_time_curve(t / 60.0)
+    }
+
+    #[test]
+    fn test_unprotected_history() {
+        let member = SteelMember::unprotected(200.0, 1.0);
+        let history = member.temperature_history(standard, 5.0, 1800.0);
+        assert_eq!(history.len(), 361);
+        assert_eq!(history[0], 20.0);
+        assert!((history[180] - 682.2101781558061).abs() < 1e-9);
+        assert!((history[360] - 828.2547805409818).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_unprotected_time_to_temperature() {
+        let member = SteelMember::unprotected(200.0, 1.0);
+        let time = member.time_to_temperature(standard, 550.0, 5.0, 3600.0);
+        assert!((time.unwrap() - 595.7222430582369).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_protected_history() {
+        let insulation = Insulation {
+            lambda_p: 0.12,
+            c_p: 1200.0,
+            rho_p: 300.0,
+            d_p: 0.02,
+        };
+        let member = SteelMember::protected(150.0, insulation);
+        let history = member.temperature_history(standard, 30.0, 3600.0);
+        assert!((history[120] - 414.39627517281997).abs() < 1e-9);
+        assert!(history.windows(2).all(|w| w[1] >= w[0]));
+    }
+
+    #[test]
+    fn test_long_steps_are_split() {
+        let member = SteelMember::unprotected(200.0, 1.0);
+        let fine = member.temperature_history(standard, 5.0, 1800.0);
+        let coarse = member.temperature_history(standard, 60.0, 1800.0);
+        assert_eq!(coarse.len(), 31);
+        assert!((coarse[15] - fine[180]).abs() < 1e-9);
+        assert!((coarse[30] - fine[360]).abs() < 1e-9);
+    }
+
+    #[test]
+    #[should_panic(expected = "the time step must be positive")]
+    fn test_zero_time_step() {
+        SteelMember::unprotected(200.0, 1.0).temperature_history(standard, 0.0, 1800.0);
+    }
+
+    #[test]
+    fn test_not_reached() {
+        let member = SteelMember::unprotected(200.0, 1.0);
+        assert_eq!(
+            member.time_to_temperature(|_| 300.0, 550.0, 5.0, 3600.0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_time_to_temperature_interpolates() {
+        let time = time_to_temperature(&[20.0, 100.0, 300.0], 200.0, 10.0);
+        assert_eq!(time, Some(15.0));
+    }
+}
@@ -0,0 +1,4 @@
+pub mod equation_a1;
+pub mod equation_a2;
+pub mod equation_a3;
+pub mod parametric_charring;
@@ -0,0 +1,37 @@
+/// Charring depth (mm) at time `t` (min) of members exposed to a
+/// parametric fire, for the charring rate `beta_par` (mm/min) and time
+/// `t_0` (min) of constant charring rate. The depth remains constant after
+/// `3 t_0`.
+pub fn charring_depth(beta_par: f64, t: f64, t_0: f64) -> f64 {
+    if t <= t_0 {
+        beta_par * t
+    } else if t <= 3.0 * t_0 {
+        beta_par * (1.5 * t - t.powi(2) / (4.0 * t_0) - t_0 / 4.0)
+    } else {
+        2.0 * beta_par * t_0
+    }
+}
+
+#[cfg(not(coverage))]
+pub fn charring_depth_equation(d_char: String, beta_par: String, t: String, t_0: String) -> String {
+    format!(
+        "{d} = \\begin{{cases}} {b} {t} & {t} \\leq {t0} \\\\ {b} \\left( 1.5 {t} - \\frac{{{t}^2}}{{4 {t0}}} - \\frac{{{t0}}}{{4}} \\right) & {t0} \\leq {t} \\leq 3 {t0} \\\\ 2 {b} {t0} & 3 {t0} \\leq {t} \\leq 5 {t0} \\end{{cases}}",
+        d = d_char,
+        b = beta_par,
+        t = t,
+        t0 = t_0,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_charring_depth() {
+        assert!((charring_depth(1.0, 10.0, 20.0) - 10.0).abs() < 1e-9);
+        assert!((charring_depth(1.0, 40.0, 20.0) - 35.0).abs() < 1e-9);
+        assert!((charring_depth(1.0, 60.0, 20.0) - 40.0).abs() < 1e-9);
+        assert!((charring_depth(1.0, 90.0, 20.0) - 40.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,36 @@
+/// Charring rate (mm/min) during the heating phase of a parametric fire,
+/// for the notional charring rate `beta_n` (mm/min) and the factor `gamma`
+/// of EN 1991-1-2 equation A.2.
+pub fn charring_rate_parametric(beta_n: f64, gamma: f64) -> f64 {
+    1.5 * beta_n * (0.2 * gamma.sqrt() - 0.04) / (0.16 * gamma.sqrt() + 0.08)
+}
+
+#[cfg(not(coverage))]
+pub fn charring_rate_parametric_equation(
+    beta_par: String,
+    beta_n: String,
+    gamma: String,
+) -> String {
+    format!(
+        "{} = 1.5 {} \\frac{{0.2 \\sqrt{{{}}} - 0.04}}{{0.16 \\sqrt{{{}}} + 0.08}}",
+        beta_par, beta_n, gamma, gamma
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_charring_rate_parametric_standard() {
+        let result = charring_rate_parametric(0.7, 1.0);
+        assert!((result - 0.7).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_charring_rate_parametric() {
+        let result = charring_rate_parametric(0.7, 4.0);
+        let expected = 1.05 * 0.36 / 0.4;
+        assert!((result - expected).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,22 @@
+/// Time (min) with constant charring rate, for the design fire load density
+/// `q_td` (MJ/m²) related to the total enclosure area and opening factor
+/// `o` (m^1/2).
+pub fn time_constant_charring(q_td: f64, o: f64) -> f64 {
+    0.009 * q_td / o
+}
+
+#[cfg(not(coverage))]
+pub fn time_constant_charring_equation(t_0: String, q_td: String, o: String) -> String {
+    format!("{} = 0.009 \\frac{{{}}}{{{}}}", t_0, q_td, o)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_constant_charring() {
+        let result = time_constant_charring(200.0, 0.06);
+        assert!((result - 30.0).abs() < 1e-9);
+    }
+}
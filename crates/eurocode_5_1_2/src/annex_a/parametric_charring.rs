@@ -0,0 +1,57 @@
+//! Charring of unprotected timber exposed to a parametric fire from
+//! EN 1991-1-2 annex A. Times are in minutes and depths in millimetres.
+
+use eurocode_1_1_2::annex_a::parametric_curve::ParametricFire;
+
+use super::{equation_a1, equation_a2, equation_a3};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParametricCharring {
+    /// Charring rate during the heating phase (mm/min).
+    pub beta_par: f64,
+    /// Time with constant charring rate (min).
+    pub t_0: f64,
+}
+
+impl ParametricCharring {
+    /// Charring for the notional charring rate `beta_n` (mm/min) under the
+    /// parametric `fire`.
+    pub fn new(fire: &ParametricFire, beta_n: f64) -> Self {
+        ParametricCharring {
+            beta_par: equation_a2::charring_rate_parametric(beta_n, fire.gamma),
+            t_0: equation_a3::time_constant_charring(fire.fire_load_density, fire.opening_factor),
+        }
+    }
+
+    /// Charring depth (mm) at time `t` (min).
+    pub fn charring_depth(&self, t: f64) -> f64 {
+        equation_a1::charring_depth(self.beta_par, t, self.t_0)
+    }
+
+    /// Charring depth at the end of the fire (mm).
+    pub fn maximum_charring_depth(&self) -> f64 {
+        2.0 * self.beta_par * self.t_0
+    }
+
+    /// Time up to which the method is valid (min).
+    pub fn validity_limit(&self) -> f64 {
+        5.0 * self.t_0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use eurocode_1_1_2::annex_a::equation_a7::FireGrowthRate;
+
+    #[test]
+    fn test_parametric_charring() {
+        let fire = ParametricFire::new(0.06, 1000.0, 300.0, FireGrowthRate::Medium);
+        let charring = ParametricCharring::new(&fire, 0.7);
+        assert!((charring.t_0 - 45.0).abs() < 1e-9);
+        assert!((charring.beta_par - 0.90234375).abs() < 1e-9);
+        assert!((charring.charring_depth(30.0) - 27.0703125).abs() < 1e-9);
+        assert!((charring.maximum_charring_depth() - 90.0 * charring.beta_par).abs() < 1e-9);
+        assert_eq!(charring.validity_limit(), 225.0);
+    }
+}
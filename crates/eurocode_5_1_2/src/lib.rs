@@ -0,0 +1,3 @@
+pub mod annex_a;
+pub mod section_3;
+pub mod section_4;
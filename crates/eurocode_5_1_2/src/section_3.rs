@@ -0,0 +1,9 @@
+pub mod charring;
+pub mod charring_rates;
+pub mod equation_3_1;
+pub mod equation_3_10;
+pub mod equation_3_11;
+pub mod equation_3_2;
+pub mod equation_3_8;
+pub mod equation_3_9;
+pub mod protection_factors;
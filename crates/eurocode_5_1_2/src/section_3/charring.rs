@@ -0,0 +1,224 @@
+//! Notional charring depth of timber members under standard fire exposure,
+//! unprotected or protected by linings. Times are in minutes and depths in
+//! millimetres.
+
+use super::protection_factors;
+use super::{equation_3_2, equation_3_8, equation_3_9, equation_3_10, equation_3_11};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Protection {
+    None,
+    /// Wood-based panel of thickness `h_p` (mm) with one-dimensional
+    /// charring rate `beta_0` (mm/min), failing at the start of charring.
+    WoodPanel {
+        h_p: f64,
+        beta_0: f64,
+    },
+    /// Gypsum plasterboard type A or H of thickness `h_p` (mm), failing at
+    /// the start of charring.
+    GypsumTypeA {
+        h_p: f64,
+    },
+    /// Gypsum plasterboard type F of thickness `h_p` (mm) with failure time
+    /// `t_f` (min) from tests.
+    GypsumTypeF {
+        h_p: f64,
+        t_f: f64,
+    },
+}
+
+impl Protection {
+    /// Start time of charring (min).
+    pub fn start_of_charring(&self) -> f64 {
+        match *self {
+            Protection::None => 0.0,
+            Protection::WoodPanel { h_p, beta_0 } => {
+                equation_3_10::start_of_charring_wood_panel(h_p, beta_0).max(0.0)
+            }
+            Protection::GypsumTypeA { h_p } | Protection::GypsumTypeF { h_p, .. } => {
+                equation_3_11::start_of_charring_gypsum(h_p).max(0.0)
+            }
+        }
+    }
+
+    /// Failure time of the protection (min), not earlier than the start of
+    /// charring.
+    pub fn failure_time(&self) -> f64 {
+        match *self {
+            Protection::GypsumTypeF { t_f, .. } => t_f.max(self.start_of_charring()),
+            _ => self.start_of_charring(),
+        }
+    }
+
+    /// Factor on the charring rate between the start of charring and the
+    /// failure of the protection.
+    pub fn k_2(&self) -> f64 {
+        match *self {
+            Protection::GypsumTypeF { h_p, .. } => protection_factors::k_2(h_p),
+            _ => 1.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Charring {
+    /// Notional design charring rate (mm/min).
+    pub beta_n: f64,
+    pub protection: Protection,
+}
+
+impl Charring {
+    pub fn new(beta_n: f64, protection: Protection) -> Self {
+        Charring { beta_n, protection }
+    }
+
+    /// Time (min) after which charring continues at the notional rate, not
+    /// earlier than the failure of the protection.
+    pub fn time_limit(&self) -> f64 {
+        let t_ch = self.protection.start_of_charring();
+        let t_f = self.protection.failure_time();
+        let k_3 = protection_factors::k_3();
+
+        match self.protection {
+            Protection::None => 0.0,
+            _ if t_f > t_ch => equation_3_9::time_limit_protected(
+                t_ch,
+                t_f,
+                self.protection.k_2(),
+                k_3,
+                self.beta_n,
+            ),
+            _ => equation_3_8::time_limit(t_f, k_3, self.beta_n),
+        }
+        .max(t_f)
+    }
+
+    /// Notional charring rate (mm/min) at time `t` (min).
+    pub fn charring_rate(&self, t: f64) -> f64 {
+        if let Protection::None = self.protection {
+            return self.beta_n;
+        }
+
+        if t <= self.protection.start_of_charring() {
+            0.0
+        } else if t <= self.protection.failure_time() {
+            self.protection.k_2() * self.beta_n
+        } else if t <= self.time_limit() {
+            protection_factors::k_3() * self.beta_n
+        } else {
+            self.beta_n
+        }
+    }
+
+    /// Notional charring depth (mm) at time `t` (min).
+    pub fn charring_depth(&self, t: f64) -> f64 {
+        if let Protection::None = self.protection {
+            return equation_3_2::charring_depth_notional(self.beta_n, t);
+        }
+
+        let t_ch = self.protection.start_of_charring();
+        let t_f = self.protection.failure_time();
+        let t_a = self.time_limit();
+        let phases = [
+            (t_ch, t_f, self.protection.k_2() * self.beta_n),
+            (t_f, t_a, protection_factors::k_3() * self.beta_n),
+            (t_a, f64::INFINITY, self.beta_n),
+        ];
+
+        phases
+            .iter()
+            .map(|&(start, end, rate)| rate * (t.min(end) - start).max(0.0))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unprotected() {
+        let charring = Charring::new(0.7, Protection::None);
+        assert!((charring.charring_depth(60.0) - 42.0).abs() < 1e-9);
+        assert_eq!(charring.charring_rate(10.0), 0.7);
+    }
+
+    #[test]
+    fn test_gypsum_type_a() {
+        let charring = Charring::new(0.7, Protection::GypsumTypeA { h_p: 12.5 });
+        assert!((charring.protection.start_of_charring() - 21.0).abs() < 1e-9);
+        assert!((charring.time_limit() - 38.85714285714286).abs() < 1e-9);
+        assert_eq!(charring.charring_depth(20.0), 0.0);
+        assert!((charring.charring_depth(30.0) - 12.6).abs() < 1e-9);
+        assert!((charring.charring_depth(60.0) - 39.8).abs() < 1e-9);
+        assert_eq!(charring.charring_rate(30.0), 1.4);
+    }
+
+    #[test]
+    fn test_gypsum_type_f() {
+        let charring = Charring::new(
+            0.7,
+            Protection::GypsumTypeF {
+                h_p: 15.0,
+                t_f: 45.0,
+            },
+        );
+        assert!((charring.protection.start_of_charring() - 28.0).abs() < 1e-9);
+        assert!((charring.time_limit() - 56.65214285714286).abs() < 1e-9);
+        assert!((charring.charring_depth(45.0) - 8.687).abs() < 1e-9);
+        assert!((charring.charring_depth(90.0) - 48.3435).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_wood_panel() {
+        let protection = Protection::WoodPanel {
+            h_p: 18.0,
+            beta_0: 0.9,
+        };
+        assert!((protection.start_of_charring() - 16.0).abs() < 1e-9);
+        assert_eq!(protection.failure_time(), 16.0);
+    }
+
+    #[test]
+    fn test_late_failure_of_protection() {
+        let charring = Charring::new(
+            0.7,
+            Protection::GypsumTypeF {
+                h_p: 15.0,
+                t_f: 90.0,
+            },
+        );
+        // Equation 3.9 gives 85.2 min, before the boards fail.
+        assert_eq!(charring.time_limit(), 90.0);
+        assert!((charring.charring_depth(120.0) - 52.682).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_depth_is_integral_of_rate() {
+        let protections = [
+            Protection::None,
+            Protection::GypsumTypeA { h_p: 12.5 },
+            Protection::GypsumTypeF {
+                h_p: 15.0,
+                t_f: 45.0,
+            },
+            Protection::GypsumTypeF {
+                h_p: 15.0,
+                t_f: 90.0,
+            },
+            Protection::WoodPanel {
+                h_p: 18.0,
+                beta_0: 0.9,
+            },
+        ];
+        let dt = 0.001;
+
+        for protection in protections {
+            let charring = Charring::new(0.7, protection);
+            let integral: f64 = (0..120_000)
+                .map(|i| charring.charring_rate((i as f64 + 0.5) * dt) * dt)
+                .sum();
+            assert!((integral - charring.charring_depth(120.0)).abs() < 1e-3);
+        }
+    }
+}
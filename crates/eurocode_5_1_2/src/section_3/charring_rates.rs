@@ -0,0 +1,80 @@
+//! Design charring rates of timber, LVL and wood-based panels.
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimberProduct {
+    /// Glued laminated softwood timber with density of at least 290 kg/m³.
+    GluedLaminatedSoftwood,
+    /// Solid softwood timber with density of at least 290 kg/m³.
+    SolidSoftwood,
+    /// Solid or glued laminated hardwood with density of 290 kg/m³.
+    Hardwood290,
+    /// Solid or glued laminated hardwood with density of at least 450 kg/m³.
+    Hardwood450,
+    /// LVL with density of at least 480 kg/m³.
+    Lvl,
+    WoodPanelling,
+    Plywood,
+    /// Wood-based panels other than plywood.
+    WoodBasedPanel,
+}
+
+impl TimberProduct {
+    /// One-dimensional design charring rate (mm/min).
+    pub fn beta_0(&self) -> f64 {
+        match self {
+            TimberProduct::GluedLaminatedSoftwood
+            | TimberProduct::SolidSoftwood
+            | TimberProduct::Hardwood290
+            | TimberProduct::Lvl => 0.65,
+            TimberProduct::Hardwood450 => 0.50,
+            TimberProduct::WoodPanelling | TimberProduct::WoodBasedPanel => 0.9,
+            TimberProduct::Plywood => 1.0,
+        }
+    }
+
+    /// Notional design charring rate (mm/min), or `None` for panels, which
+    /// only char one-dimensionally.
+    pub fn beta_n(&self) -> Option<f64> {
+        match self {
+            TimberProduct::GluedLaminatedSoftwood
+            | TimberProduct::Hardwood290
+            | TimberProduct::Lvl => Some(0.7),
+            TimberProduct::SolidSoftwood => Some(0.8),
+            TimberProduct::Hardwood450 => Some(0.55),
+            TimberProduct::WoodPanelling
+            | TimberProduct::Plywood
+            | TimberProduct::WoodBasedPanel => None,
+        }
+    }
+}
+
+/// One-dimensional charring rate (mm/min) of a panel of characteristic
+/// density `rho_k` (kg/m³) and thickness `h_p` (mm), corrected from the
+/// tabulated rate `beta_0_tab` for 450 kg/m³ and 20 mm.
+pub fn panel_charring_rate(beta_0_tab: f64, rho_k: f64, h_p: f64) -> f64 {
+    let k_rho = (450.0 / rho_k).sqrt();
+    let k_h = (20.0 / h_p).sqrt().max(1.0);
+    beta_0_tab * k_rho * k_h
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_charring_rates() {
+        assert_eq!(TimberProduct::GluedLaminatedSoftwood.beta_0(), 0.65);
+        assert_eq!(TimberProduct::GluedLaminatedSoftwood.beta_n(), Some(0.7));
+        assert_eq!(TimberProduct::SolidSoftwood.beta_n(), Some(0.8));
+        assert_eq!(TimberProduct::Hardwood450.beta_0(), 0.5);
+        assert_eq!(TimberProduct::Plywood.beta_n(), None);
+    }
+
+    #[test]
+    fn test_panel_charring_rate() {
+        let result = panel_charring_rate(1.0, 450.0, 15.0);
+        let expected = (20.0_f64 / 15.0).sqrt();
+        assert!((result - expected).abs() < 1e-9);
+        assert!((panel_charring_rate(0.9, 450.0, 25.0) - 0.9).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,25 @@
+/// Design charring depth (mm) for one-dimensional charring at time `t`
+/// (min) for the one-dimensional design charring rate `beta_0` (mm/min).
+pub fn charring_depth_one_dimensional(beta_0: f64, t: f64) -> f64 {
+    beta_0 * t
+}
+
+#[cfg(not(coverage))]
+pub fn charring_depth_one_dimensional_equation(
+    d_char_0: String,
+    beta_0: String,
+    t: String,
+) -> String {
+    format!("{} = {} \\cdot {}", d_char_0, beta_0, t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_charring_depth_one_dimensional() {
+        let result = charring_depth_one_dimensional(0.65, 60.0);
+        assert!((result - 39.0).abs() < 1e-9);
+    }
+}
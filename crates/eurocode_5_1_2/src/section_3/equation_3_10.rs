@@ -0,0 +1,22 @@
+/// Start time of charring (min) behind wood-based panel protection of
+/// thickness `h_p` (mm) with one-dimensional charring rate `beta_0`
+/// (mm/min).
+pub fn start_of_charring_wood_panel(h_p: f64, beta_0: f64) -> f64 {
+    h_p / beta_0 - 4.0
+}
+
+#[cfg(not(coverage))]
+pub fn start_of_charring_wood_panel_equation(t_ch: String, h_p: String, beta_0: String) -> String {
+    format!("{} = \\frac{{{}}}{{{}}} - 4", t_ch, h_p, beta_0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_start_of_charring_wood_panel() {
+        let result = start_of_charring_wood_panel(18.0, 0.9);
+        assert!((result - 16.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,21 @@
+/// Start time of charring (min) behind gypsum plasterboard of type A, F or H
+/// of thickness `h_p` (mm), with joints filled or less than 2 mm wide.
+pub fn start_of_charring_gypsum(h_p: f64) -> f64 {
+    2.8 * h_p - 14.0
+}
+
+#[cfg(not(coverage))]
+pub fn start_of_charring_gypsum_equation(t_ch: String, h_p: String) -> String {
+    format!("{} = 2.8 {} - 14", t_ch, h_p)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_start_of_charring_gypsum() {
+        let result = start_of_charring_gypsum(12.5);
+        assert!((result - 21.0).abs() < 1e-9);
+    }
+}
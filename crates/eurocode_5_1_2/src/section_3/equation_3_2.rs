@@ -0,0 +1,22 @@
+/// Notional design charring depth (mm) at time `t` (min), including the
+/// effect of corner roundings and fissures, for the notional design
+/// charring rate `beta_n` (mm/min).
+pub fn charring_depth_notional(beta_n: f64, t: f64) -> f64 {
+    beta_n * t
+}
+
+#[cfg(not(coverage))]
+pub fn charring_depth_notional_equation(d_char_n: String, beta_n: String, t: String) -> String {
+    format!("{} = {} \\cdot {}", d_char_n, beta_n, t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_charring_depth_notional() {
+        let result = charring_depth_notional(0.7, 60.0);
+        assert!((result - 42.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,33 @@
+/// Time (min) at which the charring rate returns to the notional rate when
+/// charring starts at the failure time `t_f` (min) of the protection, taken
+/// as the lower of twice the failure time and the time for 25 mm of charring
+/// at the increased rate.
+pub fn time_limit(t_f: f64, k_3: f64, beta_n: f64) -> f64 {
+    (2.0 * t_f).min(25.0 / (k_3 * beta_n) + t_f)
+}
+
+#[cfg(not(coverage))]
+pub fn time_limit_equation(t_a: String, t_f: String, k_3: String, beta_n: String) -> String {
+    format!(
+        "{} = \\min \\left\\{{ 2 {}; \\frac{{25}}{{{} {}}} + {} \\right\\}}",
+        t_a, t_f, k_3, beta_n, t_f
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_limit_doubled() {
+        let result = time_limit(10.0, 2.0, 0.7);
+        assert!((result - 20.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_time_limit_char_depth() {
+        let result = time_limit(30.0, 2.0, 0.7);
+        let expected = 25.0 / 1.4 + 30.0;
+        assert!((result - expected).abs() < 1e-9);
+    }
+}
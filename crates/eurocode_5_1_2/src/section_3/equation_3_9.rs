@@ -0,0 +1,33 @@
+/// Time (min) at which the charring rate returns to the notional rate when
+/// charring starts at `t_ch` (min), before the failure time `t_f` (min) of
+/// the protection.
+pub fn time_limit_protected(t_ch: f64, t_f: f64, k_2: f64, k_3: f64, beta_n: f64) -> f64 {
+    (25.0 - (t_f - t_ch) * k_2 * beta_n) / (k_3 * beta_n) + t_f
+}
+
+#[cfg(not(coverage))]
+pub fn time_limit_protected_equation(
+    t_a: String,
+    t_ch: String,
+    t_f: String,
+    k_2: String,
+    k_3: String,
+    beta_n: String,
+) -> String {
+    format!(
+        "{} = \\frac{{25 - ({} - {}) {} {}}}{{{} {}}} + {}",
+        t_a, t_f, t_ch, k_2, beta_n, k_3, beta_n, t_f
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_limit_protected() {
+        let result = time_limit_protected(21.0, 45.0, 0.725, 2.0, 0.7);
+        let expected = (25.0 - 24.0 * 0.725 * 0.7) / 1.4 + 45.0;
+        assert!((result - expected).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,29 @@
+//! Factors applied to the notional charring rate of protected surfaces
+//! between the start of charring and the failure of the protection, and
+//! after failure of the protection.
+
+/// Factor for charring behind a single layer of gypsum plasterboard type F
+/// of thickness `h_p` (mm) before the board fails.
+pub fn k_2(h_p: f64) -> f64 {
+    1.0 - 0.018 * h_p
+}
+
+/// Factor for the increased charring rate after failure of the protection.
+pub fn k_3() -> f64 {
+    2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_k_2() {
+        assert!((k_2(15.0) - 0.73).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_k_3() {
+        assert_eq!(k_3(), 2.0);
+    }
+}
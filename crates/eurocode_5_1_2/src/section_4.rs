@@ -0,0 +1,2 @@
+pub mod equation_4_1;
+pub mod reduced_cross_section;
@@ -0,0 +1,45 @@
+/// Effective charring depth (mm) from the notional charring depth
+/// `d_char_n` (mm) and the zero-strength layer `k_0 * d_0`.
+pub fn effective_charring_depth(d_char_n: f64, k_0: f64, d_0: f64) -> f64 {
+    d_char_n + k_0 * d_0
+}
+
+/// Depth of the zero-strength layer (mm).
+pub fn zero_strength_layer() -> f64 {
+    7.0
+}
+
+/// Factor on the zero-strength layer at time `t` (min), increasing linearly
+/// to 1.0 at 20 minutes, or at the start of charring `t_ch` (min) of
+/// protected surfaces where this is later.
+pub fn k_0(t: f64, t_ch: f64) -> f64 {
+    (t / t_ch.max(20.0)).min(1.0)
+}
+
+#[cfg(not(coverage))]
+pub fn effective_charring_depth_equation(
+    d_ef: String,
+    d_char_n: String,
+    k_0: String,
+    d_0: String,
+) -> String {
+    format!("{} = {} + {} \\cdot {}", d_ef, d_char_n, k_0, d_0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_effective_charring_depth() {
+        let result = effective_charring_depth(42.0, 1.0, zero_strength_layer());
+        assert!((result - 49.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_k_0() {
+        assert!((k_0(10.0, 0.0) - 0.5).abs() < 1e-9);
+        assert_eq!(k_0(30.0, 0.0), 1.0);
+        assert!((k_0(21.0, 28.0) - 0.75).abs() < 1e-9);
+    }
+}
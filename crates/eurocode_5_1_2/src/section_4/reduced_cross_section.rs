@@ -0,0 +1,89 @@
+//! Reduced cross-section method for rectangular timber members, removing
+//! the effective charring depth from each fire exposed face.
+
+use super::equation_4_1;
+use crate::section_3::charring::Charring;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Exposure {
+    /// Both sides and the bottom exposed.
+    ThreeSided,
+    /// All four faces exposed.
+    FourSided,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CrossSection {
+    /// Width (mm).
+    pub b: f64,
+    /// Depth (mm).
+    pub h: f64,
+}
+
+impl CrossSection {
+    /// Residual cross-section after removing the effective charring depth
+    /// `d_ef` (mm) from the exposed faces.
+    pub fn reduced(&self, d_ef: f64, exposure: Exposure) -> CrossSection {
+        let faces = match exposure {
+            Exposure::ThreeSided => 1.0,
+            Exposure::FourSided => 2.0,
+        };
+
+        CrossSection {
+            b: (self.b - 2.0 * d_ef).max(0.0),
+            h: (self.h - faces * d_ef).max(0.0),
+        }
+    }
+
+    /// Residual cross-section at time `t` (min) for the given charring.
+    pub fn reduced_at(&self, charring: &Charring, t: f64, exposure: Exposure) -> CrossSection {
+        let k_0 = equation_4_1::k_0(t, charring.protection.start_of_charring());
+        let d_ef = equation_4_1::effective_charring_depth(
+            charring.charring_depth(t),
+            k_0,
+            equation_4_1::zero_strength_layer(),
+        );
+
+        self.reduced(d_ef, exposure)
+    }
+
+    /// Area (mm²).
+    pub fn area(&self) -> f64 {
+        self.b * self.h
+    }
+
+    /// Elastic section modulus about the major axis (mm³).
+    pub fn section_modulus(&self) -> f64 {
+        self.b * self.h.powi(2) / 6.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::section_3::charring::Protection;
+
+    #[test]
+    fn test_reduced() {
+        let section = CrossSection { b: 200.0, h: 600.0 };
+        let reduced = section.reduced(49.0, Exposure::ThreeSided);
+        assert_eq!(reduced, CrossSection { b: 102.0, h: 551.0 });
+        assert_eq!(section.reduced(49.0, Exposure::FourSided).h, 502.0);
+    }
+
+    #[test]
+    fn test_reduced_at() {
+        let section = CrossSection { b: 200.0, h: 600.0 };
+        let charring = Charring::new(0.7, Protection::None);
+        let reduced = section.reduced_at(&charring, 60.0, Exposure::ThreeSided);
+        assert!((reduced.b - 102.0).abs() < 1e-9);
+        assert!((reduced.section_modulus() - 102.0 * 551.0_f64.powi(2) / 6.0).abs() < 1e-6);
+        assert!((reduced.area() - 102.0 * 551.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_fully_charred() {
+        let section = CrossSection { b: 80.0, h: 200.0 };
+        assert_eq!(section.reduced(50.0, Exposure::FourSided).b, 0.0);
+    }
+}
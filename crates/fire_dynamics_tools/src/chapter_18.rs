@@ -1,3 +1,4 @@
 pub mod equation_18_1;
 pub mod equation_18_2;
 pub mod equation_18_3;
+pub mod smoke_detector;
@@ -0,0 +1,245 @@
+//! Smoke detector activation under the Alpert ceiling jet of a
+//! time-varying fire. The smoke entering the detector lags the ceiling jet
+//! with a characteristic length `L`, `dY/dt = u / L (Y_jet - Y)`, and the
+//! detector activates when `Y` reaches its threshold. `Y` is either the
+//! ceiling jet temperature rise (Heskestad's surrogate) or the optical density
+//! per metre from the soot carried by the jet. Temperatures are in K, times in
+//! s and heat release rates in kW.
+
+use ode::events::{Direction, Event};
+use ode::integrator::{self, Method, Options, Status};
+use sfpe_handbook::chapter_14::alpert::ceiling_jet;
+
+use super::{equation_18_2, equation_18_3};
+use crate::chapter_2::equation_2_13::density_hot_gas_layer;
+
+/// Specific heat of the ceiling jet gases (kJ/kg·K).
+const C_P: f64 = 1.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Surrogate {
+    /// Activates at a ceiling jet temperature rise `delta_t` (K), typically
+    /// 13 K.
+    TemperatureRise { delta_t: f64 },
+    /// Activates at an optical density per metre `threshold` (1/m) for soot
+    /// yield `y_s`, heat of combustion `delta_h_c` (kJ/kg) and specific
+    /// extinction coefficient `alpha_m` (m²/kg).
+    OpticalDensity {
+        y_s: f64,
+        delta_h_c: f64,
+        alpha_m: f64,
+        threshold: f64,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SmokeDetector {
+    pub surrogate: Surrogate,
+    /// Characteristic lag length (m), zero for a detector that follows the
+    /// ceiling jet.
+    pub lag_length: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Detection {
+    /// Activation time (s).
+    pub time: f64,
+    /// Heat release rate at activation (kW).
+    pub hrr: f64,
+    /// Ceiling jet temperature rise at activation (K).
+    pub temperature_rise: f64,
+    /// Ceiling jet particulate concentration at activation (kg/m³), zero for
+    /// the temperature rise surrogate.
+    pub particulate_concentration: f64,
+}
+
+impl SmokeDetector {
+    pub fn new(surrogate: Surrogate, lag_length: f64) -> Self {
+        SmokeDetector {
+            surrogate,
+            lag_length,
+        }
+    }
+
+    /// Particulate concentration (kg/m³) in a ceiling jet at temperature
+    /// `t_jet` heated by `delta_t` (K) above ambient, assuming all of its
+    /// heat came from burning fuel.
+    pub fn particulate_concentration(y_s: f64, delta_h_c: f64, t_jet: f64, delta_t: f64) -> f64 {
+        // Fuel burnt per cubic metre of the ceiling jet.
+        let m_f = density_hot_gas_layer(t_jet) * C_P * delta_t / delta_h_c;
+        equation_18_2::concentration_particulates(
+            equation_18_3::mass_particulates_produced(m_f, y_s),
+            1.0,
+        )
+    }
+
+    /// Activation under a fire with heat release rate `hrr(t)`, at `height`
+    /// (m) above the fuel and `radial_position` (m) from the plume centreline,
+    /// with ambient temperature `t_a`. Returns `None` if the detector does
+    /// not activate by `t_end`.
+    pub fn activation<H: Fn(f64) -> f64>(
+        &self,
+        hrr: H,
+        height: f64,
+        radial_position: f64,
+        t_a: f64,
+        t_end: f64,
+    ) -> Option<Detection> {
+        let jet = |t: f64| {
+            let q = hrr(t).max(0.0);
+            let delta_t = ceiling_jet::temperature(q, height, radial_position, t_a) - t_a;
+            let u = ceiling_jet::velocity(q, height, radial_position);
+            let (m_p, value) = match self.surrogate {
+                Surrogate::TemperatureRise { .. } => (0.0, delta_t),
+                Surrogate::OpticalDensity {
+                    y_s,
+                    delta_h_c,
+                    alpha_m,
+                    ..
+                } => {
+                    let m_p =
+                        Self::particulate_concentration(y_s, delta_h_c, t_a + delta_t, delta_t);
+                    (m_p, alpha_m * m_p / std::f64::consts::LN_10)
+                }
+            };
+            (q, delta_t, u, m_p, value)
+        };
+        let threshold = match self.surrogate {
+            Surrogate::TemperatureRise { delta_t } => delta_t,
+            Surrogate::OpticalDensity { threshold, .. } => threshold,
+        };
+
+        // Without a lag the detector reads the ceiling jet directly.
+        let lagged = self.lag_length > 0.0;
+        let events = [Event::new(
+            |t, y| {
+                if lagged {
+                    y[0] - threshold
+                } else {
+                    jet(t).4 - threshold
+                }
+            },
+            Direction::Rising,
+            true,
+        )];
+        let options = Options {
+            h_max: 1.0,
+            ..Options::default()
+        };
+
+        let solution = integrator::solve(
+            Method::DormandPrince,
+            |t, y| {
+                if lagged {
+                    let (_, _, u, _, value) = jet(t);
+                    vec![u / self.lag_length * (value - y[0])]
+                } else {
+                    vec![0.0]
+                }
+            },
+            0.0,
+            &[0.0],
+            t_end,
+            t_end,
+            &events,
+            &options,
+        );
+
+        if solution.status != Status::Terminated {
+            return None;
+        }
+        let time = solution.first_event(0)?.t;
+        let (hrr, temperature_rise, _, particulate_concentration, _) = jet(time);
+        Some(Detection {
+            time,
+            hrr,
+            temperature_rise,
+            particulate_concentration,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chapter_18::equation_18_1::visibility;
+
+    const T_A: f64 = 293.15;
+
+    #[test]
+    fn test_temperature_rise_without_lag() {
+        // Alpert's near-field correlation inverted for a 13 K rise under a
+        // t-squared fire.
+        let detector = SmokeDetector::new(Surrogate::TemperatureRise { delta_t: 13.0 }, 0.0);
+        let result = detector
+            .activation(|t| 0.0469 * t * t, 4.0, 0.5, T_A, 600.0)
+            .unwrap();
+
+        let q = (13.0 * 4.0_f64.powf(5.0 / 3.0) / 16.9).powf(1.5);
+        assert!((result.hrr - q).abs() < 1e-3);
+        assert!((result.time - (q / 0.0469).sqrt()).abs() < 1e-3);
+        assert!((result.temperature_rise - 13.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_lag_delays_activation() {
+        let fire = |t: f64| 0.0469 * t * t;
+        let surrogate = Surrogate::TemperatureRise { delta_t: 13.0 };
+        let direct = SmokeDetector::new(surrogate, 0.0)
+            .activation(fire, 4.0, 3.0, T_A, 600.0)
+            .unwrap();
+        let lagged = SmokeDetector::new(surrogate, 1.8)
+            .activation(fire, 4.0, 3.0, T_A, 600.0)
+            .unwrap();
+
+        assert!(lagged.time > direct.time);
+        assert!(lagged.temperature_rise > 13.0);
+    }
+
+    #[test]
+    fn test_steady_fire_lag_matches_analytical() {
+        // For a steady fire the detector approaches the jet exponentially.
+        let (q, h, r, l) = (500.0, 3.0, 2.0, 2.0);
+        let jet_rise = ceiling_jet::temperature(q, h, r, T_A) - T_A;
+        let u = ceiling_jet::velocity(q, h, r);
+        let detector = SmokeDetector::new(Surrogate::TemperatureRise { delta_t: 13.0 }, l);
+
+        let result = detector.activation(|_| q, h, r, T_A, 600.0).unwrap();
+
+        let expected = -l / u * (1.0 - 13.0 / jet_rise).ln();
+        assert!((result.time - expected).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_optical_density() {
+        let surrogate = Surrogate::OpticalDensity {
+            y_s: 0.015,
+            delta_h_c: 20000.0,
+            alpha_m: 8700.0,
+            threshold: 0.14,
+        };
+        let result = SmokeDetector::new(surrogate, 0.0)
+            .activation(|t| 0.01172 * t * t, 3.0, 3.0, T_A, 600.0)
+            .unwrap();
+
+        let extinction = 8700.0 * result.particulate_concentration;
+        assert!((extinction / std::f64::consts::LN_10 - 0.14).abs() < 1e-6);
+        // Visibility of a light-emitting sign at activation.
+        let s = visibility(8.0, 8700.0, result.particulate_concentration);
+        assert!((s - 8.0 / extinction).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_particulate_concentration() {
+        let result = SmokeDetector::particulate_concentration(0.015, 20000.0, 313.15, 20.0);
+        let expected = 353.0 / 313.15 * 20.0 / 20000.0 * 0.015;
+        assert!((result - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_no_activation() {
+        let detector = SmokeDetector::new(Surrogate::TemperatureRise { delta_t: 13.0 }, 1.8);
+        let result = detector.activation(|_| 10.0, 10.0, 8.0, T_A, 300.0);
+        assert_eq!(result, None);
+    }
+}
@@ -1,3 +1,4 @@
+pub mod atrium_smoke_filling;
 pub mod equation_2_1;
 pub mod equation_2_10;
 pub mod equation_2_11;
@@ -11,3 +12,5 @@ pub mod equation_2_6;
 pub mod equation_2_7;
 pub mod equation_2_8;
 pub mod equation_2_9;
+pub mod two_zone_model;
+pub mod zone_network;
@@ -0,0 +1,283 @@
+//! Smoke filling of atria and large spaces after NFPA 92: the descent of an
+//! unexhausted smoke layer under steady and t-squared fires, and the mass
+//! exhaust that holds the layer at a design height. The filling correlations
+//! apply for layer heights above 20 % of the ceiling height and plan area to
+//! height squared ratios of 0.9 to 14. Times are in s, heat release rates in
+//! kW, temperatures in K and lengths in m.
+
+use cibse_guide_e::chapter_10::equation_10_1::max_volumetric_flow_rate;
+
+use crate::chapter_9::equation_9_3::virtual_origin_over_diameter;
+use crate::chapter_9::equation_9_4::effective_diameter;
+use crate::chapter_9::plume_mass_flow::{PlumeFlow, heskestad};
+
+/// Heat release rate (kW) reached at the growth time of a t-squared fire.
+const GROWTH_HRR: f64 = 1055.0;
+/// Range of plan area to height squared ratios of the filling correlations.
+pub const ASPECT_LIMITS: (f64, f64) = (0.9, 14.0);
+/// Lowest layer height, as a fraction of the ceiling height, of the filling
+/// correlations.
+pub const MIN_HEIGHT_RATIO: f64 = 0.2;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Atrium {
+    /// Plan area (m²).
+    pub area: f64,
+    /// Ceiling height above the fire (m).
+    pub height: f64,
+    /// Ambient temperature (K).
+    pub t_a: f64,
+    /// Fraction of the heat release radiated from the flame.
+    pub chi_r: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Exhaust {
+    /// Mass flow (kg/s).
+    pub mass_flow: f64,
+    /// Smoke layer temperature (K).
+    pub smoke_temperature: f64,
+    /// Volumetric flow at the smoke temperature (m³/s).
+    pub volumetric_flow: f64,
+}
+
+impl Exhaust {
+    /// Largest volumetric flow (m³/s) through one exhaust point, from a
+    /// smoke layer `depth` (m) deep below it, before air is drawn up through
+    /// the layer. `gamma` is 1.0 for a point away from walls and 0.5 for one
+    /// next to a wall.
+    pub fn plugholing_limit(&self, depth: f64, gamma: f64, t_a: f64) -> f64 {
+        max_volumetric_flow_rate(gamma, depth, self.smoke_temperature, t_a)
+    }
+
+    /// Smallest number of exhaust points that avoids plugholing.
+    pub fn exhaust_points(&self, depth: f64, gamma: f64, t_a: f64) -> usize {
+        (self.volumetric_flow / self.plugholing_limit(depth, gamma, t_a))
+            .ceil()
+            .max(1.0) as usize
+    }
+}
+
+impl Atrium {
+    /// Atrium at 20 °C with 30 % of the heat release radiated from the
+    /// flame.
+    pub fn new(area: f64, height: f64) -> Self {
+        Atrium {
+            area,
+            height,
+            t_a: 293.15,
+            chi_r: 0.3,
+        }
+    }
+
+    fn aspect(&self) -> f64 {
+        self.area / self.height.powi(2)
+    }
+
+    /// Whether the plan area to height squared ratio is within
+    /// `ASPECT_LIMITS`.
+    pub fn within_limits(&self) -> bool {
+        let (low, high) = ASPECT_LIMITS;
+        (low..=high).contains(&self.aspect())
+    }
+
+    /// Whether the filling correlations apply at layer height `z`.
+    fn applies_at(&self, z: f64) -> bool {
+        self.within_limits() && z / self.height > MIN_HEIGHT_RATIO
+    }
+
+    /// Smoke layer height at time `t` under a steady fire `q`, limited to the
+    /// ceiling height. Returns `None` outside the limits of the correlation.
+    pub fn layer_height_steady(&self, q: f64, t: f64) -> Option<f64> {
+        let x = t * q.powf(1.0 / 3.0) * self.height.powf(-4.0 / 3.0) / self.aspect();
+        let z = (self.height * (1.11 - 0.28 * x.ln())).min(self.height);
+        self.applies_at(z).then_some(z)
+    }
+
+    /// Time for the smoke layer to descend to `z` under a steady fire `q`.
+    /// Returns `None` outside the limits of the correlation.
+    pub fn filling_time_steady(&self, q: f64, z: f64) -> Option<f64> {
+        let x = ((1.11 - z / self.height) / 0.28).exp();
+        let t = x * self.aspect() * self.height.powf(4.0 / 3.0) / q.powf(1.0 / 3.0);
+        self.applies_at(z).then_some(t)
+    }
+
+    /// Smoke layer height at time `t` under a t-squared fire with growth
+    /// coefficient `alpha` (kW/s²), limited to the ceiling height. Returns
+    /// `None` outside the limits of the correlation.
+    pub fn layer_height_t_squared(&self, alpha: f64, t: f64) -> Option<f64> {
+        let t_g = (GROWTH_HRR / alpha).sqrt();
+        let x = t * t_g.powf(-2.0 / 5.0) * self.height.powf(-4.0 / 5.0) * self.aspect().powf(-0.6);
+        let z = (self.height * 0.91 * x.powf(-1.45)).min(self.height);
+        self.applies_at(z).then_some(z)
+    }
+
+    /// Time for the smoke layer to descend to `z` under a t-squared fire
+    /// with growth coefficient `alpha` (kW/s²). Returns `None` outside the
+    /// limits of the correlation.
+    pub fn filling_time_t_squared(&self, alpha: f64, z: f64) -> Option<f64> {
+        let t_g = (GROWTH_HRR / alpha).sqrt();
+        let x = (z / (0.91 * self.height)).powf(-1.0 / 1.45);
+        let t = x * t_g.powf(2.0 / 5.0) * self.height.powf(4.0 / 5.0) * self.aspect().powf(0.6);
+        self.applies_at(z).then_some(t)
+    }
+
+    /// Heskestad plume at height `z` above a fire `q` of area `a_f` (m²),
+    /// measured from the virtual origin. A zero area is a point source.
+    pub fn plume(&self, q: f64, a_f: f64, z: f64) -> PlumeFlow {
+        let z_0 = if a_f > 0.0 {
+            let d = effective_diameter(a_f);
+            d * virtual_origin_over_diameter(d, q)
+        } else {
+            0.0
+        };
+        heskestad((1.0 - self.chi_r) * q, z, z_0, self.t_a)
+    }
+
+    /// Plume mass flow (kg/s) of `plume`.
+    pub fn plume_mass_flow(&self, q: f64, a_f: f64, z: f64) -> f64 {
+        self.plume(q, a_f, z).mass_flow
+    }
+
+    /// Exhaust that holds the smoke layer at height `z` above a fire `q` of
+    /// area `a_f` (m²), with the layer heated adiabatically by the plume.
+    pub fn exhaust(&self, q: f64, a_f: f64, z: f64) -> Exhaust {
+        let plume = self.plume(q, a_f, z);
+        Exhaust {
+            mass_flow: plume.mass_flow,
+            smoke_temperature: plume.temperature,
+            volumetric_flow: plume.volumetric_flow,
+        }
+    }
+
+    /// Steady clear height above a fire `q` of area `a_f` (m²) with a mass
+    /// exhaust `m_e` (kg/s), where the plume supplies what is exhausted.
+    /// Returns the ceiling height if the exhaust exceeds the plume flow there.
+    pub fn clear_height(&self, q: f64, a_f: f64, m_e: f64) -> f64 {
+        if self.plume_mass_flow(q, a_f, self.height) <= m_e {
+            return self.height;
+        }
+
+        let mut low = 0.0;
+        let mut high = self.height;
+        while high - low > 1e-9 * self.height {
+            let z = 0.5 * (low + high);
+            if self.plume_mass_flow(q, a_f, z) < m_e {
+                low = z;
+            } else {
+                high = z;
+            }
+        }
+        0.5 * (low + high)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_steady_filling_round_trip() {
+        let atrium = Atrium::new(900.0, 20.0);
+        let t = atrium.filling_time_steady(5000.0, 10.0).unwrap();
+
+        assert!((atrium.layer_height_steady(5000.0, t).unwrap() - 10.0).abs() < 1e-9);
+        assert_eq!(atrium.layer_height_steady(5000.0, 1.0), Some(20.0));
+    }
+
+    #[test]
+    fn test_steady_filling_nfpa_92() {
+        // z/H = 1.11 - 0.28 ln(t Q^1/3 H^-4/3 / (A/H²)).
+        let atrium = Atrium::new(1600.0, 20.0);
+        let x = 120.0 * 2000.0_f64.cbrt() * 20.0_f64.powf(-4.0 / 3.0) / 4.0;
+        let expected = 20.0 * (1.11 - 0.28 * x.ln());
+
+        assert!((atrium.layer_height_steady(2000.0, 120.0).unwrap() - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_t_squared_filling() {
+        let atrium = Atrium::new(900.0, 20.0);
+        let fast = atrium.filling_time_t_squared(0.0469, 8.0).unwrap();
+        let slow = atrium.filling_time_t_squared(0.00293, 8.0).unwrap();
+
+        assert!((atrium.layer_height_t_squared(0.0469, fast).unwrap() - 8.0).abs() < 1e-9);
+        assert!(fast < slow);
+        // At the growth time, t^3/5 H^-4/5 (A/H²)^-3/5 gives the layer height.
+        let t_g = (1055.0_f64 / 0.0469).sqrt();
+        let x = t_g.powf(0.6) * 20.0_f64.powf(-0.8) * 2.25_f64.powf(-0.6);
+        let expected = 0.91 * 20.0 * x.powf(-1.45);
+        assert!((atrium.layer_height_t_squared(0.0469, t_g).unwrap() - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_filling_limits() {
+        let atrium = Atrium::new(900.0, 20.0);
+        assert!(atrium.within_limits());
+        assert_eq!(atrium.filling_time_steady(5000.0, 4.0), None);
+        assert_eq!(atrium.filling_time_t_squared(0.0469, 3.0), None);
+        assert_eq!(atrium.layer_height_steady(5000.0, 1e6), None);
+        assert_eq!(atrium.layer_height_t_squared(0.0469, 1e6), None);
+
+        let narrow = Atrium::new(100.0, 20.0);
+        assert!(!narrow.within_limits());
+        assert_eq!(narrow.filling_time_steady(5000.0, 10.0), None);
+        assert_eq!(narrow.layer_height_steady(5000.0, 1.0), None);
+        assert!(!Atrium::new(6000.0, 20.0).within_limits());
+    }
+
+    #[test]
+    fn test_exhaust_balances_plume() {
+        let atrium = Atrium::new(900.0, 20.0);
+        let exhaust = atrium.exhaust(5000.0, 0.0, 10.0);
+
+        let q_c: f64 = 3500.0;
+        let m = 0.071 * q_c.powf(1.0 / 3.0) * 10.0_f64.powf(5.0 / 3.0) + 0.0018 * q_c;
+        assert!((exhaust.mass_flow - m).abs() < 1e-9);
+        assert!((exhaust.smoke_temperature - (293.15 + q_c / m)).abs() < 1e-9);
+        assert!((exhaust.volumetric_flow - m * exhaust.smoke_temperature / 353.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_clear_height_inverts_exhaust() {
+        let atrium = Atrium::new(900.0, 20.0);
+        let exhaust = atrium.exhaust(5000.0, 9.0, 12.0);
+
+        let z = atrium.clear_height(5000.0, 9.0, exhaust.mass_flow);
+        assert!((z - 12.0).abs() < 1e-6);
+        assert_eq!(atrium.clear_height(5000.0, 9.0, 1e4), 20.0);
+    }
+
+    #[test]
+    fn test_virtual_origin_raises_mass_flow() {
+        // A large pool fire has a virtual origin below the floor.
+        let atrium = Atrium::new(900.0, 20.0);
+        assert!(
+            atrium.plume_mass_flow(5000.0, 25.0, 10.0) > atrium.plume_mass_flow(5000.0, 0.0, 10.0)
+        );
+    }
+
+    #[test]
+    fn test_no_fire_exhaust() {
+        let atrium = Atrium::new(900.0, 20.0);
+        let exhaust = atrium.exhaust(0.0, 0.0, 10.0);
+
+        assert_eq!(exhaust.mass_flow, 0.0);
+        assert_eq!(exhaust.smoke_temperature, 293.15);
+        assert_eq!(exhaust.volumetric_flow, 0.0);
+    }
+
+    #[test]
+    fn test_exhaust_points() {
+        let atrium = Atrium::new(900.0, 20.0);
+        let exhaust = atrium.exhaust(5000.0, 0.0, 10.0);
+        let limit = exhaust.plugholing_limit(2.0, 1.0, 293.15);
+        let points = exhaust.exhaust_points(2.0, 1.0, 293.15);
+
+        let expected =
+            4.16 * 32.0_f64.sqrt() * ((exhaust.smoke_temperature - 293.15) / 293.15).sqrt();
+        assert!((limit - expected).abs() < 1e-9);
+        assert!(points as f64 * limit >= exhaust.volumetric_flow);
+        assert!((points - 1) as f64 * limit < exhaust.volumetric_flow);
+    }
+}
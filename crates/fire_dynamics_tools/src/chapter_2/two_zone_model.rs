@@ -0,0 +1,512 @@
+//! Two-zone model of a single compartment with wall vents. The lower layer
+//! stays at ambient temperature; the upper layer is filled by an
+//! axisymmetric Heskestad plume and loses heat to the linings with the
+//! effective heat transfer coefficient of equations 2.3 to 2.5. Vent flows
+//! follow from Bernoulli with the floor pressure chosen so that the
+//! compartment pressure stays quasi-steady. The layer mass and volume are
+//! integrated with the adaptive Dormand-Prince method of the `ode` crate.
+//! Units follow chapter 2: kW, kJ/kg·K, kW/m·K, m, s and K.
+
+use ode::integrator::{self, Method, Options, Status};
+
+use super::{equation_2_3, equation_2_4, equation_2_5, equation_2_13};
+use crate::chapter_9::plume_mass_flow::heskestad;
+
+pub(crate) const G: f64 = 9.81;
+pub(crate) const C_P: f64 = 1.0;
+pub(crate) const DISCHARGE_COEFFICIENT: f64 = 0.7;
+/// Initial upper layer volume as a fraction of the compartment volume.
+pub(crate) const INITIAL_FRACTION: f64 = 1e-3;
+/// Largest step (s) of the integration.
+pub(crate) const MAX_STEP: f64 = 1.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vent {
+    /// Width (m).
+    pub width: f64,
+    /// Height of the bottom of the opening above the floor (m).
+    pub sill: f64,
+    /// Height of the top of the opening above the floor (m).
+    pub soffit: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lining {
+    /// Thermal conductivity (kW/m·K).
+    pub k: f64,
+    /// Density (kg/m³).
+    pub rho: f64,
+    /// Specific heat (kJ/kg·K).
+    pub c: f64,
+    /// Thickness (m).
+    pub delta: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct VentFlows {
+    /// Mass flow out of the upper layer (kg/s).
+    pub upper_out: f64,
+    /// Mass flow out of the lower layer (kg/s).
+    pub lower_out: f64,
+    /// Mass flow of ambient air into the lower layer (kg/s).
+    pub inflow: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TwoZoneModel {
+    pub width: f64,
+    pub length: f64,
+    pub height: f64,
+    pub vents: Vec<Vent>,
+    pub lining: Lining,
+    /// Ambient temperature (K).
+    pub t_a: f64,
+    /// Fraction of the heat release rate lost by flame radiation.
+    pub chi_r: f64,
+    /// Height of the base of the fire above the floor (m).
+    pub z_f: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZoneHistory {
+    /// Output times (s).
+    pub times: Vec<f64>,
+    /// Upper layer temperature (K) at each output time.
+    pub upper_temperatures: Vec<f64>,
+    /// Height of the layer interface above the floor (m).
+    pub interface_heights: Vec<f64>,
+    /// Mass flow of smoke out through the vents (kg/s).
+    pub outflows: Vec<f64>,
+    /// Mass flow of ambient air in through the vents (kg/s).
+    pub inflows: Vec<f64>,
+    /// Whether the integration reached the end time.
+    pub completed: bool,
+}
+
+impl ZoneHistory {
+    /// Time (s) at which the interface first descends to `z` (m), or `None`
+    /// if it stays above.
+    pub fn time_to_interface_height(&self, z: f64) -> Option<f64> {
+        first_crossing(&self.times, &self.interface_heights, z, false)
+    }
+
+    /// Time (s) at which the upper layer first reaches `t_g` (K), or `None`
+    /// if it stays below.
+    pub fn time_to_temperature(&self, t_g: f64) -> Option<f64> {
+        first_crossing(&self.times, &self.upper_temperatures, t_g, true)
+    }
+}
+
+/// Time at which `values` first reach `threshold`, rising or falling,
+/// interpolated between output times.
+fn first_crossing(times: &[f64], values: &[f64], threshold: f64, rising: bool) -> Option<f64> {
+    let reached = |value: f64| {
+        if rising {
+            value >= threshold
+        } else {
+            value <= threshold
+        }
+    };
+
+    values.iter().position(|&value| reached(value)).map(|i| {
+        if i == 0 {
+            return times[0];
+        }
+        let ratio = (threshold - values[i - 1]) / (values[i] - values[i - 1]);
+        times[i - 1] + ratio * (times[i] - times[i - 1])
+    })
+}
+
+/// Plume mass flow (kg/s) at height `z` (m) above the fire for a
+/// convective heat release rate `q_c` (kW), from the Heskestad plume of
+/// chapter 9. The mass flow does not depend on the ambient temperature.
+pub fn plume_mass_flow(q_c: f64, z: f64) -> f64 {
+    heskestad(q_c, z, 0.0, 293.0).mass_flow
+}
+
+/// Integrals over a vent segment of length `l` of the square root of the
+/// positive and negative parts of a pressure difference that is `p_1` (Pa)
+/// at the bottom and varies with `slope` (Pa/m).
+pub(crate) fn segment_integrals(p_1: f64, slope: f64, l: f64) -> (f64, f64) {
+    if l <= 0.0 {
+        return (0.0, 0.0);
+    }
+    if slope.abs() < 1e-12 {
+        return (p_1.max(0.0).sqrt() * l, (-p_1).max(0.0).sqrt() * l);
+    }
+
+    let p_2 = p_1 + slope * l;
+    let positive = 2.0 / 3.0 * (p_2.max(0.0).powf(1.5) - p_1.max(0.0).powf(1.5)) / slope;
+    let negative = 2.0 / 3.0 * ((-p_1).max(0.0).powf(1.5) - (-p_2).max(0.0).powf(1.5)) / slope;
+    (positive, negative)
+}
+
+/// Mass flows through a wall vent for a floor pressure difference `p_f`
+/// (Pa, inside minus outside), interface height `z` (m), upper layer density
+/// `rho_u` and ambient density `rho_a` (kg/m³).
+pub fn vent_flows(vent: &Vent, p_f: f64, z: f64, rho_u: f64, rho_a: f64) -> VentFlows {
+    let factor = DISCHARGE_COEFFICIENT * vent.width * 2.0_f64.sqrt();
+
+    let lower_top = vent.soffit.min(z);
+    let (lower_pos, lower_neg) = segment_integrals(p_f, 0.0, lower_top - vent.sill);
+
+    let upper_bottom = vent.sill.max(z);
+    let slope = (rho_a - rho_u) * G;
+    let (upper_pos, upper_neg) = segment_integrals(
+        p_f + slope * (upper_bottom - z),
+        slope,
+        vent.soffit - upper_bottom,
+    );
+
+    VentFlows {
+        upper_out: factor * rho_u.sqrt() * upper_pos,
+        lower_out: factor * rho_a.sqrt() * lower_pos,
+        inflow: factor * rho_a.sqrt() * (lower_neg + upper_neg),
+    }
+}
+
+/// Effective heat transfer coefficient (kW/m²·K) of the linings at time `t`
+/// (s), taken from 1 s to avoid the singularity at ignition.
+pub fn effective_heat_transfer_coefficient(lining: &Lining, t: f64) -> f64 {
+    let t_p = equation_2_4::thermal_penetration_time(lining.rho, lining.c, lining.k, lining.delta);
+    if t >= t_p {
+        equation_2_3::heat_transfer_coefficient_longtimes_or_thinwalls(lining.k, lining.delta)
+    } else {
+        equation_2_5::heat_transfer_coefficient_shorttimes_or_thickwalls(
+            lining.k,
+            lining.rho,
+            lining.c,
+            t.max(1.0),
+        )
+    }
+}
+
+/// Upper layer `mass` (kg) and `volume` (m³) held between the initial
+/// volume `v_min` and the compartment volume `v_max`, and no denser than
+/// ambient air of density `rho_a`.
+fn bounded_layer(mass: f64, volume: f64, v_min: f64, v_max: f64, rho_a: f64) -> (f64, f64) {
+    let volume = volume.clamp(v_min, v_max);
+    (mass.clamp(f64::MIN_POSITIVE, rho_a * volume), volume)
+}
+
+/// Upper layer conditions and rates of change at one instant.
+struct Rates {
+    flows: VentFlows,
+    d_mass: f64,
+    d_volume: f64,
+}
+
+impl TwoZoneModel {
+    /// Compartment at 20 °C with the fire at floor level and 30 % of the
+    /// heat release radiated from the flame.
+    pub fn new(width: f64, length: f64, height: f64, vents: Vec<Vent>, lining: Lining) -> Self {
+        TwoZoneModel {
+            width,
+            length,
+            height,
+            vents,
+            lining,
+            t_a: 293.15,
+            chi_r: 0.3,
+            z_f: 0.0,
+        }
+    }
+
+    fn floor_area(&self) -> f64 {
+        self.width * self.length
+    }
+
+    fn volume(&self) -> f64 {
+        self.floor_area() * self.height
+    }
+
+    fn flows(&self, p_f: f64, z: f64, rho_u: f64, rho_a: f64) -> VentFlows {
+        self.vents
+            .iter()
+            .map(|vent| vent_flows(vent, p_f, z, rho_u, rho_a))
+            .fold(VentFlows::default(), |total, flows| VentFlows {
+                upper_out: total.upper_out + flows.upper_out,
+                lower_out: total.lower_out + flows.lower_out,
+                inflow: total.inflow + flows.inflow,
+            })
+    }
+
+    fn rates(&self, q: f64, t: f64, mass: f64, volume: f64) -> Rates {
+        let rho_a = equation_2_13::density_hot_gas_layer(self.t_a);
+        let t_u = rho_a * self.t_a * volume / mass;
+        let rho_u = equation_2_13::density_hot_gas_layer(t_u);
+        let z = (self.height - volume / self.floor_area()).max(0.0);
+
+        let q_c = (1.0 - self.chi_r) * q.max(0.0);
+        let area = self.floor_area() + 2.0 * (self.width + self.length) * (self.height - z);
+        let h_k = effective_heat_transfer_coefficient(&self.lining, t);
+        let q_loss = h_k * area * (t_u - self.t_a);
+
+        // Net enthalpy flow into the compartment, which is zero when the
+        // pressure is quasi-steady. It decreases with the floor pressure.
+        let balance = |p_f: f64| {
+            let flows = self.flows(p_f, z, rho_u, rho_a);
+            q_c - q_loss + C_P * self.t_a * (flows.inflow - flows.lower_out)
+                - C_P * t_u * flows.upper_out
+        };
+
+        let mut low = -10.0;
+        let mut high = 10.0;
+        while balance(low) < 0.0 && low > -1e6 {
+            low *= 2.0;
+        }
+        while balance(high) > 0.0 && high < 1e6 {
+            high *= 2.0;
+        }
+        for _ in 0..60 {
+            let mid = 0.5 * (low + high);
+            if balance(mid) > 0.0 {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        let flows = self.flows(0.5 * (low + high), z, rho_u, rho_a);
+
+        let m_p = if z <= 0.0 {
+            flows.inflow - flows.lower_out
+        } else {
+            plume_mass_flow(q_c, z - self.z_f)
+        };
+
+        let d_mass = m_p - flows.upper_out;
+        let d_volume = (q_c - q_loss + m_p * C_P * self.t_a - flows.upper_out * C_P * t_u)
+            / (C_P * rho_a * self.t_a);
+
+        Rates {
+            flows,
+            d_mass,
+            d_volume,
+        }
+    }
+
+    /// Layer histories at times 0, `delta_t`, 2 `delta_t`, ... up to `t_end`
+    /// (s) for the heat release rate curve `hrr` (kW against s). The history
+    /// ends at the last output time reached, with `completed` false, if the
+    /// integration fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the compartment has no vents, as the pressure cannot then
+    /// stay quasi-steady, if a dimension is not positive or if `delta_t` is
+    /// not positive.
+    pub fn solve<F: Fn(f64) -> f64>(&self, hrr: F, delta_t: f64, t_end: f64) -> ZoneHistory {
+        assert!(
+            !self.vents.is_empty(),
+            "the compartment needs at least one vent"
+        );
+        assert!(
+            self.width > 0.0 && self.length > 0.0 && self.height > 0.0,
+            "the compartment dimensions must be positive"
+        );
+        assert!(delta_t > 0.0, "the time step must be positive");
+
+        let rho_a = equation_2_13::density_hot_gas_layer(self.t_a);
+        let v_min = INITIAL_FRACTION * self.volume();
+        let v_max = self.volume();
+        let layer = |y: &[f64]| bounded_layer(y[0], y[1], v_min, v_max, rho_a);
+
+        let solution = integrator::solve(
+            Method::DormandPrince,
+            |t, y| {
+                // A failed trial step is rejected by the integrator.
+                if y.iter().any(|value| !value.is_finite()) {
+                    return vec![f64::NAN; 2];
+                }
+                let (mass, volume) = layer(y);
+                let rates = self.rates(hrr(t), t, mass, volume);
+                // The layer stops growing at the floor and shrinking at its
+                // initial volume, and stays no denser than ambient air.
+                let d_volume = if (y[1] >= v_max && rates.d_volume > 0.0)
+                    || (y[1] <= v_min && rates.d_volume < 0.0)
+                {
+                    0.0
+                } else {
+                    rates.d_volume
+                };
+                let d_mass = if y[0] >= rho_a * volume {
+                    rates.d_mass.min(rho_a * d_volume)
+                } else {
+                    rates.d_mass
+                };
+                vec![d_mass, d_volume]
+            },
+            0.0,
+            &[rho_a * v_min, v_min],
+            t_end.max(0.0),
+            delta_t,
+            &[],
+            &Options {
+                h_max: MAX_STEP,
+                ..Options::default()
+            },
+        );
+
+        let mut history = ZoneHistory {
+            times: vec![],
+            upper_temperatures: vec![],
+            interface_heights: vec![],
+            outflows: vec![],
+            inflows: vec![],
+            completed: solution.status == Status::Completed,
+        };
+        for (&t, y) in solution.times.iter().zip(&solution.states) {
+            let (mass, volume) = layer(y);
+            let flows = self.rates(hrr(t), t, mass, volume).flows;
+            history.times.push(t);
+            history
+                .upper_temperatures
+                .push(rho_a * self.t_a * volume / mass);
+            history
+                .interface_heights
+                .push((self.height - volume / self.floor_area()).max(0.0));
+            history.outflows.push(flows.upper_out + flows.lower_out);
+            history.inflows.push(flows.inflow);
+        }
+
+        history
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gypsum() -> Lining {
+        Lining {
+            k: 0.00048,
+            rho: 1440.0,
+            c: 0.84,
+            delta: 0.016,
+        }
+    }
+
+    fn room() -> TwoZoneModel {
+        TwoZoneModel::new(
+            4.0,
+            5.0,
+            2.5,
+            vec![Vent {
+                width: 0.9,
+                sill: 0.0,
+                soffit: 2.0,
+            }],
+            gypsum(),
+        )
+    }
+
+    #[test]
+    fn test_plume_mass_flow() {
+        let q_c: f64 = 700.0;
+        let expected = 0.071 * q_c.powf(1.0 / 3.0) * 4.0_f64.powf(5.0 / 3.0) + 0.0018 * q_c;
+        assert!((plume_mass_flow(q_c, 4.0) - expected).abs() < 1e-9);
+        assert!((plume_mass_flow(q_c, 0.5) - 0.032 * q_c.powf(0.6) * 0.5).abs() < 1e-9);
+        assert_eq!(plume_mass_flow(0.0, 2.0), 0.0);
+    }
+
+    #[test]
+    fn test_vent_flows_uniform_pressure() {
+        let vent = Vent {
+            width: 1.0,
+            sill: 0.0,
+            soffit: 2.0,
+        };
+        let flows = vent_flows(&vent, 4.0, 3.0, 1.2, 1.2);
+        let expected = 0.7 * 2.0 * (2.0 * 1.2 * 4.0_f64).sqrt();
+
+        assert!((flows.lower_out - expected).abs() < 1e-9);
+        assert_eq!(flows.upper_out, 0.0);
+        assert_eq!(flows.inflow, 0.0);
+    }
+
+    #[test]
+    fn test_vent_flows_neutral_plane() {
+        let vent = Vent {
+            width: 1.0,
+            sill: 0.0,
+            soffit: 2.0,
+        };
+        // Interface at the floor with the neutral plane at mid-height.
+        let rho_u = 0.6;
+        let rho_a = 1.2;
+        let slope = (rho_a - rho_u) * G;
+        let flows = vent_flows(&vent, -slope, 0.0, rho_u, rho_a);
+
+        let integral = 2.0 / 3.0 * slope.sqrt();
+        let factor = 0.7 * 2.0_f64.sqrt();
+        assert!((flows.upper_out - factor * rho_u.sqrt() * integral).abs() < 1e-9);
+        assert!((flows.inflow - factor * rho_a.sqrt() * integral).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_effective_heat_transfer_coefficient() {
+        let lining = gypsum();
+        let short = (0.00048_f64 * 1440.0 * 0.84 / 60.0).sqrt();
+        assert!((effective_heat_transfer_coefficient(&lining, 60.0) - short).abs() < 1e-9);
+        assert!((effective_heat_transfer_coefficient(&lining, 1e5) - 0.03).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_no_fire_stays_ambient() {
+        let history = room().solve(|_| 0.0, 10.0, 60.0);
+
+        assert_eq!(history.times.len(), 7);
+        for t_u in &history.upper_temperatures {
+            assert!((t_u - 293.15).abs() < 1e-6);
+        }
+        assert_eq!(history.time_to_temperature(400.0), None);
+    }
+
+    #[test]
+    fn test_steady_fire_fills_room() {
+        let history = room().solve(|_| 500.0, 5.0, 600.0);
+
+        let last = history.times.len() - 1;
+        let z = history.interface_heights[last];
+        let t_u = history.upper_temperatures[last];
+
+        // The layer settles just below the door soffit with a hot upper layer
+        // and balanced mass flows.
+        assert!(z > 0.5 && z < 2.0);
+        assert!(t_u > 400.0 && t_u < 600.0);
+        assert!(
+            (history.outflows[last] - history.inflows[last]).abs() < 0.05 * history.inflows[last]
+        );
+
+        let aset = history.time_to_interface_height(2.0).unwrap();
+        assert!(aset > 0.0 && aset < 60.0);
+        for pair in history.upper_temperatures.windows(2).take(20) {
+            assert!(pair[1] >= pair[0] - 1e-6);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the time step must be positive")]
+    fn test_zero_time_step() {
+        room().solve(|_| 500.0, 0.0, 600.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "the compartment dimensions must be positive")]
+    fn test_zero_height() {
+        let model = TwoZoneModel {
+            height: 0.0,
+            ..room()
+        };
+        model.solve(|_| 500.0, 5.0, 600.0);
+    }
+
+    #[test]
+    fn test_failed_integration() {
+        let hrr = |t: f64| if t < 100.0 { 500.0 } else { f64::INFINITY };
+        let history = room().solve(hrr, 5.0, 600.0);
+        assert!(!history.completed);
+        assert_eq!(history.times.last(), Some(&95.0));
+        assert!(room().solve(|_| 500.0, 5.0, 600.0).completed);
+    }
+}
@@ -0,0 +1,838 @@
+//! Network of two-zone compartments connected by wall vents, ceiling vents
+//! and fans, extending the single compartment model. Lower layers stay at
+//! ambient temperature; gas leaving an upper layer enters the upper layer of
+//! the receiving room and gas leaving a lower layer, or the outside, enters
+//! its lower layer unless that room is filled with smoke. Floor pressures
+//! are found by sweeping the rooms until every room is quasi-steady. The
+//! ceiling vents and fans switch the rates abruptly as flows reverse or the
+//! interface passes a fan inlet, so the layers are advanced by stable
+//! explicit steps rather than with the adaptive integrator of the single
+//! compartment. Units follow the two-zone model: kW, m, s and K.
+
+use std::collections::VecDeque;
+
+use super::equation_2_13;
+use super::two_zone_model::{
+    C_P, DISCHARGE_COEFFICIENT, G, INITIAL_FRACTION, Lining, MAX_STEP, ZoneHistory,
+    effective_heat_transfer_coefficient, plume_mass_flow, segment_integrals,
+};
+
+/// Largest number of pressure sweeps at each evaluation.
+const MAX_SWEEPS: usize = 200;
+/// Pressure resolution (Pa) of the bisection and the sweeps.
+const TOLERANCE: f64 = 1e-7;
+/// Shortest stable step (s) before the integration is treated as stalled.
+const MIN_STEP: f64 = 1e-6;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Room {
+    pub width: f64,
+    pub length: f64,
+    pub height: f64,
+    /// Height of the floor above the reference level (m).
+    pub elevation: f64,
+    pub lining: Lining,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Connection {
+    /// Door or window in a wall of room `from`, leading to room `to` or to
+    /// the outside. The sill and soffit are measured from the floor of `from`.
+    WallVent {
+        from: usize,
+        to: Option<usize>,
+        width: f64,
+        sill: f64,
+        soffit: f64,
+    },
+    /// Opening of area `area` (m²) in the ceiling of room `lower`, leading
+    /// to room `upper` or to the outside. Flow is in one direction at a time.
+    CeilingVent {
+        lower: usize,
+        upper: Option<usize>,
+        area: f64,
+    },
+    /// Fan moving `flow` (m³/s) from room `from` to room `to`, with `None`
+    /// for the outside. It draws from the layer at `height` above the floor
+    /// of `from`.
+    Fan {
+        from: Option<usize>,
+        to: Option<usize>,
+        height: f64,
+        flow: f64,
+    },
+}
+
+/// Reason the pressures of a network cannot stay quasi-steady.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Fault {
+    /// A connection refers to a room that does not exist.
+    UnknownRoom,
+    /// The room has no path of wall or ceiling vents to the outside.
+    NotVented(usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetworkHistory {
+    /// Layer history of each room.
+    pub rooms: Vec<ZoneHistory>,
+    /// Whether the floor pressures converged within `MAX_SWEEPS` sweeps at
+    /// every evaluation and the stable step stayed above `MIN_STEP`. The
+    /// histories are unreliable otherwise.
+    pub converged: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZoneNetwork {
+    pub rooms: Vec<Room>,
+    pub connections: Vec<Connection>,
+    /// Ambient temperature (K).
+    pub t_a: f64,
+    /// Fraction of the heat release rate lost by flame radiation.
+    pub chi_r: f64,
+    /// Height of the base of the fire above the floor of its room (m).
+    pub z_f: f64,
+}
+
+/// Upper layer of one room.
+#[derive(Debug, Clone, Copy)]
+struct Layer {
+    mass: f64,
+    volume: f64,
+}
+
+/// Mass flow between layers, with `None` for the outside.
+#[derive(Debug, Clone, Copy)]
+struct Transfer {
+    from: Option<usize>,
+    to: Option<usize>,
+    /// Whether the gas leaves an upper layer.
+    upper: bool,
+    /// Mass flow (kg/s).
+    mass: f64,
+    /// Temperature of the gas (K).
+    temperature: f64,
+}
+
+/// Conditions in a room used to evaluate the flows.
+#[derive(Debug, Clone, Copy)]
+struct Conditions {
+    t_u: f64,
+    rho_u: f64,
+    /// Interface height above the floor (m).
+    z: f64,
+}
+
+/// Totals for one room over all transfers.
+#[derive(Debug, Clone, Copy, Default)]
+struct Totals {
+    mass_in_upper: f64,
+    mass_out_upper: f64,
+    enthalpy_in_upper: f64,
+    enthalpy_out_upper: f64,
+    enthalpy_in: f64,
+    enthalpy_out: f64,
+    mass_in: f64,
+    mass_out: f64,
+}
+
+impl ZoneNetwork {
+    /// Network at 20 °C with the fire at floor level and 30 % of the heat
+    /// release radiated from the flame.
+    pub fn new(rooms: Vec<Room>, connections: Vec<Connection>) -> Self {
+        ZoneNetwork {
+            rooms,
+            connections,
+            t_a: 293.15,
+            chi_r: 0.3,
+            z_f: 0.0,
+        }
+    }
+
+    /// First fault that stops the pressures staying quasi-steady, or `None`
+    /// if every room is vented to the outside, directly or through other
+    /// rooms.
+    pub fn fault(&self) -> Option<Fault> {
+        let n = self.rooms.len();
+        let known = |room: Option<usize>| room.is_none_or(|i| i < n);
+
+        let mut vents = vec![];
+        for connection in &self.connections {
+            let (a, b, vent) = match *connection {
+                Connection::WallVent { from, to, .. } => (Some(from), to, true),
+                Connection::CeilingVent { lower, upper, .. } => (Some(lower), upper, true),
+                Connection::Fan { from, to, .. } => (from, to, false),
+            };
+            if !known(a) || !known(b) {
+                return Some(Fault::UnknownRoom);
+            }
+            if vent {
+                vents.push((a, b));
+            }
+        }
+
+        // Rooms reached from the outside through vents, breadth first.
+        let mut vented = vec![false; n];
+        let mut queue = VecDeque::from([None]);
+        while let Some(room) = queue.pop_front() {
+            for &(a, b) in &vents {
+                let next = if a == room {
+                    b
+                } else if b == room {
+                    a
+                } else {
+                    continue;
+                };
+                if let Some(i) = next
+                    && !vented[i]
+                {
+                    vented[i] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        vented.iter().position(|&v| !v).map(Fault::NotVented)
+    }
+
+    fn rho_a(&self) -> f64 {
+        equation_2_13::density_hot_gas_layer(self.t_a)
+    }
+
+    fn conditions(&self, layers: &[Layer]) -> Vec<Conditions> {
+        let rho_a = self.rho_a();
+        self.rooms
+            .iter()
+            .zip(layers)
+            .map(|(room, layer)| {
+                let t_u = rho_a * self.t_a * layer.volume / layer.mass;
+                Conditions {
+                    t_u,
+                    rho_u: equation_2_13::density_hot_gas_layer(t_u),
+                    z: (room.height - layer.volume / (room.width * room.length)).max(0.0),
+                }
+            })
+            .collect()
+    }
+
+    /// Pressure relative to ambient (Pa), its gradient (Pa/m), density and
+    /// temperature at absolute height `y` in room `room`, and whether that
+    /// height is in the upper layer.
+    fn side(
+        &self,
+        room: Option<usize>,
+        y: f64,
+        pressures: &[f64],
+        conditions: &[Conditions],
+    ) -> (f64, f64, f64, f64, bool) {
+        let rho_a = self.rho_a();
+        match room {
+            None => (0.0, 0.0, rho_a, self.t_a, false),
+            Some(i) => {
+                let interface = self.rooms[i].elevation + conditions[i].z;
+                if y > interface {
+                    let slope = (rho_a - conditions[i].rho_u) * G;
+                    (
+                        pressures[i] + slope * (y - interface),
+                        slope,
+                        conditions[i].rho_u,
+                        conditions[i].t_u,
+                        true,
+                    )
+                } else {
+                    (pressures[i], 0.0, rho_a, self.t_a, false)
+                }
+            }
+        }
+    }
+
+    fn transfers(&self, pressures: &[f64], conditions: &[Conditions]) -> Vec<Transfer> {
+        let mut transfers = vec![];
+
+        for connection in &self.connections {
+            match *connection {
+                Connection::WallVent {
+                    from,
+                    to,
+                    width,
+                    sill,
+                    soffit,
+                } => {
+                    let bottom = self.rooms[from].elevation + sill;
+                    let top = self.rooms[from].elevation + soffit;
+
+                    let mut breaks = vec![bottom, top];
+                    for room in [Some(from), to].into_iter().flatten() {
+                        let interface = self.rooms[room].elevation + conditions[room].z;
+                        if interface > bottom && interface < top {
+                            breaks.push(interface);
+                        }
+                    }
+                    breaks.sort_by(|a, b| a.total_cmp(b));
+
+                    for pair in breaks.windows(2) {
+                        let mid = 0.5 * (pair[0] + pair[1]);
+                        let (p_i, s_i, rho_i, t_i, upper_i) =
+                            self.side(Some(from), mid, pressures, conditions);
+                        let (p_j, s_j, rho_j, t_j, upper_j) =
+                            self.side(to, mid, pressures, conditions);
+
+                        let slope = s_i - s_j;
+                        let p_bottom = (p_i - p_j) - slope * (mid - pair[0]);
+                        let (positive, negative) =
+                            segment_integrals(p_bottom, slope, pair[1] - pair[0]);
+                        let factor = DISCHARGE_COEFFICIENT * width * 2.0_f64.sqrt();
+
+                        transfers.push(Transfer {
+                            from: Some(from),
+                            to,
+                            upper: upper_i,
+                            mass: factor * rho_i.sqrt() * positive,
+                            temperature: t_i,
+                        });
+                        transfers.push(Transfer {
+                            from: to,
+                            to: Some(from),
+                            upper: upper_j,
+                            mass: factor * rho_j.sqrt() * negative,
+                            temperature: t_j,
+                        });
+                    }
+                }
+                Connection::CeilingVent { lower, upper, area } => {
+                    let y = self.rooms[lower].elevation + self.rooms[lower].height;
+                    let (p_i, _, rho_i, t_i, upper_i) =
+                        self.side(Some(lower), y, pressures, conditions);
+                    // Gas entering from above meets the receiving room at its
+                    // floor, so sample just above the ceiling.
+                    let (p_j, _, rho_j, t_j, upper_j) =
+                        self.side(upper, y + 1e-9, pressures, conditions);
+                    let delta_p = p_i - p_j;
+
+                    transfers.push(if delta_p >= 0.0 {
+                        Transfer {
+                            from: Some(lower),
+                            to: upper,
+                            upper: upper_i,
+                            mass: DISCHARGE_COEFFICIENT * area * (2.0 * rho_i * delta_p).sqrt(),
+                            temperature: t_i,
+                        }
+                    } else {
+                        Transfer {
+                            from: upper,
+                            to: Some(lower),
+                            upper: upper_j,
+                            mass: DISCHARGE_COEFFICIENT * area * (-2.0 * rho_j * delta_p).sqrt(),
+                            temperature: t_j,
+                        }
+                    });
+                }
+                Connection::Fan {
+                    from,
+                    to,
+                    height,
+                    flow,
+                } => {
+                    let y = from.map_or(0.0, |i| self.rooms[i].elevation + height);
+                    let (_, _, rho, t, upper) = self.side(from, y, pressures, conditions);
+                    transfers.push(Transfer {
+                        from,
+                        to,
+                        upper,
+                        mass: rho * flow,
+                        temperature: t,
+                    });
+                }
+            }
+        }
+
+        transfers
+    }
+
+    fn totals(&self, transfers: &[Transfer], conditions: &[Conditions]) -> Vec<Totals> {
+        let mut totals = vec![Totals::default(); self.rooms.len()];
+
+        for transfer in transfers {
+            let enthalpy = C_P * transfer.temperature * transfer.mass;
+            if let Some(i) = transfer.from {
+                totals[i].mass_out += transfer.mass;
+                totals[i].enthalpy_out += enthalpy;
+                if transfer.upper {
+                    totals[i].mass_out_upper += transfer.mass;
+                    totals[i].enthalpy_out_upper += enthalpy;
+                }
+            }
+            if let Some(j) = transfer.to {
+                totals[j].mass_in += transfer.mass;
+                totals[j].enthalpy_in += enthalpy;
+                if transfer.upper || conditions[j].z <= 0.0 {
+                    totals[j].mass_in_upper += transfer.mass;
+                    totals[j].enthalpy_in_upper += enthalpy;
+                }
+            }
+        }
+
+        totals
+    }
+
+    /// Floor pressures (Pa) at which every room is quasi-steady, starting
+    /// from `pressures`. Each room's net enthalpy flow decreases with its own
+    /// floor pressure, so each sweep bisects one room at a time. Returns
+    /// whether the sweeps converged.
+    fn solve_pressures(
+        &self,
+        pressures: &mut [f64],
+        conditions: &[Conditions],
+        heat: &[f64],
+    ) -> bool {
+        let balance = |i: usize, pressures: &[f64]| {
+            let transfers = self.transfers(pressures, conditions);
+            let totals = self.totals(&transfers, conditions);
+            heat[i] + totals[i].enthalpy_in - totals[i].enthalpy_out
+        };
+
+        for _ in 0..MAX_SWEEPS {
+            let mut change: f64 = 0.0;
+
+            for i in 0..self.rooms.len() {
+                let start = pressures[i];
+                let mut trial = pressures.to_vec();
+                let mut at = |p: f64| {
+                    trial[i] = p;
+                    balance(i, &trial)
+                };
+
+                let mut width = 1.0;
+                let mut low = start - width;
+                while at(low) < 0.0 && width < 1e6 {
+                    width *= 2.0;
+                    low = start - width;
+                }
+                width = 1.0;
+                let mut high = start + width;
+                while at(high) > 0.0 && width < 1e6 {
+                    width *= 2.0;
+                    high = start + width;
+                }
+                while high - low > TOLERANCE {
+                    let mid = 0.5 * (low + high);
+                    if at(mid) > 0.0 {
+                        low = mid;
+                    } else {
+                        high = mid;
+                    }
+                }
+
+                pressures[i] = 0.5 * (low + high);
+                change = change.max((pressures[i] - start).abs());
+            }
+
+            if change < TOLERANCE {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Layer histories of each room at times 0, `delta_t`, 2 `delta_t`, ...
+    /// up to `t_end` (s) for a fire in room `fire_room` with heat release
+    /// rate curve `hrr` (kW against s). The outflows and inflows of each
+    /// history are the totals through all of the room's connections. If the
+    /// stable step falls below `MIN_STEP` the histories end at the last
+    /// output time reached, with `completed` and `converged` false.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the network has a `Fault`, as the pressures cannot then
+    /// stay quasi-steady, if `fire_room` does not exist or if `delta_t` is
+    /// not positive.
+    pub fn solve<F: Fn(f64) -> f64>(
+        &self,
+        fire_room: usize,
+        hrr: F,
+        delta_t: f64,
+        t_end: f64,
+    ) -> NetworkHistory {
+        let fault = self.fault();
+        assert!(fault.is_none(), "invalid network: {:?}", fault);
+        assert!(fire_room < self.rooms.len(), "the fire room does not exist");
+        assert!(delta_t > 0.0, "the time step must be positive");
+
+        let rho_a = self.rho_a();
+        let mut layers: Vec<Layer> = self
+            .rooms
+            .iter()
+            .map(|room| {
+                let volume = INITIAL_FRACTION * room.width * room.length * room.height;
+                Layer {
+                    mass: rho_a * volume,
+                    volume,
+                }
+            })
+            .collect();
+        let mut pressures = vec![0.0; self.rooms.len()];
+        let mut histories = vec![
+            ZoneHistory {
+                times: vec![],
+                upper_temperatures: vec![],
+                interface_heights: vec![],
+                outflows: vec![],
+                inflows: vec![],
+                completed: true,
+            };
+            self.rooms.len()
+        ];
+
+        let steps = (t_end.max(0.0) / delta_t).round() as usize;
+        let mut t = 0.0;
+        let mut converged = true;
+        let mut stalled = false;
+
+        'output: for step in 0..=steps {
+            let t_out = step as f64 * delta_t;
+
+            while t < t_out - 1e-9 {
+                let (dt, step_converged) =
+                    self.advance(&mut layers, &mut pressures, fire_room, hrr(t), t, t_out - t);
+                converged &= step_converged;
+                if dt.is_nan() || (dt < MIN_STEP && dt < t_out - t) {
+                    stalled = true;
+                    break 'output;
+                }
+                t += dt;
+            }
+
+            let conditions = self.conditions(&layers);
+            let heat = self.heat(&conditions, fire_room, hrr(t), t);
+            converged &= self.solve_pressures(&mut pressures, &conditions, &heat);
+            let totals = self.totals(&self.transfers(&pressures, &conditions), &conditions);
+            for (i, history) in histories.iter_mut().enumerate() {
+                history.times.push(t_out);
+                history.upper_temperatures.push(conditions[i].t_u);
+                history.interface_heights.push(conditions[i].z);
+                history.outflows.push(totals[i].mass_out);
+                history.inflows.push(totals[i].mass_in);
+            }
+        }
+
+        for history in histories.iter_mut() {
+            history.completed = !stalled;
+        }
+
+        NetworkHistory {
+            rooms: histories,
+            converged: converged && !stalled,
+        }
+    }
+
+    /// Heat added to the gas less the heat lost to the linings (kW) in each
+    /// room.
+    fn heat(&self, conditions: &[Conditions], fire_room: usize, q: f64, t: f64) -> Vec<f64> {
+        self.rooms
+            .iter()
+            .zip(conditions)
+            .enumerate()
+            .map(|(i, (room, c))| {
+                let q_c = if i == fire_room {
+                    (1.0 - self.chi_r) * q.max(0.0)
+                } else {
+                    0.0
+                };
+                let area = room.width * room.length
+                    + 2.0 * (room.width + room.length) * (room.height - c.z);
+                q_c - effective_heat_transfer_coefficient(&room.lining, t)
+                    * area
+                    * (c.t_u - self.t_a)
+            })
+            .collect()
+    }
+
+    /// Advances the upper layers by one stable explicit step no longer than
+    /// `dt_max`, returning the step taken (s) and whether the pressures
+    /// converged.
+    fn advance(
+        &self,
+        layers: &mut [Layer],
+        pressures: &mut [f64],
+        fire_room: usize,
+        q: f64,
+        t: f64,
+        dt_max: f64,
+    ) -> (f64, bool) {
+        let rho_a = self.rho_a();
+        let conditions = self.conditions(layers);
+        let heat = self.heat(&conditions, fire_room, q, t);
+        let converged = self.solve_pressures(pressures, &conditions, &heat);
+        let totals = self.totals(&self.transfers(pressures, &conditions), &conditions);
+
+        let mut dt = dt_max.min(MAX_STEP);
+        let mut rates = vec![];
+        for (i, room) in self.rooms.iter().enumerate() {
+            let c = conditions[i];
+            let q_c = if i == fire_room {
+                (1.0 - self.chi_r) * q.max(0.0)
+            } else {
+                0.0
+            };
+            let m_p = if c.z > 0.0 {
+                plume_mass_flow(q_c, c.z - self.z_f)
+            } else {
+                0.0
+            };
+
+            let d_mass = m_p + totals[i].mass_in_upper - totals[i].mass_out_upper;
+            let d_volume = (heat[i] + m_p * C_P * self.t_a + totals[i].enthalpy_in_upper
+                - totals[i].enthalpy_out_upper)
+                / (C_P * rho_a * self.t_a);
+
+            let area =
+                room.width * room.length + 2.0 * (room.width + room.length) * (room.height - c.z);
+            let conductance = effective_heat_transfer_coefficient(&room.lining, t) * area
+                + (m_p + totals[i].mass_in_upper + totals[i].mass_out_upper) * C_P;
+            dt = dt.min(0.5 * layers[i].mass * C_P / conductance);
+            rates.push((d_mass, d_volume));
+        }
+
+        for ((layer, room), (d_mass, d_volume)) in layers.iter_mut().zip(&self.rooms).zip(rates) {
+            let v_max = room.width * room.length * room.height;
+            layer.mass = (layer.mass + d_mass * dt).max(f64::MIN_POSITIVE);
+            layer.volume = (layer.volume + d_volume * dt).clamp(INITIAL_FRACTION * v_max, v_max);
+            // The upper layer cannot be denser than ambient air.
+            layer.mass = layer.mass.min(rho_a * layer.volume);
+        }
+
+        (dt, converged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chapter_2::two_zone_model::{TwoZoneModel, Vent};
+
+    fn gypsum() -> Lining {
+        Lining {
+            k: 0.00048,
+            rho: 1440.0,
+            c: 0.84,
+            delta: 0.016,
+        }
+    }
+
+    fn room(width: f64, length: f64) -> Room {
+        Room {
+            width,
+            length,
+            height: 2.5,
+            elevation: 0.0,
+            lining: gypsum(),
+        }
+    }
+
+    #[test]
+    fn test_single_room_matches_two_zone_model() {
+        let network = ZoneNetwork::new(
+            vec![room(4.0, 5.0)],
+            vec![Connection::WallVent {
+                from: 0,
+                to: None,
+                width: 0.9,
+                sill: 0.0,
+                soffit: 2.0,
+            }],
+        );
+        let single = TwoZoneModel::new(
+            4.0,
+            5.0,
+            2.5,
+            vec![Vent {
+                width: 0.9,
+                sill: 0.0,
+                soffit: 2.0,
+            }],
+            gypsum(),
+        );
+
+        let result = network.solve(0, |_| 500.0, 10.0, 300.0);
+        assert!(result.converged);
+        assert!(result.rooms[0].completed);
+        let histories = result.rooms;
+        let expected = single.solve(|_| 500.0, 10.0, 300.0);
+        let last = expected.times.len() - 1;
+
+        assert_eq!(histories[0].times, expected.times);
+        assert!(
+            (histories[0].upper_temperatures[last] - expected.upper_temperatures[last]).abs() < 1.0
+        );
+        assert!(
+            (histories[0].interface_heights[last] - expected.interface_heights[last]).abs() < 0.02
+        );
+    }
+
+    #[test]
+    fn test_stalled_integration() {
+        let network = ZoneNetwork::new(
+            vec![room(4.0, 5.0)],
+            vec![Connection::WallVent {
+                from: 0,
+                to: None,
+                width: 0.9,
+                sill: 0.0,
+                soffit: 2.0,
+            }],
+        );
+
+        let hrr = |t: f64| if t < 100.0 { 500.0 } else { f64::INFINITY };
+        let result = network.solve(0, hrr, 10.0, 300.0);
+        assert!(!result.converged);
+        assert!(!result.rooms[0].completed);
+        assert_eq!(result.rooms[0].times.last(), Some(&100.0));
+    }
+
+    #[test]
+    fn test_smoke_spreads_to_corridor() {
+        let network = ZoneNetwork::new(
+            vec![room(4.0, 5.0), room(2.0, 10.0)],
+            vec![
+                Connection::WallVent {
+                    from: 0,
+                    to: Some(1),
+                    width: 0.9,
+                    sill: 0.0,
+                    soffit: 2.0,
+                },
+                Connection::WallVent {
+                    from: 1,
+                    to: None,
+                    width: 0.9,
+                    sill: 0.0,
+                    soffit: 2.0,
+                },
+            ],
+        );
+
+        let histories = network.solve(0, |_| 300.0, 10.0, 300.0).rooms;
+        let last = histories[0].times.len() - 1;
+
+        // The corridor fills later and stays cooler than the fire room.
+        let fire_room = histories[0].time_to_interface_height(2.0).unwrap();
+        let corridor = histories[1].time_to_interface_height(2.0).unwrap();
+        assert!(corridor > fire_room);
+        assert!(histories[1].upper_temperatures[last] > 300.0);
+        assert!(histories[1].upper_temperatures[last] < histories[0].upper_temperatures[last]);
+
+        // Mass is conserved through the corridor at steady state.
+        let net = histories[1].inflows[last] - histories[1].outflows[last];
+        assert!(net.abs() < 0.05 * histories[1].inflows[last]);
+    }
+
+    #[test]
+    fn test_ceiling_vent_and_exhaust_fan() {
+        let mut upper = room(4.0, 5.0);
+        upper.elevation = 2.5;
+        let network = ZoneNetwork::new(
+            vec![room(4.0, 5.0), upper],
+            vec![
+                Connection::WallVent {
+                    from: 0,
+                    to: None,
+                    width: 0.9,
+                    sill: 0.0,
+                    soffit: 1.0,
+                },
+                Connection::CeilingVent {
+                    lower: 0,
+                    upper: Some(1),
+                    area: 1.0,
+                },
+                Connection::WallVent {
+                    from: 1,
+                    to: None,
+                    width: 0.9,
+                    sill: 0.0,
+                    soffit: 2.0,
+                },
+                Connection::Fan {
+                    from: Some(1),
+                    to: None,
+                    height: 2.4,
+                    flow: 1.0,
+                },
+            ],
+        );
+
+        let histories = network.solve(0, |_| 200.0, 10.0, 200.0).rooms;
+        let last = histories[1].times.len() - 1;
+
+        assert!(histories[1].upper_temperatures[last] > 300.0);
+        assert!(histories[1].outflows[last] > 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_closed_network_panics() {
+        let network = ZoneNetwork::new(
+            vec![room(4.0, 5.0), room(4.0, 5.0)],
+            vec![Connection::WallVent {
+                from: 0,
+                to: Some(1),
+                width: 0.9,
+                sill: 0.0,
+                soffit: 2.0,
+            }],
+        );
+        network.solve(0, |_| 100.0, 10.0, 10.0);
+    }
+
+    #[test]
+    fn test_faults() {
+        let vent = |from: usize, to: Option<usize>| Connection::WallVent {
+            from,
+            to,
+            width: 0.9,
+            sill: 0.0,
+            soffit: 2.0,
+        };
+
+        // Rooms 1 and 2 are connected to each other but not to the outside.
+        let network = ZoneNetwork::new(
+            vec![room(4.0, 5.0), room(4.0, 5.0), room(4.0, 5.0)],
+            vec![vent(0, None), vent(1, Some(2))],
+        );
+        assert_eq!(network.fault(), Some(Fault::NotVented(1)));
+
+        // A fan does not vent a room.
+        let network = ZoneNetwork::new(
+            vec![room(4.0, 5.0), room(4.0, 5.0)],
+            vec![
+                vent(0, None),
+                Connection::Fan {
+                    from: Some(1),
+                    to: None,
+                    height: 2.0,
+                    flow: 1.0,
+                },
+            ],
+        );
+        assert_eq!(network.fault(), Some(Fault::NotVented(1)));
+
+        // Vented through the neighbouring room and the ceiling.
+        let network = ZoneNetwork::new(
+            vec![room(4.0, 5.0), room(4.0, 5.0), room(4.0, 5.0)],
+            vec![
+                vent(0, Some(1)),
+                Connection::CeilingVent {
+                    lower: 2,
+                    upper: None,
+                    area: 1.0,
+                },
+                vent(1, Some(2)),
+            ],
+        );
+        assert_eq!(network.fault(), None);
+
+        let network = ZoneNetwork::new(vec![room(4.0, 5.0)], vec![vent(0, Some(3))]);
+        assert_eq!(network.fault(), Some(Fault::UnknownRoom));
+    }
+}
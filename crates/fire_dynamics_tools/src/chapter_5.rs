@@ -1 +1,5 @@
+pub mod atmospheric_transmissivity;
 pub mod equation_5_1;
+pub mod model_comparison;
+pub mod solid_flame;
+pub mod wind_tilted_flame;
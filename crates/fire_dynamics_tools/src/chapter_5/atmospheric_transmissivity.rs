@@ -0,0 +1,147 @@
+//! Attenuation of thermal radiation by water vapour in the atmosphere. The
+//! radiation functions in this library assume a transmissivity of one; where
+//! the separation distance is large, `attenuated_intensity` applies the
+//! transmissivity from this module to their result, as
+//! `attenuated_point_source` does for the point source model of equation 5.1.
+
+use super::equation_5_1::thermal_radiation_point_source;
+
+/// Saturated vapour pressure of water (Pa) at ambient temperature `t_a` (K).
+pub fn saturated_vapour_pressure(t_a: f64) -> f64 {
+    (23.18986 - 3816.42 / (t_a - 46.13)).exp()
+}
+
+/// Partial pressure of water vapour (Pa) for a relative humidity `rh` (%).
+pub fn water_vapour_partial_pressure(rh: f64, t_a: f64) -> f64 {
+    rh / 100.0 * saturated_vapour_pressure(t_a)
+}
+
+/// Atmospheric transmissivity (Bagster) for a water vapour partial pressure
+/// `p_w` (Pa) and path length `x` (m), limited to one.
+pub fn transmissivity(p_w: f64, x: f64) -> f64 {
+    (2.02 * (p_w * x).powf(-0.09)).min(1.0)
+}
+
+/// Atmospheric transmissivity (API 521) for a relative humidity `rh` (%) and
+/// path length `r` (m), limited to one.
+pub fn transmissivity_api_521(rh: f64, r: f64) -> f64 {
+    (0.79 * (100.0 / rh).powf(1.0 / 16.0) * (30.5 / r).powf(1.0 / 16.0)).min(1.0)
+}
+
+/// Atmospheric transmissivity for a relative humidity `rh` (%), ambient
+/// temperature `t_a` (K) and path length `x` (m).
+pub fn transmissivity_from_humidity(rh: f64, t_a: f64, x: f64) -> f64 {
+    transmissivity(water_vapour_partial_pressure(rh, t_a), x)
+}
+
+pub fn attenuated_intensity(tau: f64, q: f64) -> f64 {
+    tau * q
+}
+
+/// Radiant heat flux (kW/m²) at distance `r` (m) from a point source fire of
+/// `q` kW with radiative fraction `x_r`, attenuated by the transmissivity
+/// `tau`.
+pub fn attenuated_point_source(tau: f64, q: f64, r: f64, x_r: f64) -> f64 {
+    attenuated_intensity(tau, thermal_radiation_point_source(q, r, x_r))
+}
+
+#[cfg(not(coverage))]
+pub fn saturated_vapour_pressure_equation(p_sat: String, t_a: String) -> String {
+    format!(
+        "{} = \\exp\\left(23.18986 - \\frac{{3816.42}}{{{} - 46.13}}\\right)",
+        p_sat, t_a
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn water_vapour_partial_pressure_equation(p_w: String, rh: String, p_sat: String) -> String {
+    format!("{} = \\frac{{{}}}{{100}} \\cdot {}", p_w, rh, p_sat)
+}
+
+#[cfg(not(coverage))]
+pub fn transmissivity_equation(tau: String, p_w: String, x: String) -> String {
+    format!(
+        "{} = 2.02 \\cdot \\left({} \\cdot {}\\right)^{{-0.09}}",
+        tau, p_w, x
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn transmissivity_api_521_equation(tau: String, rh: String, r: String) -> String {
+    format!(
+        "{} = 0.79 \\left(\\frac{{100}}{{{}}}\\right)^{{1/16}} \\left(\\frac{{30.5}}{{{}}}\\right)^{{1/16}}",
+        tau, rh, r
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn attenuated_intensity_equation(q_r: String, tau: String, q: String) -> String {
+    format!("{} = {} \\cdot {}", q_r, tau, q)
+}
+
+#[cfg(not(coverage))]
+pub fn attenuated_point_source_equation(
+    q_r: String,
+    tau: String,
+    q: String,
+    r: String,
+    x_r: String,
+) -> String {
+    format!(
+        "{} = {} \\cdot \\frac{{{} \\cdot {}}}{{4 \\cdot \\pi \\cdot {}^2}}",
+        q_r, tau, x_r, q, r
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_saturated_vapour_pressure() {
+        let result = saturated_vapour_pressure(293.15);
+        assert!((result - 2298.513484664662).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_water_vapour_partial_pressure() {
+        let result = water_vapour_partial_pressure(70.0, 293.15);
+        assert!((result - 1608.9594392652632).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_transmissivity() {
+        let result = transmissivity(1608.9594392652632, 50.0);
+        assert!((result - 0.7308950328198194).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_transmissivity_short_path_limited_to_one() {
+        assert_eq!(transmissivity(1608.9594392652632, 1.0), 1.0);
+        assert_eq!(transmissivity_api_521(70.0, 1.0), 1.0);
+    }
+
+    #[test]
+    fn test_transmissivity_api_521() {
+        let result = transmissivity_api_521(70.0, 50.0);
+        assert!((result - 0.7832340841391021).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_transmissivity_from_humidity() {
+        let result = transmissivity_from_humidity(70.0, 293.15, 50.0);
+        assert!((result - 0.7308950328198194).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_attenuated_intensity() {
+        let result = attenuated_intensity(0.75, 12.0);
+        assert!((result - 9.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_attenuated_point_source() {
+        let result = attenuated_point_source(0.8, 750.0, 2.5, 0.3);
+        assert!((result - 0.8 * 2.864788975654).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,38 @@
+//! Evaluates the point source and solid flame models for the same pool fire
+//! and target. The point source model is known to overpredict close to the
+//! fire and underpredict for large flames, so both results are reported.
+
+use super::{equation_5_1, solid_flame};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModelComparison {
+    pub point_source: f64,
+    pub solid_flame: f64,
+}
+
+/// Radiant heat flux (kW/m²) to a vertical target at ground level, at
+/// distance `l` from the centre of a pool fire of diameter `d` releasing
+/// `q` kW with radiative fraction `x_r`. Returns `None` if the target is
+/// within the pool, `l <= d / 2`.
+pub fn compare_models(q: f64, x_r: f64, d: f64, l: f64) -> Option<ModelComparison> {
+    let h_f = solid_flame::flame_height(q, d);
+    let f = solid_flame::view_factor_vertical(h_f, l, d)?;
+
+    Some(ModelComparison {
+        point_source: equation_5_1::thermal_radiation_point_source(q, l, x_r),
+        solid_flame: solid_flame::incident_heat_flux(solid_flame::emissive_power(d), f),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_models() {
+        let result = compare_models(1000.0, 0.3, 1.0, 3.0).unwrap();
+        assert!((result.point_source - 2.6525823848649224).abs() < 1e-9);
+        assert!((result.solid_flame - 3.942865533215694).abs() < 1e-9);
+        assert_eq!(compare_models(1000.0, 0.3, 1.0, 0.5), None);
+    }
+}
@@ -0,0 +1,185 @@
+//! Solid flame model for pool fires (Shokri and Beyler). The flame is a
+//! cylinder of the pool diameter and the flame height, radiating with an
+//! emissive power that decreases with pool diameter. The view factors apply
+//! to targets outside the flame, `L > D / 2`, and are `None` otherwise.
+
+use std::f64::consts::PI;
+
+pub fn emissive_power(d: f64) -> f64 {
+    58.0 * 10.0_f64.powf(-0.00823 * d)
+}
+
+/// Heskestad flame height (m), zero for a fire too small for its pool to
+/// sustain a flame above it.
+pub fn flame_height(q: f64, d: f64) -> f64 {
+    (0.235 * q.powf(2.0 / 5.0) - 1.02 * d).max(0.0)
+}
+
+/// View factor from a vertical cylinder of height `h_f` and diameter `d` to
+/// a vertical target at its base, at distance `l` from the cylinder axis.
+pub fn view_factor_vertical(h_f: f64, l: f64, d: f64) -> Option<f64> {
+    if l <= d / 2.0 {
+        return None;
+    }
+
+    let h = 2.0 * h_f / d;
+    let s = 2.0 * l / d;
+    let a = (h.powi(2) + s.powi(2) + 1.0) / (2.0 * s);
+
+    let first = (1.0 / (PI * s)) * (h / (s.powi(2) - 1.0).sqrt()).atan();
+    let second = (h / (PI * s)) * ((s - 1.0) / (s + 1.0)).sqrt().atan();
+    let third = (a * h / (PI * s * (a.powi(2) - 1.0).sqrt()))
+        * ((a + 1.0) * (s - 1.0) / ((a - 1.0) * (s + 1.0)))
+            .sqrt()
+            .atan();
+
+    Some(first - second + third)
+}
+
+/// View factor to a vertical target at height `h_t` above the base of the
+/// flame. The flame is split at the target height into two cylinders when
+/// the target is within the flame height.
+pub fn view_factor_vertical_elevated(h_f: f64, h_t: f64, l: f64, d: f64) -> Option<f64> {
+    if h_t <= 0.0 {
+        view_factor_vertical(h_f, l, d)
+    } else if h_t <= h_f {
+        Some(view_factor_vertical(h_t, l, d)? + view_factor_vertical(h_f - h_t, l, d)?)
+    } else {
+        Some(view_factor_vertical(h_t, l, d)? - view_factor_vertical(h_t - h_f, l, d)?)
+    }
+}
+
+/// View factor from a vertical cylinder to a horizontal target at its base.
+pub fn view_factor_horizontal(h_f: f64, l: f64, d: f64) -> Option<f64> {
+    if l <= d / 2.0 {
+        return None;
+    }
+
+    let h = 2.0 * h_f / d;
+    let s = 2.0 * l / d;
+    let a = (h.powi(2) + s.powi(2) + 1.0) / (2.0 * s);
+    let b = (1.0 + s.powi(2)) / (2.0 * s);
+
+    let first = ((b - 1.0 / s) / (PI * (b.powi(2) - 1.0).sqrt()))
+        * ((b + 1.0) * (s - 1.0) / ((b - 1.0) * (s + 1.0)))
+            .sqrt()
+            .atan();
+    let second = ((a - 1.0 / s) / (PI * (a.powi(2) - 1.0).sqrt()))
+        * ((a + 1.0) * (s - 1.0) / ((a - 1.0) * (s + 1.0)))
+            .sqrt()
+            .atan();
+
+    Some(first - second)
+}
+
+pub fn maximum_view_factor(f_h: f64, f_v: f64) -> f64 {
+    (f_h.powi(2) + f_v.powi(2)).sqrt()
+}
+
+pub fn incident_heat_flux(e: f64, f: f64) -> f64 {
+    e * f
+}
+
+#[cfg(not(coverage))]
+pub fn emissive_power_equation(e: String, d: String) -> String {
+    format!("{} = 58 \\cdot 10^{{-0.00823 \\cdot {}}}", e, d)
+}
+
+#[cfg(not(coverage))]
+pub fn flame_height_equation(h_f: String, q: String, d: String) -> String {
+    format!("{} = 0.235 \\cdot {}^{{2/5}} - 1.02 \\cdot {}", h_f, q, d)
+}
+
+#[cfg(not(coverage))]
+pub fn view_factor_vertical_equation(f_v: String, h: String, s: String, a: String) -> String {
+    format!(
+        "{} = \\frac{{1}}{{\\pi {}}} \\tan^{{-1}}\\left(\\frac{{{}}}{{\\sqrt{{{}^2 - 1}}}}\\right) - \\frac{{{}}}{{\\pi {}}} \\tan^{{-1}}\\sqrt{{\\frac{{{} - 1}}{{{} + 1}}}} + \\frac{{{} \\cdot {}}}{{\\pi {} \\sqrt{{{}^2 - 1}}}} \\tan^{{-1}}\\sqrt{{\\frac{{({} + 1)({} - 1)}}{{({} - 1)({} + 1)}}}}",
+        f_v, s, h, s, h, s, s, s, a, h, s, a, a, s, a, s
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn view_factor_horizontal_equation(f_h: String, s: String, a: String, b: String) -> String {
+    format!(
+        "{} = \\frac{{{} - 1/{}}}{{\\pi \\sqrt{{{}^2 - 1}}}} \\tan^{{-1}}\\sqrt{{\\frac{{({} + 1)({} - 1)}}{{({} - 1)({} + 1)}}}} - \\frac{{{} - 1/{}}}{{\\pi \\sqrt{{{}^2 - 1}}}} \\tan^{{-1}}\\sqrt{{\\frac{{({} + 1)({} - 1)}}{{({} - 1)({} + 1)}}}}",
+        f_h, b, s, b, b, s, b, s, a, s, a, a, s, a, s
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn maximum_view_factor_equation(f_max: String, f_h: String, f_v: String) -> String {
+    format!("{} = \\sqrt{{{}^2 + {}^2}}", f_max, f_h, f_v)
+}
+
+#[cfg(not(coverage))]
+pub fn incident_heat_flux_equation(q: String, e: String, f: String) -> String {
+    format!("{} = {} \\cdot {}", q, e, f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_emissive_power() {
+        let result = emissive_power(2.0);
+        assert!((result - 55.84290383711811).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_flame_height() {
+        let result = flame_height(2000.0, 1.5);
+        assert!((result - 3.3845058971789843).abs() < 1e-9);
+        assert_eq!(flame_height(10.0, 2.0), 0.0);
+    }
+
+    #[test]
+    fn test_view_factor_vertical() {
+        let result = view_factor_vertical(3.0, 4.0, 2.0).unwrap();
+        assert!((result - 0.10001374086552534).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_view_factor_vertical_elevated() {
+        assert_eq!(
+            view_factor_vertical_elevated(3.0, 0.0, 4.0, 2.0),
+            view_factor_vertical(3.0, 4.0, 2.0)
+        );
+
+        let within = view_factor_vertical_elevated(3.0, 1.0, 4.0, 2.0).unwrap();
+        assert!((within - 0.1279624011772264).abs() < 1e-9);
+
+        let above = view_factor_vertical_elevated(3.0, 4.0, 4.0, 2.0).unwrap();
+        assert!((above - 0.06313638886156527).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_view_factor_horizontal() {
+        let result = view_factor_horizontal(3.0, 4.0, 2.0).unwrap();
+        assert!((result - 0.03802320694852618).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_target_inside_flame() {
+        assert_eq!(view_factor_vertical(3.0, 1.0, 2.0), None);
+        assert_eq!(view_factor_horizontal(3.0, 0.5, 2.0), None);
+        assert_eq!(view_factor_vertical_elevated(3.0, 1.0, 1.0, 2.0), None);
+    }
+
+    #[test]
+    fn test_no_flame() {
+        assert_eq!(view_factor_vertical(0.0, 4.0, 2.0), Some(0.0));
+    }
+
+    #[test]
+    fn test_maximum_view_factor() {
+        let result = maximum_view_factor(0.3, 0.4);
+        assert!((result - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_incident_heat_flux() {
+        let result = incident_heat_flux(55.0, 0.1);
+        assert!((result - 5.5).abs() < 1e-12);
+    }
+}
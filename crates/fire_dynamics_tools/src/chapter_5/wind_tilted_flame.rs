@@ -0,0 +1,230 @@
+//! Solid flame model for large hydrocarbon pool fires including the effect
+//! of wind (Mudan and Croce). The flame is a cylinder tilted by the wind
+//! towards the target, with the angle `theta` measured from the vertical.
+//! The view factors apply to targets outside the flame base, `L > D / 2`, and
+//! are `None` otherwise.
+
+use std::f64::consts::PI;
+
+pub fn emissive_power(d: f64) -> f64 {
+    140.0 * (-0.12 * d).exp() + 20.0 * (1.0 - (-0.12 * d).exp())
+}
+
+/// Flame height in still air (Thomas).
+pub fn flame_height(d: f64, m: f64, rho_a: f64, g: f64) -> f64 {
+    42.0 * d * (m / (rho_a * (g * d).sqrt())).powf(0.61)
+}
+
+pub fn nondimensional_wind_velocity(u_w: f64, g: f64, m: f64, d: f64, rho_v: f64) -> f64 {
+    u_w / (g * m * d / rho_v).powf(1.0 / 3.0)
+}
+
+/// Flame height in wind (Thomas).
+pub fn flame_height_wind(d: f64, m: f64, rho_a: f64, g: f64, u_star: f64) -> f64 {
+    55.0 * d * (m / (rho_a * (g * d).sqrt())).powf(0.67) * u_star.powf(-0.21)
+}
+
+/// Angle of flame tilt from the vertical (radians).
+pub fn flame_tilt(u_star: f64) -> f64 {
+    if u_star <= 1.0 {
+        0.0
+    } else {
+        (1.0 / u_star.sqrt()).acos()
+    }
+}
+
+struct TiltedCylinder {
+    a: f64,
+    b: f64,
+    big_a: f64,
+    big_b: f64,
+    big_c: f64,
+    big_d: f64,
+    big_f: f64,
+    sin: f64,
+    cos: f64,
+}
+
+impl TiltedCylinder {
+    fn new(h_f: f64, l: f64, d: f64, theta: f64) -> Self {
+        let a = 2.0 * h_f / d;
+        let b = 2.0 * l / d;
+        let sin = theta.sin();
+        let cos = theta.cos();
+
+        TiltedCylinder {
+            a,
+            b,
+            big_a: (a.powi(2) + (b + 1.0).powi(2) - 2.0 * a * (b + 1.0) * sin).sqrt(),
+            big_b: (a.powi(2) + (b - 1.0).powi(2) - 2.0 * a * (b - 1.0) * sin).sqrt(),
+            big_c: (1.0 + (b.powi(2) - 1.0) * cos.powi(2)).sqrt(),
+            big_d: ((b - 1.0) / (b + 1.0)).sqrt(),
+            big_f: (b.powi(2) - 1.0).sqrt(),
+            sin,
+            cos,
+        }
+    }
+
+    fn tilt_term(&self) -> f64 {
+        let f2 = self.big_f.powi(2);
+        ((self.a * self.b - f2 * self.sin) / (self.big_f * self.big_c)).atan()
+            + (f2 * self.sin / (self.big_f * self.big_c)).atan()
+    }
+}
+
+/// View factor from a tilted cylinder to a vertical target at its base, at
+/// distance `l` from the cylinder axis.
+pub fn view_factor_vertical(h_f: f64, l: f64, d: f64, theta: f64) -> Option<f64> {
+    if l <= d / 2.0 {
+        return None;
+    }
+
+    let c = TiltedCylinder::new(h_f, l, d, theta);
+    let e = c.a * c.cos / (c.b - c.a * c.sin);
+    let ratio =
+        (c.a.powi(2) + (c.b + 1.0).powi(2) - 2.0 * c.b * (1.0 + c.a * c.sin)) / (c.big_a * c.big_b);
+
+    Some(
+        (-e * c.big_d.atan()
+            + e * ratio * (c.big_a * c.big_d / c.big_b).atan()
+            + (c.cos / c.big_c) * c.tilt_term())
+            / PI,
+    )
+}
+
+/// View factor from a tilted cylinder to a horizontal target at its base.
+pub fn view_factor_horizontal(h_f: f64, l: f64, d: f64, theta: f64) -> Option<f64> {
+    if l <= d / 2.0 {
+        return None;
+    }
+
+    let c = TiltedCylinder::new(h_f, l, d, theta);
+    let ratio = (c.a.powi(2) + (c.b + 1.0).powi(2) - 2.0 * (c.b + 1.0 + c.a * c.b * c.sin))
+        / (c.big_a * c.big_b);
+
+    Some(
+        ((1.0 / c.big_d).atan() + (c.sin / c.big_c) * c.tilt_term()
+            - ratio * (c.big_a * c.big_d / c.big_b).atan())
+            / PI,
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn emissive_power_equation(e: String, d: String) -> String {
+    format!(
+        "{} = 140 \\cdot e^{{-0.12 \\cdot {}}} + 20 \\cdot \\left(1 - e^{{-0.12 \\cdot {}}}\\right)",
+        e, d, d
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn flame_height_equation(
+    h_f: String,
+    d: String,
+    m: String,
+    rho_a: String,
+    g: String,
+) -> String {
+    format!(
+        "{} = 42 \\cdot {} \\left(\\frac{{{}}}{{{} \\sqrt{{{} \\cdot {}}}}}\\right)^{{0.61}}",
+        h_f, d, m, rho_a, g, d
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn nondimensional_wind_velocity_equation(
+    u_star: String,
+    u_w: String,
+    g: String,
+    m: String,
+    d: String,
+    rho_v: String,
+) -> String {
+    format!(
+        "{} = \\frac{{{}}}{{\\left(\\frac{{{} \\cdot {} \\cdot {}}}{{{}}}\\right)^{{1/3}}}}",
+        u_star, u_w, g, m, d, rho_v
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn flame_height_wind_equation(
+    h_f: String,
+    d: String,
+    m: String,
+    rho_a: String,
+    g: String,
+    u_star: String,
+) -> String {
+    format!(
+        "{} = 55 \\cdot {} \\left(\\frac{{{}}}{{{} \\sqrt{{{} \\cdot {}}}}}\\right)^{{0.67}} \\left({}\\right)^{{-0.21}}",
+        h_f, d, m, rho_a, g, d, u_star
+    )
+}
+
+#[cfg(not(coverage))]
+pub fn flame_tilt_equation(theta: String, u_star: String) -> String {
+    format!(
+        "\\cos {} = \\begin{{cases}} 1 & {} \\leq 1 \\\\ \\frac{{1}}{{\\sqrt{{{}}}}} & {} > 1 \\end{{cases}}",
+        theta, u_star, u_star, u_star
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chapter_5::solid_flame;
+
+    #[test]
+    fn test_emissive_power() {
+        let result = emissive_power(10.0);
+        assert!((result - 56.143305429464256).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_flame_height() {
+        let result = flame_height(10.0, 0.039, 1.2, 9.81);
+        assert!((result - 12.824460178024111).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_nondimensional_wind_velocity() {
+        let result = nondimensional_wind_velocity(5.0, 9.81, 0.039, 10.0, 2.0);
+        assert!((result - 4.027808351748019).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_flame_height_wind() {
+        let result = flame_height_wind(10.0, 0.039, 1.2, 9.81, 4.027808351748019);
+        assert!((result - 8.893054959394178).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_flame_tilt() {
+        assert_eq!(flame_tilt(0.5), 0.0);
+        assert!((flame_tilt(4.027808351748019) - 1.049192894286215).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_view_factors_untilted_match_vertical_cylinder() {
+        let vertical = view_factor_vertical(3.0, 4.0, 2.0, 0.0).unwrap();
+        let horizontal = view_factor_horizontal(3.0, 4.0, 2.0, 0.0).unwrap();
+        let expected = solid_flame::view_factor_vertical(3.0, 4.0, 2.0).unwrap();
+        assert!((vertical - expected).abs() < 1e-12);
+        let expected = solid_flame::view_factor_horizontal(3.0, 4.0, 2.0).unwrap();
+        assert!((horizontal - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_view_factors_tilted() {
+        let vertical = view_factor_vertical(3.0, 4.0, 2.0, 0.5).unwrap();
+        let horizontal = view_factor_horizontal(3.0, 4.0, 2.0, 0.5).unwrap();
+        assert!((vertical - 0.1435944383611949).abs() < 1e-9);
+        assert!((horizontal - 0.07846498931761549).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_target_inside_flame_base() {
+        assert_eq!(view_factor_vertical(3.0, 1.0, 2.0, 0.5), None);
+        assert_eq!(view_factor_horizontal(3.0, 0.5, 2.0, 0.5), None);
+    }
+}
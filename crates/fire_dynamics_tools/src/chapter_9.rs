@@ -1,3 +1,4 @@
 pub mod equation_9_2;
 pub mod equation_9_3;
 pub mod equation_9_4;
+pub mod plume_mass_flow;
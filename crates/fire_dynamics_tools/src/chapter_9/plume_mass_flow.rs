@@ -0,0 +1,239 @@
+//! Plume mass flow correlations for smoke control design: axisymmetric
+//! plumes after Heskestad, Zukoski and Thomas, balcony spill and line plumes
+//! after BRE/CIBSE Guide E and NFPA 92, and window plumes after NFPA 92. Each
+//! returns the mass flow with the mean temperature and volumetric flow of the
+//! plume, heated adiabatically by the convective heat release. Heat release
+//! rates are in kW, lengths in m and temperatures in K.
+
+use super::equation_9_3::virtual_origin_over_diameter;
+use super::equation_9_4::effective_diameter;
+use crate::chapter_2::equation_2_8::hot_gas_temperature_increase;
+use crate::chapter_2::equation_2_13::density_hot_gas_layer;
+
+/// Specific heat of the plume gases (kJ/kg·K).
+const C_P: f64 = 1.0;
+/// Convective fraction of the heat release.
+const CONVECTIVE_FRACTION: f64 = 0.7;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlumeFlow {
+    /// Mass flow (kg/s).
+    pub mass_flow: f64,
+    /// Mean plume temperature (K).
+    pub temperature: f64,
+    /// Volumetric flow at the plume temperature (m³/s).
+    pub volumetric_flow: f64,
+}
+
+impl PlumeFlow {
+    /// Plume of mass flow `mass_flow` (kg/s) carrying the convective heat
+    /// release `q_c` (kW) from ambient temperature `t_a`.
+    pub fn new(mass_flow: f64, q_c: f64, t_a: f64) -> Self {
+        if mass_flow <= 0.0 {
+            return PlumeFlow {
+                mass_flow: 0.0,
+                temperature: t_a,
+                volumetric_flow: 0.0,
+            };
+        }
+
+        let temperature = t_a + hot_gas_temperature_increase(q_c, mass_flow, C_P, 0.0, 0.0);
+        PlumeFlow {
+            mass_flow,
+            temperature,
+            volumetric_flow: mass_flow / density_hot_gas_layer(temperature),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Regime {
+    /// Within the flame, entrainment from Thomas.
+    Flame,
+    /// Above the flame, entrainment from Heskestad.
+    Plume,
+}
+
+/// Heskestad plume at height `z` above a fire with convective heat release
+/// `q_c` and virtual origin `z_0`.
+pub fn heskestad(q_c: f64, z: f64, z_0: f64, t_a: f64) -> PlumeFlow {
+    let z = z - z_0;
+    let mass_flow = if q_c <= 0.0 || z <= 0.0 {
+        0.0
+    } else if z > 0.166 * q_c.powf(2.0 / 5.0) {
+        0.071 * q_c.powf(1.0 / 3.0) * z.powf(5.0 / 3.0) + 0.0018 * q_c
+    } else {
+        0.032 * q_c.powf(3.0 / 5.0) * z
+    };
+    PlumeFlow::new(mass_flow, q_c, t_a)
+}
+
+/// Zukoski point source plume at height `z` above a fire with convective
+/// heat release `q_c`.
+pub fn zukoski(q_c: f64, z: f64, t_a: f64) -> PlumeFlow {
+    let mass_flow = 0.071 * q_c.max(0.0).powf(1.0 / 3.0) * z.max(0.0).powf(5.0 / 3.0);
+    PlumeFlow::new(mass_flow, q_c, t_a)
+}
+
+/// Thomas large fire plume at height `z` above a fire of perimeter
+/// `perimeter` with convective heat release `q_c`, for heights up to about
+/// the fire diameter.
+pub fn thomas(q_c: f64, perimeter: f64, z: f64, t_a: f64) -> PlumeFlow {
+    let mass_flow = 0.188 * perimeter * z.max(0.0).powf(3.0 / 2.0);
+    PlumeFlow::new(mass_flow, q_c, t_a)
+}
+
+/// Balcony spill plume at height `z_b` above a balcony edge of width `w`,
+/// with the balcony `h` above the fire and convective heat release `q_c`.
+pub fn balcony_spill(q_c: f64, w: f64, z_b: f64, h: f64, t_a: f64) -> PlumeFlow {
+    let mass_flow = 0.36 * (q_c * w.powi(2)).powf(1.0 / 3.0) * (z_b + 0.25 * h);
+    PlumeFlow::new(mass_flow, q_c, t_a)
+}
+
+/// Free line plume of length `l` at height `z` above its source, entraining
+/// on both faces, with convective heat release `q_c`.
+pub fn free_line(q_c: f64, l: f64, z: f64, t_a: f64) -> PlumeFlow {
+    let mass_flow = 0.21 * q_c.max(0.0).powf(1.0 / 3.0) * l.powf(2.0 / 3.0) * z.max(0.0);
+    PlumeFlow::new(mass_flow, q_c, t_a)
+}
+
+/// Line plume of length `l` adhered to a wall, at height `z` above its
+/// source, with convective heat release `q_c`. By the method of images it
+/// entrains half as much as a free line plume carrying twice the heat.
+pub fn adhered_line(q_c: f64, l: f64, z: f64, t_a: f64) -> PlumeFlow {
+    let mass_flow = 0.5 * free_line(2.0 * q_c, l, z, t_a).mass_flow;
+    PlumeFlow::new(mass_flow, q_c, t_a)
+}
+
+/// Heat release rate (kW) of a ventilation controlled fire venting through
+/// a window of area `a_w` (m²) and height `h_w` (m).
+pub fn window_heat_release(a_w: f64, h_w: f64) -> f64 {
+    1260.0 * a_w * h_w.sqrt()
+}
+
+/// Window plume at height `z_w` above the top of a window of area `a_w`
+/// (m²) and height `h_w` (m) of a ventilation controlled room fire.
+pub fn window(a_w: f64, h_w: f64, z_w: f64, t_a: f64) -> PlumeFlow {
+    let ventilation = a_w * h_w.sqrt();
+    let a = 2.4 * a_w.powf(2.0 / 5.0) * h_w.powf(1.0 / 5.0) - 2.1 * h_w;
+    let mass_flow = 0.68 * ventilation.powf(1.0 / 3.0) * (z_w + a).max(0.0).powf(5.0 / 3.0)
+        + 1.59 * ventilation;
+    let q_c = CONVECTIVE_FRACTION * window_heat_release(a_w, h_w);
+    PlumeFlow::new(mass_flow, q_c, t_a)
+}
+
+/// Heskestad mean flame height (m) of a fire with heat release `q` on area
+/// `a_f` (m²).
+pub fn flame_height(q: f64, a_f: f64) -> f64 {
+    0.235 * q.powf(2.0 / 5.0) - 1.02 * effective_diameter(a_f)
+}
+
+/// Axisymmetric plume at height `z` above a fire with heat release `q` on
+/// area `a_f` (m²), using Thomas within the flame and Heskestad from the
+/// virtual origin above it.
+pub fn axisymmetric(q: f64, a_f: f64, z: f64, t_a: f64) -> (Regime, PlumeFlow) {
+    let q_c = CONVECTIVE_FRACTION * q;
+    let d = effective_diameter(a_f);
+    if a_f > 0.0 && z <= flame_height(q, a_f) {
+        let perimeter = std::f64::consts::PI * d;
+        (Regime::Flame, thomas(q_c, perimeter, z, t_a))
+    } else {
+        let z_0 = if a_f > 0.0 {
+            d * virtual_origin_over_diameter(d, q)
+        } else {
+            0.0
+        };
+        (Regime::Plume, heskestad(q_c, z, z_0, t_a))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const T_A: f64 = 293.15;
+
+    #[test]
+    fn test_plume_flow_temperature_and_volume() {
+        let flow = PlumeFlow::new(10.0, 1000.0, T_A);
+
+        assert!((flow.temperature - 393.15).abs() < 1e-9);
+        assert!((flow.volumetric_flow - 10.0 * 393.15 / 353.0).abs() < 1e-9);
+        assert_eq!(PlumeFlow::new(0.0, 1000.0, T_A).volumetric_flow, 0.0);
+    }
+
+    #[test]
+    fn test_heskestad() {
+        let q_c: f64 = 3500.0;
+        let far = heskestad(q_c, 10.0, 0.0, T_A);
+        let near = heskestad(q_c, 1.0, 0.0, T_A);
+
+        let expected = 0.071 * q_c.cbrt() * 10.0_f64.powf(5.0 / 3.0) + 0.0018 * q_c;
+        assert!((far.mass_flow - expected).abs() < 1e-9);
+        assert!((near.mass_flow - 0.032 * q_c.powf(0.6)).abs() < 1e-9);
+        // A virtual origin below the fire adds to the plume height.
+        assert!(heskestad(q_c, 10.0, -1.0, T_A).mass_flow > far.mass_flow);
+    }
+
+    #[test]
+    fn test_zukoski() {
+        let result = zukoski(1000.0, 8.0, T_A);
+        assert!((result.mass_flow - 0.071 * 10.0 * 32.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_thomas() {
+        let result = thomas(2000.0, 12.0, 4.0, T_A);
+        assert!((result.mass_flow - 0.188 * 12.0 * 8.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_balcony_spill() {
+        let result = balcony_spill(1000.0, 10.0, 6.0, 4.0, T_A);
+        let expected = 0.36 * 100000.0_f64.cbrt() * 7.0;
+        assert!((result.mass_flow - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_line_plumes() {
+        let free = free_line(1000.0, 8.0, 5.0, T_A);
+        let adhered = adhered_line(1000.0, 8.0, 5.0, T_A);
+
+        assert!((free.mass_flow - 0.21 * 10.0 * 4.0 * 5.0).abs() < 1e-9);
+        let expected = 0.5 * 2.0_f64.cbrt() * free.mass_flow;
+        assert!((adhered.mass_flow - expected).abs() < 1e-9);
+        assert!(adhered.temperature > free.temperature);
+    }
+
+    #[test]
+    fn test_window() {
+        let (a_w, h_w, z_w): (f64, f64, f64) = (4.0, 2.0, 5.0);
+        let result = window(a_w, h_w, z_w, T_A);
+
+        let ventilation = a_w * h_w.sqrt();
+        let a = 2.4 * a_w.powf(0.4) * h_w.powf(0.2) - 2.1 * h_w;
+        let expected = 0.68 * ventilation.cbrt() * (z_w + a).powf(5.0 / 3.0) + 1.59 * ventilation;
+        assert!((result.mass_flow - expected).abs() < 1e-9);
+        assert!((window_heat_release(a_w, h_w) - 1260.0 * ventilation).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_axisymmetric_selects_regime() {
+        // A large, low intensity fire has its virtual origin below the floor.
+        let q = 5000.0;
+        let a_f = 25.0;
+        let l = flame_height(q, a_f);
+
+        let (low, in_flame) = axisymmetric(q, a_f, 0.5 * l, T_A);
+        let (high, above) = axisymmetric(q, a_f, 10.0, T_A);
+        let (_, point) = axisymmetric(q, 0.0, 10.0, T_A);
+
+        assert_eq!(low, Regime::Flame);
+        assert_eq!(high, Regime::Plume);
+        let perimeter = std::f64::consts::PI * effective_diameter(a_f);
+        let expected = 0.188 * perimeter * (0.5 * l).powf(1.5);
+        assert!((in_flame.mass_flow - expected).abs() < 1e-9);
+        assert!(above.mass_flow > point.mass_flow);
+        assert!((point.mass_flow - heskestad(3500.0, 10.0, 0.0, T_A).mass_flow).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,109 @@
+//! Explicit Dormand-Prince 5(4) Runge-Kutta pair for non-stiff problems.
+//! The fifth order solution is propagated and the difference from the
+//! embedded fourth order solution estimates the local error.
+
+const C: [f64; 7] = [0.0, 1.0 / 5.0, 3.0 / 10.0, 4.0 / 5.0, 8.0 / 9.0, 1.0, 1.0];
+const A: [[f64; 6]; 7] = [
+    [0.0; 6],
+    [1.0 / 5.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [3.0 / 40.0, 9.0 / 40.0, 0.0, 0.0, 0.0, 0.0],
+    [44.0 / 45.0, -56.0 / 15.0, 32.0 / 9.0, 0.0, 0.0, 0.0],
+    [
+        19372.0 / 6561.0,
+        -25360.0 / 2187.0,
+        64448.0 / 6561.0,
+        -212.0 / 729.0,
+        0.0,
+        0.0,
+    ],
+    [
+        9017.0 / 3168.0,
+        -355.0 / 33.0,
+        46732.0 / 5247.0,
+        49.0 / 176.0,
+        -5103.0 / 18656.0,
+        0.0,
+    ],
+    [
+        35.0 / 384.0,
+        0.0,
+        500.0 / 1113.0,
+        125.0 / 192.0,
+        -2187.0 / 6784.0,
+        11.0 / 84.0,
+    ],
+];
+/// Fifth order weights, equal to the last row of `A`.
+const B: [f64; 7] = [
+    35.0 / 384.0,
+    0.0,
+    500.0 / 1113.0,
+    125.0 / 192.0,
+    -2187.0 / 6784.0,
+    11.0 / 84.0,
+    0.0,
+];
+/// Fourth order embedded weights.
+const B_HAT: [f64; 7] = [
+    5179.0 / 57600.0,
+    0.0,
+    7571.0 / 16695.0,
+    393.0 / 640.0,
+    -92097.0 / 339200.0,
+    187.0 / 2100.0,
+    1.0 / 40.0,
+];
+
+pub const ORDER: i32 = 5;
+
+/// One step of size `h` from `(t, y)` where the derivative is `f_0`.
+/// Returns the new state and the local error estimate of each component.
+pub fn step<F: FnMut(f64, &[f64]) -> Vec<f64>>(
+    f: &mut F,
+    t: f64,
+    y: &[f64],
+    f_0: &[f64],
+    h: f64,
+) -> (Vec<f64>, Vec<f64>) {
+    let n = y.len();
+    let mut k: Vec<Vec<f64>> = vec![f_0.to_vec()];
+
+    for stage in 1..7 {
+        let y_stage: Vec<f64> = (0..n)
+            .map(|i| y[i] + h * (0..stage).map(|j| A[stage][j] * k[j][i]).sum::<f64>())
+            .collect();
+        k.push(f(t + C[stage] * h, &y_stage));
+    }
+
+    let y_new: Vec<f64> = (0..n)
+        .map(|i| y[i] + h * (0..7).map(|j| B[j] * k[j][i]).sum::<f64>())
+        .collect();
+    let error: Vec<f64> = (0..n)
+        .map(|i| h * (0..7).map(|j| (B[j] - B_HAT[j]) * k[j][i]).sum::<f64>())
+        .collect();
+
+    (y_new, error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step_exponential() {
+        let mut f = |_: f64, y: &[f64]| vec![-y[0]];
+        let (y, error) = step(&mut f, 0.0, &[1.0], &[-1.0], 0.1);
+
+        assert!((y[0] - (-0.1_f64).exp()).abs() < 1e-9);
+        assert!(error[0].abs() < 1e-7);
+    }
+
+    #[test]
+    fn test_step_exact_for_polynomial() {
+        // y' = 4t³ is integrated exactly by a fifth order method.
+        let mut f = |t: f64, _: &[f64]| vec![4.0 * t.powi(3)];
+        let (y, _) = step(&mut f, 1.0, &[1.0], &[4.0], 1.0);
+
+        assert!((y[0] - 16.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,117 @@
+//! Events are zero crossings of a function of time and state, such as a
+//! smoke layer reaching a given height or a detector activating. Crossings
+//! are located within each accepted step by bisection.
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    /// From negative to zero or positive.
+    Rising,
+    /// From positive to zero or negative.
+    Falling,
+    Either,
+}
+
+/// Event function of time and state.
+pub type EventFunction<'a> = Box<dyn Fn(f64, &[f64]) -> f64 + 'a>;
+
+pub struct Event<'a> {
+    pub function: EventFunction<'a>,
+    pub direction: Direction,
+    /// Whether the integration stops at the event.
+    pub terminal: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventRecord {
+    /// Position of the event in the list passed to the integrator.
+    pub index: usize,
+    pub t: f64,
+    pub state: Vec<f64>,
+}
+
+impl<'a> Event<'a> {
+    pub fn new<G: Fn(f64, &[f64]) -> f64 + 'a>(
+        function: G,
+        direction: Direction,
+        terminal: bool,
+    ) -> Self {
+        Event {
+            function: Box::new(function),
+            direction,
+            terminal,
+        }
+    }
+
+    /// Event when state component `component` crosses `value`.
+    pub fn threshold(component: usize, value: f64, direction: Direction, terminal: bool) -> Self {
+        Event::new(move |_, y| y[component] - value, direction, terminal)
+    }
+
+    pub fn evaluate(&self, t: f64, y: &[f64]) -> f64 {
+        (self.function)(t, y)
+    }
+
+    /// Whether the values `g_0` and `g_1` at the ends of a step cross zero
+    /// in the direction of the event.
+    pub fn triggered(&self, g_0: f64, g_1: f64) -> bool {
+        let rising = g_0 < 0.0 && g_1 >= 0.0;
+        let falling = g_0 > 0.0 && g_1 <= 0.0;
+        match self.direction {
+            Direction::Rising => rising,
+            Direction::Falling => falling,
+            Direction::Either => rising || falling,
+        }
+    }
+
+    /// Time of the crossing between `t_0` and `t_1`, where the event
+    /// function has value `g_0` at `t_0`, for the state
+    /// `state(t)`.
+    pub fn locate<S: FnMut(f64) -> Vec<f64>>(
+        &self,
+        t_0: f64,
+        g_0: f64,
+        t_1: f64,
+        mut state: S,
+    ) -> f64 {
+        let mut low = t_0;
+        let mut high = t_1;
+        let tolerance = 1e-12 * t_0.abs().max(t_1.abs()).max(1.0);
+
+        while high - low > tolerance {
+            let mid = 0.5 * (low + high);
+            let g = self.evaluate(mid, &state(mid));
+            if g == 0.0 || (g > 0.0) != (g_0 > 0.0) {
+                high = mid;
+            } else {
+                low = mid;
+            }
+        }
+
+        high
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_triggered() {
+        let rising = Event::threshold(0, 1.0, Direction::Rising, false);
+        assert!(rising.triggered(-1.0, 0.5));
+        assert!(!rising.triggered(0.5, -1.0));
+
+        let either = Event::threshold(0, 1.0, Direction::Either, false);
+        assert!(either.triggered(0.5, -1.0));
+        assert!(!either.triggered(0.5, 1.0));
+    }
+
+    #[test]
+    fn test_locate() {
+        // y = t² crosses 2 at √2.
+        let event = Event::threshold(0, 2.0, Direction::Rising, true);
+        let t = event.locate(0.0, -2.0, 3.0, |t| vec![t * t]);
+
+        assert!((t - 2.0_f64.sqrt()).abs() < 1e-10);
+    }
+}
@@ -0,0 +1,158 @@
+//! Linearly implicit backward Euler with Richardson extrapolation for stiff
+//! problems. Each step is taken once with `h` and twice with `h / 2`; the
+//! difference estimates the local error and the extrapolated second order
+//! solution is propagated. The Newton iterations use a finite difference
+//! Jacobian.
+
+/// Largest number of Newton iterations for one implicit solve.
+const MAX_ITERATIONS: usize = 10;
+
+pub const ORDER: i32 = 2;
+
+/// One step of size `h` from `(t, y)`. Returns the new state and the local
+/// error estimate of each component, or `None` if the Newton iterations do
+/// not converge.
+pub fn step<F: FnMut(f64, &[f64]) -> Vec<f64>>(
+    f: &mut F,
+    t: f64,
+    y: &[f64],
+    h: f64,
+) -> Option<(Vec<f64>, Vec<f64>)> {
+    let full = backward_euler(f, t, y, h)?;
+    let half = backward_euler(f, t, y, h / 2.0)?;
+    let double = backward_euler(f, t + h / 2.0, &half, h / 2.0)?;
+
+    let y_new = (0..y.len()).map(|i| 2.0 * double[i] - full[i]).collect();
+    let error = (0..y.len()).map(|i| double[i] - full[i]).collect();
+    Some((y_new, error))
+}
+
+/// Solves `y_1 = y + h f(t + h, y_1)` by Newton iteration.
+fn backward_euler<F: FnMut(f64, &[f64]) -> Vec<f64>>(
+    f: &mut F,
+    t: f64,
+    y: &[f64],
+    h: f64,
+) -> Option<Vec<f64>> {
+    let n = y.len();
+    let t_1 = t + h;
+    let mut y_1 = y.to_vec();
+    let jacobian = jacobian(f, t_1, &y_1);
+
+    // Iteration matrix I - h J, reused for every iteration of the step.
+    let matrix: Vec<Vec<f64>> = (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| (if i == j { 1.0 } else { 0.0 }) - h * jacobian[i][j])
+                .collect()
+        })
+        .collect();
+
+    for _ in 0..MAX_ITERATIONS {
+        let f_1 = f(t_1, &y_1);
+        let residual: Vec<f64> = (0..n).map(|i| y_1[i] - y[i] - h * f_1[i]).collect();
+        let delta = solve_linear(matrix.clone(), residual)?;
+
+        let mut converged = true;
+        for i in 0..n {
+            y_1[i] -= delta[i];
+            if delta[i].abs() > 1e-10 * (1.0 + y_1[i].abs()) {
+                converged = false;
+            }
+        }
+        if y_1.iter().any(|value| !value.is_finite()) {
+            return None;
+        }
+        if converged {
+            return Some(y_1);
+        }
+    }
+
+    None
+}
+
+/// Forward difference Jacobian of `f` at `(t, y)`.
+pub fn jacobian<F: FnMut(f64, &[f64]) -> Vec<f64>>(f: &mut F, t: f64, y: &[f64]) -> Vec<Vec<f64>> {
+    let n = y.len();
+    let f_0 = f(t, y);
+    let mut columns = vec![];
+
+    for j in 0..n {
+        let dy = f64::EPSILON.sqrt() * y[j].abs().max(1.0);
+        let mut y_perturbed = y.to_vec();
+        y_perturbed[j] += dy;
+        let f_j = f(t, &y_perturbed);
+        columns.push((0..n).map(|i| (f_j[i] - f_0[i]) / dy).collect::<Vec<f64>>());
+    }
+
+    (0..n)
+        .map(|i| (0..n).map(|j| columns[j][i]).collect())
+        .collect()
+}
+
+/// Solves `a x = b` by Gaussian elimination with partial pivoting, or
+/// returns `None` if the matrix is singular.
+pub fn solve_linear(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Option<Vec<f64>> {
+    let n = b.len();
+
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+        if a[pivot][col].abs() < 1e-300 {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+
+        let (upper, lower) = a.split_at_mut(col + 1);
+        let pivot_row = &upper[col];
+        for (offset, row) in lower.iter_mut().enumerate() {
+            let factor = row[col] / pivot_row[col];
+            for (value, pivot_value) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                *value -= factor * pivot_value;
+            }
+            b[col + 1 + offset] -= factor * b[col];
+        }
+    }
+
+    let mut x = vec![0.0; n];
+    for row in (0..n).rev() {
+        let sum: f64 = (row + 1..n).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - sum) / a[row][row];
+    }
+    Some(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_linear() {
+        let a = vec![vec![2.0, 1.0], vec![1.0, 3.0]];
+        let x = solve_linear(a, vec![3.0, 5.0]).unwrap();
+
+        assert!((x[0] - 0.8).abs() < 1e-12);
+        assert!((x[1] - 1.4).abs() < 1e-12);
+        assert_eq!(solve_linear(vec![vec![0.0]], vec![1.0]), None);
+    }
+
+    #[test]
+    fn test_jacobian() {
+        let mut f = |_: f64, y: &[f64]| vec![y[0] * y[1], y[1].powi(2)];
+        let j = jacobian(&mut f, 0.0, &[2.0, 3.0]);
+
+        assert!((j[0][0] - 3.0).abs() < 1e-6);
+        assert!((j[0][1] - 2.0).abs() < 1e-6);
+        assert!(j[1][0].abs() < 1e-6);
+        assert!((j[1][1] - 6.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_step_stiff_decay() {
+        // A step far beyond the explicit stability limit stays bounded.
+        let mut f = |_: f64, y: &[f64]| vec![-1e6 * y[0]];
+        let (y, _) = step(&mut f, 0.0, &[1.0], 1.0).unwrap();
+
+        assert!(y[0].abs() < 1e-6);
+    }
+}
@@ -0,0 +1,449 @@
+//! Adaptive integration of `dy/dt = f(t, y)` with event detection. Steps
+//! are shortened to land on the output times `t_0`, `t_0 + step`, ... so
+//! the output grid does not depend on the tolerances and no interpolation
+//! is involved.
+//!
+//! The error control needs rates that are smooth within a step. Solvers
+//! with a prescribed time step, such as the incremental steel temperature
+//! method of EN 1993-1-2, or with rates that switch as flows reverse, such
+//! as the zone network, keep their own explicit steps.
+
+use crate::events::{Event, EventRecord};
+use crate::{dormand_prince, implicit_euler};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Method {
+    /// Explicit Dormand-Prince 5(4) for non-stiff problems.
+    DormandPrince,
+    /// Extrapolated backward Euler for stiff problems.
+    ImplicitEuler,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Options {
+    /// Relative tolerance on the local error.
+    pub rtol: f64,
+    /// Absolute tolerance on the local error.
+    pub atol: f64,
+    /// First step size, estimated from the initial derivative if `None`.
+    pub h_initial: Option<f64>,
+    /// Largest step size.
+    pub h_max: f64,
+    /// Largest number of attempted steps.
+    pub max_steps: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            rtol: 1e-6,
+            atol: 1e-9,
+            h_initial: None,
+            h_max: f64::INFINITY,
+            max_steps: 100_000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Status {
+    /// Reached the end time.
+    Completed,
+    /// Stopped at a terminal event.
+    Terminated,
+    /// The step size fell below the resolution of the time.
+    StepSizeTooSmall,
+    MaxStepsReached,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solution {
+    /// Output times.
+    pub times: Vec<f64>,
+    /// State at each output time.
+    pub states: Vec<Vec<f64>>,
+    /// Events in the order they occurred.
+    pub events: Vec<EventRecord>,
+    pub status: Status,
+    pub accepted_steps: usize,
+    pub rejected_steps: usize,
+}
+
+impl Solution {
+    /// History of state component `i` at the output times.
+    pub fn component(&self, i: usize) -> Vec<f64> {
+        self.states.iter().map(|state| state[i]).collect()
+    }
+
+    /// First occurrence of event `index`.
+    pub fn first_event(&self, index: usize) -> Option<&EventRecord> {
+        self.events.iter().find(|record| record.index == index)
+    }
+}
+
+/// Root mean square of `values` scaled by the mixed tolerance.
+fn error_norm(values: &[f64], y: &[f64], y_new: &[f64], options: &Options) -> f64 {
+    let sum: f64 = (0..values.len())
+        .map(|i| {
+            let scale = options.atol + options.rtol * y[i].abs().max(y_new[i].abs());
+            (values[i] / scale).powi(2)
+        })
+        .sum();
+    (sum / values.len().max(1) as f64).sqrt()
+}
+
+/// Integrates from `(t_0, y_0)` to `t_end`, rounded to a whole number of
+/// output steps, with output every `output_step`, stopping early at the
+/// first terminal event.
+#[allow(clippy::too_many_arguments)]
+pub fn solve<F: FnMut(f64, &[f64]) -> Vec<f64>>(
+    method: Method,
+    mut f: F,
+    t_0: f64,
+    y_0: &[f64],
+    t_end: f64,
+    output_step: f64,
+    events: &[Event],
+    options: &Options,
+) -> Solution {
+    let outputs = ((t_end - t_0) / output_step).round() as usize;
+    let t_final = t_0 + outputs as f64 * output_step;
+    let order = match method {
+        Method::DormandPrince => dormand_prince::ORDER,
+        Method::ImplicitEuler => implicit_euler::ORDER,
+    };
+
+    let mut t = t_0;
+    let mut y = y_0.to_vec();
+    let mut f_0 = f(t, &y);
+    let mut g: Vec<f64> = events.iter().map(|event| event.evaluate(t, &y)).collect();
+
+    let mut solution = Solution {
+        times: vec![t_0],
+        states: vec![y.clone()],
+        events: vec![],
+        status: Status::Completed,
+        accepted_steps: 0,
+        rejected_steps: 0,
+    };
+    let mut next_output = 1;
+
+    let mut h = options.h_initial.unwrap_or_else(|| {
+        let zeros = vec![0.0; y.len()];
+        let d_0 = error_norm(&y, &y, &zeros, options);
+        let d_1 = error_norm(&f_0, &y, &zeros, options);
+        if d_0 < 1e-5 || d_1 < 1e-5 {
+            1e-6
+        } else {
+            0.01 * d_0 / d_1
+        }
+    });
+
+    while t < t_final {
+        if solution.accepted_steps + solution.rejected_steps >= options.max_steps {
+            solution.status = Status::MaxStepsReached;
+            break;
+        }
+        h = h.min(options.h_max);
+        let t_output = t_0 + next_output as f64 * output_step;
+        let h_step = h.min(t_output - t);
+        if h_step <= 1e-12 * t.abs().max(1.0) {
+            solution.status = Status::StepSizeTooSmall;
+            break;
+        }
+
+        let mut step = |h_step: f64| match method {
+            Method::DormandPrince => Some(dormand_prince::step(&mut f, t, &y, &f_0, h_step)),
+            Method::ImplicitEuler => implicit_euler::step(&mut f, t, &y, h_step),
+        };
+        let Some((y_new, error)) = step(h_step) else {
+            solution.rejected_steps += 1;
+            h = h_step * 0.25;
+            continue;
+        };
+        let norm = error_norm(&error, &y, &y_new, options);
+        let factor = if norm == 0.0 {
+            5.0
+        } else {
+            (0.9 * norm.powf(-1.0 / order as f64)).clamp(0.2, 5.0)
+        };
+
+        // A step that gives no usable error estimate is shortened as if the
+        // implicit solve had failed.
+        if norm.is_nan() || y_new.iter().any(|value| !value.is_finite()) {
+            solution.rejected_steps += 1;
+            h = h_step * 0.25;
+            continue;
+        }
+        if norm > 1.0 {
+            solution.rejected_steps += 1;
+            h = h_step * factor.min(1.0);
+            continue;
+        }
+        solution.accepted_steps += 1;
+
+        let reaches_output = h_step == t_output - t;
+        let t_new = if reaches_output { t_output } else { t + h_step };
+
+        // Events are located by repeating the step with shorter lengths, so
+        // the event state has the accuracy of the accepted step.
+        let g_new: Vec<f64> = events
+            .iter()
+            .map(|event| event.evaluate(t_new, &y_new))
+            .collect();
+        let mut crossings: Vec<(f64, usize)> = events
+            .iter()
+            .enumerate()
+            .filter(|(i, event)| event.triggered(g[*i], g_new[*i]))
+            .map(|(i, event)| {
+                let time = event.locate(t, g[i], t_new, |s| {
+                    step(s - t).map_or_else(|| y_new.clone(), |(state, _)| state)
+                });
+                (time, i)
+            })
+            .collect();
+        crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut terminal = None;
+        for &(time, index) in &crossings {
+            let state = if time == t_new {
+                y_new.clone()
+            } else {
+                step(time - t).map_or_else(|| y_new.clone(), |(state, _)| state)
+            };
+            solution.events.push(EventRecord {
+                index,
+                t: time,
+                state,
+            });
+            if events[index].terminal {
+                terminal = Some(time);
+                break;
+            }
+        }
+
+        if let Some(time) = terminal {
+            if time == t_new && reaches_output {
+                solution.times.push(t_new);
+                solution.states.push(y_new);
+            }
+            solution.status = Status::Terminated;
+            break;
+        }
+
+        if reaches_output {
+            solution.times.push(t_new);
+            solution.states.push(y_new.clone());
+            next_output += 1;
+        }
+
+        t = t_new;
+        f_0 = f(t, &y_new);
+        g = g_new;
+        y = y_new;
+        // A step shortened to land on an output time does not limit the next.
+        h = if reaches_output {
+            h.max(h_step * factor)
+        } else {
+            h_step * factor
+        };
+    }
+
+    solution
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::Direction;
+
+    #[test]
+    fn test_exponential_decay() {
+        let solution = solve(
+            Method::DormandPrince,
+            |_, y| vec![-0.5 * y[0]],
+            0.0,
+            &[2.0],
+            10.0,
+            1.0,
+            &[],
+            &Options::default(),
+        );
+
+        assert_eq!(solution.status, Status::Completed);
+        assert_eq!(solution.times.len(), 11);
+        for (t, state) in solution.times.iter().zip(&solution.states) {
+            assert!((state[0] - 2.0 * (-0.5 * t).exp()).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_harmonic_oscillator() {
+        let solution = solve(
+            Method::DormandPrince,
+            |_, y| vec![y[1], -y[0]],
+            0.0,
+            &[1.0, 0.0],
+            2.0 * std::f64::consts::PI,
+            std::f64::consts::PI / 6.0,
+            &[],
+            &Options {
+                rtol: 1e-9,
+                atol: 1e-12,
+                ..Options::default()
+            },
+        );
+
+        let last = solution.states.len() - 1;
+        assert!((solution.times[last] - 2.0 * std::f64::consts::PI).abs() < 1e-12);
+        assert!((solution.states[last][0] - 1.0).abs() < 1e-7);
+        assert!(solution.states[last][1].abs() < 1e-7);
+    }
+
+    #[test]
+    fn test_output_grid_is_independent_of_tolerance() {
+        let solve_with = |rtol: f64| {
+            solve(
+                Method::DormandPrince,
+                |t, y| vec![t.cos() - y[0]],
+                0.0,
+                &[0.0],
+                20.0,
+                0.25,
+                &[],
+                &Options {
+                    rtol,
+                    ..Options::default()
+                },
+            )
+        };
+        let coarse = solve_with(1e-3);
+        let fine = solve_with(1e-9);
+
+        assert_eq!(coarse.times, fine.times);
+        assert!(coarse.accepted_steps < fine.accepted_steps);
+    }
+
+    #[test]
+    fn test_stiff_problem() {
+        // y' = -1000 (y - cos t) follows cos t after a fast transient.
+        let f = |t: f64, y: &[f64]| vec![-1000.0 * (y[0] - t.cos())];
+        let options = Options {
+            rtol: 1e-4,
+            atol: 1e-6,
+            ..Options::default()
+        };
+
+        let implicit = solve(
+            Method::ImplicitEuler,
+            f,
+            0.0,
+            &[0.0],
+            10.0,
+            1.0,
+            &[],
+            &options,
+        );
+        let explicit = solve(
+            Method::DormandPrince,
+            f,
+            0.0,
+            &[0.0],
+            10.0,
+            1.0,
+            &[],
+            &options,
+        );
+
+        assert_eq!(implicit.status, Status::Completed);
+        let last = implicit.states.len() - 1;
+        assert!((implicit.states[last][0] - 10.0_f64.cos()).abs() < 1e-3);
+        assert!(implicit.accepted_steps < explicit.accepted_steps);
+    }
+
+    #[test]
+    fn test_terminal_event() {
+        // A ball dropped from 10 m lands at √(2 · 10 / 9.81) s.
+        let events = [Event::threshold(0, 0.0, Direction::Falling, true)];
+        let solution = solve(
+            Method::DormandPrince,
+            |_, y| vec![y[1], -9.81],
+            0.0,
+            &[10.0, 0.0],
+            5.0,
+            0.1,
+            &events,
+            &Options::default(),
+        );
+
+        let expected = (2.0 * 10.0 / 9.81_f64).sqrt();
+        let record = solution.first_event(0).unwrap();
+        assert_eq!(solution.status, Status::Terminated);
+        assert!((record.t - expected).abs() < 1e-9);
+        assert!(record.state[0].abs() < 1e-9);
+        assert!(*solution.times.last().unwrap() <= expected);
+    }
+
+    #[test]
+    fn test_repeated_events() {
+        // sin t crosses zero upwards at 2π and 4π.
+        let events = [Event::new(|_, y| y[0], Direction::Rising, false)];
+        let solution = solve(
+            Method::DormandPrince,
+            |_, y| vec![y[1], -y[0]],
+            0.0,
+            &[0.0, 1.0],
+            13.0,
+            1.0,
+            &events,
+            &Options {
+                rtol: 1e-9,
+                atol: 1e-12,
+                ..Options::default()
+            },
+        );
+
+        assert_eq!(solution.status, Status::Completed);
+        assert_eq!(solution.events.len(), 2);
+        assert!((solution.events[0].t - 2.0 * std::f64::consts::PI).abs() < 1e-6);
+        assert!((solution.events[1].t - 4.0 * std::f64::consts::PI).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_max_steps() {
+        let solution = solve(
+            Method::DormandPrince,
+            |_, y| vec![-y[0]],
+            0.0,
+            &[1.0],
+            100.0,
+            1.0,
+            &[],
+            &Options {
+                max_steps: 3,
+                h_initial: Some(0.01),
+                ..Options::default()
+            },
+        );
+
+        assert_eq!(solution.status, Status::MaxStepsReached);
+    }
+
+    #[test]
+    fn test_non_finite_rates() {
+        let solution = solve(
+            Method::DormandPrince,
+            |t, y| vec![if t <= 1.0 { -y[0] } else { f64::NAN }],
+            0.0,
+            &[1.0],
+            10.0,
+            0.5,
+            &[],
+            &Options::default(),
+        );
+
+        assert_eq!(solution.status, Status::StepSizeTooSmall);
+        assert_eq!(solution.times.last(), Some(&1.0));
+        assert!(solution.rejected_steps < 1000);
+    }
+}
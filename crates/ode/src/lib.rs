@@ -0,0 +1,4 @@
+pub mod dormand_prince;
+pub mod events;
+pub mod implicit_euler;
+pub mod integrator;
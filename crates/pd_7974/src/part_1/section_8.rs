@@ -1,3 +1,4 @@
+pub mod design_fire;
 pub mod equation_28;
 pub mod equation_29;
 pub mod equation_33;
@@ -6,3 +7,4 @@ pub mod equation_41;
 pub mod equation_42;
 pub mod equation_43;
 pub mod equation_44;
+pub mod vent_flow;
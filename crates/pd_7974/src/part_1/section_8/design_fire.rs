@@ -0,0 +1,314 @@
+//! Design fire heat release rate curve: t-squared growth to a peak, a
+//! steady phase while the fuel burns and a linear or exponential decay once
+//! a fraction of the fuel load has been consumed. Times are in seconds, heat
+//! release rates in kW and energies in kJ.
+
+use super::{equation_4, equation_33};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GrowthRate {
+    Slow,
+    Medium,
+    Fast,
+    UltraFast,
+    /// Fire growth coefficient (kW/s²).
+    Custom(f64),
+}
+
+impl GrowthRate {
+    /// Fire growth coefficient (kW/s²), reaching 1055 kW in 600, 300, 150
+    /// and 75 s for the named rates.
+    pub fn alpha(&self) -> f64 {
+        match *self {
+            GrowthRate::Slow => 0.00293,
+            GrowthRate::Medium => 0.01172,
+            GrowthRate::Fast => 0.0469,
+            GrowthRate::UltraFast => 0.1876,
+            GrowthRate::Custom(alpha) => alpha,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Decay {
+    /// Falls linearly to zero.
+    Linear,
+    /// Falls exponentially, releasing the remaining energy.
+    Exponential,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FuelLoad {
+    /// Total energy available (kJ).
+    pub energy: f64,
+    /// Fraction of the energy released before the decay starts.
+    pub decay_fraction: f64,
+    pub decay: Decay,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DesignFire {
+    /// Fire growth coefficient (kW/s²).
+    pub alpha: f64,
+    /// Peak heat release rate (kW).
+    pub q_peak: f64,
+    /// Fuel load, or `None` for a fire that stays at its peak.
+    pub fuel: Option<FuelLoad>,
+}
+
+impl DesignFire {
+    pub fn new(growth: GrowthRate, q_peak: f64) -> Self {
+        DesignFire {
+            alpha: growth.alpha(),
+            q_peak,
+            fuel: None,
+        }
+    }
+
+    /// Fire capped at the lower of the fuel controlled peak for a fire area
+    /// `a_f` (m²) and heat release rate per unit area `hrrpua` (kW/m²), and
+    /// the ventilation controlled peak for an opening of area `a_v` (m²) and
+    /// height `h_v` (m).
+    pub fn capped(growth: GrowthRate, a_f: f64, hrrpua: f64, a_v: f64, h_v: f64) -> Self {
+        let q_fc = equation_4::q_max_fc(a_f, hrrpua);
+        let q_vc = equation_33::q_max_vc(a_v, h_v);
+        DesignFire::new(growth, q_fc.min(q_vc))
+    }
+
+    /// Limits the fire to a fuel load density `q_f` (MJ/m²) over floor area
+    /// `a_f` (m²), with the decay starting once 70 % of it has burnt.
+    pub fn with_fuel_load(self, q_f: f64, a_f: f64, decay: Decay) -> Self {
+        DesignFire {
+            fuel: Some(FuelLoad {
+                energy: q_f * a_f * 1000.0,
+                decay_fraction: 0.7,
+                decay,
+            }),
+            ..self
+        }
+    }
+
+    /// Time (s) at which the growth phase reaches the peak.
+    pub fn growth_time(&self) -> f64 {
+        (self.q_peak / self.alpha).sqrt()
+    }
+
+    /// Time (s) and heat release rate (kW) at the start of the decay, or
+    /// `None` without a fuel load. The decay starts during the growth phase
+    /// if the fuel is consumed before the peak is reached.
+    pub fn decay_start(&self) -> Option<(f64, f64)> {
+        let fuel = self.fuel?;
+        let energy = fuel.decay_fraction * fuel.energy;
+        let t_g = self.growth_time();
+        let growth_energy = self.alpha * t_g.powi(3) / 3.0;
+
+        if energy <= growth_energy {
+            let t_d = (3.0 * energy / self.alpha).cbrt();
+            Some((t_d, self.alpha * t_d.powi(2)))
+        } else {
+            Some((t_g + (energy - growth_energy) / self.q_peak, self.q_peak))
+        }
+    }
+
+    /// Time (s) at which the fire goes out, or `None` if it burns
+    /// indefinitely, including an exponential decay.
+    pub fn end_time(&self) -> Option<f64> {
+        let fuel = self.fuel?;
+        let (t_d, q_d) = self.decay_start()?;
+        match fuel.decay {
+            Decay::Linear => Some(t_d + 2.0 * (1.0 - fuel.decay_fraction) * fuel.energy / q_d),
+            Decay::Exponential => None,
+        }
+    }
+
+    /// Heat release rate (kW) at time `t` (s).
+    pub fn hrr(&self, t: f64) -> f64 {
+        if t <= 0.0 {
+            return 0.0;
+        }
+
+        let growing = (self.alpha * t.powi(2)).min(self.q_peak);
+        let Some(fuel) = self.fuel else {
+            return growing;
+        };
+        let (t_d, q_d) = self.decay_start().unwrap();
+        if t <= t_d {
+            return growing;
+        }
+
+        let remaining = (1.0 - fuel.decay_fraction) * fuel.energy;
+        match fuel.decay {
+            Decay::Linear => {
+                let duration = 2.0 * remaining / q_d;
+                (q_d * (1.0 - (t - t_d) / duration)).max(0.0)
+            }
+            Decay::Exponential => q_d * (-(t - t_d) * q_d / remaining).exp(),
+        }
+    }
+
+    /// Energy (kJ) released up to time `t` (s).
+    pub fn energy(&self, t: f64) -> f64 {
+        if t <= 0.0 {
+            return 0.0;
+        }
+
+        let t_g = self.growth_time();
+        let steady = |t: f64| {
+            if t <= t_g {
+                self.alpha * t.powi(3) / 3.0
+            } else {
+                self.alpha * t_g.powi(3) / 3.0 + self.q_peak * (t - t_g)
+            }
+        };
+        let Some(fuel) = self.fuel else {
+            return steady(t);
+        };
+        let (t_d, q_d) = self.decay_start().unwrap();
+        if t <= t_d {
+            return steady(t);
+        }
+
+        let released = fuel.decay_fraction * fuel.energy;
+        let remaining = fuel.energy - released;
+        let elapsed = t - t_d;
+        match fuel.decay {
+            Decay::Linear => {
+                let duration = 2.0 * remaining / q_d;
+                let elapsed = elapsed.min(duration);
+                released + q_d * elapsed - q_d * elapsed.powi(2) / (2.0 * duration)
+            }
+            Decay::Exponential => released + remaining * (1.0 - (-elapsed * q_d / remaining).exp()),
+        }
+    }
+
+    /// Total energy (kJ) released, or `None` without a fuel load.
+    pub fn total_energy(&self) -> Option<f64> {
+        self.fuel.map(|fuel| fuel.energy)
+    }
+
+    /// Heat release rates (kW) at times 0, `step`, 2 `step`, ... up to `t_end`
+    /// (s), as `(time, hrr)` pairs. `step` must be positive.
+    pub fn sample(&self, step: f64, t_end: f64) -> Vec<(f64, f64)> {
+        assert!(step > 0.0, "the time step must be positive");
+        let steps = (t_end / step).round() as usize;
+        (0..=steps)
+            .map(|i| {
+                let t = i as f64 * step;
+                (t, self.hrr(t))
+            })
+            .collect()
+    }
+
+    /// Sampled curve as CSV with a `time_s,hrr_kw` header.
+    pub fn to_csv(&self, step: f64, t_end: f64) -> String {
+        let mut csv = String::from("time_s,hrr_kw\n");
+        for (t, q) in self.sample(step, t_end) {
+            csv.push_str(&format!("{},{}\n", t, q));
+        }
+        csv
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_named_growth_rates_reach_1055_kw() {
+        let times = [
+            (GrowthRate::Slow, 600.0),
+            (GrowthRate::Medium, 300.0),
+            (GrowthRate::Fast, 150.0),
+            (GrowthRate::UltraFast, 75.0),
+        ];
+        for (growth, t) in times {
+            let q = DesignFire::new(growth, 1e6).hrr(t);
+            assert!((q - 1055.0).abs() < 1.0);
+        }
+    }
+
+    #[test]
+    fn test_capped_by_ventilation() {
+        let fire = DesignFire::capped(GrowthRate::Fast, 20.0, 500.0, 2.0, 2.25);
+
+        assert!((fire.q_peak - 4500.0).abs() < 1e-9);
+        assert_eq!(fire.hrr(1000.0), 4500.0);
+    }
+
+    #[test]
+    fn test_capped_by_fuel() {
+        let fire = DesignFire::capped(GrowthRate::Medium, 4.0, 250.0, 2.0, 2.0);
+
+        assert!((fire.q_peak - 1000.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_steady_fire() {
+        let fire = DesignFire::new(GrowthRate::Medium, 2000.0);
+        let t_g = (2000.0_f64 / 0.01172).sqrt();
+
+        assert!((fire.growth_time() - t_g).abs() < 1e-9);
+        assert_eq!(fire.decay_start(), None);
+        assert_eq!(fire.end_time(), None);
+        assert!((fire.hrr(100.0) - 0.01172 * 1e4).abs() < 1e-9);
+        let expected = 0.01172 * t_g.powi(3) / 3.0 + 2000.0 * (1000.0 - t_g);
+        assert!((fire.energy(1000.0) - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_linear_decay_releases_fuel_load() {
+        let fire =
+            DesignFire::new(GrowthRate::Fast, 5000.0).with_fuel_load(400.0, 20.0, Decay::Linear);
+        let end = fire.end_time().unwrap();
+        let (t_d, q_d) = fire.decay_start().unwrap();
+
+        assert_eq!(q_d, 5000.0);
+        assert!((fire.energy(t_d) - 0.7 * 8e6).abs() < 1e-3);
+        assert!((fire.energy(end) - 8e6).abs() < 1e-3);
+        assert_eq!(fire.hrr(end + 1.0), 0.0);
+        assert!((fire.hrr(0.5 * (t_d + end)) - 2500.0).abs() < 1e-6);
+
+        // The analytical energy matches a numerical integral of the curve.
+        let samples = fire.sample(1.0, end.ceil());
+        let numerical: f64 = samples.windows(2).map(|w| 0.5 * (w[0].1 + w[1].1)).sum();
+        assert!((numerical - 8e6).abs() / 8e6 < 1e-4);
+    }
+
+    #[test]
+    fn test_exponential_decay() {
+        let fire = DesignFire::new(GrowthRate::Fast, 5000.0).with_fuel_load(
+            400.0,
+            20.0,
+            Decay::Exponential,
+        );
+        let (t_d, _) = fire.decay_start().unwrap();
+        let tau = 0.3 * 8e6 / 5000.0;
+
+        assert_eq!(fire.end_time(), None);
+        assert!((fire.hrr(t_d + tau) - 5000.0 / std::f64::consts::E).abs() < 1e-6);
+        assert!((fire.energy(t_d + 50.0 * tau) - 8e6).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_decay_before_peak() {
+        // A small fuel load is consumed before the fire reaches its peak.
+        let fire = DesignFire::new(GrowthRate::Slow, 1e5).with_fuel_load(1.0, 1.0, Decay::Linear);
+        let (t_d, q_d) = fire.decay_start().unwrap();
+
+        assert!(q_d < 1e5);
+        assert!((fire.energy(t_d) - 700.0).abs() < 1e-9);
+        assert!((fire.energy(fire.end_time().unwrap()) - 1000.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let csv = DesignFire::new(GrowthRate::Custom(1.0), 100.0).to_csv(5.0, 10.0);
+        assert_eq!(csv, "time_s,hrr_kw\n0,0\n5,25\n10,100\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "the time step must be positive")]
+    fn test_sample_zero_step() {
+        DesignFire::new(GrowthRate::Medium, 1000.0).sample(0.0, 600.0);
+    }
+}
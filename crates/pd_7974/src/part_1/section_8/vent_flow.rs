@@ -0,0 +1,208 @@
+//! Buoyancy driven flow through a vertical opening of a fire compartment.
+//! The hot gas at `t_g` fills the compartment down to a layer interface
+//! above the sill, with gas at ambient `t_a` below it and outside. From
+//! Bernoulli, hot gas flows out above the neutral plane and ambient air flows
+//! in below it. Temperatures are in K, lengths in m, mass flows in kg/s and
+//! pressures in Pa.
+
+const G: f64 = 9.81;
+/// Air drawn in per unit ventilation factor `A√H` (kg/s·m^-5/2).
+const KAWAGOE: f64 = 0.5;
+
+/// Gas density (kg/m³) at temperature `t`.
+fn density(t: f64) -> f64 {
+    353.0 / t
+}
+
+/// Air inflow (kg/s) of a fully developed, ventilation controlled fire
+/// through an opening of area `a_v` (m²) and height `h_v` (m), after
+/// Kawagoe. At 3000 kJ per kg of air this burns at the rate of
+/// `equation_33`.
+pub fn kawagoe_air_flow(a_v: f64, h_v: f64) -> f64 {
+    KAWAGOE * a_v * h_v.sqrt()
+}
+
+/// Pressure difference (Pa) across the opening at height `z` above the
+/// neutral plane, positive outwards.
+pub fn pressure_difference(t_g: f64, t_a: f64, z: f64) -> f64 {
+    (density(t_a) - density(t_g)) * G * z
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Opening {
+    pub width: f64,
+    /// Height of the soffit above the sill.
+    pub height: f64,
+    /// Discharge coefficient.
+    pub c_d: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VentFlow {
+    /// Height of the neutral plane above the sill.
+    pub neutral_plane: f64,
+    /// Hot gas outflow (kg/s).
+    pub outflow: f64,
+    /// Ambient air inflow (kg/s).
+    pub inflow: f64,
+}
+
+impl Opening {
+    /// Sharp edged opening with a discharge coefficient of 0.7.
+    pub fn new(width: f64, height: f64) -> Self {
+        Opening {
+            width,
+            height,
+            c_d: 0.7,
+        }
+    }
+
+    /// Hot gas outflow (kg/s) above a neutral plane at `h_n`.
+    pub fn outflow(&self, t_g: f64, t_a: f64, h_n: f64) -> f64 {
+        let (rho_g, rho_a) = (density(t_g), density(t_a));
+        let head = (self.height - h_n).max(0.0);
+        2.0 / 3.0
+            * self.c_d
+            * self.width
+            * (2.0 * G * rho_g * (rho_a - rho_g).max(0.0)).sqrt()
+            * head.powf(1.5)
+    }
+
+    /// Ambient air inflow (kg/s) below a neutral plane at `h_n`, with the
+    /// layer interface at `h_d`. Below the interface the pressure difference
+    /// is uniform.
+    pub fn inflow(&self, t_g: f64, t_a: f64, h_n: f64, h_d: f64) -> f64 {
+        let (rho_g, rho_a) = (density(t_g), density(t_a));
+        let h_d = h_d.clamp(0.0, h_n.max(0.0));
+        let head = (h_n - h_d).max(0.0);
+        2.0 / 3.0
+            * self.c_d
+            * self.width
+            * (2.0 * G * rho_a * (rho_a - rho_g).max(0.0)).sqrt()
+            * head.sqrt()
+            * (h_n + 0.5 * h_d)
+    }
+
+    /// Neutral plane height above the sill of a fully mixed compartment
+    /// with no fuel mass added, `H / (1 + (T_g / T_a)^1/3)`.
+    pub fn neutral_plane_mixed(&self, t_g: f64, t_a: f64) -> f64 {
+        self.height / (1.0 + (t_g / t_a).cbrt())
+    }
+
+    /// Flows with the layer interface at `h_d` above the sill and fuel
+    /// pyrolysing at `m_f` (kg/s), placing the neutral plane where the
+    /// outflow balances the inflow and the fuel. Returns `None` if the
+    /// layer is not hotter than ambient, the interface is not below the
+    /// soffit or the fuel exceeds the outflow with the neutral plane at the
+    /// interface, so that no neutral plane balances the flows.
+    pub fn flows(&self, t_g: f64, t_a: f64, h_d: f64, m_f: f64) -> Option<VentFlow> {
+        if t_g <= t_a || h_d >= self.height {
+            return None;
+        }
+
+        let h_d = h_d.max(0.0);
+        let excess = |h_n: f64| self.outflow(t_g, t_a, h_n) - self.inflow(t_g, t_a, h_n, h_d) - m_f;
+        if excess(h_d) < 0.0 {
+            return None;
+        }
+        let mut low = h_d;
+        let mut high = self.height;
+        while high - low > 1e-12 * self.height {
+            let h_n = 0.5 * (low + high);
+            if excess(h_n) > 0.0 {
+                low = h_n;
+            } else {
+                high = h_n;
+            }
+        }
+
+        let neutral_plane = 0.5 * (low + high);
+        Some(VentFlow {
+            neutral_plane,
+            outflow: self.outflow(t_g, t_a, neutral_plane),
+            inflow: self.inflow(t_g, t_a, neutral_plane, h_d),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part_1::section_8::equation_33;
+
+    const T_A: f64 = 293.15;
+
+    #[test]
+    fn test_kawagoe_matches_ventilation_controlled_peak() {
+        let air = kawagoe_air_flow(3.0, 9.0);
+        assert!((air - 4.5).abs() < 1e-12);
+        let q = 3000.0 * air;
+        assert!((q - equation_33::q_max_vc(3.0, 9.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_pressure_difference() {
+        // 3460 (1/T_a - 1/T_g) z with 353 g = 3463.
+        let result = pressure_difference(873.15, T_A, 1.0);
+        let expected = 353.0 * 9.81 * (1.0 / T_A - 1.0 / 873.15);
+        assert!((result - expected).abs() < 1e-9);
+        assert!(pressure_difference(873.15, T_A, -1.0) < 0.0);
+    }
+
+    #[test]
+    fn test_mixed_compartment_neutral_plane() {
+        let opening = Opening::new(1.0, 2.0);
+        let flows = opening.flows(1073.15, T_A, 0.0, 0.0).unwrap();
+
+        let expected = opening.neutral_plane_mixed(1073.15, T_A);
+        assert!((flows.neutral_plane - expected).abs() < 1e-9);
+        assert!((flows.outflow - flows.inflow).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_inflow_approaches_kawagoe() {
+        // Over typical post-flashover temperatures the inflow is close to
+        // 0.5 A√H.
+        let opening = Opening::new(2.0, 2.0);
+        let air = kawagoe_air_flow(4.0, 2.0);
+        for t_g in [873.15, 1073.15, 1273.15] {
+            let flows = opening.flows(t_g, T_A, 0.0, 0.0).unwrap();
+            assert!((flows.inflow - air).abs() / air < 0.1);
+        }
+    }
+
+    #[test]
+    fn test_layer_interface() {
+        let opening = Opening::new(1.0, 2.0);
+        let mixed = opening.flows(573.15, T_A, 0.0, 0.0).unwrap();
+        let layer = opening.flows(573.15, T_A, 1.0, 0.0).unwrap();
+
+        assert!(layer.neutral_plane > mixed.neutral_plane);
+        assert!(layer.neutral_plane > 1.0);
+        assert!(layer.outflow < mixed.outflow);
+        assert!((layer.outflow - layer.inflow).abs() < 1e-9);
+        // With the interface at the neutral plane no air flows in.
+        assert_eq!(opening.inflow(573.15, T_A, 1.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn test_fuel_adds_to_outflow() {
+        let opening = Opening::new(1.0, 2.0);
+        let flows = opening.flows(1073.15, T_A, 0.0, 0.1).unwrap();
+
+        assert!((flows.outflow - flows.inflow - 0.1).abs() < 1e-9);
+        assert!(flows.neutral_plane < opening.neutral_plane_mixed(1073.15, T_A));
+    }
+
+    #[test]
+    fn test_no_flow() {
+        let opening = Opening::new(1.0, 2.0);
+        assert_eq!(opening.flows(T_A, T_A, 0.0, 0.0), None);
+        assert_eq!(opening.flows(573.15, T_A, 2.0, 0.0), None);
+
+        // More fuel than the opening can vent with no air drawn in.
+        let limit = opening.outflow(1073.15, T_A, 0.0);
+        assert!(opening.flows(1073.15, T_A, 0.0, 0.99 * limit).is_some());
+        assert_eq!(opening.flows(1073.15, T_A, 0.0, 1.01 * limit), None);
+    }
+}
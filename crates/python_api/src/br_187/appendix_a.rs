@@ -1,3 +1,4 @@
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::wrap_pymodule;
 
@@ -5,9 +6,12 @@ use pyo3::wrap_pymodule;
 use openfire::br_187::appendix_a::{
     equation_a1 as rust_equation_a1, equation_a2 as rust_equation_a2,
     equation_a3 as rust_equation_a3, equation_a4 as rust_equation_a4,
-    equation_a5 as rust_equation_a5,
+    equation_a5 as rust_equation_a5, external_flame as rust_external_flame,
+    radiant_exposure as rust_radiant_exposure,
 };
 
+use crate::eurocode_1_1_2::fire_curve::parse_fire_curve;
+
 // Equation A1 module functions
 #[pyfunction]
 /// Calculate radiation intensity from a fire source (Equation A1).
@@ -416,6 +420,451 @@ fn equation_a5(m: &Bound<'_, PyModule>) -> PyResult<()> {
     Ok(())
 }
 
+// External flame module functions
+#[pyfunction]
+/// Height of the external flame above the top of the opening.
+///
+/// Uses CIBSE Guide E Equation 6.57, limited to zero when the flame does
+/// not reach above the opening.
+///
+/// .. math::
+///
+///    z_f = \max\left(12.8 \cdot \left(\frac{R}{W}\right)^{2/3} - h_o, 0\right)
+///
+/// Args:
+///     r (float): Rate of burning (kg/s)
+///     w (float): Width of the opening (m)
+///     h_o (float): Height of the opening (m)
+///
+/// Returns:
+///     float: Flame height above the opening (m)
+///
+/// Example:
+///     >>> import ofire
+///     >>> z_f = ofire.br_187.appendix_a.external_flame.flame_height(0.2, 1.0, 2.1)
+fn flame_height(r: f64, w: f64, h_o: f64) -> PyResult<f64> {
+    Ok(rust_external_flame::flame_height(r, w, h_o))
+}
+
+#[pyfunction]
+/// Horizontal projection of the external flame from the façade.
+///
+/// .. math::
+///
+///    L_H = \begin{cases} h_o / 3 & h_o \leq 1.25 w \\ 0.3 \cdot h_o \left(\frac{h_o}{w}\right)^{0.54} & h_o > 1.25 w \end{cases}
+///
+/// Args:
+///     h_o (float): Height of the opening (m)
+///     w (float): Width of the opening (m)
+///
+/// Returns:
+///     float: Horizontal flame projection (m)
+///
+/// Example:
+///     >>> import ofire
+///     >>> l_h = ofire.br_187.appendix_a.external_flame.flame_projection(2.1, 1.0)
+fn flame_projection(h_o: f64, w: f64) -> PyResult<f64> {
+    Ok(rust_external_flame::flame_projection(h_o, w))
+}
+
+#[pyfunction]
+/// Thickness of the external flame.
+///
+/// .. math::
+///
+///    d_f = \frac{2}{3} h_o
+///
+/// Args:
+///     h_o (float): Height of the opening (m)
+///
+/// Returns:
+///     float: Flame thickness (m)
+///
+/// Example:
+///     >>> import ofire
+///     >>> d_f = ofire.br_187.appendix_a.external_flame.flame_thickness(2.1)
+fn flame_thickness(h_o: f64) -> PyResult<f64> {
+    Ok(rust_external_flame::flame_thickness(h_o))
+}
+
+#[pyfunction]
+/// Emissivity of the external flame.
+///
+/// .. math::
+///
+///    \varepsilon_f = 1 - e^{-0.3 d_f}
+///
+/// Args:
+///     d_f (float): Flame thickness (m)
+///
+/// Returns:
+///     float: Flame emissivity (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> eps = ofire.br_187.appendix_a.external_flame.flame_emissivity(1.4)
+fn flame_emissivity(d_f: f64) -> PyResult<f64> {
+    Ok(rust_external_flame::flame_emissivity(d_f))
+}
+
+#[pyfunction]
+/// View factor from the external flame to a receiver.
+///
+/// The receiver is opposite the centre of the opening. The flame is a
+/// rectangle of the opening width extending from the top of the opening to
+/// the flame tip, built up from corner aligned rectangles (Equation A4).
+///
+/// Args:
+///     w (float): Width of the opening (m)
+///     h_o (float): Height of the opening (m)
+///     z_f (float): Flame height above the opening (m)
+///     s (float): Distance from the flame face to the receiver (m)
+///
+/// Returns:
+///     float: View factor (dimensionless)
+///
+/// Raises:
+///     ValueError: If the distance from the flame face is not positive
+///
+/// Example:
+///     >>> import ofire
+///     >>> phi_f = ofire.br_187.appendix_a.external_flame.flame_view_factor(1.0, 2.1, 2.28, 4.0)
+fn flame_view_factor(w: f64, h_o: f64, z_f: f64, s: f64) -> PyResult<f64> {
+    rust_external_flame::flame_view_factor(w, h_o, z_f, s).ok_or_else(|| {
+        PyValueError::new_err("The distance from the flame face to the receiver must be positive")
+    })
+}
+
+#[pyfunction]
+/// Radiation intensity received from the external flame of an opening.
+///
+/// Builds the flame emitter from the rate of burning and the opening
+/// geometry, then applies the flame view factor at the receiver distance
+/// measured from the façade, less the flame projection.
+///
+/// Args:
+///     r (float): Rate of burning (kg/s)
+///     w (float): Width of the opening (m)
+///     h_o (float): Height of the opening (m)
+///     theta_f (float): Flame temperature (°C)
+///     s (float): Distance from the façade to the receiver (m)
+///     sigma (float): Stefan-Boltzmann constant (kW/m²K⁴)
+///
+/// Returns:
+///     float: Radiation intensity at the receiver from the flame (kW/m²)
+///
+/// Raises:
+///     ValueError: If the receiver is within the flame projection
+///
+/// Example:
+///     >>> import ofire
+///     >>> i_f = ofire.br_187.appendix_a.external_flame.flame_received_intensity(0.2, 1.0, 2.1, 800.0, 5.0, 5.67e-11)
+fn flame_received_intensity(
+    r: f64,
+    w: f64,
+    h_o: f64,
+    theta_f: f64,
+    s: f64,
+    sigma: f64,
+) -> PyResult<f64> {
+    let flame = rust_external_flame::ExternalFlame::from_compartment(r, w, h_o, theta_f);
+    flame.received_intensity(h_o, s, sigma).ok_or_else(|| {
+        PyValueError::new_err(format!(
+            "The receiver is within the flame projection of {} m",
+            flame.projection
+        ))
+    })
+}
+
+#[pyfunction]
+/// Combined radiation intensity at the receiver from the opening and the
+/// external flame.
+///
+/// .. math::
+///
+///    I_R = \phi_o \cdot I_o + \phi_f \cdot I_f
+///
+/// Args:
+///     phi_o (float): View factor of the opening (dimensionless)
+///     i_o (float): Radiation intensity of the opening (kW/m²)
+///     phi_f (float): View factor of the flame (dimensionless)
+///     i_f (float): Radiation intensity of the flame (kW/m²)
+///
+/// Returns:
+///     float: Combined radiation intensity at the receiver (kW/m²)
+///
+/// Example:
+///     >>> import ofire
+///     >>> ofire.br_187.appendix_a.external_flame.combined_receiver_intensity(0.05, 84.0, 0.02, 30.0)
+fn combined_receiver_intensity(phi_o: f64, i_o: f64, phi_f: f64, i_f: f64) -> PyResult<f64> {
+    Ok(rust_external_flame::combined_receiver_intensity(
+        phi_o, i_o, phi_f, i_f,
+    ))
+}
+
+#[pymodule]
+/// External flames projecting from openings and their contribution to the
+/// radiation received opposite the opening.
+fn external_flame(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(flame_height, m)?)?;
+    m.add_function(wrap_pyfunction!(flame_projection, m)?)?;
+    m.add_function(wrap_pyfunction!(flame_thickness, m)?)?;
+    m.add_function(wrap_pyfunction!(flame_emissivity, m)?)?;
+    m.add_function(wrap_pyfunction!(flame_view_factor, m)?)?;
+    m.add_function(wrap_pyfunction!(flame_received_intensity, m)?)?;
+    m.add_function(wrap_pyfunction!(combined_receiver_intensity, m)?)?;
+    Ok(())
+}
+
+// Radiant exposure module functions
+fn parse_receiver(receiver: &str) -> PyResult<rust_radiant_exposure::Receiver> {
+    match receiver.to_lowercase().as_str() {
+        "parallel_centre" => Ok(rust_radiant_exposure::Receiver::ParallelCentre),
+        "parallel_corner" => Ok(rust_radiant_exposure::Receiver::ParallelCorner),
+        "perpendicular_corner" => Ok(rust_radiant_exposure::Receiver::PerpendicularCorner),
+        _ => Err(PyValueError::new_err(format!(
+            "Unknown receiver '{}', expected 'parallel_centre', 'parallel_corner' or 'perpendicular_corner'",
+            receiver
+        ))),
+    }
+}
+
+fn check_lengths(times: &[f64], intensities: &[f64]) -> PyResult<()> {
+    if times.len() != intensities.len() {
+        return Err(PyValueError::new_err(format!(
+            "times and intensities must have the same length, got {} and {}",
+            times.len(),
+            intensities.len()
+        )));
+    }
+    Ok(())
+}
+
+#[pyfunction]
+#[pyo3(signature = (curve, times, points=None))]
+/// Compartment temperature history from a fire curve.
+///
+/// Args:
+///     curve (str): Fire curve: "standard", "external", "hydrocarbon", "astm_e119", "iso_834", "rws" or "piecewise"
+///     times (list[float]): Times (s)
+///     points (list[tuple[float, float]], optional): Time (min) and temperature (°C) points of a piecewise curve
+///
+/// Returns:
+///     list[float]: Compartment temperature at each time (°C)
+///
+/// Raises:
+///     ValueError: If the curve is not recognised or a piecewise curve has no points
+///
+/// Example:
+///     >>> import ofire
+///     >>> theta = ofire.br_187.appendix_a.radiant_exposure.temperature_history("standard", [0.0, 600.0, 1800.0])
+fn temperature_history(
+    curve: &str,
+    times: Vec<f64>,
+    points: Option<Vec<(f64, f64)>>,
+) -> PyResult<Vec<f64>> {
+    let curve = parse_fire_curve(curve, points)?;
+    Ok(rust_radiant_exposure::fire_curve_history(&curve, &times))
+}
+
+#[pyfunction]
+#[pyo3(signature = (temperatures, receiver, width, height, separation, sigma=5.67e-11, emissivity=1.0))]
+/// Received radiation intensity history from a compartment temperature history.
+///
+/// Each compartment temperature is converted into an emitter intensity
+/// (Equation A1) and multiplied by the view factor of Equations A3 to A5
+/// (Equation A2).
+///
+/// .. math::
+///
+///    I_R(t) = \phi \cdot \sigma \cdot \varepsilon \cdot (\theta(t) + 273)^4
+///
+/// Args:
+///     temperatures (list[float]): Compartment temperatures (°C), for example from :func:`temperature_history`
+///     receiver (str): Receiver position: "parallel_centre" (Equation A3), "parallel_corner" (Equation A4) or "perpendicular_corner" (Equation A5)
+///     width (float): Width of the emitter (m)
+///     height (float): Height of the emitter (m)
+///     separation (float): Separation between the emitter and the receiver (m)
+///     sigma (float): Stefan-Boltzmann constant (kW/m²K⁴)
+///     emissivity (float): Emissivity of the emitter (dimensionless)
+///
+/// Returns:
+///     list[float]: Received radiation intensity at each time (kW/m²)
+///
+/// Raises:
+///     ValueError: If the receiver is not recognised
+///
+/// Example:
+///     >>> import ofire
+///     >>> q = ofire.br_187.appendix_a.radiant_exposure.received_intensity_history([20.0, 500.0, 900.0], "parallel_centre", 5.0, 3.0, 10.0)
+fn received_intensity_history(
+    temperatures: Vec<f64>,
+    receiver: &str,
+    width: f64,
+    height: f64,
+    separation: f64,
+    sigma: f64,
+    emissivity: f64,
+) -> PyResult<Vec<f64>> {
+    let emitter =
+        rust_radiant_exposure::Emitter::new(width, height, separation, parse_receiver(receiver)?);
+    Ok(rust_radiant_exposure::received_intensity_history(
+        &temperatures,
+        &emitter,
+        sigma,
+        emissivity,
+    ))
+}
+
+#[pyfunction]
+/// Cumulative radiant dose at each time.
+///
+/// .. math::
+///
+///    D(t) = \int_0^{t} I_R \, dt
+///
+/// The integral is evaluated with the trapezoidal rule.
+///
+/// Args:
+///     times (list[float]): Times (s)
+///     intensities (list[float]): Received radiation intensity (kW/m²)
+///
+/// Returns:
+///     list[float]: Cumulative radiant dose (kJ/m²)
+///
+/// Raises:
+///     ValueError: If times and intensities have different lengths
+///
+/// Example:
+///     >>> import ofire
+///     >>> d = ofire.br_187.appendix_a.radiant_exposure.cumulative_radiant_dose([0.0, 60.0], [10.0, 10.0])
+fn cumulative_radiant_dose(times: Vec<f64>, intensities: Vec<f64>) -> PyResult<Vec<f64>> {
+    check_lengths(&times, &intensities)?;
+    Ok(rust_radiant_exposure::cumulative_radiant_dose(
+        &times,
+        &intensities,
+    ))
+}
+
+#[pyfunction]
+/// Total radiant dose received over the history.
+///
+/// Args:
+///     times (list[float]): Times (s)
+///     intensities (list[float]): Received radiation intensity (kW/m²)
+///
+/// Returns:
+///     float: Radiant dose (kJ/m²)
+///
+/// Raises:
+///     ValueError: If times and intensities have different lengths
+///
+/// Example:
+///     >>> import ofire
+///     >>> ofire.br_187.appendix_a.radiant_exposure.radiant_dose([0.0, 60.0], [10.0, 10.0])
+///     600.0
+fn radiant_dose(times: Vec<f64>, intensities: Vec<f64>) -> PyResult<f64> {
+    check_lengths(&times, &intensities)?;
+    Ok(rust_radiant_exposure::radiant_dose(&times, &intensities))
+}
+
+#[pyfunction]
+/// Cumulative flux-time product at each time.
+///
+/// .. math::
+///
+///    FTP = \sum \left(I_R - I_{cr}\right)^n \Delta t
+///
+/// Intervals with a received intensity below the critical intensity do not
+/// contribute.
+///
+/// Args:
+///     times (list[float]): Times (s)
+///     intensities (list[float]): Received radiation intensity (kW/m²)
+///     q_cr (float): Critical intensity for ignition of the receiver (kW/m²)
+///     n (float): Flux-time product index (dimensionless)
+///
+/// Returns:
+///     list[float]: Cumulative flux-time product
+///
+/// Raises:
+///     ValueError: If times and intensities have different lengths
+///
+/// Example:
+///     >>> import ofire
+///     >>> ftp = ofire.br_187.appendix_a.radiant_exposure.cumulative_flux_time_product([0.0, 60.0], [20.0, 20.0], 10.0, 1.5)
+fn cumulative_flux_time_product(
+    times: Vec<f64>,
+    intensities: Vec<f64>,
+    q_cr: f64,
+    n: f64,
+) -> PyResult<Vec<f64>> {
+    check_lengths(&times, &intensities)?;
+    Ok(rust_radiant_exposure::cumulative_flux_time_product(
+        &times,
+        &intensities,
+        q_cr,
+        n,
+    ))
+}
+
+#[pyfunction]
+/// Time to ignition of the receiver by the flux-time product method.
+///
+/// Returns the time at which the flux-time product first reaches the
+/// critical value, interpolated between samples.
+///
+/// Args:
+///     times (list[float]): Times (s)
+///     intensities (list[float]): Received radiation intensity (kW/m²)
+///     q_cr (float): Critical intensity for ignition of the receiver (kW/m²)
+///     n (float): Flux-time product index (dimensionless)
+///     ftp_crit (float): Critical flux-time product for ignition
+///
+/// Returns:
+///     float | None: Time to ignition (s), or None if ignition does not occur
+///
+/// Raises:
+///     ValueError: If times and intensities have different lengths or the critical flux-time product is not positive
+///
+/// Example:
+///     >>> import ofire
+///     >>> t_ig = ofire.br_187.appendix_a.radiant_exposure.time_to_ignition([0.0, 100.0, 200.0], [20.0, 20.0, 20.0], 10.0, 1.0, 1500.0)
+fn time_to_ignition(
+    times: Vec<f64>,
+    intensities: Vec<f64>,
+    q_cr: f64,
+    n: f64,
+    ftp_crit: f64,
+) -> PyResult<Option<f64>> {
+    check_lengths(&times, &intensities)?;
+    if ftp_crit.is_nan() || ftp_crit <= 0.0 {
+        return Err(PyValueError::new_err(
+            "The critical flux-time product must be positive",
+        ));
+    }
+    Ok(rust_radiant_exposure::time_to_ignition(
+        &times,
+        &intensities,
+        q_cr,
+        n,
+        ftp_crit,
+    ))
+}
+
+#[pymodule]
+/// Time-dependent radiation received from a compartment fire, the radiant
+/// dose and the time to ignition of the receiver.
+fn radiant_exposure(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(temperature_history, m)?)?;
+    m.add_function(wrap_pyfunction!(received_intensity_history, m)?)?;
+    m.add_function(wrap_pyfunction!(cumulative_radiant_dose, m)?)?;
+    m.add_function(wrap_pyfunction!(radiant_dose, m)?)?;
+    m.add_function(wrap_pyfunction!(cumulative_flux_time_product, m)?)?;
+    m.add_function(wrap_pyfunction!(time_to_ignition, m)?)?;
+    Ok(())
+}
+
 #[pymodule]
 /// This appendix provides comprehensive calculations for thermal radiation
 /// from fire sources, including radiation intensity calculations and view
@@ -429,5 +878,7 @@ pub fn appendix_a(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pymodule!(equation_a3))?;
     m.add_wrapped(wrap_pymodule!(equation_a4))?;
     m.add_wrapped(wrap_pymodule!(equation_a5))?;
+    m.add_wrapped(wrap_pymodule!(external_flame))?;
+    m.add_wrapped(wrap_pymodule!(radiant_exposure))?;
     Ok(())
 }
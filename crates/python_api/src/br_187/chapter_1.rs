@@ -1,8 +1,13 @@
 use pyo3::prelude::*;
 use pyo3::wrap_pymodule;
 
+use pyo3::exceptions::PyValueError;
+
 // Import BR_187 chapter 1 functions
-use openfire::br_187::chapter_1::equation_1 as rust_equation_1;
+use openfire::br_187::chapter_1::{
+    equation_1 as rust_equation_1, fire_severity as rust_fire_severity,
+    purpose_group as rust_purpose_group,
+};
 
 // Equation 1 module functions
 #[pyfunction]
@@ -51,10 +56,287 @@ fn equation_1(m: &Bound<'_, PyModule>) -> PyResult<()> {
     Ok(())
 }
 
+fn parse_purpose_group(purpose_group: &str) -> PyResult<rust_purpose_group::PurposeGroup> {
+    match purpose_group.to_lowercase().as_str() {
+        "residential" => Ok(rust_purpose_group::PurposeGroup::Residential),
+        "office" => Ok(rust_purpose_group::PurposeGroup::Office),
+        "assembly_and_recreation" => Ok(rust_purpose_group::PurposeGroup::AssemblyAndRecreation),
+        "shop" => Ok(rust_purpose_group::PurposeGroup::Shop),
+        "commercial" => Ok(rust_purpose_group::PurposeGroup::Commercial),
+        "industrial" => Ok(rust_purpose_group::PurposeGroup::Industrial),
+        "storage" => Ok(rust_purpose_group::PurposeGroup::Storage),
+        _ => Err(PyValueError::new_err(format!(
+            "Unknown purpose group '{}'",
+            purpose_group
+        ))),
+    }
+}
+
+fn parse_fire_load(fire_load: &str) -> PyResult<rust_purpose_group::FireLoad> {
+    match fire_load.to_lowercase().as_str() {
+        "normal" => Ok(rust_purpose_group::FireLoad::Normal),
+        "high" => Ok(rust_purpose_group::FireLoad::High),
+        _ => Err(PyValueError::new_err(format!(
+            "Unknown fire load '{}'",
+            fire_load
+        ))),
+    }
+}
+
+// Purpose group module functions
+#[pyfunction]
+/// Classify the fire load of a compartment by its purpose group.
+///
+/// Residential, office and assembly and recreation buildings are classed as
+/// normal fire load. Shop, commercial, industrial and storage buildings are
+/// classed as high fire load.
+///
+/// Args:
+///     purpose_group (str): One of ``"residential"``, ``"office"``,
+///         ``"assembly_and_recreation"``, ``"shop"``, ``"commercial"``,
+///         ``"industrial"`` or ``"storage"``
+///
+/// Returns:
+///     str: Fire load classification, ``"normal"`` or ``"high"``
+///
+/// Example:
+///     >>> import ofire
+///     >>> ofire.br_187.chapter_1.purpose_group.fire_load("office")
+///     'normal'
+fn fire_load(purpose_group: &str) -> PyResult<String> {
+    match parse_purpose_group(purpose_group)?.fire_load() {
+        rust_purpose_group::FireLoad::Normal => Ok("normal".to_string()),
+        rust_purpose_group::FireLoad::High => Ok("high".to_string()),
+    }
+}
+
+#[pyfunction]
+/// Emitter radiation intensity for a purpose group.
+///
+/// Normal fire load purpose groups use 84 kW/m² and high fire load purpose
+/// groups use 168 kW/m².
+///
+/// Args:
+///     purpose_group (str): Purpose group of the compartment
+///
+/// Returns:
+///     float: Radiation intensity at the emitter (kW/m²)
+///
+/// Example:
+///     >>> import ofire
+///     >>> ofire.br_187.chapter_1.purpose_group.radiation_intensity("storage")
+///     168.0
+fn radiation_intensity(purpose_group: &str) -> PyResult<f64> {
+    Ok(rust_purpose_group::radiation_intensity(
+        parse_purpose_group(purpose_group)?,
+    ))
+}
+
+#[pymodule]
+/// Fire load classification by purpose group and the associated emitter
+/// radiation intensity.
+fn purpose_group(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(fire_load, m)?)?;
+    m.add_function(wrap_pyfunction!(radiation_intensity, m)?)?;
+    Ok(())
+}
+
+// Fire severity module functions
+#[pyfunction]
+/// Maximum compartment temperature from the ventilation factor (Law).
+///
+/// .. math::
+///
+///    \theta_{max} = 6000 \cdot \frac{1 - e^{-0.1 \Omega}}{\sqrt{\Omega}}
+///
+/// where:
+///
+/// - :math:`\theta_{max}` is the maximum compartment temperature (°C)
+/// - :math:`\Omega` is the ventilation factor from equation 1 (m⁻¹/²)
+///
+/// Args:
+///     omega (float): Ventilation factor (m⁻¹/²)
+///
+/// Returns:
+///     float: Maximum compartment temperature (°C)
+///
+/// Example:
+///     >>> import ofire
+///     >>> theta_max = ofire.br_187.chapter_1.fire_severity.maximum_temperature(14.387)
+fn maximum_temperature(omega: f64) -> PyResult<f64> {
+    Ok(rust_fire_severity::maximum_temperature(omega))
+}
+
+#[pyfunction]
+/// Fire load factor.
+///
+/// .. math::
+///
+///    \psi = \frac{L}{\sqrt{A_v \cdot A_t}}
+///
+/// where:
+///
+/// - :math:`\psi` is the fire load factor (kg/m²)
+/// - :math:`L` is the fire load in wood equivalent (kg)
+/// - :math:`A_v` is the area of openings (m²)
+/// - :math:`A_t` is the internal surface area of the compartment (m²)
+///
+/// Args:
+///     l (float): Fire load in wood equivalent (kg)
+///     a_v (float): Area of openings (m²)
+///     a_t (float): Internal surface area (m²)
+///
+/// Returns:
+///     float: Fire load factor (kg/m²)
+///
+/// Example:
+///     >>> import ofire
+///     >>> psi = ofire.br_187.chapter_1.fire_severity.fire_load_factor(500.0, 1.785, 37.215)
+fn fire_load_factor(l: f64, a_v: f64, a_t: f64) -> PyResult<f64> {
+    Ok(rust_fire_severity::fire_load_factor(l, a_v, a_t))
+}
+
+#[pyfunction]
+/// Compartment temperature moderated by the fire load factor.
+///
+/// .. math::
+///
+///    \theta = \theta_{max} \cdot \left(1 - e^{-0.05 \psi}\right)
+///
+/// Args:
+///     theta_max (float): Maximum compartment temperature (°C)
+///     psi (float): Fire load factor (kg/m²)
+///
+/// Returns:
+///     float: Compartment temperature (°C)
+///
+/// Example:
+///     >>> import ofire
+///     >>> theta = ofire.br_187.chapter_1.fire_severity.temperature(1206.6, 61.3)
+fn temperature(theta_max: f64, psi: f64) -> PyResult<f64> {
+    Ok(rust_fire_severity::temperature(theta_max, psi))
+}
+
+#[pyfunction]
+/// Emitter radiation intensity for a compartment temperature.
+///
+/// .. math::
+///
+///    I_s = \sigma \cdot \varepsilon \cdot (\theta + 273)^4
+///
+/// Args:
+///     sigma (float): Stefan-Boltzmann constant (kW/m²K⁴)
+///     emissivity (float): Emissivity of the emitter (dimensionless)
+///     theta (float): Compartment temperature (°C)
+///
+/// Returns:
+///     float: Radiation intensity at the emitter (kW/m²)
+///
+/// Example:
+///     >>> import ofire
+///     >>> i_s = ofire.br_187.chapter_1.fire_severity.emitter_intensity(5.67e-11, 1.0, 1000.0)
+fn emitter_intensity(sigma: f64, emissivity: f64, theta: f64) -> PyResult<f64> {
+    Ok(rust_fire_severity::emitter_intensity(
+        sigma, emissivity, theta,
+    ))
+}
+
+#[pyfunction]
+/// Compartment temperature equivalent to an emitter radiation intensity.
+///
+/// .. math::
+///
+///    \theta = \left(\frac{I_s}{\sigma \cdot \varepsilon}\right)^{1/4} - 273
+///
+/// Args:
+///     sigma (float): Stefan-Boltzmann constant (kW/m²K⁴)
+///     emissivity (float): Emissivity of the emitter (dimensionless)
+///     i_s (float): Radiation intensity at the emitter (kW/m²)
+///
+/// Returns:
+///     float: Compartment temperature (°C)
+///
+/// Example:
+///     >>> import ofire
+///     >>> theta = ofire.br_187.chapter_1.fire_severity.emitter_temperature(5.67e-11, 1.0, 84.0)
+fn emitter_temperature(sigma: f64, emissivity: f64, i_s: f64) -> PyResult<f64> {
+    Ok(rust_fire_severity::emitter_temperature(
+        sigma, emissivity, i_s,
+    ))
+}
+
+#[pyfunction]
+/// Emitter radiation intensity from the ventilation factor and fire load factor.
+///
+/// Combines the maximum temperature, fire load moderation and emitter
+/// intensity so the result can be passed directly to the appendix A
+/// receiver calculations.
+///
+/// Args:
+///     omega (float): Ventilation factor (m⁻¹/²)
+///     psi (float): Fire load factor (kg/m²)
+///     sigma (float): Stefan-Boltzmann constant (kW/m²K⁴)
+///     emissivity (float): Emissivity of the emitter (dimensionless)
+///
+/// Returns:
+///     float: Radiation intensity at the emitter (kW/m²)
+///
+/// Example:
+///     >>> import ofire
+///     >>> i_s = ofire.br_187.chapter_1.fire_severity.emitter_intensity_from_ventilation(14.387, 61.3, 5.67e-11, 1.0)
+fn emitter_intensity_from_ventilation(
+    omega: f64,
+    psi: f64,
+    sigma: f64,
+    emissivity: f64,
+) -> PyResult<f64> {
+    Ok(rust_fire_severity::emitter_intensity_from_ventilation(
+        omega, psi, sigma, emissivity,
+    ))
+}
+
+#[pyfunction]
+/// Compartment temperature equivalent to a fire load classification.
+///
+/// Args:
+///     fire_load (str): Fire load classification, ``"normal"`` or ``"high"``
+///     sigma (float): Stefan-Boltzmann constant (kW/m²K⁴)
+///     emissivity (float): Emissivity of the emitter (dimensionless)
+///
+/// Returns:
+///     float: Compartment temperature (°C)
+///
+/// Example:
+///     >>> import ofire
+///     >>> theta = ofire.br_187.chapter_1.fire_severity.fire_load_temperature("high", 5.67e-11, 1.0)
+fn fire_load_temperature(fire_load: &str, sigma: f64, emissivity: f64) -> PyResult<f64> {
+    Ok(rust_fire_severity::fire_load_temperature(
+        parse_fire_load(fire_load)?,
+        sigma,
+        emissivity,
+    ))
+}
+
+#[pymodule]
+/// Compartment fire severity inputs used to derive the emitter radiation
+/// intensity from the ventilation factor and fire load.
+fn fire_severity(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(maximum_temperature, m)?)?;
+    m.add_function(wrap_pyfunction!(fire_load_factor, m)?)?;
+    m.add_function(wrap_pyfunction!(temperature, m)?)?;
+    m.add_function(wrap_pyfunction!(emitter_intensity, m)?)?;
+    m.add_function(wrap_pyfunction!(emitter_temperature, m)?)?;
+    m.add_function(wrap_pyfunction!(emitter_intensity_from_ventilation, m)?)?;
+    m.add_function(wrap_pyfunction!(fire_load_temperature, m)?)?;
+    Ok(())
+}
+
 #[pymodule]
 /// This chapter provides fundamental calculations for external fire spread
 /// assessment including ventilation factors and building geometry considerations.
 pub fn chapter_1(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pymodule!(equation_1))?;
+    m.add_wrapped(wrap_pymodule!(purpose_group))?;
+    m.add_wrapped(wrap_pymodule!(fire_severity))?;
     Ok(())
 }
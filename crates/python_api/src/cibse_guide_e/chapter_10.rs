@@ -7,7 +7,7 @@ use openfire::cibse_guide_e::chapter_10::{
     equation_10_3 as rust_equation_10_3, equation_10_4 as rust_equation_10_4,
     equation_10_7 as rust_equation_10_7, equation_10_8 as rust_equation_10_8,
     equation_10_10 as rust_equation_10_10, equation_10_11 as rust_equation_10_11,
-    equation_10_12 as rust_equation_10_12,
+    equation_10_12 as rust_equation_10_12, smoke_reservoir as rust_smoke_reservoir,
 };
 
 // Equation 10_1 module functions
@@ -461,6 +461,113 @@ fn equation_10_12(m: &Bound<'_, PyModule>) -> PyResult<()> {
     Ok(())
 }
 
+// Smoke reservoir module functions
+type ReservoirDesignTuple = (
+    f64,
+    f64,
+    f64,
+    f64,
+    Option<f64>,
+    usize,
+    f64,
+    f64,
+    Vec<&'static str>,
+);
+
+fn violation_name(violation: rust_smoke_reservoir::Violation) -> &'static str {
+    match violation {
+        rust_smoke_reservoir::Violation::NoPlume => "no_plume",
+        rust_smoke_reservoir::Violation::NoSmokeLayer => "no_smoke_layer",
+        rust_smoke_reservoir::Violation::AreaTooLarge => "area_too_large",
+        rust_smoke_reservoir::Violation::LengthTooLarge => "length_too_large",
+        rust_smoke_reservoir::Violation::ExtractSpacing => "extract_spacing",
+    }
+}
+
+#[pyfunction]
+#[pyo3(signature = (reservoir, q_c, perimeter, clear_height, inlet_ratio=None, gamma=1.0, t_0=293.15))]
+/// Smoke reservoir design for natural or mechanical extract.
+///
+/// The plume entering the reservoir follows the large fire correlation and
+/// heats the layer adiabatically:
+///
+/// .. math::
+///
+///    M = 0.19 P Y^{3/2}, \quad \theta_l = \frac{\dot{Q}_c}{M c_p}
+///
+/// Natural vents are sized from the buoyancy head of a layer of depth
+/// :math:`d_l`, with inlets of :math:`r = A_i C_i / A_v C_v` times the vent
+/// area:
+///
+/// .. math::
+///
+///    A_v C_v = \frac{M \left(T_l^2 + T_l T_0 / r^2\right)^{1/2}}{\rho_0 \left(2 g d_l \theta_l T_0\right)^{1/2}}
+///
+/// Extract points are limited by plugholing (Equation 10.1) and spaced by
+/// Equation 10.2.
+///
+/// Args:
+///     reservoir (tuple[float, float, float]): Width (m), length (m) and ceiling height (m) of the reservoir
+///     q_c (float): Convective heat release rate (kW)
+///     perimeter (float): Fire perimeter (m)
+///     clear_height (float): Clear height above the floor (m)
+///     inlet_ratio (float, optional): Inlet to vent aerodynamic area ratio for natural extract, or None for mechanical extract
+///     gamma (float): 1.0 for extract points away from walls, 0.5 next to a wall
+///     t_0 (float): Ambient temperature (K)
+///
+/// Returns:
+///     tuple: Mass flow (kg/s), layer temperature (K), layer depth (m),
+///     volumetric flow (m³/s), natural vent aerodynamic free area (m²) or
+///     None, number of extract points, maximum flow per extract point
+///     (m³/s), minimum separation (m) and a list of violations, any of
+///     'no_plume', 'no_smoke_layer', 'area_too_large', 'length_too_large'
+///     and 'extract_spacing'
+///
+/// Example:
+///     >>> import ofire
+///     >>> m, t, d, v, a, n, v_max, s, violations = ofire.cibse_guide_e.chapter_10.smoke_reservoir.design((30.0, 40.0, 5.0), 2500.0, 12.0, 3.0, inlet_ratio=2.0)
+fn design(
+    reservoir: (f64, f64, f64),
+    q_c: f64,
+    perimeter: f64,
+    clear_height: f64,
+    inlet_ratio: Option<f64>,
+    gamma: f64,
+    t_0: f64,
+) -> PyResult<ReservoirDesignTuple> {
+    let (width, length, height) = reservoir;
+    let reservoir = rust_smoke_reservoir::Reservoir {
+        t_0,
+        ..rust_smoke_reservoir::Reservoir::new(width, length, height)
+    };
+    let extract = match inlet_ratio {
+        Some(inlet_ratio) => rust_smoke_reservoir::Extract::Natural { inlet_ratio },
+        None => rust_smoke_reservoir::Extract::Mechanical,
+    };
+    let result = reservoir.design(q_c, perimeter, clear_height, extract, gamma);
+    Ok((
+        result.mass_flow,
+        result.layer_temperature,
+        result.layer_depth,
+        result.volumetric_flow,
+        result.vent_area,
+        result.extract_points,
+        result.max_flow_per_point,
+        result.min_separation,
+        result.violations.into_iter().map(violation_name).collect(),
+    ))
+}
+
+#[pymodule]
+/// Smoke Reservoir Design.
+///
+/// Layer temperature and depth, natural vent area or mechanical extract
+/// points, and plugholing and geometry checks for a smoke reservoir.
+fn smoke_reservoir(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(design, m)?)?;
+    Ok(())
+}
+
 #[pymodule]
 pub fn chapter_10(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pymodule!(equation_10_1))?;
@@ -472,5 +579,6 @@ pub fn chapter_10(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pymodule!(equation_10_10))?;
     m.add_wrapped(wrap_pymodule!(equation_10_11))?;
     m.add_wrapped(wrap_pymodule!(equation_10_12))?;
+    m.add_wrapped(wrap_pymodule!(smoke_reservoir))?;
     Ok(())
 }
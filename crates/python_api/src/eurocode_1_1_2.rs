@@ -1,3 +1,10 @@
+pub mod annex_a;
+pub mod annex_b;
+pub mod annex_c;
+pub mod annex_e;
+pub mod annex_f;
+pub mod conduction;
+pub mod fire_curve;
 pub mod section_3;
 
 use pyo3::prelude::*;
@@ -5,6 +12,13 @@ use pyo3::wrap_pymodule;
 
 #[pymodule]
 pub fn eurocode_1_1_2(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_wrapped(wrap_pymodule!(annex_a::annex_a))?;
+    m.add_wrapped(wrap_pymodule!(annex_b::annex_b))?;
+    m.add_wrapped(wrap_pymodule!(annex_c::annex_c))?;
+    m.add_wrapped(wrap_pymodule!(annex_e::annex_e))?;
+    m.add_wrapped(wrap_pymodule!(annex_f::annex_f))?;
+    m.add_wrapped(wrap_pymodule!(conduction::conduction))?;
+    m.add_wrapped(wrap_pymodule!(fire_curve::fire_curve))?;
     m.add_wrapped(wrap_pymodule!(section_3::section_3))?;
     Ok(())
 }
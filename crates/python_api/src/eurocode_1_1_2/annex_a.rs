@@ -0,0 +1,685 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::wrap_pymodule;
+
+use openfire::eurocode_1_1_2::annex_a::{
+    equation_a1 as rust_equation_a1, equation_a2 as rust_equation_a2,
+    equation_a3 as rust_equation_a3, equation_a4 as rust_equation_a4,
+    equation_a5 as rust_equation_a5, equation_a6 as rust_equation_a6,
+    equation_a7 as rust_equation_a7, equation_a8 as rust_equation_a8,
+    equation_a9 as rust_equation_a9, equation_a10 as rust_equation_a10,
+    equation_a11 as rust_equation_a11, parametric_curve as rust_parametric_curve,
+};
+
+pub(crate) fn parse_growth_rate(growth_rate: &str) -> PyResult<rust_equation_a7::FireGrowthRate> {
+    match growth_rate.to_lowercase().as_str() {
+        "slow" => Ok(rust_equation_a7::FireGrowthRate::Slow),
+        "medium" => Ok(rust_equation_a7::FireGrowthRate::Medium),
+        "fast" => Ok(rust_equation_a7::FireGrowthRate::Fast),
+        _ => Err(PyValueError::new_err(format!(
+            "Unknown fire growth rate '{}'",
+            growth_rate
+        ))),
+    }
+}
+
+// Equation A.1 module functions
+#[pyfunction]
+/// Gas temperature in the heating phase of the parametric fire.
+///
+/// .. math::
+///
+///    \theta_g = 20 + 1325 \left( 1 - 0.324 e^{-0.2 t^*} - 0.204 e^{-1.7 t^*} - 0.472 e^{-19 t^*} \right)
+///
+/// Args:
+///     t_star (float): Fictitious time (h)
+///
+/// Returns:
+///     float: Gas temperature in the fire compartment (°C)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_a.equation_a1.heating_phase_temperature(1.0)
+fn heating_phase_temperature(t_star: f64) -> f64 {
+    rust_equation_a1::heating_phase_temperature(t_star)
+}
+
+// Equations A.1 to A.5 are added with add_submodule as BR 187 appendix A
+// exports pymodules with the same names.
+/// Equation A.1 - Heating phase temperature.
+pub fn equation_a1(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(heating_phase_temperature, m)?)?;
+    Ok(())
+}
+
+// Equation A.2 module functions
+#[pyfunction]
+/// Fictitious time.
+///
+/// .. math::
+///
+///    t^* = t \cdot \Gamma
+///
+/// Args:
+///     t (float): Time (h)
+///     gamma (float): Time scaling factor (dimensionless)
+///
+/// Returns:
+///     float: Fictitious time (h)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_a.equation_a2.fictitious_time(0.5, 3.0)
+fn fictitious_time(t: f64, gamma: f64) -> f64 {
+    rust_equation_a2::fictitious_time(t, gamma)
+}
+
+#[pyfunction]
+/// Time scaling factor Γ.
+///
+/// .. math::
+///
+///    \Gamma = \frac{\left[ O / b \right]^2}{\left( 0.04 / 1160 \right)^2}
+///
+/// Args:
+///     o (float): Opening factor (m^1/2)
+///     b (float): Thermal absorptivity of the enclosure (J/m²s^1/2K)
+///
+/// Returns:
+///     float: Time scaling factor (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_a.equation_a2.gamma(0.06, 1000.0)
+fn gamma(o: f64, b: f64) -> f64 {
+    rust_equation_a2::gamma(o, b)
+}
+
+#[pyfunction]
+/// Opening factor of the enclosure.
+///
+/// .. math::
+///
+///    O = \frac{A_v \sqrt{h_{eq}}}{A_t}
+///
+/// Args:
+///     a_v (float): Total area of vertical openings (m²)
+///     h_eq (float): Weighted average height of vertical openings (m)
+///     a_t (float): Total area of the enclosure including openings (m²)
+///
+/// Returns:
+///     float: Opening factor (m^1/2)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_a.equation_a2.opening_factor(3.0, 2.0, 100.0)
+fn opening_factor(a_v: f64, h_eq: f64, a_t: f64) -> f64 {
+    rust_equation_a2::opening_factor(a_v, h_eq, a_t)
+}
+
+/// Equation A.2 - Fictitious time, time scaling factor and opening factor.
+pub fn equation_a2(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(fictitious_time, m)?)?;
+    m.add_function(wrap_pyfunction!(gamma, m)?)?;
+    m.add_function(wrap_pyfunction!(opening_factor, m)?)?;
+    Ok(())
+}
+
+// Equation A.3 module functions
+#[pyfunction]
+/// Thermal absorptivity of a boundary material.
+///
+/// .. math::
+///
+///    b = \sqrt{\rho \cdot c \cdot \lambda}
+///
+/// Args:
+///     rho (float): Density (kg/m³)
+///     c (float): Specific heat (J/kgK)
+///     lambda (float): Thermal conductivity (W/mK)
+///
+/// Returns:
+///     float: Thermal absorptivity (J/m²s^1/2K)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_a.equation_a3.thermal_absorptivity(2300.0, 1000.0, 1.6)
+#[pyo3(signature = (rho, c, lambda))]
+fn thermal_absorptivity(rho: f64, c: f64, lambda: f64) -> f64 {
+    rust_equation_a3::thermal_absorptivity(rho, c, lambda)
+}
+
+/// Equation A.3 - Thermal absorptivity.
+pub fn equation_a3(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(thermal_absorptivity, m)?)?;
+    Ok(())
+}
+
+// Equation A.4 module functions
+#[pyfunction]
+/// Thermal absorptivity of an enclosure with different boundary surfaces.
+///
+/// .. math::
+///
+///    b = \frac{\sum_j b_j A_j}{A_t - A_v}
+///
+/// Args:
+///     b (list[float]): Thermal absorptivity of each surface (J/m²s^1/2K)
+///     a (list[float]): Area of each surface excluding openings (m²)
+///     a_t (float): Total area of the enclosure (m²)
+///     a_v (float): Total area of vertical openings (m²)
+///
+/// Returns:
+///     float: Thermal absorptivity of the enclosure (J/m²s^1/2K)
+///
+/// Raises:
+///     ValueError: If ``b`` and ``a`` have different lengths or ``a_v`` is not less than ``a_t``.
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_a.equation_a4.thermal_absorptivity_enclosure([1500.0, 500.0], [60.0, 40.0], 110.0, 10.0)
+fn thermal_absorptivity_enclosure(b: Vec<f64>, a: Vec<f64>, a_t: f64, a_v: f64) -> PyResult<f64> {
+    if b.len() != a.len() {
+        return Err(PyValueError::new_err(
+            "b and a must have one value per surface",
+        ));
+    }
+    rust_equation_a4::thermal_absorptivity_enclosure(&b, &a, a_t, a_v)
+        .ok_or_else(|| PyValueError::new_err("a_v must be less than a_t"))
+}
+
+/// Equation A.4 - Thermal absorptivity of the enclosure.
+pub fn equation_a4(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(thermal_absorptivity_enclosure, m)?)?;
+    Ok(())
+}
+
+// Equation A.5 module functions
+#[pyfunction]
+/// Limiting thickness of the exposed layer of a lining.
+///
+/// .. math::
+///
+///    s_{lim} = \sqrt{\frac{3600 \cdot t_{max} \cdot \lambda_1}{c_1 \cdot \rho_1}}
+///
+/// Args:
+///     t_max (float): Time of maximum temperature (h)
+///     lambda (float): Thermal conductivity of the exposed layer (W/mK)
+///     c (float): Specific heat of the exposed layer (J/kgK)
+///     rho (float): Density of the exposed layer (kg/m³)
+///
+/// Returns:
+///     float: Limiting thickness (m)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_a.equation_a5.limiting_thickness(0.5, 0.2, 1000.0, 900.0)
+#[pyo3(signature = (t_max, lambda, c, rho))]
+fn limiting_thickness(t_max: f64, lambda: f64, c: f64, rho: f64) -> f64 {
+    rust_equation_a5::limiting_thickness(t_max, lambda, c, rho)
+}
+
+#[pyfunction]
+/// Thermal absorptivity of a surface with two layers.
+///
+/// The exposed layer governs where its absorptivity is lower than the
+/// second layer or where it is thicker than the limiting thickness.
+///
+/// .. math::
+///
+///    b = \frac{s_1}{s_{lim}} b_1 + \left(1 - \frac{s_1}{s_{lim}}\right) b_2
+///
+/// Args:
+///     b_1 (float): Thermal absorptivity of the exposed layer (J/m²s^1/2K)
+///     b_2 (float): Thermal absorptivity of the second layer (J/m²s^1/2K)
+///     s_1 (float): Thickness of the exposed layer (m)
+///     s_lim (float): Limiting thickness (m)
+///
+/// Returns:
+///     float: Thermal absorptivity of the surface (J/m²s^1/2K)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_a.equation_a5.thermal_absorptivity_layered(1500.0, 400.0, 0.01, 0.02)
+fn thermal_absorptivity_layered(b_1: f64, b_2: f64, s_1: f64, s_lim: f64) -> f64 {
+    rust_equation_a5::thermal_absorptivity_layered(b_1, b_2, s_1, s_lim)
+}
+
+/// Equation A.5 - Thermal absorptivity of layered linings.
+pub fn equation_a5(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(limiting_thickness, m)?)?;
+    m.add_function(wrap_pyfunction!(thermal_absorptivity_layered, m)?)?;
+    Ok(())
+}
+
+// Equation A.6 module functions
+#[pyfunction]
+/// Fictitious time of maximum temperature.
+///
+/// .. math::
+///
+///    t^*_{max} = t_{max} \cdot \Gamma
+///
+/// Args:
+///     t_max (float): Time of maximum temperature (h)
+///     gamma (float): Time scaling factor (dimensionless)
+///
+/// Returns:
+///     float: Fictitious time of maximum temperature (h)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_a.equation_a6.fictitious_time_max(1.0, 3.0276)
+fn fictitious_time_max(t_max: f64, gamma: f64) -> f64 {
+    rust_equation_a6::fictitious_time_max(t_max, gamma)
+}
+
+#[pymodule]
+/// Equation A.6 - Fictitious time of maximum temperature.
+pub fn equation_a6(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(fictitious_time_max, m)?)?;
+    Ok(())
+}
+
+// Equation A.7 module functions
+#[pyfunction]
+/// Limiting time for a fuel controlled fire.
+///
+/// Args:
+///     growth_rate (str): Fire growth rate ("slow", "medium" or "fast")
+///
+/// Returns:
+///     float: Limiting time (h)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_a.equation_a7.t_lim("medium")
+fn t_lim(growth_rate: &str) -> PyResult<f64> {
+    Ok(parse_growth_rate(growth_rate)?.t_lim())
+}
+
+#[pyfunction]
+/// Time of maximum gas temperature.
+///
+/// .. math::
+///
+///    t_{max} = \max \left( 0.2 \cdot 10^{-3} \cdot \frac{q_{t,d}}{O}, t_{lim} \right)
+///
+/// Args:
+///     q_td (float): Design fire load density related to the total enclosure area (MJ/m²)
+///     o (float): Opening factor (m^1/2)
+///     t_lim (float): Limiting time for a fuel controlled fire (h)
+///
+/// Returns:
+///     float: Time of maximum temperature (h)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_a.equation_a7.time_max_temperature(300.0, 0.06, 1.0 / 3.0)
+fn time_max_temperature(q_td: f64, o: f64, t_lim: f64) -> f64 {
+    rust_equation_a7::time_max_temperature(q_td, o, t_lim)
+}
+
+#[pyfunction]
+/// Design fire load density related to the total enclosure area.
+///
+/// .. math::
+///
+///    q_{t,d} = q_{f,d} \cdot \frac{A_f}{A_t}
+///
+/// Args:
+///     q_fd (float): Design fire load density related to the floor area (MJ/m²)
+///     a_f (float): Floor area (m²)
+///     a_t (float): Total area of the enclosure (m²)
+///
+/// Returns:
+///     float: Design fire load density related to the total area (MJ/m²)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_a.equation_a7.fire_load_density_total_area(600.0, 50.0, 200.0)
+fn fire_load_density_total_area(q_fd: f64, a_f: f64, a_t: f64) -> f64 {
+    rust_equation_a7::fire_load_density_total_area(q_fd, a_f, a_t)
+}
+
+#[pymodule]
+/// Equation A.7 - Time of maximum gas temperature.
+pub fn equation_a7(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(t_lim, m)?)?;
+    m.add_function(wrap_pyfunction!(time_max_temperature, m)?)?;
+    m.add_function(wrap_pyfunction!(fire_load_density_total_area, m)?)?;
+    Ok(())
+}
+
+// Equation A.8 module functions
+#[pyfunction]
+/// Fictitious time in the heating phase of a fuel controlled fire.
+///
+/// .. math::
+///
+///    t^* = t \cdot \Gamma_{lim}
+///
+/// Args:
+///     t (float): Time (h)
+///     gamma_lim (float): Limiting time scaling factor (dimensionless)
+///
+/// Returns:
+///     float: Fictitious time (h)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_a.equation_a8.fictitious_time_fuel_controlled(0.25, 0.4)
+fn fictitious_time_fuel_controlled(t: f64, gamma_lim: f64) -> f64 {
+    rust_equation_a8::fictitious_time_fuel_controlled(t, gamma_lim)
+}
+
+#[pymodule]
+/// Equation A.8 - Fictitious time for a fuel controlled fire.
+pub fn equation_a8(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(fictitious_time_fuel_controlled, m)?)?;
+    Ok(())
+}
+
+// Equation A.9 module functions
+#[pyfunction]
+/// Limiting opening factor for a fuel controlled fire.
+///
+/// .. math::
+///
+///    O_{lim} = 0.1 \cdot 10^{-3} \cdot \frac{q_{t,d}}{t_{lim}}
+///
+/// Args:
+///     q_td (float): Design fire load density related to the total enclosure area (MJ/m²)
+///     t_lim (float): Limiting time (h)
+///
+/// Returns:
+///     float: Limiting opening factor (m^1/2)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_a.equation_a9.opening_factor_lim(60.0, 1.0 / 3.0)
+fn opening_factor_lim(q_td: f64, t_lim: f64) -> f64 {
+    rust_equation_a9::opening_factor_lim(q_td, t_lim)
+}
+
+#[pyfunction]
+/// Limiting time scaling factor for a fuel controlled fire.
+///
+/// .. math::
+///
+///    \Gamma_{lim} = \frac{\left[ O_{lim} / b \right]^2}{\left( 0.04 / 1160 \right)^2}
+///
+/// Args:
+///     o_lim (float): Limiting opening factor (m^1/2)
+///     b (float): Thermal absorptivity of the enclosure (J/m²s^1/2K)
+///
+/// Returns:
+///     float: Limiting time scaling factor (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_a.equation_a9.gamma_lim(0.018, 800.0)
+fn gamma_lim(o_lim: f64, b: f64) -> f64 {
+    rust_equation_a9::gamma_lim(o_lim, b)
+}
+
+#[pymodule]
+/// Equation A.9 - Limiting opening factor and time scaling factor.
+pub fn equation_a9(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(opening_factor_lim, m)?)?;
+    m.add_function(wrap_pyfunction!(gamma_lim, m)?)?;
+    Ok(())
+}
+
+// Equation A.10 module functions
+#[pyfunction]
+/// Factor applied to the limiting time scaling factor.
+///
+/// Applies where O > 0.04, q_t,d < 75 and b < 1160, otherwise one.
+///
+/// .. math::
+///
+///    k = 1 + \left( \frac{O - 0.04}{0.04} \right) \left( \frac{q_{t,d} - 75}{75} \right) \left( \frac{1160 - b}{1160} \right)
+///
+/// Args:
+///     o (float): Opening factor (m^1/2)
+///     q_td (float): Design fire load density related to the total enclosure area (MJ/m²)
+///     b (float): Thermal absorptivity of the enclosure (J/m²s^1/2K)
+///
+/// Returns:
+///     float: Factor k (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_a.equation_a10.gamma_lim_factor(0.1, 60.0, 800.0)
+fn gamma_lim_factor(o: f64, q_td: f64, b: f64) -> f64 {
+    rust_equation_a10::gamma_lim_factor(o, q_td, b)
+}
+
+#[pymodule]
+/// Equation A.10 - Factor for the limiting time scaling factor.
+pub fn equation_a10(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(gamma_lim_factor, m)?)?;
+    Ok(())
+}
+
+// Equation A.11 module functions
+#[pyfunction]
+/// Gas temperature in the cooling phase.
+///
+/// .. math::
+///
+///    \theta_g = \theta_{max} - r \left( t^* - t^*_{max} \cdot x \right)
+///
+/// where the cooling rate r is 625 for t*_max ≤ 0.5, 250(3 - t*_max) for
+/// 0.5 < t*_max < 2 and 250 for t*_max ≥ 2.
+///
+/// Args:
+///     theta_max (float): Maximum gas temperature (°C)
+///     t_star (float): Fictitious time (h)
+///     t_star_max (float): Fictitious time of maximum temperature (h)
+///     x (float): Cooling phase factor (dimensionless)
+///
+/// Returns:
+///     float: Gas temperature (°C)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_a.equation_a11.cooling_phase_temperature(800.0, 0.6, 0.4, 1.0)
+fn cooling_phase_temperature(theta_max: f64, t_star: f64, t_star_max: f64, x: f64) -> f64 {
+    rust_equation_a11::cooling_phase_temperature(theta_max, t_star, t_star_max, x)
+}
+
+#[pyfunction]
+/// Cooling phase factor x.
+///
+/// One for a ventilation controlled fire, otherwise t_lim · Γ / t*_max.
+///
+/// Args:
+///     t_max (float): Time of maximum temperature (h)
+///     t_lim (float): Limiting time (h)
+///     gamma (float): Time scaling factor (dimensionless)
+///     t_star_max (float): Fictitious time of maximum temperature (h)
+///
+/// Returns:
+///     float: Cooling phase factor (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_a.equation_a11.cooling_phase_factor(1.0 / 3.0, 1.0 / 3.0, 13.14, 1.58)
+fn cooling_phase_factor(t_max: f64, t_lim: f64, gamma: f64, t_star_max: f64) -> f64 {
+    rust_equation_a11::cooling_phase_factor(t_max, t_lim, gamma, t_star_max)
+}
+
+#[pymodule]
+/// Equation A.11 - Cooling phase temperature.
+pub fn equation_a11(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(cooling_phase_temperature, m)?)?;
+    m.add_function(wrap_pyfunction!(cooling_phase_factor, m)?)?;
+    Ok(())
+}
+
+// Parametric curve module functions
+fn build_parametric_fire(
+    o: f64,
+    b: f64,
+    q_td: f64,
+    growth_rate: &str,
+) -> PyResult<rust_parametric_curve::ParametricFire> {
+    let growth_rate = parse_growth_rate(growth_rate)?;
+    if o.is_nan() || o <= 0.0 || b.is_nan() || b <= 0.0 {
+        return Err(PyValueError::new_err(
+            "the opening factor and thermal absorptivity must be positive",
+        ));
+    }
+    Ok(rust_parametric_curve::ParametricFire::new(
+        o,
+        b,
+        q_td,
+        growth_rate,
+    ))
+}
+
+#[pyfunction]
+/// Maximum gas temperature of the parametric fire.
+///
+/// Args:
+///     o (float): Opening factor (m^1/2)
+///     b (float): Thermal absorptivity of the enclosure (J/m²s^1/2K)
+///     q_td (float): Design fire load density related to the total enclosure area (MJ/m²)
+///     growth_rate (str): Fire growth rate ("slow", "medium" or "fast")
+///
+/// Returns:
+///     float: Maximum gas temperature (°C)
+///
+/// Raises:
+///     ValueError: If the growth rate is not recognised or ``o`` or ``b`` is not positive
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_a.parametric_curve.maximum_temperature(0.06, 1000.0, 300.0, "medium")
+fn maximum_temperature(o: f64, b: f64, q_td: f64, growth_rate: &str) -> PyResult<f64> {
+    Ok(build_parametric_fire(o, b, q_td, growth_rate)?.maximum_temperature())
+}
+
+#[pyfunction]
+/// Gas temperature of the parametric fire at a time.
+///
+/// The heating phase uses Γ_lim for a fuel controlled fire and Γ otherwise.
+/// The cooling phase is limited to 20 °C.
+///
+/// Args:
+///     o (float): Opening factor (m^1/2)
+///     b (float): Thermal absorptivity of the enclosure (J/m²s^1/2K)
+///     q_td (float): Design fire load density related to the total enclosure area (MJ/m²)
+///     growth_rate (str): Fire growth rate ("slow", "medium" or "fast")
+///     t (float): Time (min)
+///
+/// Returns:
+///     float: Gas temperature (°C)
+///
+/// Raises:
+///     ValueError: If the growth rate is not recognised or ``o`` or ``b`` is not positive
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_a.parametric_curve.temperature(0.06, 1000.0, 300.0, "medium", 30.0)
+fn temperature(o: f64, b: f64, q_td: f64, growth_rate: &str, t: f64) -> PyResult<f64> {
+    Ok(build_parametric_fire(o, b, q_td, growth_rate)?.temperature(t))
+}
+
+#[pyfunction]
+/// Gas temperature history of the parametric fire.
+///
+/// Args:
+///     o (float): Opening factor (m^1/2)
+///     b (float): Thermal absorptivity of the enclosure (J/m²s^1/2K)
+///     q_td (float): Design fire load density related to the total enclosure area (MJ/m²)
+///     growth_rate (str): Fire growth rate ("slow", "medium" or "fast")
+///     times (list[float]): Times (min)
+///
+/// Returns:
+///     list[float]: Gas temperature at each time (°C)
+///
+/// Raises:
+///     ValueError: If the growth rate is not recognised or ``o`` or ``b`` is not positive
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_a.parametric_curve.temperature_history(0.06, 1000.0, 300.0, "medium", [0.0, 30.0, 60.0, 90.0])
+fn temperature_history(
+    o: f64,
+    b: f64,
+    q_td: f64,
+    growth_rate: &str,
+    times: Vec<f64>,
+) -> PyResult<Vec<f64>> {
+    Ok(build_parametric_fire(o, b, q_td, growth_rate)?.temperature_history(&times))
+}
+
+#[pyfunction]
+/// Whether the parametric fire is within the limits of A(7).
+///
+/// The curves apply for opening factors of 0.02 to 0.20 m^1/2 and thermal
+/// absorptivities of 100 to 2200 J/m²s^1/2K, and are extrapolated outside
+/// them.
+///
+/// Args:
+///     o (float): Opening factor (m^1/2)
+///     b (float): Thermal absorptivity of the enclosure (J/m²s^1/2K)
+///     q_td (float): Design fire load density related to the total enclosure area (MJ/m²)
+///     growth_rate (str): Fire growth rate ("slow", "medium" or "fast")
+///
+/// Returns:
+///     bool: True if both values are within the limits
+///
+/// Raises:
+///     ValueError: If the growth rate is not recognised or ``o`` or ``b`` is not positive
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_a.parametric_curve.within_limits(0.06, 1000.0, 300.0, "medium")
+fn within_limits(o: f64, b: f64, q_td: f64, growth_rate: &str) -> PyResult<bool> {
+    Ok(build_parametric_fire(o, b, q_td, growth_rate)?.within_limits())
+}
+
+#[pymodule]
+/// Parametric temperature-time curve for a fire compartment.
+pub fn parametric_curve(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(maximum_temperature, m)?)?;
+    m.add_function(wrap_pyfunction!(temperature, m)?)?;
+    m.add_function(wrap_pyfunction!(temperature_history, m)?)?;
+    m.add_function(wrap_pyfunction!(within_limits, m)?)?;
+    Ok(())
+}
+
+#[pymodule]
+/// Annex A - Parametric temperature-time curves.
+///
+/// This annex contains the parametric temperature-time curves for fire compartments.
+pub fn annex_a(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let equation_a1_module = PyModule::new_bound(m.py(), "equation_a1")?;
+    equation_a1(&equation_a1_module)?;
+    m.add_submodule(&equation_a1_module)?;
+    let equation_a2_module = PyModule::new_bound(m.py(), "equation_a2")?;
+    equation_a2(&equation_a2_module)?;
+    m.add_submodule(&equation_a2_module)?;
+    let equation_a3_module = PyModule::new_bound(m.py(), "equation_a3")?;
+    equation_a3(&equation_a3_module)?;
+    m.add_submodule(&equation_a3_module)?;
+    let equation_a4_module = PyModule::new_bound(m.py(), "equation_a4")?;
+    equation_a4(&equation_a4_module)?;
+    m.add_submodule(&equation_a4_module)?;
+    let equation_a5_module = PyModule::new_bound(m.py(), "equation_a5")?;
+    equation_a5(&equation_a5_module)?;
+    m.add_submodule(&equation_a5_module)?;
+    m.add_wrapped(wrap_pymodule!(equation_a6))?;
+    m.add_wrapped(wrap_pymodule!(equation_a7))?;
+    m.add_wrapped(wrap_pymodule!(equation_a8))?;
+    m.add_wrapped(wrap_pymodule!(equation_a9))?;
+    m.add_wrapped(wrap_pymodule!(equation_a10))?;
+    m.add_wrapped(wrap_pymodule!(equation_a11))?;
+    m.add_wrapped(wrap_pymodule!(parametric_curve))?;
+
+    Ok(())
+}
@@ -0,0 +1,732 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::wrap_pymodule;
+
+use openfire::eurocode_1_1_2::annex_b::{
+    compartment_temperature as rust_compartment_temperature, external_flame as rust_external_flame,
+    flame_emissivity as rust_flame_emissivity, forced_draught as rust_forced_draught,
+    no_forced_draught as rust_no_forced_draught, rate_of_burning as rust_rate_of_burning,
+};
+
+fn long_flame() -> PyErr {
+    PyValueError::new_err("The flame is too long for the rate of burning")
+}
+
+fn beyond_axis_limit() -> PyErr {
+    PyValueError::new_err("The distance along the axis is beyond the validity limit")
+}
+
+// Rate of burning module functions
+#[pyfunction]
+/// Fuel controlled rate of burning.
+///
+/// .. math::
+///
+///    Q = \frac{A_f \cdot q_{f,d}}{\tau_F}
+///
+/// Args:
+///     a_f (float): Floor area of the fire compartment (m²)
+///     q_fd (float): Design fire load density (MJ/m²)
+///     tau_f (float): Free burning fire duration (s)
+///
+/// Returns:
+///     float: Rate of burning (MW)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_b.rate_of_burning.fuel_controlled(100.0, 500.0, 1200.0)
+fn fuel_controlled(a_f: f64, q_fd: f64, tau_f: f64) -> f64 {
+    rust_rate_of_burning::fuel_controlled(a_f, q_fd, tau_f)
+}
+
+#[pyfunction]
+/// Ventilation controlled rate of burning.
+///
+/// .. math::
+///
+///    Q = 3.15 \left( 1 - e^{-0.036 / O} \right) A_v \left( \frac{h_{eq}}{D/W} \right)^{1/2}
+///
+/// Args:
+///     o (float): Opening factor (m^1/2)
+///     a_v (float): Window area (m²)
+///     h_eq (float): Window height (m)
+///     d_over_w (float): Ratio of compartment depth to width (dimensionless)
+///
+/// Returns:
+///     float: Rate of burning (MW)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_b.rate_of_burning.ventilation_controlled(0.0212, 6.0, 2.0, 1.0)
+fn ventilation_controlled(o: f64, a_v: f64, h_eq: f64, d_over_w: f64) -> f64 {
+    rust_rate_of_burning::ventilation_controlled(o, a_v, h_eq, d_over_w)
+}
+
+#[pyfunction]
+/// Rate of burning without forced draught.
+///
+/// Args:
+///     fuel_controlled (float): Fuel controlled rate of burning (MW)
+///     ventilation_controlled (float): Ventilation controlled rate of burning (MW)
+///
+/// Returns:
+///     float: Lower of the two rates of burning (MW)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_b.rate_of_burning.no_forced_draught(41.7, 21.8)
+#[pyo3(name = "no_forced_draught")]
+fn rate_of_burning_no_forced_draught(fuel_controlled: f64, ventilation_controlled: f64) -> f64 {
+    rust_rate_of_burning::no_forced_draught(fuel_controlled, ventilation_controlled)
+}
+
+#[pymodule]
+/// Rate of burning of the fire compartment.
+pub fn rate_of_burning(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(fuel_controlled, m)?)?;
+    m.add_function(wrap_pyfunction!(ventilation_controlled, m)?)?;
+    m.add_function(wrap_pyfunction!(rate_of_burning_no_forced_draught, m)?)?;
+    Ok(())
+}
+
+// Compartment temperature module functions
+#[pyfunction]
+/// Fire load ratio of the compartment.
+///
+/// .. math::
+///
+///    \Omega = \frac{A_f \cdot q_{f,d}}{\sqrt{A_v \cdot A_t}}
+///
+/// Args:
+///     a_f (float): Floor area (m²)
+///     q_fd (float): Design fire load density (MJ/m²)
+///     a_v (float): Window area (m²)
+///     a_t (float): Total area of the enclosure (m²)
+///
+/// Returns:
+///     float: Fire load ratio (MJ/m²)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_b.compartment_temperature.fire_load_ratio(100.0, 500.0, 6.0, 400.0)
+fn fire_load_ratio(a_f: f64, q_fd: f64, a_v: f64, a_t: f64) -> f64 {
+    rust_compartment_temperature::fire_load_ratio(a_f, q_fd, a_v, a_t)
+}
+
+#[pyfunction]
+/// Temperature of the fire compartment.
+///
+/// .. math::
+///
+///    T_f = 6000 \left( 1 - e^{-0.1 / O} \right) O^{1/2} \left( 1 - e^{-0.00286 \Omega} \right) + T_0
+///
+/// Args:
+///     o (float): Opening factor (m^1/2)
+///     omega (float): Fire load ratio (MJ/m²)
+///     t_0 (float): Ambient temperature (K)
+///
+/// Returns:
+///     float: Compartment temperature (K)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_b.compartment_temperature.compartment_temperature(0.0212, 1020.6, 293.0)
+#[pyo3(name = "compartment_temperature")]
+fn compartment_temperature_k(o: f64, omega: f64, t_0: f64) -> f64 {
+    rust_compartment_temperature::compartment_temperature(o, omega, t_0)
+}
+
+#[pyfunction]
+/// Internal gas density at the compartment temperature.
+///
+/// Args:
+///     t_f (float): Compartment temperature (K)
+///
+/// Returns:
+///     float: Gas density (kg/m³)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_b.compartment_temperature.gas_density(1000.0)
+fn gas_density(t_f: f64) -> f64 {
+    rust_compartment_temperature::gas_density(t_f)
+}
+
+#[pymodule]
+/// Temperature of the fire compartment.
+pub fn compartment_temperature(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(fire_load_ratio, m)?)?;
+    m.add_function(wrap_pyfunction!(compartment_temperature_k, m)?)?;
+    m.add_function(wrap_pyfunction!(gas_density, m)?)?;
+    Ok(())
+}
+
+// Flame emissivity module functions
+#[pyfunction]
+/// Emissivity of the flame.
+///
+/// .. math::
+///
+///    \varepsilon_f = 1 - e^{-0.3 d_f}
+///
+/// Args:
+///     d_f (float): Flame thickness (m)
+///
+/// Returns:
+///     float: Flame emissivity (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_b.flame_emissivity.flame_emissivity(2.0)
+#[pyo3(name = "flame_emissivity")]
+fn emissivity_of_flame(d_f: f64) -> f64 {
+    rust_flame_emissivity::flame_emissivity(d_f)
+}
+
+#[pymodule]
+/// Emissivity of the external flame.
+pub fn flame_emissivity(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(emissivity_of_flame, m)?)?;
+    Ok(())
+}
+
+// No forced draught module functions
+#[pyfunction]
+/// Flame height above the top of the window without forced draught.
+///
+/// .. math::
+///
+///    L_L = \max \left[ 0; h_{eq} \left( 2.37 \left( \frac{Q}{A_v \rho_g \sqrt{h_{eq} g}} \right)^{2/3} - 1 \right) \right]
+///
+/// Args:
+///     q (float): Rate of burning (MW)
+///     a_v (float): Window area (m²)
+///     rho_g (float): Internal gas density (kg/m³)
+///     h_eq (float): Window height (m)
+///     g (float): Gravitational acceleration (m/s²)
+///
+/// Returns:
+///     float: Flame height (m)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_b.no_forced_draught.flame_height(21.8, 6.0, 0.45, 2.0, 9.81)
+#[pyo3(name = "flame_height")]
+fn no_forced_flame_height(q: f64, a_v: f64, rho_g: f64, h_eq: f64, g: f64) -> f64 {
+    rust_no_forced_draught::flame_height(q, a_v, rho_g, h_eq, g)
+}
+
+#[pyfunction]
+/// Horizontal projection of the flame without forced draught.
+///
+/// Args:
+///     h_eq (float): Window height (m)
+///     w_t (float): Window width (m)
+///     isolated (bool): Distance to other windows more than 4 w_t
+///
+/// Returns:
+///     float: Horizontal projection (m)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_b.no_forced_draught.horizontal_projection(3.0, 2.0, True)
+#[pyo3(name = "horizontal_projection")]
+fn no_forced_horizontal_projection(h_eq: f64, w_t: f64, isolated: bool) -> f64 {
+    rust_no_forced_draught::horizontal_projection(h_eq, w_t, isolated)
+}
+
+#[pyfunction]
+/// Flame length along the axis without forced draught.
+///
+/// Args:
+///     l_l (float): Flame height (m)
+///     l_h (float): Horizontal projection (m)
+///     h_eq (float): Window height (m)
+///     w_t (float): Window width (m)
+///     wall_above (bool): Wall above the window
+///
+/// Returns:
+///     float: Flame length (m)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_b.no_forced_draught.flame_length(5.08, 0.67, 2.0, 3.0, True)
+#[pyo3(name = "flame_length")]
+fn no_forced_flame_length(l_l: f64, l_h: f64, h_eq: f64, w_t: f64, wall_above: bool) -> f64 {
+    rust_no_forced_draught::flame_length(l_l, l_h, h_eq, w_t, wall_above)
+}
+
+#[pyfunction]
+/// Flame thickness without forced draught.
+///
+/// Args:
+///     h_eq (float): Window height (m)
+///
+/// Returns:
+///     float: Flame thickness (m)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_b.no_forced_draught.flame_thickness(2.0)
+#[pyo3(name = "flame_thickness")]
+fn no_forced_flame_thickness(h_eq: f64) -> f64 {
+    rust_no_forced_draught::flame_thickness(h_eq)
+}
+
+#[pyfunction]
+/// Flame temperature at the window without forced draught.
+///
+/// .. math::
+///
+///    T_w = \frac{520}{1 - 0.4725 \left( L_f w_t / Q \right)} + T_0
+///
+/// Args:
+///     l_f (float): Flame length (m)
+///     w_t (float): Window width (m)
+///     q (float): Rate of burning (MW)
+///     t_0 (float): Ambient temperature (K)
+///
+/// Returns:
+///     float: Flame temperature at the window (K)
+///
+/// Raises:
+///     ValueError: If the flame is too long for the rate of burning,
+///         ``l_f w_t / q >= 1``.
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_b.no_forced_draught.window_temperature(10.21, 3.0, 31.3, 293.0)
+#[pyo3(name = "window_temperature")]
+fn no_forced_window_temperature(l_f: f64, w_t: f64, q: f64, t_0: f64) -> PyResult<f64> {
+    rust_no_forced_draught::window_temperature(l_f, w_t, q, t_0).ok_or_else(long_flame)
+}
+
+#[pyfunction]
+/// Flame temperature along the axis without forced draught.
+///
+/// .. math::
+///
+///    T_z = \left( T_w - T_0 \right) \left( 1 - 0.4725 \left( L_x w_t / Q \right) \right) + T_0
+///
+/// Args:
+///     t_w (float): Flame temperature at the window (K)
+///     l_x (float): Distance along the flame axis (m)
+///     w_t (float): Window width (m)
+///     q (float): Rate of burning (MW)
+///     t_0 (float): Ambient temperature (K)
+///
+/// Returns:
+///     float: Flame temperature (K)
+///
+/// Raises:
+///     ValueError: If the distance is beyond the validity limit,
+///         ``l_x w_t / q >= 1``.
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_b.no_forced_draught.axis_temperature(1259.8, 1.0, 3.0, 31.3, 293.0)
+#[pyo3(name = "axis_temperature")]
+fn no_forced_axis_temperature(t_w: f64, l_x: f64, w_t: f64, q: f64, t_0: f64) -> PyResult<f64> {
+    rust_no_forced_draught::axis_temperature(t_w, l_x, w_t, q, t_0).ok_or_else(beyond_axis_limit)
+}
+
+#[pyfunction]
+/// Convective heat transfer coefficient without forced draught.
+///
+/// .. math::
+///
+///    \alpha_c = 4.67 \left( 1 / d_{eq} \right)^{0.4} \left( Q / A_v \right)^{0.6}
+///
+/// Args:
+///     d_eq (float): Characteristic size of the member (m)
+///     q (float): Rate of burning (MW)
+///     a_v (float): Window area (m²)
+///
+/// Returns:
+///     float: Convective heat transfer coefficient (W/m²K)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_b.no_forced_draught.convective_coefficient(2.0, 21.8, 6.0)
+#[pyo3(name = "convective_coefficient")]
+fn no_forced_convective_coefficient(d_eq: f64, q: f64, a_v: f64) -> f64 {
+    rust_no_forced_draught::convective_coefficient(d_eq, q, a_v)
+}
+
+#[pymodule]
+/// External flame without forced draught.
+pub fn no_forced_draught(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(no_forced_flame_height, m)?)?;
+    m.add_function(wrap_pyfunction!(no_forced_horizontal_projection, m)?)?;
+    m.add_function(wrap_pyfunction!(no_forced_flame_length, m)?)?;
+    m.add_function(wrap_pyfunction!(no_forced_flame_thickness, m)?)?;
+    m.add_function(wrap_pyfunction!(no_forced_window_temperature, m)?)?;
+    m.add_function(wrap_pyfunction!(no_forced_axis_temperature, m)?)?;
+    m.add_function(wrap_pyfunction!(no_forced_convective_coefficient, m)?)?;
+    Ok(())
+}
+
+// Forced draught module functions
+#[pyfunction]
+/// Flame height above the top of the window with forced draught.
+///
+/// .. math::
+///
+///    L_L = 1.366 \left( \frac{1}{u} \right)^{0.43} \frac{Q}{\sqrt{A_v}} - h_{eq}
+///
+/// Args:
+///     u (float): Wind speed (m/s)
+///     q (float): Rate of burning (MW)
+///     a_v (float): Window area (m²)
+///     h_eq (float): Window height (m)
+///
+/// Returns:
+///     float: Flame height (m)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_b.forced_draught.flame_height(6.0, 41.7, 6.0, 2.0)
+#[pyo3(name = "flame_height")]
+fn forced_flame_height(u: f64, q: f64, a_v: f64, h_eq: f64) -> f64 {
+    rust_forced_draught::flame_height(u, q, a_v, h_eq)
+}
+
+#[pyfunction]
+/// Horizontal projection of the flame with forced draught.
+///
+/// .. math::
+///
+///    L_H = 0.605 \left( \frac{u^2}{h_{eq}} \right)^{0.22} \left( L_L + h_{eq} \right)
+///
+/// Args:
+///     u (float): Wind speed (m/s)
+///     h_eq (float): Window height (m)
+///     l_l (float): Flame height (m)
+///
+/// Returns:
+///     float: Horizontal projection (m)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_b.forced_draught.horizontal_projection(6.0, 2.0, 8.75)
+#[pyo3(name = "horizontal_projection")]
+fn forced_horizontal_projection(u: f64, h_eq: f64, l_l: f64) -> f64 {
+    rust_forced_draught::horizontal_projection(u, h_eq, l_l)
+}
+
+#[pyfunction]
+/// Flame width with forced draught.
+///
+/// .. math::
+///
+///    w_f = w_t + 0.4 L_H
+///
+/// Args:
+///     w_t (float): Window width (m)
+///     l_h (float): Horizontal projection (m)
+///
+/// Returns:
+///     float: Flame width (m)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_b.forced_draught.flame_width(3.0, 12.3)
+fn flame_width(w_t: f64, l_h: f64) -> f64 {
+    rust_forced_draught::flame_width(w_t, l_h)
+}
+
+#[pyfunction]
+/// Flame length along the axis with forced draught.
+///
+/// Args:
+///     l_l (float): Flame height (m)
+///     l_h (float): Horizontal projection (m)
+///
+/// Returns:
+///     float: Flame length (m)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_b.forced_draught.flame_length(8.75, 12.3)
+#[pyo3(name = "flame_length")]
+fn forced_flame_length(l_l: f64, l_h: f64) -> f64 {
+    rust_forced_draught::flame_length(l_l, l_h)
+}
+
+#[pyfunction]
+/// Flame thickness with forced draught.
+///
+/// Args:
+///     h_eq (float): Window height (m)
+///
+/// Returns:
+///     float: Flame thickness (m)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_b.forced_draught.flame_thickness(2.0)
+#[pyo3(name = "flame_thickness")]
+fn forced_flame_thickness(h_eq: f64) -> f64 {
+    rust_forced_draught::flame_thickness(h_eq)
+}
+
+#[pyfunction]
+/// Flame temperature at the window with forced draught.
+///
+/// .. math::
+///
+///    T_w = \frac{520}{1 - 0.3325 L_f \sqrt{A_v} / Q} + T_0
+///
+/// Args:
+///     l_f (float): Flame length (m)
+///     a_v (float): Window area (m²)
+///     q (float): Rate of burning (MW)
+///     t_0 (float): Ambient temperature (K)
+///
+/// Returns:
+///     float: Flame temperature at the window (K)
+///
+/// Raises:
+///     ValueError: If the flame is too long for the rate of burning,
+///         ``l_f sqrt(a_v) / q >= 1``.
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_b.forced_draught.window_temperature(15.1, 6.0, 41.7, 293.0)
+#[pyo3(name = "window_temperature")]
+fn forced_window_temperature(l_f: f64, a_v: f64, q: f64, t_0: f64) -> PyResult<f64> {
+    rust_forced_draught::window_temperature(l_f, a_v, q, t_0).ok_or_else(long_flame)
+}
+
+#[pyfunction]
+/// Flame temperature along the axis with forced draught.
+///
+/// .. math::
+///
+///    T_z = \left( T_w - T_0 \right) \left( 1 - 0.3325 L_x \sqrt{A_v} / Q \right) + T_0
+///
+/// Args:
+///     t_w (float): Flame temperature at the window (K)
+///     l_x (float): Distance along the flame axis (m)
+///     a_v (float): Window area (m²)
+///     q (float): Rate of burning (MW)
+///     t_0 (float): Ambient temperature (K)
+///
+/// Returns:
+///     float: Flame temperature (K)
+///
+/// Raises:
+///     ValueError: If the distance is beyond the validity limit,
+///         ``l_x sqrt(a_v) / q >= 1``.
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_b.forced_draught.axis_temperature(1030.5, 1.0, 6.0, 41.7, 293.0)
+#[pyo3(name = "axis_temperature")]
+fn forced_axis_temperature(t_w: f64, l_x: f64, a_v: f64, q: f64, t_0: f64) -> PyResult<f64> {
+    rust_forced_draught::axis_temperature(t_w, l_x, a_v, q, t_0).ok_or_else(beyond_axis_limit)
+}
+
+#[pyfunction]
+/// Convective heat transfer coefficient with forced draught.
+///
+/// .. math::
+///
+///    \alpha_c = 9.8 \left( 1 / d_{eq} \right)^{0.4} \left( \frac{Q}{17.5 A_v} + \frac{u}{1.6} \right)^{0.6}
+///
+/// Args:
+///     d_eq (float): Characteristic size of the member (m)
+///     q (float): Rate of burning (MW)
+///     a_v (float): Window area (m²)
+///     u (float): Wind speed (m/s)
+///
+/// Returns:
+///     float: Convective heat transfer coefficient (W/m²K)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_b.forced_draught.convective_coefficient(2.0, 41.7, 6.0, 6.0)
+#[pyo3(name = "convective_coefficient")]
+fn forced_convective_coefficient(d_eq: f64, q: f64, a_v: f64, u: f64) -> f64 {
+    rust_forced_draught::convective_coefficient(d_eq, q, a_v, u)
+}
+
+#[pymodule]
+/// External flame with forced draught.
+pub fn forced_draught(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(forced_flame_height, m)?)?;
+    m.add_function(wrap_pyfunction!(forced_horizontal_projection, m)?)?;
+    m.add_function(wrap_pyfunction!(flame_width, m)?)?;
+    m.add_function(wrap_pyfunction!(forced_flame_length, m)?)?;
+    m.add_function(wrap_pyfunction!(forced_flame_thickness, m)?)?;
+    m.add_function(wrap_pyfunction!(forced_window_temperature, m)?)?;
+    m.add_function(wrap_pyfunction!(forced_axis_temperature, m)?)?;
+    m.add_function(wrap_pyfunction!(forced_convective_coefficient, m)?)?;
+    Ok(())
+}
+
+// External flame module functions
+type CompartmentArgs = (f64, f64, f64, f64, f64, f64, f64);
+type FlameProperties = (f64, f64, f64, f64, f64, f64, f64, f64);
+
+fn build_flame(
+    compartment: CompartmentArgs,
+    u: Option<f64>,
+    wall_above: bool,
+    isolated: bool,
+) -> PyResult<rust_external_flame::ExternalFlame> {
+    let (a_f, a_t, q_fd, w_t, h_eq, d_over_w, t_0) = compartment;
+    let compartment = rust_external_flame::Compartment {
+        a_f,
+        a_t,
+        q_fd,
+        w_t,
+        h_eq,
+        d_over_w,
+        t_0,
+    };
+
+    match u {
+        Some(u) => rust_external_flame::ExternalFlame::forced_draught(compartment, u),
+        None => {
+            rust_external_flame::ExternalFlame::no_forced_draught(compartment, wall_above, isolated)
+        }
+    }
+    .ok_or_else(long_flame)
+}
+
+#[pyfunction]
+/// Properties of the external flame from a window.
+///
+/// Args:
+///     compartment (tuple[float, ...]): Floor area a_f (m²), total enclosure area
+///         a_t (m²), design fire load density q_fd (MJ/m²), window width w_t (m),
+///         window height h_eq (m), ratio of depth to width d_over_w and ambient
+///         temperature t_0 (K)
+///     u (float, optional): Wind speed for forced draught (m/s), None for no forced draught
+///     wall_above (bool): Wall above the window (no forced draught)
+///     isolated (bool): Distance to other windows more than 4 w_t (no forced draught)
+///
+/// Returns:
+///     tuple[float, ...]: Rate of burning (MW), flame height (m), width (m),
+///     horizontal projection (m), length (m), thickness (m), emissivity and
+///     temperature at the window (K)
+///
+/// Raises:
+///     ValueError: If the flame is too long for the rate of burning.
+///
+/// Example:
+///     >>> import ofire
+///     >>> props = ofire.eurocode_1_1_2.annex_b.external_flame.flame_properties((200.0, 600.0, 600.0, 3.0, 2.5, 1.0, 293.0))
+#[pyo3(signature = (compartment, u=None, wall_above=true, isolated=true))]
+fn flame_properties(
+    compartment: CompartmentArgs,
+    u: Option<f64>,
+    wall_above: bool,
+    isolated: bool,
+) -> PyResult<FlameProperties> {
+    let flame = build_flame(compartment, u, wall_above, isolated)?;
+    Ok((
+        flame.rate_of_burning,
+        flame.height,
+        flame.width,
+        flame.projection,
+        flame.length,
+        flame.thickness,
+        flame.emissivity,
+        flame.window_temperature,
+    ))
+}
+
+#[pyfunction]
+/// Flame temperature along the axis of the external flame.
+///
+/// The temperature less 273 can be used as the radiation temperature in
+/// ``ofire.eurocode_1_1_2.section_3.equation_3_3``.
+///
+/// Args:
+///     compartment (tuple[float, ...]): Compartment as for ``flame_properties``
+///     l_x (float): Distance along the flame axis (m)
+///     u (float, optional): Wind speed for forced draught (m/s)
+///     wall_above (bool): Wall above the window (no forced draught)
+///     isolated (bool): Distance to other windows more than 4 w_t (no forced draught)
+///
+/// Returns:
+///     float: Flame temperature (K)
+///
+/// Raises:
+///     ValueError: If the flame is too long for the rate of burning or
+///         ``l_x`` is beyond the validity limit of the axis temperature.
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_b.external_flame.axis_temperature((200.0, 600.0, 600.0, 3.0, 2.5, 1.0, 293.0), 1.0)
+#[pyo3(signature = (compartment, l_x, u=None, wall_above=true, isolated=true))]
+fn axis_temperature(
+    compartment: CompartmentArgs,
+    l_x: f64,
+    u: Option<f64>,
+    wall_above: bool,
+    isolated: bool,
+) -> PyResult<f64> {
+    build_flame(compartment, u, wall_above, isolated)?
+        .axis_temperature(l_x)
+        .ok_or_else(beyond_axis_limit)
+}
+
+#[pyfunction]
+/// Convective heat transfer coefficient from the external flame.
+///
+/// Args:
+///     compartment (tuple[float, ...]): Compartment as for ``flame_properties``
+///     d_eq (float): Characteristic size of the member (m)
+///     u (float, optional): Wind speed for forced draught (m/s)
+///     wall_above (bool): Wall above the window (no forced draught)
+///     isolated (bool): Distance to other windows more than 4 w_t (no forced draught)
+///
+/// Returns:
+///     float: Convective heat transfer coefficient (W/m²K)
+///
+/// Raises:
+///     ValueError: If the flame is too long for the rate of burning.
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_b.external_flame.convective_coefficient((200.0, 600.0, 600.0, 3.0, 2.5, 1.0, 293.0), 0.3)
+#[pyo3(signature = (compartment, d_eq, u=None, wall_above=true, isolated=true))]
+fn convective_coefficient(
+    compartment: CompartmentArgs,
+    d_eq: f64,
+    u: Option<f64>,
+    wall_above: bool,
+    isolated: bool,
+) -> PyResult<f64> {
+    Ok(build_flame(compartment, u, wall_above, isolated)?.convective_coefficient(d_eq))
+}
+
+// Added with add_submodule as BR 187 appendix A exports a pymodule with the
+// same name.
+/// External flame from a window of the fire compartment.
+pub fn external_flame(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(flame_properties, m)?)?;
+    m.add_function(wrap_pyfunction!(axis_temperature, m)?)?;
+    m.add_function(wrap_pyfunction!(convective_coefficient, m)?)?;
+    Ok(())
+}
+
+#[pymodule]
+/// Annex B - Thermal actions for external members.
+///
+/// This annex contains the external flame model for members outside the fire compartment.
+pub fn annex_b(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_wrapped(wrap_pymodule!(compartment_temperature))?;
+    let external_flame_module = PyModule::new_bound(m.py(), "external_flame")?;
+    external_flame(&external_flame_module)?;
+    m.add_submodule(&external_flame_module)?;
+    m.add_wrapped(wrap_pymodule!(flame_emissivity))?;
+    m.add_wrapped(wrap_pymodule!(forced_draught))?;
+    m.add_wrapped(wrap_pymodule!(no_forced_draught))?;
+    m.add_wrapped(wrap_pymodule!(rate_of_burning))?;
+
+    Ok(())
+}
@@ -0,0 +1,430 @@
+use pyo3::prelude::*;
+use pyo3::wrap_pymodule;
+
+use openfire::eurocode_1_1_2::annex_c::{
+    equation_c1 as rust_equation_c1, equation_c2 as rust_equation_c2,
+    equation_c3 as rust_equation_c3, equation_c4 as rust_equation_c4,
+    equation_c5 as rust_equation_c5, equation_c6 as rust_equation_c6,
+    equation_c7 as rust_equation_c7, equation_c8 as rust_equation_c8,
+    equation_c9 as rust_equation_c9, localised_fire as rust_localised_fire,
+};
+
+// Equation C.1 module functions
+#[pyfunction]
+/// Flame length of a localised fire.
+///
+/// .. math::
+///
+///    L_f = -1.02 \cdot D + 0.0148 \cdot Q^{0.4}
+///
+/// Args:
+///     d (float): Diameter of the fire (m)
+///     q (float): Rate of heat release of the fire (W)
+///
+/// Returns:
+///     float: Flame length (m)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_c.equation_c1.flame_length(2.0, 2.0e6)
+fn flame_length(d: f64, q: f64) -> f64 {
+    rust_equation_c1::flame_length(d, q)
+}
+
+#[pymodule]
+/// Equation C.1 - Flame length.
+pub fn equation_c1(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(flame_length, m)?)?;
+    Ok(())
+}
+
+// Equation C.2 module functions
+#[pyfunction]
+/// Plume temperature along the vertical flame axis (Heskestad).
+///
+/// .. math::
+///
+///    \Theta_{(z)} = 20 + 0.25 \cdot Q_c^{2/3} \cdot \left( z - z_0 \right)^{-5/3} \leq 900
+///
+/// Args:
+///     q_c (float): Convective part of the rate of heat release (W)
+///     z (float): Height along the flame axis (m)
+///     z_0 (float): Virtual origin (m)
+///
+/// Returns:
+///     float: Plume temperature (°C)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_c.equation_c2.plume_temperature(1.6e6, 5.0, -0.3)
+fn plume_temperature(q_c: f64, z: f64, z_0: f64) -> f64 {
+    rust_equation_c2::plume_temperature(q_c, z, z_0)
+}
+
+#[pyfunction]
+/// Convective part of the rate of heat release.
+///
+/// .. math::
+///
+///    Q_c = 0.8 \cdot Q
+///
+/// Args:
+///     q (float): Rate of heat release (W)
+///
+/// Returns:
+///     float: Convective part of the rate of heat release (W)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_c.equation_c2.convective_heat_release(2.0e6)
+fn convective_heat_release(q: f64) -> f64 {
+    rust_equation_c2::convective_heat_release(q)
+}
+
+#[pymodule]
+/// Equation C.2 - Plume temperature.
+pub fn equation_c2(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(plume_temperature, m)?)?;
+    m.add_function(wrap_pyfunction!(convective_heat_release, m)?)?;
+    Ok(())
+}
+
+// Equation C.3 module functions
+#[pyfunction]
+/// Vertical position of the virtual origin.
+///
+/// .. math::
+///
+///    z_0 = -1.02 \cdot D + 0.00524 \cdot Q^{0.4}
+///
+/// Args:
+///     d (float): Diameter of the fire (m)
+///     q (float): Rate of heat release of the fire (W)
+///
+/// Returns:
+///     float: Virtual origin (m)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_c.equation_c3.virtual_origin(2.0, 2.0e6)
+fn virtual_origin(d: f64, q: f64) -> f64 {
+    rust_equation_c3::virtual_origin(d, q)
+}
+
+#[pymodule]
+/// Equation C.3 - Virtual origin.
+pub fn equation_c3(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(virtual_origin, m)?)?;
+    Ok(())
+}
+
+// Equation C.4 module functions
+#[pyfunction]
+/// Horizontal flame length along the ceiling (Hasemi).
+///
+/// .. math::
+///
+///    L_h = 2.9 \cdot H \cdot \left( Q_H^* \right)^{0.33} - H
+///
+/// Args:
+///     h (float): Distance between the fire source and the ceiling (m)
+///     q_h_star (float): Non-dimensional rate of heat release (dimensionless)
+///
+/// Returns:
+///     float: Horizontal flame length (m)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_c.equation_c4.horizontal_flame_length(4.0, 0.225)
+fn horizontal_flame_length(h: f64, q_h_star: f64) -> f64 {
+    rust_equation_c4::horizontal_flame_length(h, q_h_star)
+}
+
+#[pymodule]
+/// Equation C.4 - Horizontal flame length.
+pub fn equation_c4(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(horizontal_flame_length, m)?)?;
+    Ok(())
+}
+
+// Equation C.5 module functions
+#[pyfunction]
+/// Non-dimensional rate of heat release related to the ceiling height.
+///
+/// .. math::
+///
+///    Q_H^* = \frac{Q}{1.11 \cdot 10^6 \cdot H^{2.5}}
+///
+/// Args:
+///     q (float): Rate of heat release (W)
+///     h (float): Distance between the fire source and the ceiling (m)
+///
+/// Returns:
+///     float: Non-dimensional rate of heat release (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_c.equation_c5.nondimensional_heat_release_ceiling(8.0e6, 4.0)
+fn nondimensional_heat_release_ceiling(q: f64, h: f64) -> f64 {
+    rust_equation_c5::nondimensional_heat_release_ceiling(q, h)
+}
+
+#[pymodule]
+/// Equation C.5 - Non-dimensional rate of heat release at the ceiling.
+pub fn equation_c5(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(nondimensional_heat_release_ceiling, m)?)?;
+    Ok(())
+}
+
+// Equation C.6 module functions
+#[pyfunction]
+/// Heat flux received at the ceiling level (Hasemi).
+///
+/// .. math::
+///
+///    \dot{h} = \begin{cases} 100000 & y \leq 0.30 \\ 136300 - 121000 \cdot y & 0.30 < y < 1.0 \\ 15000 \cdot y^{-3.7} & y \geq 1.0 \end{cases}
+///
+/// Args:
+///     y (float): Parameter y (dimensionless)
+///
+/// Returns:
+///     float: Heat flux (W/m²)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_c.equation_c6.heat_flux(0.62)
+fn heat_flux(y: f64) -> f64 {
+    rust_equation_c6::heat_flux(y)
+}
+
+#[pymodule]
+/// Equation C.6 - Heat flux at the ceiling.
+pub fn equation_c6(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(heat_flux, m)?)?;
+    Ok(())
+}
+
+// Equation C.7 module functions
+#[pyfunction]
+/// Parameter y for a point at the ceiling.
+///
+/// .. math::
+///
+///    y = \frac{r + H + z'}{L_h + H + z'}
+///
+/// Args:
+///     r (float): Horizontal distance from the flame axis (m)
+///     h (float): Distance between the fire source and the ceiling (m)
+///     z_prime (float): Vertical position of the virtual heat source (m)
+///     l_h (float): Horizontal flame length (m)
+///
+/// Returns:
+///     float: Parameter y (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_c.equation_c7.parameter_y(2.0, 4.0, 0.98, 3.09)
+fn parameter_y(r: f64, h: f64, z_prime: f64, l_h: f64) -> f64 {
+    rust_equation_c7::parameter_y(r, h, z_prime, l_h)
+}
+
+#[pymodule]
+/// Equation C.7 - Parameter y.
+pub fn equation_c7(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(parameter_y, m)?)?;
+    Ok(())
+}
+
+// Equation C.8 module functions
+#[pyfunction]
+/// Net heat flux received by the fire exposed surface at the ceiling level.
+///
+/// .. math::
+///
+///    \dot{h}_{net} = \dot{h} - \alpha_c \left( \Theta_m - 20 \right) - \Phi \varepsilon_m \varepsilon_f \sigma \left[ \left( \Theta_m + 273 \right)^4 - 293^4 \right]
+///
+/// Args:
+///     h (float): Heat flux received at the ceiling (W/m²)
+///     alpha_c (float): Coefficient of heat transfer by convection (W/m²K)
+///     theta_m (float): Surface temperature of the member (°C)
+///     phi (float): Configuration factor (dimensionless)
+///     epsilon_m (float): Surface emissivity of the member (dimensionless)
+///     epsilon_f (float): Emissivity of the fire (dimensionless)
+///     sigma (float): Stefan-Boltzmann constant (W/m²K⁴)
+///
+/// Returns:
+///     float: Net heat flux (W/m²)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_c.equation_c8.net_heat_flux(50000.0, 25.0, 200.0, 1.0, 0.7, 1.0, 5.67e-8)
+fn net_heat_flux(
+    h: f64,
+    alpha_c: f64,
+    theta_m: f64,
+    phi: f64,
+    epsilon_m: f64,
+    epsilon_f: f64,
+    sigma: f64,
+) -> f64 {
+    rust_equation_c8::net_heat_flux(h, alpha_c, theta_m, phi, epsilon_m, epsilon_f, sigma)
+}
+
+#[pyfunction]
+/// Total heat flux from several localised fires, limited to 100 kW/m².
+///
+/// Args:
+///     h (list[float]): Heat flux from each fire (W/m²)
+///
+/// Returns:
+///     float: Total heat flux (W/m²)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_c.equation_c8.total_heat_flux([30000.0, 20000.0])
+fn total_heat_flux(h: Vec<f64>) -> f64 {
+    rust_equation_c8::total_heat_flux(&h)
+}
+
+#[pymodule]
+/// Equation C.8 - Net heat flux at the ceiling.
+pub fn equation_c8(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(net_heat_flux, m)?)?;
+    m.add_function(wrap_pyfunction!(total_heat_flux, m)?)?;
+    Ok(())
+}
+
+// Equation C.9 module functions
+#[pyfunction]
+/// Non-dimensional rate of heat release related to the fire diameter.
+///
+/// .. math::
+///
+///    Q_D^* = \frac{Q}{1.11 \cdot 10^6 \cdot D^{2.5}}
+///
+/// Args:
+///     q (float): Rate of heat release (W)
+///     d (float): Diameter of the fire (m)
+///
+/// Returns:
+///     float: Non-dimensional rate of heat release (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_c.equation_c9.nondimensional_heat_release_diameter(8.0e6, 3.0)
+fn nondimensional_heat_release_diameter(q: f64, d: f64) -> f64 {
+    rust_equation_c9::nondimensional_heat_release_diameter(q, d)
+}
+
+#[pyfunction]
+/// Vertical position of the virtual heat source.
+///
+/// .. math::
+///
+///    z' = \begin{cases} 2.4 D \left( Q_D^{*2/5} - Q_D^{*2/3} \right) & Q_D^* < 1.0 \\ 2.4 D \left( 1.0 - Q_D^{*2/5} \right) & Q_D^* \geq 1.0 \end{cases}
+///
+/// Args:
+///     d (float): Diameter of the fire (m)
+///     q_d_star (float): Non-dimensional rate of heat release (dimensionless)
+///
+/// Returns:
+///     float: Vertical position of the virtual heat source (m)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_c.equation_c9.virtual_heat_source(3.0, 0.46)
+fn virtual_heat_source(d: f64, q_d_star: f64) -> f64 {
+    rust_equation_c9::virtual_heat_source(d, q_d_star)
+}
+
+#[pymodule]
+/// Equation C.9 - Virtual heat source.
+pub fn equation_c9(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(nondimensional_heat_release_diameter, m)?)?;
+    m.add_function(wrap_pyfunction!(virtual_heat_source, m)?)?;
+    Ok(())
+}
+
+// Localised fire module functions
+#[pyfunction]
+/// Whether the flame of a localised fire impacts the ceiling.
+///
+/// Args:
+///     d (float): Diameter of the fire (m)
+///     q (float): Rate of heat release of the fire (W)
+///     h (float): Distance between the fire source and the ceiling (m)
+///
+/// Returns:
+///     bool: True if the flame length reaches the ceiling
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_c.localised_fire.impacts_ceiling(3.0, 8.0e6, 4.0)
+fn impacts_ceiling(d: f64, q: f64, h: f64) -> bool {
+    rust_localised_fire::LocalisedFire::new(d, q).impacts_ceiling(h)
+}
+
+#[pyfunction]
+/// Plume temperature on the flame axis of a localised fire (Heskestad).
+///
+/// Args:
+///     d (float): Diameter of the fire (m)
+///     q (float): Rate of heat release of the fire (W)
+///     z (float): Height along the flame axis (m)
+///
+/// Returns:
+///     float: Plume temperature (°C)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_c.localised_fire.axis_temperature(2.0, 2.0e6, 5.0)
+fn axis_temperature(d: f64, q: f64, z: f64) -> f64 {
+    rust_localised_fire::LocalisedFire::new(d, q).plume_temperature(z)
+}
+
+#[pyfunction]
+/// Heat flux at the ceiling from a localised fire impacting it (Hasemi).
+///
+/// Args:
+///     d (float): Diameter of the fire (m)
+///     q (float): Rate of heat release of the fire (W)
+///     r (float): Horizontal distance from the flame axis (m)
+///     h (float): Distance between the fire source and the ceiling (m)
+///
+/// Returns:
+///     float: Heat flux (W/m²)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_c.localised_fire.ceiling_heat_flux(3.0, 8.0e6, 2.0, 4.0)
+fn ceiling_heat_flux(d: f64, q: f64, r: f64, h: f64) -> f64 {
+    rust_localised_fire::LocalisedFire::new(d, q).ceiling_heat_flux(r, h)
+}
+
+#[pymodule]
+/// Localised fire combining the Heskestad and Hasemi methods.
+pub fn localised_fire(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(impacts_ceiling, m)?)?;
+    m.add_function(wrap_pyfunction!(axis_temperature, m)?)?;
+    m.add_function(wrap_pyfunction!(ceiling_heat_flux, m)?)?;
+    Ok(())
+}
+
+#[pymodule]
+/// Annex C - Localised fires.
+///
+/// This annex contains the thermal actions of localised fires.
+pub fn annex_c(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_wrapped(wrap_pymodule!(equation_c1))?;
+    m.add_wrapped(wrap_pymodule!(equation_c2))?;
+    m.add_wrapped(wrap_pymodule!(equation_c3))?;
+    m.add_wrapped(wrap_pymodule!(equation_c4))?;
+    m.add_wrapped(wrap_pymodule!(equation_c5))?;
+    m.add_wrapped(wrap_pymodule!(equation_c6))?;
+    m.add_wrapped(wrap_pymodule!(equation_c7))?;
+    m.add_wrapped(wrap_pymodule!(equation_c8))?;
+    m.add_wrapped(wrap_pymodule!(equation_c9))?;
+    m.add_wrapped(wrap_pymodule!(localised_fire))?;
+
+    Ok(())
+}
@@ -0,0 +1,586 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::wrap_pymodule;
+
+use super::annex_a::parse_growth_rate;
+use openfire::eurocode_1_1_2::annex_a::equation_a7::FireGrowthRate;
+use openfire::eurocode_1_1_2::annex_e::{
+    equation_e1 as rust_equation_e1, equation_e2 as rust_equation_e2,
+    equation_e3 as rust_equation_e3, equation_e4 as rust_equation_e4,
+    equation_e5 as rust_equation_e5, equation_e6 as rust_equation_e6,
+    heat_release_curve as rust_heat_release_curve, occupancy as rust_occupancy,
+    partial_factors as rust_partial_factors,
+};
+
+fn parse_occupancy(occupancy: &str) -> PyResult<rust_occupancy::Occupancy> {
+    match occupancy.to_lowercase().as_str() {
+        "dwelling" => Ok(rust_occupancy::Occupancy::Dwelling),
+        "hospital" => Ok(rust_occupancy::Occupancy::Hospital),
+        "hotel" => Ok(rust_occupancy::Occupancy::Hotel),
+        "library" => Ok(rust_occupancy::Occupancy::Library),
+        "office" => Ok(rust_occupancy::Occupancy::Office),
+        "classroom" => Ok(rust_occupancy::Occupancy::Classroom),
+        "shopping_centre" => Ok(rust_occupancy::Occupancy::ShoppingCentre),
+        "theatre" => Ok(rust_occupancy::Occupancy::Theatre),
+        "transport" => Ok(rust_occupancy::Occupancy::Transport),
+        _ => Err(PyValueError::new_err(format!(
+            "Unknown occupancy '{}'",
+            occupancy
+        ))),
+    }
+}
+
+fn parse_activation_risk(activation_risk: &str) -> PyResult<rust_partial_factors::ActivationRisk> {
+    match activation_risk.to_lowercase().as_str() {
+        "art_gallery" => Ok(rust_partial_factors::ActivationRisk::ArtGallery),
+        "office" => Ok(rust_partial_factors::ActivationRisk::Office),
+        "machinery_manufacture" => Ok(rust_partial_factors::ActivationRisk::MachineryManufacture),
+        "chemical_laboratory" => Ok(rust_partial_factors::ActivationRisk::ChemicalLaboratory),
+        "fireworks_manufacture" => Ok(rust_partial_factors::ActivationRisk::FireworksManufacture),
+        _ => Err(PyValueError::new_err(format!(
+            "Unknown activation risk '{}'",
+            activation_risk
+        ))),
+    }
+}
+
+fn parse_detection(detection: &str) -> PyResult<rust_partial_factors::FireDetection> {
+    match detection.to_lowercase().as_str() {
+        "none" => Ok(rust_partial_factors::FireDetection::None),
+        "heat" => Ok(rust_partial_factors::FireDetection::Heat),
+        "smoke" => Ok(rust_partial_factors::FireDetection::Smoke),
+        _ => Err(PyValueError::new_err(format!(
+            "Unknown fire detection '{}'",
+            detection
+        ))),
+    }
+}
+
+fn parse_fire_brigade(fire_brigade: &str) -> PyResult<rust_partial_factors::FireBrigade> {
+    match fire_brigade.to_lowercase().as_str() {
+        "none" => Ok(rust_partial_factors::FireBrigade::None),
+        "work" => Ok(rust_partial_factors::FireBrigade::Work),
+        "off_site" => Ok(rust_partial_factors::FireBrigade::OffSite),
+        _ => Err(PyValueError::new_err(format!(
+            "Unknown fire brigade '{}'",
+            fire_brigade
+        ))),
+    }
+}
+
+fn parse_safe_access_routes(
+    safe_access_routes: &str,
+) -> PyResult<rust_partial_factors::SafeAccessRoutes> {
+    match safe_access_routes.to_lowercase().as_str() {
+        "enhanced" => Ok(rust_partial_factors::SafeAccessRoutes::Enhanced),
+        "normal" => Ok(rust_partial_factors::SafeAccessRoutes::Normal),
+        "inadequate" => Ok(rust_partial_factors::SafeAccessRoutes::Inadequate),
+        _ => Err(PyValueError::new_err(format!(
+            "Unknown safe access routes '{}'",
+            safe_access_routes
+        ))),
+    }
+}
+
+// Equation E.1 module functions
+#[pyfunction]
+/// Design fire load density.
+///
+/// .. math::
+///
+///    q_{f,d} = q_{f,k} \cdot m \cdot \delta_{q1} \cdot \delta_{q2} \cdot \delta_n
+///
+/// Args:
+///     q_fk (float): Characteristic fire load density (MJ/m²)
+///     m (float): Combustion factor (dimensionless)
+///     delta_q1 (float): Factor for the compartment size (dimensionless)
+///     delta_q2 (float): Factor for the type of occupancy (dimensionless)
+///     delta_n (float): Factor for active fire fighting measures (dimensionless)
+///
+/// Returns:
+///     float: Design fire load density (MJ/m²)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_e.equation_e1.design_fire_load_density(511.0, 0.8, 1.5, 1.0, 0.61)
+fn design_fire_load_density(q_fk: f64, m: f64, delta_q1: f64, delta_q2: f64, delta_n: f64) -> f64 {
+    rust_equation_e1::design_fire_load_density(q_fk, m, delta_q1, delta_q2, delta_n)
+}
+
+#[pyfunction]
+/// Combustion factor for mainly cellulosic fire loads.
+///
+/// Returns:
+///     float: Combustion factor (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> m = ofire.eurocode_1_1_2.annex_e.equation_e1.combustion_factor_cellulosic()
+fn combustion_factor_cellulosic() -> f64 {
+    rust_equation_e1::combustion_factor_cellulosic()
+}
+
+#[pyfunction]
+/// Factor for active fire fighting measures.
+///
+/// .. math::
+///
+///    \delta_n = \prod_{i=1}^{10} \delta_{ni}
+///
+/// Args:
+///     delta_n_i (list[float]): Factors for each measure (dimensionless)
+///
+/// Returns:
+///     float: Factor for active fire fighting measures (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_e.equation_e1.active_measures_factor([0.61, 0.87, 0.73])
+fn active_measures_factor(delta_n_i: Vec<f64>) -> f64 {
+    rust_equation_e1::active_measures_factor(&delta_n_i)
+}
+
+#[pymodule]
+/// Equation E.1 - Design fire load density.
+pub fn equation_e1(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(design_fire_load_density, m)?)?;
+    m.add_function(wrap_pyfunction!(combustion_factor_cellulosic, m)?)?;
+    m.add_function(wrap_pyfunction!(active_measures_factor, m)?)?;
+    Ok(())
+}
+
+// Equation E.2 module functions
+#[pyfunction]
+/// Characteristic fire load density.
+///
+/// .. math::
+///
+///    q_{f,k} = \frac{Q_{fi,k}}{A}
+///
+/// Args:
+///     q_fik (float): Characteristic fire load (MJ)
+///     a (float): Floor area (m²)
+///
+/// Returns:
+///     float: Characteristic fire load density (MJ/m²)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_e.equation_e2.characteristic_fire_load_density(12000.0, 25.0)
+fn characteristic_fire_load_density(q_fik: f64, a: f64) -> f64 {
+    rust_equation_e2::characteristic_fire_load_density(q_fik, a)
+}
+
+#[pymodule]
+/// Equation E.2 - Characteristic fire load density.
+pub fn equation_e2(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(characteristic_fire_load_density, m)?)?;
+    Ok(())
+}
+
+// Equation E.3 module functions
+#[pyfunction]
+/// Characteristic fire load.
+///
+/// .. math::
+///
+///    Q_{fi,k} = \sum_i M_{k,i} \cdot H_{ui} \cdot \Psi_i
+///
+/// Args:
+///     m_k (list[float]): Mass of each combustible material (kg)
+///     h_u (list[float]): Net calorific value of each material (MJ/kg)
+///     psi (list[float]): Protection factor of each material (dimensionless)
+///
+/// Returns:
+///     float: Characteristic fire load (MJ)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_e.equation_e3.characteristic_fire_load([500.0, 100.0], [17.5, 40.0], [1.0, 0.5])
+fn characteristic_fire_load(m_k: Vec<f64>, h_u: Vec<f64>, psi: Vec<f64>) -> f64 {
+    rust_equation_e3::characteristic_fire_load(&m_k, &h_u, &psi)
+}
+
+#[pymodule]
+/// Equation E.3 - Characteristic fire load.
+pub fn equation_e3(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(characteristic_fire_load, m)?)?;
+    Ok(())
+}
+
+// Equation E.4 module functions
+#[pyfunction]
+/// Net calorific value of a material with moisture.
+///
+/// .. math::
+///
+///    H_u = H_{u0} \cdot \left( 1 - 0.01 \cdot u \right) - 0.025 \cdot u
+///
+/// Args:
+///     h_u0 (float): Net calorific value of the dry material (MJ/kg)
+///     u (float): Moisture content (% of dry weight)
+///
+/// Returns:
+///     float: Net calorific value (MJ/kg)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_e.equation_e4.net_calorific_value(17.5, 12.0)
+fn net_calorific_value(h_u0: f64, u: f64) -> f64 {
+    rust_equation_e4::net_calorific_value(h_u0, u)
+}
+
+#[pymodule]
+/// Equation E.4 - Net calorific value.
+pub fn equation_e4(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(net_calorific_value, m)?)?;
+    Ok(())
+}
+
+// Equation E.5 module functions
+#[pyfunction]
+/// Rate of heat release in the growing phase.
+///
+/// .. math::
+///
+///    Q = 10^6 \cdot \left( \frac{t}{t_\alpha} \right)^2
+///
+/// Args:
+///     t (float): Time (s)
+///     t_alpha (float): Time needed to reach 1 MW (s)
+///
+/// Returns:
+///     float: Rate of heat release (W)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_e.equation_e5.growing_phase_heat_release(600.0, 300.0)
+fn growing_phase_heat_release(t: f64, t_alpha: f64) -> f64 {
+    rust_equation_e5::growing_phase_heat_release(t, t_alpha)
+}
+
+#[pymodule]
+/// Equation E.5 - Growing phase rate of heat release.
+pub fn equation_e5(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(growing_phase_heat_release, m)?)?;
+    Ok(())
+}
+
+// Equation E.6 module functions
+#[pyfunction]
+/// Maximum rate of heat release for a ventilation controlled fire.
+///
+/// .. math::
+///
+///    Q_{max} = 0.10 \cdot m \cdot H_u \cdot A_v \cdot \sqrt{h_{eq}}
+///
+/// Args:
+///     m (float): Combustion factor (dimensionless)
+///     h_u (float): Net calorific value of wood (MJ/kg)
+///     a_v (float): Area of vertical openings (m²)
+///     h_eq (float): Weighted average height of openings (m)
+///
+/// Returns:
+///     float: Maximum rate of heat release (W)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_e.equation_e6.ventilation_controlled_heat_release(0.8, 17.5, 4.0, 2.25)
+fn ventilation_controlled_heat_release(m: f64, h_u: f64, a_v: f64, h_eq: f64) -> f64 {
+    rust_equation_e6::ventilation_controlled_heat_release(m, h_u, a_v, h_eq)
+}
+
+#[pyfunction]
+/// Maximum rate of heat release for a fuel controlled fire.
+///
+/// .. math::
+///
+///    Q_{max} = RHR_f \cdot A_{fi}
+///
+/// Args:
+///     rhr_f (float): Maximum rate of heat release density (W/m²)
+///     a_fi (float): Maximum fire area (m²)
+///
+/// Returns:
+///     float: Maximum rate of heat release (W)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_e.equation_e6.fuel_controlled_heat_release(250000.0, 20.0)
+fn fuel_controlled_heat_release(rhr_f: f64, a_fi: f64) -> f64 {
+    rust_equation_e6::fuel_controlled_heat_release(rhr_f, a_fi)
+}
+
+#[pymodule]
+/// Equation E.6 - Maximum rate of heat release.
+pub fn equation_e6(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(ventilation_controlled_heat_release, m)?)?;
+    m.add_function(wrap_pyfunction!(fuel_controlled_heat_release, m)?)?;
+    Ok(())
+}
+
+// Occupancy module functions
+#[pyfunction]
+/// Characteristic (80% fractile) fire load density for an occupancy.
+///
+/// Args:
+///     occupancy (str): One of "dwelling", "hospital", "hotel", "library",
+///         "office", "classroom", "shopping_centre", "theatre" or "transport"
+///
+/// Returns:
+///     float: Characteristic fire load density (MJ/m²)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_e.occupancy.characteristic_fire_load_density("office")
+#[pyo3(name = "characteristic_fire_load_density")]
+fn occupancy_characteristic_fire_load_density(occupancy: &str) -> PyResult<f64> {
+    Ok(parse_occupancy(occupancy)?.characteristic_fire_load_density())
+}
+
+#[pyfunction]
+/// Average fire load density for an occupancy.
+///
+/// Args:
+///     occupancy (str): Occupancy name
+///
+/// Returns:
+///     float: Average fire load density (MJ/m²)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_e.occupancy.mean_fire_load_density("office")
+fn mean_fire_load_density(occupancy: &str) -> PyResult<f64> {
+    Ok(parse_occupancy(occupancy)?.mean_fire_load_density())
+}
+
+#[pyfunction]
+/// Fire growth rate for an occupancy.
+///
+/// Args:
+///     occupancy (str): Occupancy name
+///
+/// Returns:
+///     str: Fire growth rate ("slow", "medium" or "fast")
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_e.occupancy.growth_rate("library")
+fn growth_rate(occupancy: &str) -> PyResult<String> {
+    let growth_rate = match parse_occupancy(occupancy)?.growth_rate() {
+        FireGrowthRate::Slow => "slow",
+        FireGrowthRate::Medium => "medium",
+        FireGrowthRate::Fast => "fast",
+    };
+    Ok(growth_rate.to_string())
+}
+
+#[pyfunction]
+/// Maximum rate of heat release density for an occupancy.
+///
+/// Args:
+///     occupancy (str): Occupancy name
+///
+/// Returns:
+///     float: Rate of heat release density (W/m²)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_e.occupancy.rate_of_heat_release_density("office")
+fn rate_of_heat_release_density(occupancy: &str) -> PyResult<f64> {
+    Ok(parse_occupancy(occupancy)?.rate_of_heat_release_density())
+}
+
+#[pyfunction]
+/// Time needed to reach a rate of heat release of 1 MW.
+///
+/// Args:
+///     growth_rate (str): Fire growth rate ("slow", "medium" or "fast")
+///
+/// Returns:
+///     float: Time to reach 1 MW (s)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_e.occupancy.t_alpha("medium")
+fn t_alpha(growth_rate: &str) -> PyResult<f64> {
+    Ok(rust_occupancy::t_alpha(parse_growth_rate(growth_rate)?))
+}
+
+#[pymodule]
+/// Fire load densities and rates of heat release by occupancy.
+pub fn occupancy(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(
+        occupancy_characteristic_fire_load_density,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(mean_fire_load_density, m)?)?;
+    m.add_function(wrap_pyfunction!(growth_rate, m)?)?;
+    m.add_function(wrap_pyfunction!(rate_of_heat_release_density, m)?)?;
+    m.add_function(wrap_pyfunction!(t_alpha, m)?)?;
+    Ok(())
+}
+
+// Partial factors module functions
+#[pyfunction]
+/// Factor for the activation risk due to the compartment size.
+///
+/// Interpolated linearly between the tabulated floor areas.
+///
+/// Args:
+///     a_f (float): Compartment floor area (m²)
+///
+/// Returns:
+///     float: Factor delta_q1 (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_e.partial_factors.delta_q1(1375.0)
+fn delta_q1(a_f: f64) -> f64 {
+    rust_partial_factors::delta_q1(a_f)
+}
+
+#[pyfunction]
+/// Factor for the activation risk due to the type of occupancy.
+///
+/// Args:
+///     activation_risk (str): One of "art_gallery", "office",
+///         "machinery_manufacture", "chemical_laboratory" or "fireworks_manufacture"
+///
+/// Returns:
+///     float: Factor delta_q2 (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_e.partial_factors.delta_q2("office")
+fn delta_q2(activation_risk: &str) -> PyResult<f64> {
+    Ok(parse_activation_risk(activation_risk)?.delta_q2())
+}
+
+#[pyfunction]
+/// Factor for active fire fighting measures.
+///
+/// Args:
+///     sprinklers (bool): Automatic water extinguishing system
+///     independent_water_supplies (int): Number of independent water supplies
+///     detection (str): Automatic fire detection ("none", "heat" or "smoke")
+///     alarm_transmission (bool): Automatic alarm transmission to the fire brigade
+///     fire_brigade (str): Fire brigade ("none", "work" or "off_site")
+///     normal_measures (tuple[str, bool, bool]): Safe access routes
+///         ("enhanced", "normal" or "inadequate"), and whether fire fighting
+///         devices and a smoke exhaust system are provided
+///
+/// Returns:
+///     float: Factor delta_n (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_e.partial_factors.delta_n(True, 1, "smoke", True, "off_site")
+#[pyo3(signature = (sprinklers, independent_water_supplies, detection, alarm_transmission, fire_brigade, normal_measures=("normal".to_string(), true, true)))]
+fn delta_n(
+    sprinklers: bool,
+    independent_water_supplies: u32,
+    detection: &str,
+    alarm_transmission: bool,
+    fire_brigade: &str,
+    normal_measures: (String, bool, bool),
+) -> PyResult<f64> {
+    let measures = rust_partial_factors::ActiveMeasures {
+        sprinklers,
+        independent_water_supplies,
+        detection: parse_detection(detection)?,
+        alarm_transmission,
+        fire_brigade: parse_fire_brigade(fire_brigade)?,
+        safe_access_routes: parse_safe_access_routes(&normal_measures.0)?,
+        fire_fighting_devices: normal_measures.1,
+        smoke_exhaust: normal_measures.2,
+    };
+    Ok(measures.delta_n())
+}
+
+#[pymodule]
+/// Partial factors for activation risk and active fire fighting measures.
+pub fn partial_factors(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(delta_q1, m)?)?;
+    m.add_function(wrap_pyfunction!(delta_q2, m)?)?;
+    m.add_function(wrap_pyfunction!(delta_n, m)?)?;
+    Ok(())
+}
+
+// Heat release curve module functions
+#[pyfunction]
+/// Design rate of heat release history.
+///
+/// The curve grows with t², is limited to the maximum rate of heat release
+/// and decays linearly once 70% of the fire load has been consumed.
+///
+/// Args:
+///     t_alpha (float): Time needed to reach 1 MW (s)
+///     q_max (float): Maximum rate of heat release (W)
+///     q_fd (float): Design fire load density (MJ/m²)
+///     a_fi (float): Fire area (m²)
+///     times (list[float]): Times (s)
+///
+/// Returns:
+///     list[float]: Rate of heat release at each time (W)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_e.heat_release_curve.rate_of_heat_release_history(300.0, 4.0e6, 300.0, 20.0, [0.0, 600.0, 1800.0])
+fn rate_of_heat_release_history(
+    t_alpha: f64,
+    q_max: f64,
+    q_fd: f64,
+    a_fi: f64,
+    times: Vec<f64>,
+) -> Vec<f64> {
+    rust_heat_release_curve::HeatReleaseCurve::new(t_alpha, q_max, q_fd, a_fi)
+        .rate_of_heat_release_history(&times)
+}
+
+#[pyfunction]
+/// Phase times of the design rate of heat release.
+///
+/// Args:
+///     t_alpha (float): Time needed to reach 1 MW (s)
+///     q_max (float): Maximum rate of heat release (W)
+///     q_fd (float): Design fire load density (MJ/m²)
+///     a_fi (float): Fire area (m²)
+///
+/// Returns:
+///     tuple[float, float, float, float]: Peak rate of heat release (W), end
+///     of growth (s), start of decay (s) and end of the fire (s)
+///
+/// Example:
+///     >>> import ofire
+///     >>> q_peak, t_growth, t_decay, t_end = ofire.eurocode_1_1_2.annex_e.heat_release_curve.phase_times(300.0, 4.0e6, 300.0, 20.0)
+fn phase_times(t_alpha: f64, q_max: f64, q_fd: f64, a_fi: f64) -> (f64, f64, f64, f64) {
+    let curve = rust_heat_release_curve::HeatReleaseCurve::new(t_alpha, q_max, q_fd, a_fi);
+    (curve.q_peak, curve.t_growth, curve.t_decay, curve.t_end)
+}
+
+#[pymodule]
+/// Design rate of heat release curve.
+pub fn heat_release_curve(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(rate_of_heat_release_history, m)?)?;
+    m.add_function(wrap_pyfunction!(phase_times, m)?)?;
+    Ok(())
+}
+
+#[pymodule]
+/// Annex E - Fire load densities.
+///
+/// This annex contains the design fire load density and rate of heat release.
+pub fn annex_e(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_wrapped(wrap_pymodule!(equation_e1))?;
+    m.add_wrapped(wrap_pymodule!(equation_e2))?;
+    m.add_wrapped(wrap_pymodule!(equation_e3))?;
+    m.add_wrapped(wrap_pymodule!(equation_e4))?;
+    m.add_wrapped(wrap_pymodule!(equation_e5))?;
+    m.add_wrapped(wrap_pymodule!(equation_e6))?;
+    m.add_wrapped(wrap_pymodule!(heat_release_curve))?;
+    m.add_wrapped(wrap_pymodule!(occupancy))?;
+    m.add_wrapped(wrap_pymodule!(partial_factors))?;
+
+    Ok(())
+}
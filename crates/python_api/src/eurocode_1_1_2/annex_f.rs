@@ -0,0 +1,258 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::wrap_pymodule;
+
+use openfire::eurocode_1_1_2::annex_f::{
+    conversion_factors as rust_conversion_factors, equation_f1 as rust_equation_f1,
+    equation_f2 as rust_equation_f2, equation_f3 as rust_equation_f3,
+};
+
+fn parse_member_material(material: &str) -> PyResult<rust_conversion_factors::MemberMaterial> {
+    match material.to_lowercase().as_str() {
+        "reinforced_concrete" => Ok(rust_conversion_factors::MemberMaterial::ReinforcedConcrete),
+        "protected_steel" => Ok(rust_conversion_factors::MemberMaterial::ProtectedSteel),
+        "unprotected_steel" => Ok(rust_conversion_factors::MemberMaterial::UnprotectedSteel),
+        _ => Err(PyValueError::new_err(format!(
+            "Unknown member material '{}'",
+            material
+        ))),
+    }
+}
+
+// Equation F.1 module functions
+#[pyfunction]
+/// Equivalent time of standard fire exposure.
+///
+/// .. math::
+///
+///    t_{e,d} = \left( q_{f,d} \cdot k_b \cdot w_f \right) \cdot k_c
+///
+/// Args:
+///     q_fd (float): Design fire load density (MJ/m²)
+///     k_b (float): Conversion factor (min·m²/MJ)
+///     w_f (float): Ventilation factor (dimensionless)
+///     k_c (float): Correction factor for the member material (dimensionless)
+///
+/// Returns:
+///     float: Equivalent time of standard fire exposure (min)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_f.equation_f1.equivalent_time(600.0, 0.055, 1.1, 1.0)
+fn equivalent_time(q_fd: f64, k_b: f64, w_f: f64, k_c: f64) -> f64 {
+    rust_equation_f1::equivalent_time(q_fd, k_b, w_f, k_c)
+}
+
+#[pyfunction]
+/// Standard temperature-time curve temperature at the equivalent time.
+///
+/// Args:
+///     t_ed (float): Equivalent time of standard fire exposure (min)
+///
+/// Returns:
+///     float: Gas temperature (°C)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_f.equation_f1.equivalent_temperature(36.2)
+fn equivalent_temperature(t_ed: f64) -> f64 {
+    rust_equation_f1::equivalent_temperature(t_ed)
+}
+
+#[pyfunction]
+/// Whether the design fire resistance is at least the equivalent time.
+///
+/// Args:
+///     t_fid (float): Design fire resistance of the member (min)
+///     t_ed (float): Equivalent time of standard fire exposure (min)
+///
+/// Returns:
+///     bool: True if the fire resistance is sufficient
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_f.equation_f1.fire_resistance_sufficient(60.0, 36.2)
+fn fire_resistance_sufficient(t_fid: f64, t_ed: f64) -> bool {
+    rust_equation_f1::fire_resistance_sufficient(t_fid, t_ed)
+}
+
+#[pymodule]
+/// Equation F.1 - Equivalent time of standard fire exposure.
+pub fn equation_f1(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(equivalent_time, m)?)?;
+    m.add_function(wrap_pyfunction!(equivalent_temperature, m)?)?;
+    m.add_function(wrap_pyfunction!(fire_resistance_sufficient, m)?)?;
+    Ok(())
+}
+
+// Equation F.2 module functions
+#[pyfunction]
+/// Ventilation factor.
+///
+/// .. math::
+///
+///    w_f = \left( \frac{6.0}{H} \right)^{0.3} \left[ 0.62 + \frac{90 \left( 0.4 - \alpha_v \right)^4}{1 + b_v \alpha_h} \right] \geq 0.5
+///
+/// Args:
+///     h (float): Height of the fire compartment (m)
+///     alpha_v (float): Ratio of vertical openings to floor area (dimensionless)
+///     alpha_h (float): Ratio of horizontal openings to floor area (dimensionless)
+///
+/// Returns:
+///     float: Ventilation factor (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_f.equation_f2.ventilation_factor(4.0, 0.15, 0.0)
+fn ventilation_factor(h: f64, alpha_v: f64, alpha_h: f64) -> f64 {
+    rust_equation_f2::ventilation_factor(h, alpha_v, alpha_h)
+}
+
+#[pyfunction]
+/// Ratio of vertical openings to floor area, limited to between 0.025 and 0.25.
+///
+/// Args:
+///     a_v (float): Area of vertical openings (m²)
+///     a_f (float): Floor area (m²)
+///
+/// Returns:
+///     float: Ratio alpha_v (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_f.equation_f2.vertical_opening_ratio(30.0, 200.0)
+fn vertical_opening_ratio(a_v: f64, a_f: f64) -> f64 {
+    rust_equation_f2::vertical_opening_ratio(a_v, a_f)
+}
+
+#[pyfunction]
+/// Ratio of horizontal openings in the roof to floor area.
+///
+/// Args:
+///     a_h (float): Area of horizontal openings (m²)
+///     a_f (float): Floor area (m²)
+///
+/// Returns:
+///     float: Ratio alpha_h (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_f.equation_f2.horizontal_opening_ratio(10.0, 200.0)
+fn horizontal_opening_ratio(a_h: f64, a_f: f64) -> f64 {
+    rust_equation_f2::horizontal_opening_ratio(a_h, a_f)
+}
+
+#[pyfunction]
+/// Factor b_v for the ventilation factor.
+///
+/// .. math::
+///
+///    b_v = 12.5 \left( 1 + 10 \alpha_v - \alpha_v^2 \right) \geq 10.0
+///
+/// Args:
+///     alpha_v (float): Ratio of vertical openings to floor area (dimensionless)
+///
+/// Returns:
+///     float: Factor b_v (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_f.equation_f2.factor_b_v(0.15)
+fn factor_b_v(alpha_v: f64) -> f64 {
+    rust_equation_f2::factor_b_v(alpha_v)
+}
+
+#[pymodule]
+/// Equation F.2 - Ventilation factor.
+pub fn equation_f2(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(ventilation_factor, m)?)?;
+    m.add_function(wrap_pyfunction!(vertical_opening_ratio, m)?)?;
+    m.add_function(wrap_pyfunction!(horizontal_opening_ratio, m)?)?;
+    m.add_function(wrap_pyfunction!(factor_b_v, m)?)?;
+    Ok(())
+}
+
+// Equation F.3 module functions
+#[pyfunction]
+/// Ventilation factor for small compartments without roof openings.
+///
+/// .. math::
+///
+///    w_f = O^{-1/2} \cdot \frac{A_f}{A_t}
+///
+/// Args:
+///     o (float): Opening factor (m^1/2)
+///     a_f (float): Floor area (m²)
+///     a_t (float): Total area of the enclosure (m²)
+///
+/// Returns:
+///     float: Ventilation factor (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_f.equation_f3.ventilation_factor_small_compartment(0.04, 50.0, 180.0)
+fn ventilation_factor_small_compartment(o: f64, a_f: f64, a_t: f64) -> f64 {
+    rust_equation_f3::ventilation_factor_small_compartment(o, a_f, a_t)
+}
+
+#[pymodule]
+/// Equation F.3 - Ventilation factor for small compartments.
+pub fn equation_f3(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(ventilation_factor_small_compartment, m)?)?;
+    Ok(())
+}
+
+// Conversion factors module functions
+#[pyfunction]
+/// Conversion factor for the thermal absorptivity of the enclosure.
+///
+/// Args:
+///     b (float): Thermal absorptivity of the enclosure (J/m²s^1/2K)
+///
+/// Returns:
+///     float: Conversion factor k_b (min·m²/MJ)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_f.conversion_factors.k_b(1000.0)
+fn k_b(b: f64) -> f64 {
+    rust_conversion_factors::k_b(b)
+}
+
+#[pyfunction]
+/// Correction factor for the member material.
+///
+/// Args:
+///     material (str): One of "reinforced_concrete", "protected_steel" or "unprotected_steel"
+///     o (float): Opening factor (m^1/2)
+///
+/// Returns:
+///     float: Correction factor k_c (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.annex_f.conversion_factors.k_c("unprotected_steel", 0.04)
+fn k_c(material: &str, o: f64) -> PyResult<f64> {
+    Ok(parse_member_material(material)?.k_c(o))
+}
+
+#[pymodule]
+/// Conversion and correction factors for the equivalent time.
+pub fn conversion_factors(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(k_b, m)?)?;
+    m.add_function(wrap_pyfunction!(k_c, m)?)?;
+    Ok(())
+}
+
+#[pymodule]
+/// Annex F - Equivalent time of fire exposure.
+///
+/// This annex relates a real fire to an equivalent time of standard fire exposure.
+pub fn annex_f(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_wrapped(wrap_pymodule!(conversion_factors))?;
+    m.add_wrapped(wrap_pymodule!(equation_f1))?;
+    m.add_wrapped(wrap_pymodule!(equation_f2))?;
+    m.add_wrapped(wrap_pymodule!(equation_f3))?;
+
+    Ok(())
+}
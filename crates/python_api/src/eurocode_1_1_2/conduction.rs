@@ -0,0 +1,220 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::wrap_pymodule;
+
+use openfire::eurocode_1_1_2::conduction::{
+    material as rust_material,
+    wall::{self as rust_wall, Layer, Wall},
+};
+
+use crate::history::History;
+
+fn parse_material(material: &str) -> PyResult<rust_material::Material> {
+    match material.to_lowercase().as_str() {
+        "concrete" => Ok(rust_material::Material::concrete()),
+        "gypsum" => Ok(rust_material::Material::Gypsum),
+        "mineral_wool" => Ok(rust_material::Material::MineralWool { rho: 100.0 }),
+        _ => Err(PyValueError::new_err(format!(
+            "Unknown material '{}'",
+            material
+        ))),
+    }
+}
+
+fn build_wall(layers: Vec<(String, f64)>, elements: usize) -> PyResult<Wall> {
+    if layers.is_empty() {
+        return Err(PyValueError::new_err("layers must not be empty"));
+    }
+    if elements == 0 {
+        return Err(PyValueError::new_err("elements must be at least 1"));
+    }
+    if layers.iter().any(|(_, thickness)| *thickness <= 0.0) {
+        return Err(PyValueError::new_err("Layer thicknesses must be positive"));
+    }
+
+    let layers = layers
+        .iter()
+        .map(|(material, thickness)| {
+            Ok(Layer {
+                material: parse_material(material)?,
+                thickness: *thickness,
+            })
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+
+    Ok(Wall::new(layers, elements))
+}
+
+fn solve(
+    layers: Vec<(String, f64)>,
+    gas_temperatures: &[f64],
+    delta_t: f64,
+    elements: usize,
+) -> PyResult<rust_wall::TemperatureProfiles> {
+    let gas = History::new("gas_temperatures", gas_temperatures, delta_t)?;
+    Ok(build_wall(layers, elements)?.solve(|t| gas.at(t), delta_t, gas.end()))
+}
+
+// Material module functions
+#[pyfunction]
+/// Thermal properties of a wall or slab material at a given temperature.
+///
+/// Concrete follows EN 1992-1-2 with 1.5 % moisture and the lower limit of
+/// thermal conductivity. Gypsum plasterboard and mineral wool (100 kg/m³) use
+/// typical effective values.
+///
+/// Args:
+///     material (str): Material name ("concrete", "gypsum" or "mineral_wool")
+///     theta (float): Temperature (°C)
+///
+/// Returns:
+///     tuple[float, float, float]: Thermal conductivity (W/mK), density (kg/m³) and specific heat (J/kgK)
+///
+/// Raises:
+///     ValueError: If the material is not recognised
+///
+/// Example:
+///     >>> import ofire
+///     >>> k, rho, c = ofire.eurocode_1_1_2.conduction.material.thermal_properties("concrete", 500.0)
+fn thermal_properties(material: &str, theta: f64) -> PyResult<(f64, f64, f64)> {
+    let material = parse_material(material)?;
+    Ok((
+        material.conductivity(theta),
+        material.density(theta),
+        material.specific_heat(theta),
+    ))
+}
+
+#[pymodule]
+/// Temperature dependent thermal properties of wall and slab materials.
+pub fn material(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(thermal_properties, m)?)?;
+    Ok(())
+}
+
+// Wall module functions
+#[pyfunction]
+#[pyo3(signature = (layers, gas_temperatures, delta_t, elements=10))]
+/// Temperature profiles through a layered wall or slab exposed to fire on one face.
+///
+/// The exposed face exchanges heat with the gas using equations 3.1 to 3.3 with
+/// a convective coefficient of 25 W/m²K and surface emissivity of 0.8. The
+/// unexposed face loses heat to 20 °C ambient with a convective coefficient of
+/// 4 W/m²K.
+///
+/// Args:
+///     layers (list[tuple[str, float]]): Material name and thickness (m) of each layer from the exposed face
+///     gas_temperatures (list[float]): Gas temperatures at times 0, delta_t, 2 delta_t, ... (°C)
+///     delta_t (float): Output time step (s)
+///     elements (int): Number of finite difference elements in each layer
+///
+/// Returns:
+///     tuple[list[float], list[list[float]]]: Node distances from the exposed face (m) and node temperatures (°C) at each time
+///
+/// Raises:
+///     ValueError: If a material is not recognised, there are no layers or gas
+///         temperatures, a thickness or delta_t is not positive or elements is zero
+///
+/// Example:
+///     >>> import ofire
+///     >>> curve = ofire.eurocode_1_1_2.section_3.equation_3_4.standard_temp_time_curve
+///     >>> gas = [curve(i) for i in range(121)]
+///     >>> x, temps = ofire.eurocode_1_1_2.conduction.wall.temperature_profiles([("concrete", 0.2)], gas, 60.0)
+fn temperature_profiles(
+    layers: Vec<(String, f64)>,
+    gas_temperatures: Vec<f64>,
+    delta_t: f64,
+    elements: usize,
+) -> PyResult<(Vec<f64>, Vec<Vec<f64>>)> {
+    let profiles = solve(layers, &gas_temperatures, delta_t, elements)?;
+    Ok((profiles.positions, profiles.temperatures))
+}
+
+#[pyfunction]
+#[pyo3(signature = (layers, gas_temperatures, delta_t, elements=10))]
+/// Unexposed face temperature of a layered wall or slab exposed to fire on one face.
+///
+/// Args:
+///     layers (list[tuple[str, float]]): Material name and thickness (m) of each layer from the exposed face
+///     gas_temperatures (list[float]): Gas temperatures at times 0, delta_t, 2 delta_t, ... (°C)
+///     delta_t (float): Output time step (s)
+///     elements (int): Number of finite difference elements in each layer
+///
+/// Returns:
+///     list[float]: Unexposed face temperatures at each time (°C)
+///
+/// Raises:
+///     ValueError: If a material is not recognised, there are no layers or gas
+///         temperatures, a thickness or delta_t is not positive or elements is zero
+///
+/// Example:
+///     >>> import ofire
+///     >>> curve = ofire.eurocode_1_1_2.section_3.equation_3_4.standard_temp_time_curve
+///     >>> gas = [curve(i) for i in range(121)]
+///     >>> result = ofire.eurocode_1_1_2.conduction.wall.unexposed_temperature([("concrete", 0.2)], gas, 60.0)
+fn unexposed_temperature(
+    layers: Vec<(String, f64)>,
+    gas_temperatures: Vec<f64>,
+    delta_t: f64,
+    elements: usize,
+) -> PyResult<Vec<f64>> {
+    Ok(solve(layers, &gas_temperatures, delta_t, elements)?.unexposed_temperature())
+}
+
+#[pyfunction]
+#[pyo3(signature = (layers, gas_temperatures, delta_t, rise=140.0, elements=10))]
+/// Time for the unexposed face temperature to rise by a given amount.
+///
+/// With the default rise of 140 K this gives the time to failure of the
+/// insulation criterion based on the average unexposed face temperature.
+///
+/// Args:
+///     layers (list[tuple[str, float]]): Material name and thickness (m) of each layer from the exposed face
+///     gas_temperatures (list[float]): Gas temperatures at times 0, delta_t, 2 delta_t, ... (°C)
+///     delta_t (float): Output time step (s)
+///     rise (float): Temperature rise of the unexposed face (K)
+///     elements (int): Number of finite difference elements in each layer
+///
+/// Returns:
+///     float | None: Time to reach the temperature rise (s), None if not reached
+///
+/// Raises:
+///     ValueError: If a material is not recognised, there are no layers or gas
+///         temperatures, a thickness or delta_t is not positive or elements is zero
+///
+/// Example:
+///     >>> import ofire
+///     >>> curve = ofire.eurocode_1_1_2.section_3.equation_3_4.standard_temp_time_curve
+///     >>> gas = [curve(i / 2.0) for i in range(241)]
+///     >>> layers = [("gypsum", 0.0125), ("mineral_wool", 0.05), ("gypsum", 0.0125)]
+///     >>> result = ofire.eurocode_1_1_2.conduction.wall.insulation_time(layers, gas, 30.0)
+fn insulation_time(
+    layers: Vec<(String, f64)>,
+    gas_temperatures: Vec<f64>,
+    delta_t: f64,
+    rise: f64,
+    elements: usize,
+) -> PyResult<Option<f64>> {
+    Ok(solve(layers, &gas_temperatures, delta_t, elements)?.insulation_time(rise))
+}
+
+#[pymodule]
+/// One-dimensional heat conduction through layered walls and slabs.
+pub fn wall(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(temperature_profiles, m)?)?;
+    m.add_function(wrap_pyfunction!(unexposed_temperature, m)?)?;
+    m.add_function(wrap_pyfunction!(insulation_time, m)?)?;
+    Ok(())
+}
+
+#[pymodule]
+/// Conduction - Temperature analysis of walls and slabs.
+///
+/// This module contains a finite difference solver for layered walls and slabs
+/// using the net heat flux of section 3 at the surfaces.
+pub fn conduction(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_wrapped(wrap_pymodule!(material))?;
+    m.add_wrapped(wrap_pymodule!(wall))?;
+
+    Ok(())
+}
@@ -0,0 +1,222 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use openfire::eurocode_1_1_2::fire_curve::FireCurve;
+
+pub(crate) fn parse_fire_curve(
+    curve: &str,
+    points: Option<Vec<(f64, f64)>>,
+) -> PyResult<FireCurve> {
+    match curve.to_lowercase().as_str() {
+        "standard" => Ok(FireCurve::Standard),
+        "external" => Ok(FireCurve::External),
+        "hydrocarbon" => Ok(FireCurve::Hydrocarbon),
+        "astm_e119" => Ok(FireCurve::AstmE119),
+        "iso_834" => Ok(FireCurve::Iso834Tabulated),
+        "rws" => Ok(FireCurve::Rws),
+        "piecewise" => points
+            .and_then(FireCurve::piecewise_linear)
+            .ok_or_else(|| {
+                PyValueError::new_err(
+                    "A piecewise curve requires a list of (time, temperature) points in increasing time",
+                )
+            }),
+        _ => Err(PyValueError::new_err(format!(
+            "Unknown fire curve '{}'",
+            curve
+        ))),
+    }
+}
+
+fn check_step(step: f64) -> PyResult<()> {
+    if step <= 0.0 {
+        return Err(PyValueError::new_err("step must be positive"));
+    }
+    Ok(())
+}
+
+#[pyfunction]
+#[pyo3(signature = (curve, t, points=None))]
+/// Gas temperature of a fire curve.
+///
+/// Args:
+///     curve (str): Fire curve ("standard", "external", "hydrocarbon", "astm_e119",
+///         "iso_834", "rws" or "piecewise")
+///     t (float): Time (min)
+///     points (list[tuple[float, float]], optional): Time (min) and temperature (°C)
+///         points of a piecewise curve
+///
+/// Returns:
+///     float: Gas temperature (°C)
+///
+/// Raises:
+///     ValueError: If the curve is not recognised or points are missing or unsorted for a
+///         piecewise curve
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.fire_curve.temperature("rws", 4.0)
+fn temperature(curve: &str, t: f64, points: Option<Vec<(f64, f64)>>) -> PyResult<f64> {
+    Ok(parse_fire_curve(curve, points)?.temperature(t))
+}
+
+#[pyfunction]
+#[pyo3(signature = (curve, step, t_end, points=None))]
+/// Fire curve sampled at a constant time step.
+///
+/// Args:
+///     curve (str): Fire curve, as for ``temperature``
+///     step (float): Time step (min)
+///     t_end (float): End time (min)
+///     points (list[tuple[float, float]], optional): Points of a piecewise curve
+///
+/// Returns:
+///     list[tuple[float, float]]: Time (min) and gas temperature (°C) pairs
+///
+/// Raises:
+///     ValueError: If the curve is not recognised, points are missing or
+///         unsorted for a piecewise curve or step is not positive
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.fire_curve.sample("standard", 1.0, 60.0)
+fn sample(
+    curve: &str,
+    step: f64,
+    t_end: f64,
+    points: Option<Vec<(f64, f64)>>,
+) -> PyResult<Vec<(f64, f64)>> {
+    check_step(step)?;
+    Ok(parse_fire_curve(curve, points)?.sample(step, t_end))
+}
+
+#[pyfunction]
+#[pyo3(signature = (curve, step, t_end, points=None))]
+/// Fire curve sampled at a constant time step as comma separated values.
+///
+/// Args:
+///     curve (str): Fire curve, as for ``temperature``
+///     step (float): Time step (min)
+///     t_end (float): End time (min)
+///     points (list[tuple[float, float]], optional): Points of a piecewise curve
+///
+/// Returns:
+///     str: CSV text with a ``time_min,temperature_c`` header row
+///
+/// Raises:
+///     ValueError: If the curve is not recognised, points are missing or
+///         unsorted for a piecewise curve or step is not positive
+///
+/// Example:
+///     >>> import ofire
+///     >>> csv = ofire.eurocode_1_1_2.fire_curve.to_csv("hydrocarbon", 1.0, 120.0)
+fn to_csv(curve: &str, step: f64, t_end: f64, points: Option<Vec<(f64, f64)>>) -> PyResult<String> {
+    check_step(step)?;
+    Ok(parse_fire_curve(curve, points)?.to_csv(step, t_end))
+}
+
+#[pyfunction]
+#[pyo3(signature = (curve, theta, t_end, points=None))]
+/// Time for a fire curve to first reach a temperature.
+///
+/// Args:
+///     curve (str): Fire curve, as for ``temperature``
+///     theta (float): Temperature (°C)
+///     t_end (float): End of the search (min)
+///     points (list[tuple[float, float]], optional): Points of a piecewise curve
+///
+/// Returns:
+///     float | None: Time to reach the temperature (min), None if not reached
+///
+/// Raises:
+///     ValueError: If the curve is not recognised or points are missing or unsorted for a
+///         piecewise curve
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.fire_curve.time_to_temperature("standard", 900.0, 240.0)
+fn time_to_temperature(
+    curve: &str,
+    theta: f64,
+    t_end: f64,
+    points: Option<Vec<(f64, f64)>>,
+) -> PyResult<Option<f64>> {
+    Ok(parse_fire_curve(curve, points)?.time_to_temperature(theta, t_end))
+}
+
+#[pyfunction]
+#[pyo3(signature = (curve, t_end, theta_base=20.0, points=None))]
+/// Fire severity as the area under the curve above a base temperature.
+///
+/// .. math::
+///
+///    S = \int_0^{t_{end}} \max \left( \theta_g - \theta_{base}, 0 \right) dt
+///
+/// Args:
+///     curve (str): Fire curve, as for ``temperature``
+///     t_end (float): Duration (min)
+///     theta_base (float): Base temperature (°C)
+///     points (list[tuple[float, float]], optional): Points of a piecewise curve
+///
+/// Returns:
+///     float: Area under the curve (°C·min)
+///
+/// Raises:
+///     ValueError: If the curve is not recognised or points are missing or unsorted for a
+///         piecewise curve
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.fire_curve.severity("astm_e119", 60.0, 150.0)
+fn severity(
+    curve: &str,
+    t_end: f64,
+    theta_base: f64,
+    points: Option<Vec<(f64, f64)>>,
+) -> PyResult<f64> {
+    Ok(parse_fire_curve(curve, points)?.severity(t_end, theta_base))
+}
+
+#[pyfunction]
+#[pyo3(signature = (curve, t_end, theta_base=20.0, points=None))]
+/// Duration of the standard curve with equal severity (Ingberg).
+///
+/// Args:
+///     curve (str): Fire curve, as for ``temperature``
+///     t_end (float): Duration of the fire curve (min)
+///     theta_base (float): Base temperature (°C)
+///     points (list[tuple[float, float]], optional): Points of a piecewise curve
+///
+/// Returns:
+///     float: Equivalent duration of standard fire exposure (min)
+///
+/// Raises:
+///     ValueError: If the curve is not recognised or points are missing or unsorted for a
+///         piecewise curve
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_1_1_2.fire_curve.equivalent_standard_time("hydrocarbon", 60.0)
+fn equivalent_standard_time(
+    curve: &str,
+    t_end: f64,
+    theta_base: f64,
+    points: Option<Vec<(f64, f64)>>,
+) -> PyResult<f64> {
+    Ok(parse_fire_curve(curve, points)?.equivalent_standard_time(t_end, theta_base))
+}
+
+#[pymodule]
+/// Fire curves - Nominal, tabulated and user-defined temperature-time curves.
+///
+/// Curves can be sampled, inverted for the time to reach a temperature and
+/// compared by the area under the curve.
+pub fn fire_curve(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(temperature, m)?)?;
+    m.add_function(wrap_pyfunction!(sample, m)?)?;
+    m.add_function(wrap_pyfunction!(to_csv, m)?)?;
+    m.add_function(wrap_pyfunction!(time_to_temperature, m)?)?;
+    m.add_function(wrap_pyfunction!(severity, m)?)?;
+    m.add_function(wrap_pyfunction!(equivalent_standard_time, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,18 @@
+pub mod annex_b;
+pub mod section_3;
+
+use pyo3::prelude::*;
+
+// Sections and annexes are added with add_submodule as Eurocode 1-1-2 and
+// Eurocode 3-1-2 export pymodules with the same names.
+#[pymodule]
+pub fn eurocode_2_1_2(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let annex_b_module = PyModule::new_bound(m.py(), "annex_b")?;
+    annex_b::annex_b(&annex_b_module)?;
+    m.add_submodule(&annex_b_module)?;
+
+    let section_3_module = PyModule::new_bound(m.py(), "section_3")?;
+    section_3::section_3(&section_3_module)?;
+    m.add_submodule(&section_3_module)?;
+    Ok(())
+}
@@ -0,0 +1,150 @@
+use pyo3::prelude::*;
+use pyo3::wrap_pymodule;
+
+use openfire::eurocode_2_1_2::annex_b::isotherm_method as rust_isotherm_method;
+use openfire::eurocode_2_1_2::section_3::reinforcement_strength::Manufacture;
+
+use crate::eurocode_1_1_2::fire_curve::parse_fire_curve;
+
+// Isotherm method module functions
+#[pyfunction]
+#[pyo3(signature = (h, a, a_s, f_ck, f_yk, duration, curve="standard"))]
+/// Sagging moment resistance of a slab heated from below by the 500 °C
+/// isotherm method.
+///
+/// Concrete temperatures are calculated with the one-dimensional conduction
+/// solver of EN 1991-1-2 and the material partial factors are taken as 1.0.
+///
+/// Where the reinforcement force exceeds the compression the reduced section
+/// can carry, the force is limited to a compression zone over the full
+/// reduced depth.
+///
+/// Args:
+///     h (float): Slab depth (mm)
+///     a (float): Axis distance of the bottom reinforcement (mm)
+///     a_s (float): Area of reinforcement per metre width (mm²/m)
+///     f_ck (float): Characteristic compressive strength of concrete (MPa)
+///     f_yk (float): Characteristic yield strength of hot rolled reinforcement (MPa)
+///     duration (float): Fire duration (min)
+///     curve (str): Fire curve ("standard", "external", "hydrocarbon", "astm_e119",
+///         "iso_834" or "rws")
+///
+/// Returns:
+///     tuple[float, float, float]: Depth of the 500 °C isotherm (mm),
+///     reinforcement temperature (°C) and moment resistance (kNm/m)
+///
+/// Raises:
+///     ValueError: If the fire curve is not recognised
+///
+/// Example:
+///     >>> import ofire
+///     >>> a_500, theta_s, m_rd = ofire.eurocode_2_1_2.annex_b.isotherm_method.slab(200.0, 30.0, 524.0, 30.0, 500.0, 60.0)
+fn slab(
+    h: f64,
+    a: f64,
+    a_s: f64,
+    f_ck: f64,
+    f_yk: f64,
+    duration: f64,
+    curve: &str,
+) -> PyResult<(f64, f64, f64)> {
+    let method = rust_isotherm_method::IsothermMethod {
+        member: rust_isotherm_method::Member::Slab { h },
+        bars: vec![rust_isotherm_method::Bar {
+            area: a_s,
+            axis_bottom: a,
+            axis_side: 0.0,
+        }],
+        f_ck,
+        f_yk,
+        manufacture: Manufacture::HotRolled,
+    };
+    let result = method.analyse(&parse_fire_curve(curve, None)?, duration);
+    Ok((
+        result.a_500_bottom,
+        result.bar_temperatures[0],
+        result.moment_resistance,
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (b, h, bars, f_ck, f_yk, duration, curve="standard"))]
+/// Sagging moment resistance of a beam heated from below and both sides by
+/// the 500 °C isotherm method.
+///
+/// Bar temperatures combine the bottom and side temperature profiles and
+/// corner effects on the isotherm are neglected.
+///
+/// Where the reinforcement force exceeds the compression the reduced section
+/// can carry, the force is limited to a compression zone over the full
+/// reduced depth.
+///
+/// Args:
+///     b (float): Beam width (mm)
+///     h (float): Beam depth (mm)
+///     bars (list[tuple[float, float, float]]): Area (mm²), axis distance from the
+///         bottom (mm) and axis distance from the nearest side (mm) of each bar
+///     f_ck (float): Characteristic compressive strength of concrete (MPa)
+///     f_yk (float): Characteristic yield strength of hot rolled reinforcement (MPa)
+///     duration (float): Fire duration (min)
+///     curve (str): Fire curve ("standard", "external", "hydrocarbon", "astm_e119",
+///         "iso_834" or "rws")
+///
+/// Returns:
+///     tuple[float, float, list[float], float]: Reduced width (mm), reduced
+///     depth (mm), bar temperatures (°C) and moment resistance (kNm)
+///
+/// Raises:
+///     ValueError: If the fire curve is not recognised
+///
+/// Example:
+///     >>> import ofire
+///     >>> bars = [(314.0, 40.0, 40.0), (314.0, 40.0, 40.0)]
+///     >>> b_fi, h_fi, temperatures, m_rd = ofire.eurocode_2_1_2.annex_b.isotherm_method.beam(300.0, 500.0, bars, 30.0, 500.0, 90.0)
+fn beam(
+    b: f64,
+    h: f64,
+    bars: Vec<(f64, f64, f64)>,
+    f_ck: f64,
+    f_yk: f64,
+    duration: f64,
+    curve: &str,
+) -> PyResult<(f64, f64, Vec<f64>, f64)> {
+    let method = rust_isotherm_method::IsothermMethod {
+        member: rust_isotherm_method::Member::Beam { b, h },
+        bars: bars
+            .into_iter()
+            .map(|(area, axis_bottom, axis_side)| rust_isotherm_method::Bar {
+                area,
+                axis_bottom,
+                axis_side,
+            })
+            .collect(),
+        f_ck,
+        f_yk,
+        manufacture: Manufacture::HotRolled,
+    };
+    let result = method.analyse(&parse_fire_curve(curve, None)?, duration);
+    Ok((
+        result.b_fi,
+        result.h_fi,
+        result.bar_temperatures,
+        result.moment_resistance,
+    ))
+}
+
+#[pymodule]
+/// 500 °C isotherm method for reinforced concrete slabs and beams.
+pub fn isotherm_method(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(slab, m)?)?;
+    m.add_function(wrap_pyfunction!(beam, m)?)?;
+    Ok(())
+}
+
+/// Annex B - Simplified calculation methods.
+///
+/// This annex contains the 500 °C isotherm method.
+pub fn annex_b(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_wrapped(wrap_pymodule!(isotherm_method))?;
+    Ok(())
+}
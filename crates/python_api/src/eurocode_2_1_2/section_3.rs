@@ -0,0 +1,108 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::wrap_pymodule;
+
+use openfire::eurocode_2_1_2::section_3::{
+    concrete_strength as rust_concrete_strength,
+    reinforcement_strength as rust_reinforcement_strength,
+};
+
+fn parse_aggregate(aggregate: &str) -> PyResult<rust_concrete_strength::Aggregate> {
+    match aggregate.to_lowercase().as_str() {
+        "siliceous" => Ok(rust_concrete_strength::Aggregate::Siliceous),
+        "calcareous" => Ok(rust_concrete_strength::Aggregate::Calcareous),
+        _ => Err(PyValueError::new_err(format!(
+            "Unknown aggregate '{}'",
+            aggregate
+        ))),
+    }
+}
+
+fn parse_manufacture(manufacture: &str) -> PyResult<rust_reinforcement_strength::Manufacture> {
+    match manufacture.to_lowercase().as_str() {
+        "hot_rolled" => Ok(rust_reinforcement_strength::Manufacture::HotRolled),
+        "cold_worked" => Ok(rust_reinforcement_strength::Manufacture::ColdWorked),
+        _ => Err(PyValueError::new_err(format!(
+            "Unknown reinforcement manufacture '{}'",
+            manufacture
+        ))),
+    }
+}
+
+// Concrete strength module functions
+#[pyfunction]
+#[pyo3(signature = (theta, aggregate="siliceous"))]
+/// Reduction factor for the compressive strength of normal weight concrete.
+///
+/// Values are interpolated linearly between the tabulated temperatures.
+///
+/// Args:
+///     theta (float): Concrete temperature (°C)
+///     aggregate (str): "siliceous" or "calcareous"
+///
+/// Returns:
+///     float: Reduction factor k_c (dimensionless)
+///
+/// Raises:
+///     ValueError: If the aggregate is not recognised
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_2_1_2.section_3.concrete_strength.k_c(550.0)
+fn k_c(theta: f64, aggregate: &str) -> PyResult<f64> {
+    Ok(rust_concrete_strength::k_c(
+        theta,
+        parse_aggregate(aggregate)?,
+    ))
+}
+
+#[pymodule]
+/// Reduction of the compressive strength of concrete at elevated temperatures.
+pub fn concrete_strength(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(k_c, m)?)?;
+    Ok(())
+}
+
+// Reinforcement strength module functions
+#[pyfunction]
+#[pyo3(signature = (theta, manufacture="hot_rolled"))]
+/// Reduction factor for the yield strength of class N tension reinforcement.
+///
+/// Values are interpolated linearly between the tabulated temperatures.
+///
+/// Args:
+///     theta (float): Reinforcement temperature (°C)
+///     manufacture (str): "hot_rolled" or "cold_worked"
+///
+/// Returns:
+///     float: Reduction factor k_s (dimensionless)
+///
+/// Raises:
+///     ValueError: If the manufacture is not recognised
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_2_1_2.section_3.reinforcement_strength.k_s(550.0)
+fn k_s(theta: f64, manufacture: &str) -> PyResult<f64> {
+    Ok(rust_reinforcement_strength::k_s(
+        theta,
+        parse_manufacture(manufacture)?,
+    ))
+}
+
+#[pymodule]
+/// Reduction of the strength of reinforcing steel at elevated temperatures.
+pub fn reinforcement_strength(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(k_s, m)?)?;
+    Ok(())
+}
+
+/// Section 3 - Material properties.
+///
+/// This section contains the strength reduction factors of concrete and
+/// reinforcing steel.
+pub fn section_3(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_wrapped(wrap_pymodule!(concrete_strength))?;
+    m.add_wrapped(wrap_pymodule!(reinforcement_strength))?;
+    Ok(())
+}
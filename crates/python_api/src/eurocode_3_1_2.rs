@@ -0,0 +1,14 @@
+pub mod section_3;
+pub mod section_4;
+
+use pyo3::prelude::*;
+use pyo3::wrap_pymodule;
+
+#[pymodule]
+pub fn eurocode_3_1_2(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let section_3_module = PyModule::new_bound(m.py(), "section_3")?;
+    section_3::section_3(&section_3_module)?;
+    m.add_submodule(&section_3_module)?;
+    m.add_wrapped(wrap_pymodule!(section_4::section_4))?;
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use pyo3::prelude::*;
+use pyo3::wrap_pymodule;
+
+use openfire::eurocode_3_1_2::section_3::{
+    equation_3_2 as rust_equation_3_2, unit_mass as rust_unit_mass,
+};
+
+// Equation 3.2 module functions
+#[pyfunction]
+/// Specific heat of steel.
+///
+/// .. math::
+///
+///    c_a = \begin{cases}
+///    425 + 7.73 \times 10^{-1} \theta_a - 1.69 \times 10^{-3} \theta_a^2 + 2.22 \times 10^{-6} \theta_a^3 & 20 \leq \theta_a < 600 \\
+///    666 + \frac{13002}{738 - \theta_a} & 600 \leq \theta_a < 735 \\
+///    545 + \frac{17820}{\theta_a - 731} & 735 \leq \theta_a < 900 \\
+///    650 & 900 \leq \theta_a \leq 1200
+///    \end{cases}
+///
+/// Args:
+///     theta_a (float): Steel temperature (°C)
+///
+/// Returns:
+///     float: Specific heat of steel (J/kgK)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_3_1_2.section_3.equation_3_2.specific_heat(500.0)
+fn specific_heat(theta_a: f64) -> f64 {
+    rust_equation_3_2::specific_heat(theta_a)
+}
+
+// Equation 3.2 is added with add_submodule as Eurocode 1-1-2 section 3
+// exports a pymodule with the same name.
+/// Equation 3.2 - Specific heat of steel.
+pub fn equation_3_2(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(specific_heat, m)?)?;
+    Ok(())
+}
+
+// Unit mass module functions
+#[pyfunction]
+#[pyo3(name = "unit_mass")]
+/// Unit mass of steel, independent of the steel temperature.
+///
+/// Returns:
+///     float: Unit mass of steel (kg/m³)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_3_1_2.section_3.unit_mass.unit_mass()
+fn steel_unit_mass() -> f64 {
+    rust_unit_mass::unit_mass()
+}
+
+#[pymodule]
+/// Unit mass of steel.
+pub fn unit_mass(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(steel_unit_mass, m)?)?;
+    Ok(())
+}
+
+// Section 3 is added with add_submodule as Eurocode 1-1-2 exports a pymodule
+// with the same name.
+/// Section 3 - Material properties.
+///
+/// This section contains the thermal properties of steel at elevated temperatures.
+pub fn section_3(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let equation_3_2_module = PyModule::new_bound(m.py(), "equation_3_2")?;
+    equation_3_2(&equation_3_2_module)?;
+    m.add_submodule(&equation_3_2_module)?;
+    m.add_wrapped(wrap_pymodule!(unit_mass))?;
+
+    Ok(())
+}
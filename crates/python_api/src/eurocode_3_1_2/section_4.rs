@@ -0,0 +1,361 @@
+use pyo3::prelude::*;
+use pyo3::wrap_pymodule;
+
+use crate::history::History;
+
+use openfire::eurocode_3_1_2::section_4::{
+    equation_4_22 as rust_equation_4_22, equation_4_25 as rust_equation_4_25,
+    equation_4_26 as rust_equation_4_26, equation_4_27 as rust_equation_4_27,
+    member_temperature as rust_member_temperature,
+};
+
+// Equation 4.22 module functions
+#[pyfunction]
+/// Critical temperature of steel.
+///
+/// .. math::
+///
+///    \theta_{a,cr} = 39.19 \ln \left[ \frac{1}{0.9674 \mu_0^{3.833}} - 1 \right] + 482
+///
+/// Args:
+///     mu_0 (float): Degree of utilisation (dimensionless)
+///
+/// Returns:
+///     float: Critical temperature (°C)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_3_1_2.section_4.equation_4_22.critical_temperature(0.6)
+fn critical_temperature(mu_0: f64) -> f64 {
+    rust_equation_4_22::critical_temperature(mu_0)
+}
+
+#[pymodule]
+/// Equation 4.22 - Critical temperature of steel.
+pub fn equation_4_22(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(critical_temperature, m)?)?;
+    Ok(())
+}
+
+// Equation 4.25 module functions
+#[pyfunction]
+/// Increase of temperature of an unprotected steel member during a time interval.
+///
+/// .. math::
+///
+///    \Delta \theta_{a,t} = k_{sh} \frac{A_m / V}{c_a \rho_a} \dot{h}_{net} \Delta t
+///
+/// Args:
+///     k_sh (float): Correction factor for the shadow effect (dimensionless)
+///     a_m_v (float): Section factor (1/m)
+///     c_a (float): Specific heat of steel (J/kgK)
+///     rho_a (float): Unit mass of steel (kg/m³)
+///     h_net (float): Net heat flux per unit area (W/m²)
+///     delta_t (float): Time interval (s)
+///
+/// Returns:
+///     float: Increase of steel temperature (°C)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_3_1_2.section_4.equation_4_25.temperature_increase_unprotected(1.0, 200.0, 600.0, 7850.0, 50000.0, 5.0)
+fn temperature_increase_unprotected(
+    k_sh: f64,
+    a_m_v: f64,
+    c_a: f64,
+    rho_a: f64,
+    h_net: f64,
+    delta_t: f64,
+) -> f64 {
+    rust_equation_4_25::temperature_increase_unprotected(k_sh, a_m_v, c_a, rho_a, h_net, delta_t)
+}
+
+#[pymodule]
+/// Equation 4.25 - Temperature increase of unprotected steel members.
+pub fn equation_4_25(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(temperature_increase_unprotected, m)?)?;
+    Ok(())
+}
+
+// Equation 4.26 module functions
+#[pyfunction]
+/// Correction factor for the shadow effect of I-sections under nominal fire actions.
+///
+/// .. math::
+///
+///    k_{sh} = 0.9 \frac{[A_m / V]_b}{A_m / V}
+///
+/// Args:
+///     a_m_v_b (float): Box value of the section factor (1/m)
+///     a_m_v (float): Section factor (1/m)
+///
+/// Returns:
+///     float: Correction factor for the shadow effect (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_3_1_2.section_4.equation_4_26.shadow_factor_i_section(140.0, 180.0)
+fn shadow_factor_i_section(a_m_v_b: f64, a_m_v: f64) -> f64 {
+    rust_equation_4_26::shadow_factor_i_section(a_m_v_b, a_m_v)
+}
+
+#[pyfunction]
+/// Correction factor for the shadow effect of all other cases.
+///
+/// .. math::
+///
+///    k_{sh} = \frac{[A_m / V]_b}{A_m / V}
+///
+/// Args:
+///     a_m_v_b (float): Box value of the section factor (1/m)
+///     a_m_v (float): Section factor (1/m)
+///
+/// Returns:
+///     float: Correction factor for the shadow effect (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_3_1_2.section_4.equation_4_26.shadow_factor(140.0, 200.0)
+fn shadow_factor(a_m_v_b: f64, a_m_v: f64) -> f64 {
+    rust_equation_4_26::shadow_factor(a_m_v_b, a_m_v)
+}
+
+#[pymodule]
+/// Equation 4.26 - Correction factor for the shadow effect.
+pub fn equation_4_26(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(shadow_factor_i_section, m)?)?;
+    m.add_function(wrap_pyfunction!(shadow_factor, m)?)?;
+    Ok(())
+}
+
+// Equation 4.27 module functions
+#[pyfunction]
+/// Increase of temperature of an insulated steel member during a time interval.
+///
+/// .. math::
+///
+///    \Delta \theta_{a,t} = k_p \frac{\theta_{g,t} - \theta_{a,t}}{1 + \phi / 3} \Delta t - \left( e^{\phi / 10} - 1 \right) \Delta \theta_{g,t}
+///
+/// The result is not negative while the gas temperature increases.
+///
+/// Args:
+///     k_p (float): Heat transfer factor (1/s)
+///     phi (float): Ratio of heat stored in the protection to that in the steel (dimensionless)
+///     theta_g (float): Gas temperature (°C)
+///     theta_a (float): Steel temperature (°C)
+///     delta_t (float): Time interval (s)
+///     delta_theta_g (float): Increase of gas temperature during the interval (°C)
+///
+/// Returns:
+///     float: Increase of steel temperature (°C)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_3_1_2.section_4.equation_4_27.temperature_increase_protected(2e-4, 0.3, 800.0, 200.0, 30.0, 5.0)
+fn temperature_increase_protected(
+    k_p: f64,
+    phi: f64,
+    theta_g: f64,
+    theta_a: f64,
+    delta_t: f64,
+    delta_theta_g: f64,
+) -> f64 {
+    rust_equation_4_27::temperature_increase_protected(
+        k_p,
+        phi,
+        theta_g,
+        theta_a,
+        delta_t,
+        delta_theta_g,
+    )
+}
+
+#[pyfunction]
+/// Heat transfer factor of an insulated steel member.
+///
+/// .. math::
+///
+///    k_p = \frac{\lambda_p A_p / V}{d_p c_a \rho_a}
+///
+/// Args:
+///     lambda_p (float): Thermal conductivity of the protection (W/mK)
+///     a_p_v (float): Section factor of the protected member (1/m)
+///     d_p (float): Thickness of the protection (m)
+///     c_a (float): Specific heat of steel (J/kgK)
+///     rho_a (float): Unit mass of steel (kg/m³)
+///
+/// Returns:
+///     float: Heat transfer factor (1/s)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_3_1_2.section_4.equation_4_27.heat_transfer_factor(0.12, 150.0, 0.02, 600.0, 7850.0)
+fn heat_transfer_factor(lambda_p: f64, a_p_v: f64, d_p: f64, c_a: f64, rho_a: f64) -> f64 {
+    rust_equation_4_27::heat_transfer_factor(lambda_p, a_p_v, d_p, c_a, rho_a)
+}
+
+#[pyfunction]
+/// Ratio of the heat stored in the protection to that stored in the steel.
+///
+/// .. math::
+///
+///    \phi = \frac{c_p \rho_p}{c_a \rho_a} d_p A_p / V
+///
+/// Args:
+///     c_p (float): Specific heat of the protection (J/kgK)
+///     rho_p (float): Density of the protection (kg/m³)
+///     d_p (float): Thickness of the protection (m)
+///     a_p_v (float): Section factor of the protected member (1/m)
+///     c_a (float): Specific heat of steel (J/kgK)
+///     rho_a (float): Unit mass of steel (kg/m³)
+///
+/// Returns:
+///     float: Phi factor (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_3_1_2.section_4.equation_4_27.phi_factor(1200.0, 300.0, 0.02, 150.0, 600.0, 7850.0)
+fn phi_factor(c_p: f64, rho_p: f64, d_p: f64, a_p_v: f64, c_a: f64, rho_a: f64) -> f64 {
+    rust_equation_4_27::phi_factor(c_p, rho_p, d_p, a_p_v, c_a, rho_a)
+}
+
+#[pymodule]
+/// Equation 4.27 - Temperature increase of insulated steel members.
+pub fn equation_4_27(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(temperature_increase_protected, m)?)?;
+    m.add_function(wrap_pyfunction!(heat_transfer_factor, m)?)?;
+    m.add_function(wrap_pyfunction!(phi_factor, m)?)?;
+    Ok(())
+}
+
+// Member temperature module functions
+fn member(
+    section_factor: f64,
+    k_sh: f64,
+    insulation: Option<(f64, f64, f64, f64)>,
+) -> rust_member_temperature::SteelMember {
+    match insulation {
+        Some((lambda_p, c_p, rho_p, d_p)) => rust_member_temperature::SteelMember::protected(
+            section_factor,
+            rust_member_temperature::Insulation {
+                lambda_p,
+                c_p,
+                rho_p,
+                d_p,
+            },
+        ),
+        None => rust_member_temperature::SteelMember::unprotected(section_factor, k_sh),
+    }
+}
+
+#[pyfunction]
+/// Steel temperature history for a sampled gas temperature curve.
+///
+/// The steel temperature is stepped with equation 4.25 for unprotected members
+/// and equation 4.27 for insulated members, starting from 20 °C. Time steps
+/// longer than 5 s (unprotected) or 30 s (insulated) are split into sub-steps
+/// within the limit, interpolating the gas temperatures linearly.
+///
+/// Args:
+///     section_factor (float): Section factor Am/V, or Ap/V for insulated members (1/m)
+///     gas_temperatures (list[float]): Gas temperatures at times 0, delta_t, 2 delta_t, ... (°C)
+///     delta_t (float): Time step (s)
+///     k_sh (float): Correction factor for the shadow effect of unprotected members
+///     insulation (tuple[float, float, float, float], optional): Thermal conductivity
+///         (W/mK), specific heat (J/kgK), density (kg/m³) and thickness (m) of the
+///         protection, None for unprotected members
+///
+/// Returns:
+///     list[float]: Steel temperatures at the same times as the gas temperatures (°C)
+///
+/// Raises:
+///     ValueError: If gas_temperatures is empty or delta_t is not positive
+///
+/// Example:
+///     >>> import ofire
+///     >>> curve = ofire.eurocode_1_1_2.section_3.equation_3_4.standard_temp_time_curve
+///     >>> gas = [curve(i * 5.0 / 60.0) for i in range(361)]
+///     >>> result = ofire.eurocode_3_1_2.section_4.member_temperature.temperature_history(200.0, gas, 5.0)
+#[pyo3(signature = (section_factor, gas_temperatures, delta_t, k_sh=1.0, insulation=None))]
+fn temperature_history(
+    section_factor: f64,
+    gas_temperatures: Vec<f64>,
+    delta_t: f64,
+    k_sh: f64,
+    insulation: Option<(f64, f64, f64, f64)>,
+) -> PyResult<Vec<f64>> {
+    let gas = History::new("gas_temperatures", &gas_temperatures, delta_t)?;
+    Ok(
+        member(section_factor, k_sh, insulation).temperature_history(
+            |t| gas.at(t),
+            delta_t,
+            gas.end(),
+        ),
+    )
+}
+
+#[pyfunction]
+/// Time for the steel to reach a critical temperature.
+///
+/// Args:
+///     section_factor (float): Section factor Am/V, or Ap/V for insulated members (1/m)
+///     gas_temperatures (list[float]): Gas temperatures at times 0, delta_t, 2 delta_t, ... (°C)
+///     delta_t (float): Time step (s)
+///     theta_cr (float): Critical temperature (°C)
+///     k_sh (float): Correction factor for the shadow effect of unprotected members
+///     insulation (tuple[float, float, float, float], optional): Protection properties
+///         as for ``temperature_history``
+///
+/// Returns:
+///     float | None: Time to reach the critical temperature (s), None if not reached
+///
+/// Raises:
+///     ValueError: If gas_temperatures is empty or delta_t is not positive
+///
+/// Example:
+///     >>> import ofire
+///     >>> curve = ofire.eurocode_1_1_2.section_3.equation_3_4.standard_temp_time_curve
+///     >>> gas = [curve(i * 5.0 / 60.0) for i in range(721)]
+///     >>> result = ofire.eurocode_3_1_2.section_4.member_temperature.time_to_temperature(200.0, gas, 5.0, 550.0)
+#[pyo3(signature = (section_factor, gas_temperatures, delta_t, theta_cr, k_sh=1.0, insulation=None))]
+fn time_to_temperature(
+    section_factor: f64,
+    gas_temperatures: Vec<f64>,
+    delta_t: f64,
+    theta_cr: f64,
+    k_sh: f64,
+    insulation: Option<(f64, f64, f64, f64)>,
+) -> PyResult<Option<f64>> {
+    let gas = History::new("gas_temperatures", &gas_temperatures, delta_t)?;
+    Ok(
+        member(section_factor, k_sh, insulation).time_to_temperature(
+            |t| gas.at(t),
+            theta_cr,
+            delta_t,
+            gas.end(),
+        ),
+    )
+}
+
+#[pymodule]
+/// Incremental temperature of steel members exposed to a gas temperature curve.
+pub fn member_temperature(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(temperature_history, m)?)?;
+    m.add_function(wrap_pyfunction!(time_to_temperature, m)?)?;
+    Ok(())
+}
+
+#[pymodule]
+/// Section 4 - Structural fire design.
+///
+/// This section contains the critical temperature and the temperature development
+/// of unprotected and insulated steel members.
+pub fn section_4(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_wrapped(wrap_pymodule!(equation_4_22))?;
+    m.add_wrapped(wrap_pymodule!(equation_4_25))?;
+    m.add_wrapped(wrap_pymodule!(equation_4_26))?;
+    m.add_wrapped(wrap_pymodule!(equation_4_27))?;
+    m.add_wrapped(wrap_pymodule!(member_temperature))?;
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+pub mod annex_a;
+pub mod section_3;
+pub mod section_4;
+
+use pyo3::prelude::*;
+
+// Sections and annexes are added with add_submodule as Eurocode 1-1-2 and
+// Eurocode 3-1-2 export pymodules with the same names.
+#[pymodule]
+pub fn eurocode_5_1_2(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let annex_a_module = PyModule::new_bound(m.py(), "annex_a")?;
+    annex_a::annex_a(&annex_a_module)?;
+    m.add_submodule(&annex_a_module)?;
+
+    let section_3_module = PyModule::new_bound(m.py(), "section_3")?;
+    section_3::section_3(&section_3_module)?;
+    m.add_submodule(&section_3_module)?;
+
+    let section_4_module = PyModule::new_bound(m.py(), "section_4")?;
+    section_4::section_4(&section_4_module)?;
+    m.add_submodule(&section_4_module)?;
+    Ok(())
+}
@@ -0,0 +1,169 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::wrap_pymodule;
+
+use crate::eurocode_1_1_2::annex_a::parse_growth_rate;
+use openfire::eurocode_1_1_2::annex_a::parametric_curve::ParametricFire;
+use openfire::eurocode_5_1_2::annex_a::{
+    equation_a1 as rust_equation_a1, equation_a2 as rust_equation_a2,
+    equation_a3 as rust_equation_a3, parametric_charring as rust_parametric_charring,
+};
+
+// Equation A.1 module functions
+#[pyfunction]
+/// Charring depth of members exposed to a parametric fire.
+///
+/// .. math::
+///
+///    d_{char} = \begin{cases}
+///    \beta_{par} t & t \leq t_0 \\
+///    \beta_{par} \left( 1.5 t - \frac{t^2}{4 t_0} - \frac{t_0}{4} \right) & t_0 \leq t \leq 3 t_0 \\
+///    2 \beta_{par} t_0 & 3 t_0 \leq t \leq 5 t_0
+///    \end{cases}
+///
+/// Args:
+///     beta_par (float): Charring rate during the heating phase (mm/min)
+///     t (float): Time (min)
+///     t_0 (float): Time with constant charring rate (min)
+///
+/// Returns:
+///     float: Charring depth (mm)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_5_1_2.annex_a.equation_a1.charring_depth(0.9, 40.0, 20.0)
+fn charring_depth(beta_par: f64, t: f64, t_0: f64) -> f64 {
+    rust_equation_a1::charring_depth(beta_par, t, t_0)
+}
+
+/// Equation A.1 - Charring depth under parametric fire exposure.
+pub fn equation_a1(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(charring_depth, m)?)?;
+    Ok(())
+}
+
+// Equation A.2 module functions
+#[pyfunction]
+/// Charring rate during the heating phase of a parametric fire.
+///
+/// .. math::
+///
+///    \beta_{par} = 1.5 \beta_n \frac{0.2 \sqrt{\Gamma} - 0.04}{0.16 \sqrt{\Gamma} + 0.08}
+///
+/// Args:
+///     beta_n (float): Notional design charring rate (mm/min)
+///     gamma (float): Factor Γ of EN 1991-1-2 equation A.2 (dimensionless)
+///
+/// Returns:
+///     float: Charring rate (mm/min)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_5_1_2.annex_a.equation_a2.charring_rate_parametric(0.7, 4.0)
+fn charring_rate_parametric(beta_n: f64, gamma: f64) -> f64 {
+    rust_equation_a2::charring_rate_parametric(beta_n, gamma)
+}
+
+/// Equation A.2 - Charring rate under parametric fire exposure.
+pub fn equation_a2(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(charring_rate_parametric, m)?)?;
+    Ok(())
+}
+
+// Equation A.3 module functions
+#[pyfunction]
+/// Time with constant charring rate under parametric fire exposure.
+///
+/// .. math::
+///
+///    t_0 = 0.009 \frac{q_{t,d}}{O}
+///
+/// Args:
+///     q_td (float): Design fire load density related to the total enclosure area (MJ/m²)
+///     o (float): Opening factor (m^1/2)
+///
+/// Returns:
+///     float: Time with constant charring rate (min)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_5_1_2.annex_a.equation_a3.time_constant_charring(200.0, 0.06)
+fn time_constant_charring(q_td: f64, o: f64) -> f64 {
+    rust_equation_a3::time_constant_charring(q_td, o)
+}
+
+/// Equation A.3 - Time with constant charring rate.
+pub fn equation_a3(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(time_constant_charring, m)?)?;
+    Ok(())
+}
+
+// Parametric charring module functions
+#[pyfunction]
+#[pyo3(name = "charring_depth", signature = (o, b, q_td, beta_n, t, growth_rate="medium"))]
+/// Charring depth of unprotected timber exposed to an EN 1991-1-2 parametric fire.
+///
+/// Args:
+///     o (float): Opening factor (m^1/2)
+///     b (float): Thermal absorptivity of the enclosure (J/m²s^1/2K)
+///     q_td (float): Design fire load density related to the total enclosure area (MJ/m²)
+///     beta_n (float): Notional design charring rate (mm/min)
+///     t (float): Time (min)
+///     growth_rate (str): Fire growth rate ("slow", "medium" or "fast")
+///
+/// Returns:
+///     float: Charring depth (mm)
+///
+/// Raises:
+///     ValueError: If the growth rate is not recognised or ``o`` or ``b`` is not positive
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_5_1_2.annex_a.parametric_charring.charring_depth(0.06, 1000.0, 300.0, 0.7, 30.0)
+fn parametric_charring_depth(
+    o: f64,
+    b: f64,
+    q_td: f64,
+    beta_n: f64,
+    t: f64,
+    growth_rate: &str,
+) -> PyResult<f64> {
+    let growth_rate = parse_growth_rate(growth_rate)?;
+    if o.is_nan() || o <= 0.0 || b.is_nan() || b <= 0.0 {
+        return Err(PyValueError::new_err(
+            "the opening factor and thermal absorptivity must be positive",
+        ));
+    }
+    let fire = ParametricFire::new(o, b, q_td, growth_rate);
+    Ok(rust_parametric_charring::ParametricCharring::new(&fire, beta_n).charring_depth(t))
+}
+
+#[pymodule]
+/// Charring of unprotected timber exposed to a parametric fire.
+pub fn parametric_charring(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(parametric_charring_depth, m)?)?;
+    Ok(())
+}
+
+/// Annex A - Parametric fire exposure.
+///
+/// This annex contains the charring of timber members exposed to parametric fires.
+pub fn annex_a(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    // Equations A.1 to A.3 are added with add_submodule as BR 187 appendix A
+    // exports pymodules with the same names.
+    let equation_a1_module = PyModule::new_bound(m.py(), "equation_a1")?;
+    equation_a1(&equation_a1_module)?;
+    m.add_submodule(&equation_a1_module)?;
+
+    let equation_a2_module = PyModule::new_bound(m.py(), "equation_a2")?;
+    equation_a2(&equation_a2_module)?;
+    m.add_submodule(&equation_a2_module)?;
+
+    let equation_a3_module = PyModule::new_bound(m.py(), "equation_a3")?;
+    equation_a3(&equation_a3_module)?;
+    m.add_submodule(&equation_a3_module)?;
+
+    m.add_wrapped(wrap_pymodule!(parametric_charring))?;
+
+    Ok(())
+}
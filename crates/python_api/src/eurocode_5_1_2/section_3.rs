@@ -0,0 +1,484 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::wrap_pymodule;
+
+use openfire::eurocode_5_1_2::section_3::{
+    charring as rust_charring, charring_rates as rust_charring_rates,
+    equation_3_1 as rust_equation_3_1, equation_3_2 as rust_equation_3_2,
+    equation_3_8 as rust_equation_3_8, equation_3_9 as rust_equation_3_9,
+    equation_3_10 as rust_equation_3_10, equation_3_11 as rust_equation_3_11,
+    protection_factors as rust_protection_factors,
+};
+
+fn parse_timber_product(product: &str) -> PyResult<rust_charring_rates::TimberProduct> {
+    match product.to_lowercase().as_str() {
+        "glued_laminated_softwood" => {
+            Ok(rust_charring_rates::TimberProduct::GluedLaminatedSoftwood)
+        }
+        "solid_softwood" => Ok(rust_charring_rates::TimberProduct::SolidSoftwood),
+        "hardwood_290" => Ok(rust_charring_rates::TimberProduct::Hardwood290),
+        "hardwood_450" => Ok(rust_charring_rates::TimberProduct::Hardwood450),
+        "lvl" => Ok(rust_charring_rates::TimberProduct::Lvl),
+        "wood_panelling" => Ok(rust_charring_rates::TimberProduct::WoodPanelling),
+        "plywood" => Ok(rust_charring_rates::TimberProduct::Plywood),
+        "wood_based_panel" => Ok(rust_charring_rates::TimberProduct::WoodBasedPanel),
+        _ => Err(PyValueError::new_err(format!(
+            "Unknown timber product '{}'",
+            product
+        ))),
+    }
+}
+
+pub(crate) fn parse_protection(
+    protection: &str,
+    h_p: f64,
+    t_f: Option<f64>,
+    beta_0: f64,
+) -> PyResult<rust_charring::Protection> {
+    match protection.to_lowercase().as_str() {
+        "none" => Ok(rust_charring::Protection::None),
+        "wood_panel" => Ok(rust_charring::Protection::WoodPanel { h_p, beta_0 }),
+        "gypsum_a" => Ok(rust_charring::Protection::GypsumTypeA { h_p }),
+        "gypsum_f" => match t_f {
+            Some(t_f) => Ok(rust_charring::Protection::GypsumTypeF { h_p, t_f }),
+            None => Err(PyValueError::new_err(
+                "Gypsum plasterboard type F requires the failure time t_f",
+            )),
+        },
+        _ => Err(PyValueError::new_err(format!(
+            "Unknown protection '{}'",
+            protection
+        ))),
+    }
+}
+
+// Charring rates module functions
+#[pyfunction]
+/// One-dimensional design charring rate of timber, LVL and wood-based panels.
+///
+/// Args:
+///     product (str): Timber product ("glued_laminated_softwood", "solid_softwood",
+///         "hardwood_290", "hardwood_450", "lvl", "wood_panelling", "plywood" or
+///         "wood_based_panel")
+///
+/// Returns:
+///     float: One-dimensional design charring rate (mm/min)
+///
+/// Raises:
+///     ValueError: If the product is not recognised
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_5_1_2.section_3.charring_rates.beta_0("glued_laminated_softwood")
+fn beta_0(product: &str) -> PyResult<f64> {
+    Ok(parse_timber_product(product)?.beta_0())
+}
+
+#[pyfunction]
+/// Notional design charring rate of timber and LVL.
+///
+/// Args:
+///     product (str): Timber product, as for ``beta_0``
+///
+/// Returns:
+///     float | None: Notional design charring rate (mm/min), None for panels
+///
+/// Raises:
+///     ValueError: If the product is not recognised
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_5_1_2.section_3.charring_rates.beta_n("solid_softwood")
+fn beta_n(product: &str) -> PyResult<Option<f64>> {
+    Ok(parse_timber_product(product)?.beta_n())
+}
+
+#[pyfunction]
+/// One-dimensional charring rate of a panel corrected for density and thickness.
+///
+/// .. math::
+///
+///    \beta_{0,\rho,t} = \beta_0 \sqrt{\frac{450}{\rho_k}} \max \left( \sqrt{\frac{20}{h_p}}; 1 \right)
+///
+/// Args:
+///     beta_0_tab (float): Tabulated charring rate for 450 kg/m³ and 20 mm (mm/min)
+///     rho_k (float): Characteristic density (kg/m³)
+///     h_p (float): Panel thickness (mm)
+///
+/// Returns:
+///     float: Charring rate of the panel (mm/min)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_5_1_2.section_3.charring_rates.panel_charring_rate(1.0, 450.0, 15.0)
+fn panel_charring_rate(beta_0_tab: f64, rho_k: f64, h_p: f64) -> f64 {
+    rust_charring_rates::panel_charring_rate(beta_0_tab, rho_k, h_p)
+}
+
+#[pymodule]
+/// Design charring rates of timber, LVL and wood-based panels.
+pub fn charring_rates(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(beta_0, m)?)?;
+    m.add_function(wrap_pyfunction!(beta_n, m)?)?;
+    m.add_function(wrap_pyfunction!(panel_charring_rate, m)?)?;
+    Ok(())
+}
+
+// Equation 3.1 module functions
+#[pyfunction]
+/// Design charring depth for one-dimensional charring.
+///
+/// .. math::
+///
+///    d_{char,0} = \beta_0 t
+///
+/// Args:
+///     beta_0 (float): One-dimensional design charring rate (mm/min)
+///     t (float): Time of fire exposure (min)
+///
+/// Returns:
+///     float: Design charring depth (mm)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_5_1_2.section_3.equation_3_1.charring_depth_one_dimensional(0.65, 60.0)
+fn charring_depth_one_dimensional(beta_0: f64, t: f64) -> f64 {
+    rust_equation_3_1::charring_depth_one_dimensional(beta_0, t)
+}
+
+/// Equation 3.1 - One-dimensional charring depth.
+pub fn equation_3_1(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(charring_depth_one_dimensional, m)?)?;
+    Ok(())
+}
+
+// Equation 3.2 module functions
+#[pyfunction]
+/// Notional design charring depth including corner roundings and fissures.
+///
+/// .. math::
+///
+///    d_{char,n} = \beta_n t
+///
+/// Args:
+///     beta_n (float): Notional design charring rate (mm/min)
+///     t (float): Time of fire exposure (min)
+///
+/// Returns:
+///     float: Notional design charring depth (mm)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_5_1_2.section_3.equation_3_2.charring_depth_notional(0.7, 60.0)
+fn charring_depth_notional(beta_n: f64, t: f64) -> f64 {
+    rust_equation_3_2::charring_depth_notional(beta_n, t)
+}
+
+/// Equation 3.2 - Notional charring depth.
+pub fn equation_3_2(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(charring_depth_notional, m)?)?;
+    Ok(())
+}
+
+// Equation 3.8 module functions
+#[pyfunction]
+/// Time limit of the increased charring rate when the protection fails at the start of charring.
+///
+/// .. math::
+///
+///    t_a = \min \left\{ 2 t_f; \frac{25}{k_3 \beta_n} + t_f \right\}
+///
+/// Args:
+///     t_f (float): Failure time of the protection (min)
+///     k_3 (float): Factor for the increased charring rate
+///     beta_n (float): Notional design charring rate (mm/min)
+///
+/// Returns:
+///     float: Time limit (min)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_5_1_2.section_3.equation_3_8.time_limit(21.0, 2.0, 0.7)
+fn time_limit(t_f: f64, k_3: f64, beta_n: f64) -> f64 {
+    rust_equation_3_8::time_limit(t_f, k_3, beta_n)
+}
+
+#[pymodule]
+/// Equation 3.8 - Time limit of increased charring.
+pub fn equation_3_8(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(time_limit, m)?)?;
+    Ok(())
+}
+
+// Equation 3.9 module functions
+#[pyfunction]
+/// Time limit of the increased charring rate when charring starts before the protection fails.
+///
+/// .. math::
+///
+///    t_a = \frac{25 - (t_f - t_{ch}) k_2 \beta_n}{k_3 \beta_n} + t_f
+///
+/// Args:
+///     t_ch (float): Start time of charring (min)
+///     t_f (float): Failure time of the protection (min)
+///     k_2 (float): Insulation factor of the protection
+///     k_3 (float): Factor for the increased charring rate
+///     beta_n (float): Notional design charring rate (mm/min)
+///
+/// Returns:
+///     float: Time limit (min)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_5_1_2.section_3.equation_3_9.time_limit_protected(28.0, 45.0, 0.73, 2.0, 0.7)
+fn time_limit_protected(t_ch: f64, t_f: f64, k_2: f64, k_3: f64, beta_n: f64) -> f64 {
+    rust_equation_3_9::time_limit_protected(t_ch, t_f, k_2, k_3, beta_n)
+}
+
+#[pymodule]
+/// Equation 3.9 - Time limit of increased charring behind protection.
+pub fn equation_3_9(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(time_limit_protected, m)?)?;
+    Ok(())
+}
+
+// Equation 3.10 module functions
+#[pyfunction]
+/// Start time of charring behind wood-based panel protection.
+///
+/// .. math::
+///
+///    t_{ch} = \frac{h_p}{\beta_0} - 4
+///
+/// Args:
+///     h_p (float): Panel thickness (mm)
+///     beta_0 (float): One-dimensional charring rate of the panel (mm/min)
+///
+/// Returns:
+///     float: Start time of charring (min)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_5_1_2.section_3.equation_3_10.start_of_charring_wood_panel(18.0, 0.9)
+fn start_of_charring_wood_panel(h_p: f64, beta_0: f64) -> f64 {
+    rust_equation_3_10::start_of_charring_wood_panel(h_p, beta_0)
+}
+
+#[pymodule]
+/// Equation 3.10 - Start of charring behind wood-based panels.
+pub fn equation_3_10(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(start_of_charring_wood_panel, m)?)?;
+    Ok(())
+}
+
+// Equation 3.11 module functions
+#[pyfunction]
+/// Start time of charring behind gypsum plasterboard of type A, F or H.
+///
+/// .. math::
+///
+///    t_{ch} = 2.8 h_p - 14
+///
+/// Args:
+///     h_p (float): Board thickness (mm)
+///
+/// Returns:
+///     float: Start time of charring (min)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_5_1_2.section_3.equation_3_11.start_of_charring_gypsum(12.5)
+fn start_of_charring_gypsum(h_p: f64) -> f64 {
+    rust_equation_3_11::start_of_charring_gypsum(h_p)
+}
+
+#[pymodule]
+/// Equation 3.11 - Start of charring behind gypsum plasterboard.
+pub fn equation_3_11(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(start_of_charring_gypsum, m)?)?;
+    Ok(())
+}
+
+// Protection factors module functions
+#[pyfunction]
+/// Factor on the charring rate behind gypsum plasterboard type F before it fails.
+///
+/// .. math::
+///
+///    k_2 = 1 - 0.018 h_p
+///
+/// Args:
+///     h_p (float): Board thickness (mm)
+///
+/// Returns:
+///     float: Insulation factor (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_5_1_2.section_3.protection_factors.k_2(15.0)
+fn k_2(h_p: f64) -> f64 {
+    rust_protection_factors::k_2(h_p)
+}
+
+#[pyfunction]
+/// Factor for the increased charring rate after failure of the protection.
+///
+/// Returns:
+///     float: Post-protection factor (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_5_1_2.section_3.protection_factors.k_3()
+fn k_3() -> f64 {
+    rust_protection_factors::k_3()
+}
+
+#[pymodule]
+/// Factors on the charring rate of protected surfaces.
+pub fn protection_factors(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(k_2, m)?)?;
+    m.add_function(wrap_pyfunction!(k_3, m)?)?;
+    Ok(())
+}
+
+// Charring module functions
+#[pyfunction]
+#[pyo3(signature = (beta_n, t, protection="none", h_p=0.0, t_f=None, beta_0=0.9))]
+/// Notional charring depth of a timber member, unprotected or protected by linings.
+///
+/// Charring starts at the start time of charring of the protection, at a
+/// reduced rate until the protection fails, at twice the notional rate until
+/// 25 mm of charring and at the notional rate afterwards.
+///
+/// Args:
+///     beta_n (float): Notional design charring rate (mm/min)
+///     t (float): Time of fire exposure (min)
+///     protection (str): "none", "wood_panel", "gypsum_a" (types A and H) or "gypsum_f"
+///     h_p (float): Thickness of the protection (mm)
+///     t_f (float, optional): Failure time of gypsum plasterboard type F (min)
+///     beta_0 (float): One-dimensional charring rate of a wood panel protection (mm/min)
+///
+/// Returns:
+///     float: Notional charring depth (mm)
+///
+/// Raises:
+///     ValueError: If the protection is not recognised or t_f is missing for type F
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_5_1_2.section_3.charring.charring_depth(0.7, 60.0, "gypsum_a", 12.5)
+fn charring_depth(
+    beta_n: f64,
+    t: f64,
+    protection: &str,
+    h_p: f64,
+    t_f: Option<f64>,
+    beta_0: f64,
+) -> PyResult<f64> {
+    let protection = parse_protection(protection, h_p, t_f, beta_0)?;
+    Ok(rust_charring::Charring::new(beta_n, protection).charring_depth(t))
+}
+
+#[pyfunction]
+#[pyo3(signature = (beta_n, t, protection="none", h_p=0.0, t_f=None, beta_0=0.9))]
+/// Notional charring rate of a timber member, unprotected or protected by linings.
+///
+/// Args:
+///     beta_n (float): Notional design charring rate (mm/min)
+///     t (float): Time of fire exposure (min)
+///     protection (str): "none", "wood_panel", "gypsum_a" (types A and H) or "gypsum_f"
+///     h_p (float): Thickness of the protection (mm)
+///     t_f (float, optional): Failure time of gypsum plasterboard type F (min)
+///     beta_0 (float): One-dimensional charring rate of a wood panel protection (mm/min)
+///
+/// Returns:
+///     float: Charring rate (mm/min)
+///
+/// Raises:
+///     ValueError: If the protection is not recognised or t_f is missing for type F
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_5_1_2.section_3.charring.charring_rate(0.7, 30.0, "gypsum_a", 12.5)
+fn charring_rate(
+    beta_n: f64,
+    t: f64,
+    protection: &str,
+    h_p: f64,
+    t_f: Option<f64>,
+    beta_0: f64,
+) -> PyResult<f64> {
+    let protection = parse_protection(protection, h_p, t_f, beta_0)?;
+    Ok(rust_charring::Charring::new(beta_n, protection).charring_rate(t))
+}
+
+#[pyfunction]
+#[pyo3(signature = (beta_n, protection, h_p, t_f=None, beta_0=0.9))]
+/// Start of charring, failure time of the protection and time limit of increased charring.
+///
+/// Args:
+///     beta_n (float): Notional design charring rate (mm/min)
+///     protection (str): "wood_panel", "gypsum_a" (types A and H) or "gypsum_f"
+///     h_p (float): Thickness of the protection (mm)
+///     t_f (float, optional): Failure time of gypsum plasterboard type F (min)
+///     beta_0 (float): One-dimensional charring rate of a wood panel protection (mm/min)
+///
+/// Returns:
+///     tuple[float, float, float]: Start of charring, failure time and time limit (min)
+///
+/// Raises:
+///     ValueError: If the protection is not recognised or t_f is missing for type F
+///
+/// Example:
+///     >>> import ofire
+///     >>> t_ch, t_f, t_a = ofire.eurocode_5_1_2.section_3.charring.phase_times(0.7, "gypsum_f", 15.0, 45.0)
+fn phase_times(
+    beta_n: f64,
+    protection: &str,
+    h_p: f64,
+    t_f: Option<f64>,
+    beta_0: f64,
+) -> PyResult<(f64, f64, f64)> {
+    let protection = parse_protection(protection, h_p, t_f, beta_0)?;
+    let charring = rust_charring::Charring::new(beta_n, protection);
+    Ok((
+        protection.start_of_charring(),
+        protection.failure_time(),
+        charring.time_limit(),
+    ))
+}
+
+#[pymodule]
+/// Notional charring of unprotected and protected timber members.
+pub fn charring(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(charring_depth, m)?)?;
+    m.add_function(wrap_pyfunction!(charring_rate, m)?)?;
+    m.add_function(wrap_pyfunction!(phase_times, m)?)?;
+    Ok(())
+}
+
+/// Section 3 - Material properties.
+///
+/// This section contains the charring of timber members under standard fire exposure.
+pub fn section_3(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_wrapped(wrap_pymodule!(charring))?;
+    m.add_wrapped(wrap_pymodule!(charring_rates))?;
+
+    // Equations 3.1 and 3.2 are added with add_submodule as Eurocode 1-1-2
+    // section 3 exports pymodules with the same names.
+    let equation_3_1_module = PyModule::new_bound(m.py(), "equation_3_1")?;
+    equation_3_1(&equation_3_1_module)?;
+    m.add_submodule(&equation_3_1_module)?;
+
+    let equation_3_2_module = PyModule::new_bound(m.py(), "equation_3_2")?;
+    equation_3_2(&equation_3_2_module)?;
+    m.add_submodule(&equation_3_2_module)?;
+
+    m.add_wrapped(wrap_pymodule!(equation_3_8))?;
+    m.add_wrapped(wrap_pymodule!(equation_3_9))?;
+    m.add_wrapped(wrap_pymodule!(equation_3_10))?;
+    m.add_wrapped(wrap_pymodule!(equation_3_11))?;
+    m.add_wrapped(wrap_pymodule!(protection_factors))?;
+
+    Ok(())
+}
@@ -0,0 +1,133 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::wrap_pymodule;
+
+use openfire::eurocode_5_1_2::section_4::{
+    equation_4_1 as rust_equation_4_1, reduced_cross_section as rust_reduced_cross_section,
+};
+
+fn parse_exposure(exposure: &str) -> PyResult<rust_reduced_cross_section::Exposure> {
+    match exposure.to_lowercase().as_str() {
+        "three_sided" => Ok(rust_reduced_cross_section::Exposure::ThreeSided),
+        "four_sided" => Ok(rust_reduced_cross_section::Exposure::FourSided),
+        _ => Err(PyValueError::new_err(format!(
+            "Unknown exposure '{}'",
+            exposure
+        ))),
+    }
+}
+
+// Equation 4.1 module functions
+#[pyfunction]
+/// Effective charring depth including the zero-strength layer.
+///
+/// .. math::
+///
+///    d_{ef} = d_{char,n} + k_0 d_0
+///
+/// Args:
+///     d_char_n (float): Notional charring depth (mm)
+///     k_0 (float): Factor on the zero-strength layer (dimensionless)
+///     d_0 (float): Depth of the zero-strength layer (mm)
+///
+/// Returns:
+///     float: Effective charring depth (mm)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_5_1_2.section_4.equation_4_1.effective_charring_depth(42.0, 1.0, 7.0)
+fn effective_charring_depth(d_char_n: f64, k_0: f64, d_0: f64) -> f64 {
+    rust_equation_4_1::effective_charring_depth(d_char_n, k_0, d_0)
+}
+
+#[pyfunction]
+/// Depth of the zero-strength layer.
+///
+/// Returns:
+///     float: Depth of the zero-strength layer (mm)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_5_1_2.section_4.equation_4_1.zero_strength_layer()
+fn zero_strength_layer() -> f64 {
+    rust_equation_4_1::zero_strength_layer()
+}
+
+#[pyfunction]
+/// Factor on the zero-strength layer.
+///
+/// Increases linearly to 1.0 at 20 minutes, or at the start of charring of
+/// protected surfaces where this is later.
+///
+/// Args:
+///     t (float): Time of fire exposure (min)
+///     t_ch (float): Start time of charring, 0 for unprotected surfaces (min)
+///
+/// Returns:
+///     float: Factor on the zero-strength layer (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.eurocode_5_1_2.section_4.equation_4_1.k_0(10.0, 0.0)
+fn k_0(t: f64, t_ch: f64) -> f64 {
+    rust_equation_4_1::k_0(t, t_ch)
+}
+
+/// Equation 4.1 - Effective charring depth.
+pub fn equation_4_1(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(effective_charring_depth, m)?)?;
+    m.add_function(wrap_pyfunction!(zero_strength_layer, m)?)?;
+    m.add_function(wrap_pyfunction!(k_0, m)?)?;
+    Ok(())
+}
+
+// Reduced cross-section module functions
+#[pyfunction]
+#[pyo3(signature = (b, h, d_ef, exposure="three_sided"))]
+/// Residual cross-section of a rectangular timber member.
+///
+/// The effective charring depth is removed from both sides and the bottom,
+/// and also from the top for four-sided exposure.
+///
+/// Args:
+///     b (float): Width (mm)
+///     h (float): Depth (mm)
+///     d_ef (float): Effective charring depth (mm)
+///     exposure (str): "three_sided" or "four_sided"
+///
+/// Returns:
+///     tuple[float, float]: Residual width and depth (mm)
+///
+/// Raises:
+///     ValueError: If the exposure is not recognised
+///
+/// Example:
+///     >>> import ofire
+///     >>> b, h = ofire.eurocode_5_1_2.section_4.reduced_cross_section.reduced(200.0, 600.0, 49.0)
+fn reduced(b: f64, h: f64, d_ef: f64, exposure: &str) -> PyResult<(f64, f64)> {
+    let section = rust_reduced_cross_section::CrossSection { b, h };
+    let reduced = section.reduced(d_ef, parse_exposure(exposure)?);
+    Ok((reduced.b, reduced.h))
+}
+
+#[pymodule]
+/// Reduced cross-section method for rectangular timber members.
+pub fn reduced_cross_section(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(reduced, m)?)?;
+    Ok(())
+}
+
+/// Section 4 - Design procedures for mechanical resistance.
+///
+/// This section contains the reduced cross-section method.
+pub fn section_4(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    // Equation 4.1 is added with add_submodule as Fire Dynamics Tools chapter 4
+    // exports a pymodule with the same name.
+    let equation_4_1_module = PyModule::new_bound(m.py(), "equation_4_1")?;
+    equation_4_1(&equation_4_1_module)?;
+    m.add_submodule(&equation_4_1_module)?;
+
+    m.add_wrapped(wrap_pymodule!(reduced_cross_section))?;
+
+    Ok(())
+}
@@ -3,9 +3,11 @@ use pyo3::wrap_pymodule;
 
 use openfire::fire_dynamics_tools::chapter_18::{
     equation_18_1 as rust_equation_18_1, equation_18_2 as rust_equation_18_2,
-    equation_18_3 as rust_equation_18_3,
+    equation_18_3 as rust_equation_18_3, smoke_detector as rust_smoke_detector,
 };
 
+use crate::history::History;
+
 #[pyfunction]
 /// Visibility through smoke (Equation 18-1).
 ///
@@ -116,6 +118,137 @@ fn mass_particulates_produced(m_f: f64, y_p: f64) -> PyResult<f64> {
     Ok(rust_equation_18_3::mass_particulates_produced(m_f, y_p))
 }
 
+fn detect(
+    surrogate: rust_smoke_detector::Surrogate,
+    lag_length: f64,
+    hrr: Vec<f64>,
+    delta_t: f64,
+    height: f64,
+    radial_position: f64,
+    t_a: f64,
+) -> PyResult<Option<(f64, f64)>> {
+    let hrr = History::new("hrr", &hrr, delta_t)?;
+
+    let curve = |t: f64| hrr.at(t);
+
+    let detector = rust_smoke_detector::SmokeDetector::new(surrogate, lag_length);
+    Ok(detector
+        .activation(curve, height, radial_position, t_a, hrr.end())
+        .map(|result| (result.time, result.hrr)))
+}
+
+#[pyfunction]
+#[pyo3(signature = (detector, hrr, delta_t, height, radial_position, t_a=293.15))]
+/// Smoke detector activation using Heskestad's temperature rise surrogate.
+///
+/// The detector activates when the smoke inside it, lagging the Alpert
+/// ceiling jet with a characteristic length :math:`L`, reaches a temperature
+/// rise threshold:
+///
+/// .. math::
+///
+///    \frac{d \Delta T_d}{dt} = \frac{u}{L} (\Delta T_{jet} - \Delta T_d)
+///
+/// Args:
+///     detector (tuple[float, float]): Temperature rise at activation (K), typically 13 K,
+///         and characteristic lag length (m)
+///     hrr (list[float]): Heat release rates at times 0, delta_t, 2 delta_t, ... (kW)
+///     delta_t (float): Time step of the heat release rates (s)
+///     height (float): Height of ceiling above fire (m)
+///     radial_position (float): Radial distance from fire centerline (m)
+///     t_a (float): Ambient temperature (K)
+///
+/// Returns:
+///     tuple[float, float] | None: Activation time (s) and heat release rate at activation (kW),
+///     or None if the detector does not activate within the heat release rate history
+///
+/// Raises:
+///     ValueError: If there are no heat release rates or delta_t is not positive
+///
+/// Example:
+///     >>> import ofire
+///     >>> hrr = [0.0469 * (10.0 * i) ** 2 for i in range(61)]
+///     >>> result = ofire.fire_dynamics_tools.chapter_18.smoke_detector.temperature_rise((13.0, 1.8), hrr, 10.0, 4.0, 3.0)
+fn temperature_rise(
+    detector: (f64, f64),
+    hrr: Vec<f64>,
+    delta_t: f64,
+    height: f64,
+    radial_position: f64,
+    t_a: f64,
+) -> PyResult<Option<(f64, f64)>> {
+    let (threshold, lag_length) = detector;
+    let surrogate = rust_smoke_detector::Surrogate::TemperatureRise { delta_t: threshold };
+    detect(
+        surrogate,
+        lag_length,
+        hrr,
+        delta_t,
+        height,
+        radial_position,
+        t_a,
+    )
+}
+
+#[pyfunction]
+#[pyo3(signature = (detector, soot, hrr, delta_t, height, radial_position, t_a=293.15))]
+/// Smoke detector activation at an optical density per metre.
+///
+/// The particulate concentration of the Alpert ceiling jet follows from the
+/// fuel burnt to heat it (Equations 18-2 and 18-3), and its optical density
+/// per metre is :math:`D = \alpha_m m_p / \ln 10`. The smoke inside the
+/// detector lags the ceiling jet with a characteristic length :math:`L`.
+///
+/// Args:
+///     detector (tuple[float, float]): Optical density per metre at activation (1/m)
+///         and characteristic lag length (m)
+///     soot (tuple[float, float, float]): Soot yield (dimensionless), heat of combustion (kJ/kg)
+///         and specific extinction coefficient (m²/kg)
+///     hrr (list[float]): Heat release rates at times 0, delta_t, 2 delta_t, ... (kW)
+///     delta_t (float): Time step of the heat release rates (s)
+///     height (float): Height of ceiling above fire (m)
+///     radial_position (float): Radial distance from fire centerline (m)
+///     t_a (float): Ambient temperature (K)
+///
+/// Returns:
+///     tuple[float, float] | None: Activation time (s) and heat release rate at activation (kW),
+///     or None if the detector does not activate within the heat release rate history
+///
+/// Raises:
+///     ValueError: If there are no heat release rates or delta_t is not positive
+///
+/// Example:
+///     >>> import ofire
+///     >>> hrr = [0.01172 * (10.0 * i) ** 2 for i in range(61)]
+///     >>> result = ofire.fire_dynamics_tools.chapter_18.smoke_detector.optical_density((0.14, 1.8), (0.015, 20000.0, 8700.0), hrr, 10.0, 3.0, 3.0)
+fn optical_density(
+    detector: (f64, f64),
+    soot: (f64, f64, f64),
+    hrr: Vec<f64>,
+    delta_t: f64,
+    height: f64,
+    radial_position: f64,
+    t_a: f64,
+) -> PyResult<Option<(f64, f64)>> {
+    let (threshold, lag_length) = detector;
+    let (y_s, delta_h_c, alpha_m) = soot;
+    let surrogate = rust_smoke_detector::Surrogate::OpticalDensity {
+        y_s,
+        delta_h_c,
+        alpha_m,
+        threshold,
+    };
+    detect(
+        surrogate,
+        lag_length,
+        hrr,
+        delta_t,
+        height,
+        radial_position,
+        t_a,
+    )
+}
+
 #[pymodule]
 /// Equation 18-1 - Visibility through smoke.
 ///
@@ -146,6 +279,17 @@ fn equation_18_3(m: &Bound<'_, PyModule>) -> PyResult<()> {
     Ok(())
 }
 
+#[pymodule]
+/// Smoke detector - Activation from temperature rise or optical density.
+///
+/// This module estimates smoke detector activation in the ceiling jet of a
+/// time-varying fire, the first step of an RSET timeline.
+fn smoke_detector(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(temperature_rise, m)?)?;
+    m.add_function(wrap_pyfunction!(optical_density, m)?)?;
+    Ok(())
+}
+
 #[pymodule]
 /// Chapter 18 - Visibility calculations.
 ///
@@ -154,5 +298,6 @@ pub fn chapter_18(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pymodule!(equation_18_1))?;
     m.add_wrapped(wrap_pymodule!(equation_18_2))?;
     m.add_wrapped(wrap_pymodule!(equation_18_3))?;
+    m.add_wrapped(wrap_pymodule!(smoke_detector))?;
     Ok(())
 }
@@ -1,16 +1,20 @@
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::wrap_pymodule;
 
 use openfire::fire_dynamics_tools::chapter_2::{
-    equation_2_1 as rust_equation_2_1, equation_2_2 as rust_equation_2_2,
-    equation_2_3 as rust_equation_2_3, equation_2_4 as rust_equation_2_4,
-    equation_2_5 as rust_equation_2_5, equation_2_6 as rust_equation_2_6,
-    equation_2_7 as rust_equation_2_7, equation_2_8 as rust_equation_2_8,
-    equation_2_9 as rust_equation_2_9, equation_2_10 as rust_equation_2_10,
-    equation_2_11 as rust_equation_2_11, equation_2_12 as rust_equation_2_12,
-    equation_2_13 as rust_equation_2_13,
+    atrium_smoke_filling as rust_atrium_smoke_filling, equation_2_1 as rust_equation_2_1,
+    equation_2_2 as rust_equation_2_2, equation_2_3 as rust_equation_2_3,
+    equation_2_4 as rust_equation_2_4, equation_2_5 as rust_equation_2_5,
+    equation_2_6 as rust_equation_2_6, equation_2_7 as rust_equation_2_7,
+    equation_2_8 as rust_equation_2_8, equation_2_9 as rust_equation_2_9,
+    equation_2_10 as rust_equation_2_10, equation_2_11 as rust_equation_2_11,
+    equation_2_12 as rust_equation_2_12, equation_2_13 as rust_equation_2_13,
+    two_zone_model as rust_two_zone_model, zone_network as rust_zone_network,
 };
 
+use crate::history::History;
+
 #[pyfunction]
 /// Calculate hot gas temperature increase for natural ventilation using the MQH method (Equation 2.1).
 ///
@@ -610,6 +614,470 @@ fn density_hot_gas_layer(t_g: f64) -> PyResult<f64> {
     Ok(rust_equation_2_13::density_hot_gas_layer(t_g))
 }
 
+// Two-zone model module functions
+#[pyfunction]
+/// Axisymmetric plume mass flow after Heskestad.
+///
+/// .. math::
+///
+///    \dot{m}_p = 0.071 \cdot Q_c^{1/3} \cdot z^{5/3} + 0.0018 \cdot Q_c
+///
+/// above the mean flame height :math:`z_l = 0.166 \cdot Q_c^{2/5}`, and
+/// :math:`\dot{m}_p = 0.032 \cdot Q_c^{3/5} \cdot z` within the flame.
+///
+/// Args:
+///     q_c (float): Convective heat release rate (kW)
+///     z (float): Height above the base of the fire (m)
+///
+/// Returns:
+///     float: Plume mass flow (kg/s)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.fire_dynamics_tools.chapter_2.two_zone_model.plume_mass_flow(700.0, 2.0)
+fn plume_mass_flow(q_c: f64, z: f64) -> PyResult<f64> {
+    Ok(rust_two_zone_model::plume_mass_flow(q_c, z))
+}
+
+type LayerHistories = (Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>);
+type CompartmentHistories = (Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>, bool);
+
+#[pyfunction]
+/// Time histories of the smoke layer in a compartment with wall vents.
+///
+/// The lower layer stays at ambient (293.15 K). The upper layer is filled by
+/// a Heskestad plume carrying 70 % of the heat release rate and loses heat to
+/// the linings with the effective heat transfer coefficient of equations 2.3
+/// to 2.5. Vent flows follow from Bernoulli with a discharge coefficient of
+/// 0.7.
+///
+/// Args:
+///     width (float): Compartment width (m)
+///     length (float): Compartment length (m)
+///     height (float): Compartment height (m)
+///     vents (list[tuple[float, float, float]]): Width, sill height and soffit height (m) of each wall vent
+///     lining (tuple[float, float, float, float]): Thermal conductivity (kW/m·K), density (kg/m³),
+///         specific heat (kJ/kg·K) and thickness (m) of the linings
+///     hrr (list[float]): Heat release rates at times 0, delta_t, 2 delta_t, ... (kW)
+///     delta_t (float): Output time step (s)
+///
+/// Returns:
+///     tuple[list[float], list[float], list[float], list[float], list[float], bool]: Times (s), upper
+///     layer temperatures (K), interface heights (m), mass flows out and in through the vents (kg/s),
+///     and whether the integration reached the end time. The histories stop at the last output time
+///     reached if it did not.
+///
+/// Raises:
+///     ValueError: If there are no vents or heat release rates, a dimension is not positive, or
+///         delta_t is not positive
+///
+/// Example:
+///     >>> import ofire
+///     >>> vents = [(0.9, 0.0, 2.0)]
+///     >>> lining = (0.00048, 1440.0, 0.84, 0.016)
+///     >>> hrr = [500.0] * 61
+///     >>> times, t_u, z, m_out, m_in, completed = ofire.fire_dynamics_tools.chapter_2.two_zone_model.simulate(4.0, 5.0, 2.5, vents, lining, hrr, 10.0)
+fn simulate(
+    width: f64,
+    length: f64,
+    height: f64,
+    vents: Vec<(f64, f64, f64)>,
+    lining: (f64, f64, f64, f64),
+    hrr: Vec<f64>,
+    delta_t: f64,
+) -> PyResult<CompartmentHistories> {
+    if vents.is_empty() {
+        return Err(PyValueError::new_err("vents must not be empty"));
+    }
+    if [width, length, height]
+        .iter()
+        .any(|&d| d.is_nan() || d <= 0.0)
+    {
+        return Err(PyValueError::new_err(
+            "width, length and height must be positive",
+        ));
+    }
+    let hrr = History::new("hrr", &hrr, delta_t)?;
+
+    let (k, rho, c, delta) = lining;
+    let model = rust_two_zone_model::TwoZoneModel::new(
+        width,
+        length,
+        height,
+        vents
+            .into_iter()
+            .map(|(width, sill, soffit)| rust_two_zone_model::Vent {
+                width,
+                sill,
+                soffit,
+            })
+            .collect(),
+        rust_two_zone_model::Lining { k, rho, c, delta },
+    );
+
+    let curve = |t: f64| hrr.at(t);
+
+    let history = model.solve(curve, delta_t, hrr.end());
+    Ok((
+        history.times,
+        history.upper_temperatures,
+        history.interface_heights,
+        history.outflows,
+        history.inflows,
+        history.completed,
+    ))
+}
+
+// Zone network module functions
+type RoomArgs = (f64, f64, f64, f64, (f64, f64, f64, f64));
+
+#[pyfunction]
+#[pyo3(name = "simulate")]
+/// Time histories of the smoke layers in a network of compartments.
+///
+/// Each room is a two-zone model with its lower layer at ambient (293.15 K).
+/// Gas leaving an upper layer enters the upper layer of the receiving room,
+/// and gas leaving a lower layer or the outside enters the lower layer unless
+/// that room is filled with smoke. The fire is a Heskestad plume carrying
+/// 70 % of the heat release rate. Vent flows follow from Bernoulli with a
+/// discharge coefficient of 0.7 and fans move a fixed volume flow.
+///
+/// Args:
+///     rooms (list[tuple[float, float, float, float, tuple[float, float, float, float]]]): Width, length,
+///         height and floor elevation (m) of each room, with the thermal conductivity (kW/m·K), density
+///         (kg/m³), specific heat (kJ/kg·K) and thickness (m) of its linings
+///     wall_vents (list[tuple[int, int | None, float, float, float]]): Room, connected room (None for the
+///         outside), width, sill height and soffit height (m) of each wall vent, with heights from the
+///         floor of the first room
+///     ceiling_vents (list[tuple[int, int | None, float]]): Room below, room above (None for the outside)
+///         and area (m²) of each ceiling vent
+///     fans (list[tuple[int | None, int | None, float, float]]): Room extracted from and room supplied
+///         (None for the outside), extraction height above the floor (m) and volume flow (m³/s) of each fan
+///     fire_room (int): Index of the room containing the fire
+///     hrr (list[float]): Heat release rates at times 0, delta_t, 2 delta_t, ... (kW)
+///     delta_t (float): Output time step (s)
+///
+/// Returns:
+///     list[tuple[list[float], list[float], list[float], list[float], list[float]]]: For each room, times (s),
+///     upper layer temperatures (K), interface heights (m), and total mass flows out and in (kg/s)
+///
+/// Raises:
+///     ValueError: If a room index is out of range, a room has no path of wall or ceiling vents to
+///         the outside, there are no heat release rates, delta_t is not positive, or the floor
+///         pressures do not converge or the integration stalls
+///
+/// Example:
+///     >>> import ofire
+///     >>> gypsum = (0.00048, 1440.0, 0.84, 0.016)
+///     >>> rooms = [(4.0, 5.0, 2.5, 0.0, gypsum), (2.0, 10.0, 2.5, 0.0, gypsum)]
+///     >>> wall_vents = [(0, 1, 0.9, 0.0, 2.0), (1, None, 0.9, 0.0, 2.0)]
+///     >>> histories = ofire.fire_dynamics_tools.chapter_2.zone_network.simulate(rooms, wall_vents, [], [], 0, [300.0] * 31, 10.0)
+fn simulate_network(
+    rooms: Vec<RoomArgs>,
+    wall_vents: Vec<(usize, Option<usize>, f64, f64, f64)>,
+    ceiling_vents: Vec<(usize, Option<usize>, f64)>,
+    fans: Vec<(Option<usize>, Option<usize>, f64, f64)>,
+    fire_room: usize,
+    hrr: Vec<f64>,
+    delta_t: f64,
+) -> PyResult<Vec<LayerHistories>> {
+    if fire_room >= rooms.len() {
+        return Err(PyValueError::new_err("room index out of range"));
+    }
+    let hrr = History::new("hrr", &hrr, delta_t)?;
+
+    let mut connections: Vec<rust_zone_network::Connection> = wall_vents
+        .into_iter()
+        .map(
+            |(from, to, width, sill, soffit)| rust_zone_network::Connection::WallVent {
+                from,
+                to,
+                width,
+                sill,
+                soffit,
+            },
+        )
+        .collect();
+    connections.extend(ceiling_vents.into_iter().map(|(lower, upper, area)| {
+        rust_zone_network::Connection::CeilingVent { lower, upper, area }
+    }));
+    connections.extend(fans.into_iter().map(|(from, to, height, flow)| {
+        rust_zone_network::Connection::Fan {
+            from,
+            to,
+            height,
+            flow,
+        }
+    }));
+
+    let network = rust_zone_network::ZoneNetwork::new(
+        rooms
+            .into_iter()
+            .map(
+                |(width, length, height, elevation, (k, rho, c, delta))| rust_zone_network::Room {
+                    width,
+                    length,
+                    height,
+                    elevation,
+                    lining: rust_two_zone_model::Lining { k, rho, c, delta },
+                },
+            )
+            .collect(),
+        connections,
+    );
+    match network.fault() {
+        Some(rust_zone_network::Fault::UnknownRoom) => {
+            return Err(PyValueError::new_err("room index out of range"));
+        }
+        Some(rust_zone_network::Fault::NotVented(i)) => {
+            return Err(PyValueError::new_err(format!(
+                "room {} has no path of vents to the outside",
+                i
+            )));
+        }
+        None => {}
+    }
+
+    let curve = |t: f64| hrr.at(t);
+    let result = network.solve(fire_room, curve, delta_t, hrr.end());
+    if !result.converged {
+        return Err(PyValueError::new_err(
+            "the floor pressures did not converge or the integration stalled",
+        ));
+    }
+
+    Ok(result
+        .rooms
+        .into_iter()
+        .map(|history| {
+            (
+                history.times,
+                history.upper_temperatures,
+                history.interface_heights,
+                history.outflows,
+                history.inflows,
+            )
+        })
+        .collect())
+}
+
+fn outside_nfpa_92() -> PyErr {
+    PyValueError::new_err("outside the limits of the NFPA 92 filling correlations")
+}
+
+#[pyfunction]
+/// Smoke layer height in an atrium under a steady fire (NFPA 92).
+///
+/// .. math::
+///
+///    \frac{z}{H} = 1.11 - 0.28 \ln \left( \frac{t \dot{Q}^{1/3} H^{-4/3}}{A / H^2} \right)
+///
+/// The correlation applies for :math:`z / H > 0.2` and
+/// :math:`0.9 \leq A / H^2 \leq 14`.
+///
+/// Args:
+///     area (float): Plan area of the atrium (m²)
+///     height (float): Ceiling height above the fire (m)
+///     q (float): Heat release rate (kW)
+///     t (float): Time (s)
+///
+/// Returns:
+///     float: Smoke layer height above the fire (m), at most the ceiling height
+///
+/// Raises:
+///     ValueError: If the layer height or atrium proportions are outside the limits of the
+///         correlation, z / H > 0.2 and 0.9 <= A / H² <= 14
+///
+/// Example:
+///     >>> import ofire
+///     >>> z = ofire.fire_dynamics_tools.chapter_2.atrium_smoke_filling.layer_height_steady(900.0, 20.0, 5000.0, 120.0)
+fn layer_height_steady(area: f64, height: f64, q: f64, t: f64) -> PyResult<f64> {
+    rust_atrium_smoke_filling::Atrium::new(area, height)
+        .layer_height_steady(q, t)
+        .ok_or_else(outside_nfpa_92)
+}
+
+#[pyfunction]
+/// Time for the smoke layer in an atrium to descend to a height under a steady fire (NFPA 92).
+///
+/// Args:
+///     area (float): Plan area of the atrium (m²)
+///     height (float): Ceiling height above the fire (m)
+///     q (float): Heat release rate (kW)
+///     z (float): Smoke layer height above the fire (m)
+///
+/// Returns:
+///     float: Filling time (s)
+///
+/// Raises:
+///     ValueError: If the layer height or atrium proportions are outside the limits of the
+///         correlation, z / H > 0.2 and 0.9 <= A / H² <= 14
+///
+/// Example:
+///     >>> import ofire
+///     >>> t = ofire.fire_dynamics_tools.chapter_2.atrium_smoke_filling.filling_time_steady(900.0, 20.0, 5000.0, 10.0)
+fn filling_time_steady(area: f64, height: f64, q: f64, z: f64) -> PyResult<f64> {
+    rust_atrium_smoke_filling::Atrium::new(area, height)
+        .filling_time_steady(q, z)
+        .ok_or_else(outside_nfpa_92)
+}
+
+#[pyfunction]
+/// Smoke layer height in an atrium under a t-squared fire (NFPA 92).
+///
+/// .. math::
+///
+///    \frac{z}{H} = 0.91 \left( t \, t_g^{-2/5} H^{-4/5} \left( \frac{A}{H^2} \right)^{-3/5} \right)^{-1.45}
+///
+/// where :math:`t_g` is the time for the fire to reach 1055 kW.
+///
+/// Args:
+///     area (float): Plan area of the atrium (m²)
+///     height (float): Ceiling height above the fire (m)
+///     alpha (float): Fire growth coefficient (kW/s²)
+///     t (float): Time (s)
+///
+/// Returns:
+///     float: Smoke layer height above the fire (m), at most the ceiling height
+///
+/// Raises:
+///     ValueError: If the layer height or atrium proportions are outside the limits of the
+///         correlation, z / H > 0.2 and 0.9 <= A / H² <= 14
+///
+/// Example:
+///     >>> import ofire
+///     >>> z = ofire.fire_dynamics_tools.chapter_2.atrium_smoke_filling.layer_height_t_squared(900.0, 20.0, 0.0469, 300.0)
+fn layer_height_t_squared(area: f64, height: f64, alpha: f64, t: f64) -> PyResult<f64> {
+    rust_atrium_smoke_filling::Atrium::new(area, height)
+        .layer_height_t_squared(alpha, t)
+        .ok_or_else(outside_nfpa_92)
+}
+
+#[pyfunction]
+/// Time for the smoke layer in an atrium to descend to a height under a t-squared fire (NFPA 92).
+///
+/// Args:
+///     area (float): Plan area of the atrium (m²)
+///     height (float): Ceiling height above the fire (m)
+///     alpha (float): Fire growth coefficient (kW/s²)
+///     z (float): Smoke layer height above the fire (m)
+///
+/// Returns:
+///     float: Filling time (s)
+///
+/// Raises:
+///     ValueError: If the layer height or atrium proportions are outside the limits of the
+///         correlation, z / H > 0.2 and 0.9 <= A / H² <= 14
+///
+/// Example:
+///     >>> import ofire
+///     >>> t = ofire.fire_dynamics_tools.chapter_2.atrium_smoke_filling.filling_time_t_squared(900.0, 20.0, 0.0469, 8.0)
+fn filling_time_t_squared(area: f64, height: f64, alpha: f64, z: f64) -> PyResult<f64> {
+    rust_atrium_smoke_filling::Atrium::new(area, height)
+        .filling_time_t_squared(alpha, z)
+        .ok_or_else(outside_nfpa_92)
+}
+
+#[pyfunction]
+#[pyo3(signature = (q, a_f, z, t_a=293.15))]
+/// Mechanical exhaust that holds the smoke layer at a design height.
+///
+/// The exhaust equals the mass flow of an axisymmetric plume carrying 70 % of
+/// the heat release rate, measured from the virtual origin of Equation 9.3,
+/// and the smoke layer is heated adiabatically by the plume.
+///
+/// Args:
+///     q (float): Heat release rate (kW)
+///     a_f (float): Fire area (m²), zero for a point source
+///     z (float): Design smoke layer height above the fire (m)
+///     t_a (float): Ambient temperature (K)
+///
+/// Returns:
+///     tuple[float, float, float]: Mass flow (kg/s), smoke temperature (K) and volumetric flow (m³/s)
+///
+/// Example:
+///     >>> import ofire
+///     >>> m, t_s, v = ofire.fire_dynamics_tools.chapter_2.atrium_smoke_filling.exhaust(5000.0, 9.0, 10.0)
+fn exhaust(q: f64, a_f: f64, z: f64, t_a: f64) -> PyResult<(f64, f64, f64)> {
+    let atrium = rust_atrium_smoke_filling::Atrium {
+        t_a,
+        ..rust_atrium_smoke_filling::Atrium::new(0.0, z)
+    };
+    let exhaust = atrium.exhaust(q, a_f, z);
+    Ok((
+        exhaust.mass_flow,
+        exhaust.smoke_temperature,
+        exhaust.volumetric_flow,
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (height, q, a_f, m_e, t_a=293.15))]
+/// Steady clear height under a mechanical exhaust.
+///
+/// The smoke layer settles where the plume mass flow equals the exhaust.
+///
+/// Args:
+///     height (float): Ceiling height above the fire (m)
+///     q (float): Heat release rate (kW)
+///     a_f (float): Fire area (m²), zero for a point source
+///     m_e (float): Mass exhaust rate (kg/s)
+///     t_a (float): Ambient temperature (K)
+///
+/// Returns:
+///     float: Clear height above the fire (m), the ceiling height if the exhaust exceeds the plume flow there
+///
+/// Example:
+///     >>> import ofire
+///     >>> z = ofire.fire_dynamics_tools.chapter_2.atrium_smoke_filling.clear_height(20.0, 5000.0, 9.0, 40.0)
+fn clear_height(height: f64, q: f64, a_f: f64, m_e: f64, t_a: f64) -> PyResult<f64> {
+    let atrium = rust_atrium_smoke_filling::Atrium {
+        t_a,
+        ..rust_atrium_smoke_filling::Atrium::new(0.0, height)
+    };
+    Ok(atrium.clear_height(q, a_f, m_e))
+}
+
+#[pyfunction]
+#[pyo3(signature = (q, a_f, z, depth, gamma, t_a=293.15))]
+/// Plugholing limit and number of exhaust points for a design smoke layer.
+///
+/// The limit per exhaust point follows CIBSE Guide E Equation 10.1 for the
+/// smoke temperature of ``exhaust``.
+///
+/// Args:
+///     q (float): Heat release rate (kW)
+///     a_f (float): Fire area (m²), zero for a point source
+///     z (float): Design smoke layer height above the fire (m)
+///     depth (float): Depth of the smoke layer below the exhaust points (m)
+///     gamma (float): Exhaust location factor, 1.0 away from walls and 0.5 next to a wall
+///     t_a (float): Ambient temperature (K)
+///
+/// Returns:
+///     tuple[float, int]: Largest volumetric flow per exhaust point (m³/s) and smallest number of exhaust points
+///
+/// Example:
+///     >>> import ofire
+///     >>> limit, n = ofire.fire_dynamics_tools.chapter_2.atrium_smoke_filling.exhaust_points(5000.0, 9.0, 10.0, 2.0, 1.0)
+fn exhaust_points(
+    q: f64,
+    a_f: f64,
+    z: f64,
+    depth: f64,
+    gamma: f64,
+    t_a: f64,
+) -> PyResult<(f64, usize)> {
+    let atrium = rust_atrium_smoke_filling::Atrium {
+        t_a,
+        ..rust_atrium_smoke_filling::Atrium::new(0.0, z)
+    };
+    let exhaust = atrium.exhaust(q, a_f, z);
+    Ok((
+        exhaust.plugholing_limit(depth, gamma, t_a),
+        exhaust.exhaust_points(depth, gamma, t_a),
+    ))
+}
+
 #[pymodule]
 /// Natural ventilation calculations using the MQH correlation method.
 fn equation_2_1(m: &Bound<'_, PyModule>) -> PyResult<()> {
@@ -722,6 +1190,34 @@ fn equation_2_13(m: &Bound<'_, PyModule>) -> PyResult<()> {
     Ok(())
 }
 
+#[pymodule]
+/// Two-zone model of a single compartment fire.
+fn two_zone_model(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(plume_mass_flow, m)?)?;
+    m.add_function(wrap_pyfunction!(simulate, m)?)?;
+    Ok(())
+}
+
+#[pymodule]
+/// Network of two-zone compartments connected by vents and fans.
+fn zone_network(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(simulate_network, m)?)?;
+    Ok(())
+}
+
+#[pymodule]
+/// Atrium smoke filling, exhaust and plugholing after NFPA 92.
+fn atrium_smoke_filling(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(layer_height_steady, m)?)?;
+    m.add_function(wrap_pyfunction!(filling_time_steady, m)?)?;
+    m.add_function(wrap_pyfunction!(layer_height_t_squared, m)?)?;
+    m.add_function(wrap_pyfunction!(filling_time_t_squared, m)?)?;
+    m.add_function(wrap_pyfunction!(exhaust, m)?)?;
+    m.add_function(wrap_pyfunction!(clear_height, m)?)?;
+    m.add_function(wrap_pyfunction!(exhaust_points, m)?)?;
+    Ok(())
+}
+
 #[pymodule]
 /// Chapter 2 - Predicting Hot Gas Layer Temperature and Smoke Layer Height in a Room Fire with Natural and Forced Ventilation.
 ///
@@ -745,5 +1241,8 @@ pub fn chapter_2(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pymodule!(equation_2_11))?;
     m.add_wrapped(wrap_pymodule!(equation_2_12))?;
     m.add_wrapped(wrap_pymodule!(equation_2_13))?;
+    m.add_wrapped(wrap_pymodule!(two_zone_model))?;
+    m.add_wrapped(wrap_pymodule!(zone_network))?;
+    m.add_wrapped(wrap_pymodule!(atrium_smoke_filling))?;
     Ok(())
 }
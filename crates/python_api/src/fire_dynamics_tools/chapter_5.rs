@@ -1,7 +1,12 @@
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::wrap_pymodule;
 
-use openfire::fire_dynamics_tools::chapter_5::equation_5_1 as rust_equation_5_1;
+use openfire::fire_dynamics_tools::chapter_5::{
+    atmospheric_transmissivity as rust_atmospheric_transmissivity,
+    equation_5_1 as rust_equation_5_1, model_comparison as rust_model_comparison,
+    solid_flame as rust_solid_flame, wind_tilted_flame as rust_wind_tilted_flame,
+};
 
 #[pyfunction]
 /// This equation calculates the thermal radiation incident flux from a point
@@ -48,11 +53,607 @@ fn equation_5_1(m: &Bound<'_, PyModule>) -> PyResult<()> {
     Ok(())
 }
 
+// Solid flame module functions
+fn outside_flame(view_factor: Option<f64>) -> PyResult<f64> {
+    view_factor
+        .ok_or_else(|| PyValueError::new_err("The target must be outside the flame, l > d / 2"))
+}
+
+#[pyfunction]
+#[pyo3(name = "emissive_power")]
+/// Flame emissive power of a pool fire (Shokri and Beyler).
+///
+/// .. math::
+///
+///    E = 58 \cdot 10^{-0.00823 D}
+///
+/// where:
+///
+/// - :math:`E` is the flame emissive power (kW/m²)
+/// - :math:`D` is the pool diameter (m)
+///
+/// Args:
+///     d (float): Pool diameter (m)
+///
+/// Returns:
+///     float: Flame emissive power (kW/m²)
+///
+/// Example:
+///     >>> import ofire
+///     >>> e = ofire.fire_dynamics_tools.chapter_5.solid_flame.emissive_power(2.0)
+fn emissive_power_solid_flame(d: f64) -> PyResult<f64> {
+    Ok(rust_solid_flame::emissive_power(d))
+}
+
+#[pyfunction]
+/// Pool fire flame height (Heskestad).
+///
+/// .. math::
+///
+///    H_f = 0.235 \cdot \dot{Q}^{2/5} - 1.02 \cdot D
+///
+/// Args:
+///     q (float): Heat release rate (kW)
+///     d (float): Pool diameter (m)
+///
+/// Returns:
+///     float: Flame height (m), zero if the correlation gives a negative height
+///
+/// Example:
+///     >>> import ofire
+///     >>> h_f = ofire.fire_dynamics_tools.chapter_5.solid_flame.flame_height(2000.0, 1.5)
+fn flame_height(q: f64, d: f64) -> PyResult<f64> {
+    Ok(rust_solid_flame::flame_height(q, d))
+}
+
+#[pyfunction]
+#[pyo3(name = "view_factor_vertical")]
+/// View factor from a cylindrical flame to a vertical target at its base.
+///
+/// .. math::
+///
+///    F_{V} = \frac{1}{\pi S} \tan^{-1}\left(\frac{h}{\sqrt{S^2 - 1}}\right) - \frac{h}{\pi S} \tan^{-1}\sqrt{\frac{S - 1}{S + 1}} + \frac{A h}{\pi S \sqrt{A^2 - 1}} \tan^{-1}\sqrt{\frac{(A + 1)(S - 1)}{(A - 1)(S + 1)}}
+///
+/// where :math:`h = 2H_f/D`, :math:`S = 2L/D` and :math:`A = (h^2 + S^2 + 1)/(2S)`.
+///
+/// Args:
+///     h_f (float): Flame height (m)
+///     l (float): Distance from the centre of the pool to the target (m)
+///     d (float): Pool diameter (m)
+///
+/// Returns:
+///     float: View factor (dimensionless)
+///
+/// Raises:
+///     ValueError: If the target is not outside the flame, l <= d / 2
+///
+/// Example:
+///     >>> import ofire
+///     >>> f_v = ofire.fire_dynamics_tools.chapter_5.solid_flame.view_factor_vertical(3.0, 4.0, 2.0)
+fn view_factor_vertical_solid_flame(h_f: f64, l: f64, d: f64) -> PyResult<f64> {
+    outside_flame(rust_solid_flame::view_factor_vertical(h_f, l, d))
+}
+
+#[pyfunction]
+/// View factor from a cylindrical flame to a vertical target above the base.
+///
+/// The flame is split at the target height into two cylinders whose view
+/// factors are summed. For a target above the flame the view factor of the
+/// cylinder between the flame tip and the target is subtracted.
+///
+/// Args:
+///     h_f (float): Flame height (m)
+///     h_t (float): Height of the target above the base of the flame (m)
+///     l (float): Distance from the centre of the pool to the target (m)
+///     d (float): Pool diameter (m)
+///
+/// Returns:
+///     float: View factor (dimensionless)
+///
+/// Raises:
+///     ValueError: If the target is not outside the flame, l <= d / 2
+///
+/// Example:
+///     >>> import ofire
+///     >>> f_v = ofire.fire_dynamics_tools.chapter_5.solid_flame.view_factor_vertical_elevated(3.0, 1.0, 4.0, 2.0)
+fn view_factor_vertical_elevated(h_f: f64, h_t: f64, l: f64, d: f64) -> PyResult<f64> {
+    outside_flame(rust_solid_flame::view_factor_vertical_elevated(
+        h_f, h_t, l, d,
+    ))
+}
+
+#[pyfunction]
+#[pyo3(name = "view_factor_horizontal")]
+/// View factor from a cylindrical flame to a horizontal target at its base.
+///
+/// .. math::
+///
+///    F_{H} = \frac{B - 1/S}{\pi \sqrt{B^2 - 1}} \tan^{-1}\sqrt{\frac{(B + 1)(S - 1)}{(B - 1)(S + 1)}} - \frac{A - 1/S}{\pi \sqrt{A^2 - 1}} \tan^{-1}\sqrt{\frac{(A + 1)(S - 1)}{(A - 1)(S + 1)}}
+///
+/// where :math:`B = (1 + S^2)/(2S)`.
+///
+/// Args:
+///     h_f (float): Flame height (m)
+///     l (float): Distance from the centre of the pool to the target (m)
+///     d (float): Pool diameter (m)
+///
+/// Returns:
+///     float: View factor (dimensionless)
+///
+/// Raises:
+///     ValueError: If the target is not outside the flame, l <= d / 2
+///
+/// Example:
+///     >>> import ofire
+///     >>> f_h = ofire.fire_dynamics_tools.chapter_5.solid_flame.view_factor_horizontal(3.0, 4.0, 2.0)
+fn view_factor_horizontal_solid_flame(h_f: f64, l: f64, d: f64) -> PyResult<f64> {
+    outside_flame(rust_solid_flame::view_factor_horizontal(h_f, l, d))
+}
+
+#[pyfunction]
+/// Maximum view factor from the horizontal and vertical components.
+///
+/// .. math::
+///
+///    F_{max} = \sqrt{F_H^2 + F_V^2}
+///
+/// Args:
+///     f_h (float): Horizontal view factor (dimensionless)
+///     f_v (float): Vertical view factor (dimensionless)
+///
+/// Returns:
+///     float: Maximum view factor (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> ofire.fire_dynamics_tools.chapter_5.solid_flame.maximum_view_factor(0.3, 0.4)
+///     0.5
+fn maximum_view_factor(f_h: f64, f_v: f64) -> PyResult<f64> {
+    Ok(rust_solid_flame::maximum_view_factor(f_h, f_v))
+}
+
+#[pyfunction]
+/// Incident radiant heat flux from a solid flame.
+///
+/// .. math::
+///
+///    \dot{q}'' = E \cdot F
+///
+/// Args:
+///     e (float): Flame emissive power (kW/m²)
+///     f (float): View factor (dimensionless)
+///
+/// Returns:
+///     float: Incident radiant heat flux (kW/m²)
+///
+/// Example:
+///     >>> import ofire
+///     >>> q = ofire.fire_dynamics_tools.chapter_5.solid_flame.incident_heat_flux(55.0, 0.1)
+fn incident_heat_flux(e: f64, f: f64) -> PyResult<f64> {
+    Ok(rust_solid_flame::incident_heat_flux(e, f))
+}
+
+#[pymodule]
+/// Solid flame model for pool fires (Shokri and Beyler).
+fn solid_flame(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(emissive_power_solid_flame, m)?)?;
+    m.add_function(wrap_pyfunction!(flame_height, m)?)?;
+    m.add_function(wrap_pyfunction!(view_factor_vertical_solid_flame, m)?)?;
+    m.add_function(wrap_pyfunction!(view_factor_vertical_elevated, m)?)?;
+    m.add_function(wrap_pyfunction!(view_factor_horizontal_solid_flame, m)?)?;
+    m.add_function(wrap_pyfunction!(maximum_view_factor, m)?)?;
+    m.add_function(wrap_pyfunction!(incident_heat_flux, m)?)?;
+    Ok(())
+}
+
+// Wind tilted flame module functions
+#[pyfunction]
+#[pyo3(name = "emissive_power")]
+/// Flame emissive power of a large hydrocarbon pool fire (Mudan and Croce).
+///
+/// .. math::
+///
+///    E = 140 \cdot e^{-0.12 D} + 20 \cdot \left(1 - e^{-0.12 D}\right)
+///
+/// Args:
+///     d (float): Pool diameter (m)
+///
+/// Returns:
+///     float: Flame emissive power (kW/m²)
+///
+/// Example:
+///     >>> import ofire
+///     >>> e = ofire.fire_dynamics_tools.chapter_5.wind_tilted_flame.emissive_power(10.0)
+fn emissive_power_wind_tilted_flame(d: f64) -> PyResult<f64> {
+    Ok(rust_wind_tilted_flame::emissive_power(d))
+}
+
+#[pyfunction]
+#[pyo3(name = "flame_height")]
+/// Pool fire flame height in still air (Thomas).
+///
+/// .. math::
+///
+///    H_f = 42 \cdot D \left(\frac{\dot{m}''}{\rho_a \sqrt{g D}}\right)^{0.61}
+///
+/// Args:
+///     d (float): Pool diameter (m)
+///     m (float): Mass burning rate per unit area (kg/m²s)
+///     rho_a (float): Ambient air density (kg/m³)
+///     g (float): Acceleration due to gravity (m/s²)
+///
+/// Returns:
+///     float: Flame height (m)
+///
+/// Example:
+///     >>> import ofire
+///     >>> h_f = ofire.fire_dynamics_tools.chapter_5.wind_tilted_flame.flame_height(10.0, 0.039, 1.2, 9.81)
+fn flame_height_wind_tilted_flame(d: f64, m: f64, rho_a: f64, g: f64) -> PyResult<f64> {
+    Ok(rust_wind_tilted_flame::flame_height(d, m, rho_a, g))
+}
+
+#[pyfunction]
+/// Non-dimensional wind velocity.
+///
+/// .. math::
+///
+///    u^* = \frac{u_w}{\left(\frac{g \dot{m}'' D}{\rho_v}\right)^{1/3}}
+///
+/// Args:
+///     u_w (float): Wind speed (m/s)
+///     g (float): Acceleration due to gravity (m/s²)
+///     m (float): Mass burning rate per unit area (kg/m²s)
+///     d (float): Pool diameter (m)
+///     rho_v (float): Fuel vapour density (kg/m³)
+///
+/// Returns:
+///     float: Non-dimensional wind velocity (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> u_star = ofire.fire_dynamics_tools.chapter_5.wind_tilted_flame.nondimensional_wind_velocity(5.0, 9.81, 0.039, 10.0, 2.0)
+fn nondimensional_wind_velocity(u_w: f64, g: f64, m: f64, d: f64, rho_v: f64) -> PyResult<f64> {
+    Ok(rust_wind_tilted_flame::nondimensional_wind_velocity(
+        u_w, g, m, d, rho_v,
+    ))
+}
+
+#[pyfunction]
+/// Pool fire flame height in wind (Thomas).
+///
+/// .. math::
+///
+///    H_f = 55 \cdot D \left(\frac{\dot{m}''}{\rho_a \sqrt{g D}}\right)^{0.67} \left(u^*\right)^{-0.21}
+///
+/// Args:
+///     d (float): Pool diameter (m)
+///     m (float): Mass burning rate per unit area (kg/m²s)
+///     rho_a (float): Ambient air density (kg/m³)
+///     g (float): Acceleration due to gravity (m/s²)
+///     u_star (float): Non-dimensional wind velocity (dimensionless)
+///
+/// Returns:
+///     float: Flame height (m)
+///
+/// Example:
+///     >>> import ofire
+///     >>> h_f = ofire.fire_dynamics_tools.chapter_5.wind_tilted_flame.flame_height_wind(10.0, 0.039, 1.2, 9.81, 4.03)
+fn flame_height_wind(d: f64, m: f64, rho_a: f64, g: f64, u_star: f64) -> PyResult<f64> {
+    Ok(rust_wind_tilted_flame::flame_height_wind(
+        d, m, rho_a, g, u_star,
+    ))
+}
+
+#[pyfunction]
+/// Angle of flame tilt from the vertical.
+///
+/// .. math::
+///
+///    \cos \theta = \begin{cases} 1 & u^* \leq 1 \\ 1 / \sqrt{u^*} & u^* > 1 \end{cases}
+///
+/// Args:
+///     u_star (float): Non-dimensional wind velocity (dimensionless)
+///
+/// Returns:
+///     float: Flame tilt angle (radians)
+///
+/// Example:
+///     >>> import ofire
+///     >>> theta = ofire.fire_dynamics_tools.chapter_5.wind_tilted_flame.flame_tilt(4.03)
+fn flame_tilt(u_star: f64) -> PyResult<f64> {
+    Ok(rust_wind_tilted_flame::flame_tilt(u_star))
+}
+
+#[pyfunction]
+#[pyo3(name = "view_factor_vertical")]
+/// View factor from a wind tilted cylindrical flame to a vertical target.
+///
+/// The flame is tilted towards the target by the angle :math:`\theta`
+/// measured from the vertical. With no tilt the result is the same as the
+/// solid flame vertical view factor.
+///
+/// Args:
+///     h_f (float): Flame length (m)
+///     l (float): Distance from the centre of the pool to the target (m)
+///     d (float): Pool diameter (m)
+///     theta (float): Flame tilt angle (radians)
+///
+/// Returns:
+///     float: View factor (dimensionless)
+///
+/// Raises:
+///     ValueError: If the target is not outside the flame, l <= d / 2
+///
+/// Example:
+///     >>> import ofire
+///     >>> f_v = ofire.fire_dynamics_tools.chapter_5.wind_tilted_flame.view_factor_vertical(3.0, 4.0, 2.0, 0.5)
+fn view_factor_vertical_wind_tilted_flame(h_f: f64, l: f64, d: f64, theta: f64) -> PyResult<f64> {
+    outside_flame(rust_wind_tilted_flame::view_factor_vertical(
+        h_f, l, d, theta,
+    ))
+}
+
+#[pyfunction]
+#[pyo3(name = "view_factor_horizontal")]
+/// View factor from a wind tilted cylindrical flame to a horizontal target.
+///
+/// Args:
+///     h_f (float): Flame length (m)
+///     l (float): Distance from the centre of the pool to the target (m)
+///     d (float): Pool diameter (m)
+///     theta (float): Flame tilt angle (radians)
+///
+/// Returns:
+///     float: View factor (dimensionless)
+///
+/// Raises:
+///     ValueError: If the target is not outside the flame, l <= d / 2
+///
+/// Example:
+///     >>> import ofire
+///     >>> f_h = ofire.fire_dynamics_tools.chapter_5.wind_tilted_flame.view_factor_horizontal(3.0, 4.0, 2.0, 0.5)
+fn view_factor_horizontal_wind_tilted_flame(h_f: f64, l: f64, d: f64, theta: f64) -> PyResult<f64> {
+    outside_flame(rust_wind_tilted_flame::view_factor_horizontal(
+        h_f, l, d, theta,
+    ))
+}
+
+#[pymodule]
+/// Solid flame model for large hydrocarbon pool fires in wind (Mudan and Croce).
+fn wind_tilted_flame(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(emissive_power_wind_tilted_flame, m)?)?;
+    m.add_function(wrap_pyfunction!(flame_height_wind_tilted_flame, m)?)?;
+    m.add_function(wrap_pyfunction!(nondimensional_wind_velocity, m)?)?;
+    m.add_function(wrap_pyfunction!(flame_height_wind, m)?)?;
+    m.add_function(wrap_pyfunction!(flame_tilt, m)?)?;
+    m.add_function(wrap_pyfunction!(view_factor_vertical_wind_tilted_flame, m)?)?;
+    m.add_function(wrap_pyfunction!(
+        view_factor_horizontal_wind_tilted_flame,
+        m
+    )?)?;
+    Ok(())
+}
+
+// Model comparison module functions
+#[pyfunction]
+/// Compare the point source and solid flame models for a pool fire.
+///
+/// Evaluates the radiant heat flux to a vertical target at ground level
+/// using the point source model (Equation 5-1) and the solid flame model
+/// with the Heskestad flame height and Shokri and Beyler emissive power.
+///
+/// Args:
+///     q (float): Heat release rate (kW)
+///     x_r (float): Radiative fraction (dimensionless)
+///     d (float): Pool diameter (m)
+///     l (float): Distance from the centre of the pool to the target (m)
+///
+/// Returns:
+///     tuple[float, float]: Point source and solid flame heat fluxes (kW/m²)
+///
+/// Raises:
+///     ValueError: If the target is within the pool, l <= d / 2
+///
+/// Example:
+///     >>> import ofire
+///     >>> point, solid = ofire.fire_dynamics_tools.chapter_5.model_comparison.compare_models(1000.0, 0.3, 1.0, 3.0)
+fn compare_models(q: f64, x_r: f64, d: f64, l: f64) -> PyResult<(f64, f64)> {
+    let result = rust_model_comparison::compare_models(q, x_r, d, l)
+        .ok_or_else(|| PyValueError::new_err("The target must be outside the pool, l > d / 2"))?;
+    Ok((result.point_source, result.solid_flame))
+}
+
+#[pymodule]
+/// Comparison of the point source and solid flame radiation models.
+fn model_comparison(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(compare_models, m)?)?;
+    Ok(())
+}
+
+// Atmospheric transmissivity module functions
+#[pyfunction]
+/// Saturated vapour pressure of water.
+///
+/// .. math::
+///
+///    p_{sat} = \exp\left(23.18986 - \frac{3816.42}{T_a - 46.13}\right)
+///
+/// Args:
+///     t_a (float): Ambient temperature (K)
+///
+/// Returns:
+///     float: Saturated vapour pressure (Pa)
+///
+/// Example:
+///     >>> import ofire
+///     >>> p_sat = ofire.fire_dynamics_tools.chapter_5.atmospheric_transmissivity.saturated_vapour_pressure(293.15)
+fn saturated_vapour_pressure(t_a: f64) -> PyResult<f64> {
+    Ok(rust_atmospheric_transmissivity::saturated_vapour_pressure(
+        t_a,
+    ))
+}
+
+#[pyfunction]
+/// Partial pressure of water vapour in the atmosphere.
+///
+/// .. math::
+///
+///    p_w = \frac{RH}{100} \cdot p_{sat}(T_a)
+///
+/// Args:
+///     rh (float): Relative humidity (%)
+///     t_a (float): Ambient temperature (K)
+///
+/// Returns:
+///     float: Partial pressure of water vapour (Pa)
+///
+/// Example:
+///     >>> import ofire
+///     >>> p_w = ofire.fire_dynamics_tools.chapter_5.atmospheric_transmissivity.water_vapour_partial_pressure(70.0, 293.15)
+fn water_vapour_partial_pressure(rh: f64, t_a: f64) -> PyResult<f64> {
+    Ok(rust_atmospheric_transmissivity::water_vapour_partial_pressure(rh, t_a))
+}
+
+#[pyfunction]
+/// Atmospheric transmissivity (Bagster).
+///
+/// .. math::
+///
+///    \tau = 2.02 \cdot \left(p_w \cdot X\right)^{-0.09}
+///
+/// The result is limited to a maximum of one.
+///
+/// Args:
+///     p_w (float): Partial pressure of water vapour (Pa)
+///     x (float): Path length from the flame surface to the target (m)
+///
+/// Returns:
+///     float: Atmospheric transmissivity (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> tau = ofire.fire_dynamics_tools.chapter_5.atmospheric_transmissivity.transmissivity(1609.0, 50.0)
+fn transmissivity(p_w: f64, x: f64) -> PyResult<f64> {
+    Ok(rust_atmospheric_transmissivity::transmissivity(p_w, x))
+}
+
+#[pyfunction]
+/// Atmospheric transmissivity (API 521).
+///
+/// .. math::
+///
+///    \tau = 0.79 \left(\frac{100}{RH}\right)^{1/16} \left(\frac{30.5}{R}\right)^{1/16}
+///
+/// The result is limited to a maximum of one.
+///
+/// Args:
+///     rh (float): Relative humidity (%)
+///     r (float): Path length from the flame to the target (m)
+///
+/// Returns:
+///     float: Atmospheric transmissivity (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> tau = ofire.fire_dynamics_tools.chapter_5.atmospheric_transmissivity.transmissivity_api_521(70.0, 50.0)
+fn transmissivity_api_521(rh: f64, r: f64) -> PyResult<f64> {
+    Ok(rust_atmospheric_transmissivity::transmissivity_api_521(
+        rh, r,
+    ))
+}
+
+#[pyfunction]
+/// Atmospheric transmissivity from relative humidity and ambient temperature.
+///
+/// Combines the water vapour partial pressure and the Bagster
+/// transmissivity.
+///
+/// Args:
+///     rh (float): Relative humidity (%)
+///     t_a (float): Ambient temperature (K)
+///     x (float): Path length from the flame surface to the target (m)
+///
+/// Returns:
+///     float: Atmospheric transmissivity (dimensionless)
+///
+/// Example:
+///     >>> import ofire
+///     >>> tau = ofire.fire_dynamics_tools.chapter_5.atmospheric_transmissivity.transmissivity_from_humidity(70.0, 293.15, 50.0)
+fn transmissivity_from_humidity(rh: f64, t_a: f64, x: f64) -> PyResult<f64> {
+    Ok(rust_atmospheric_transmissivity::transmissivity_from_humidity(rh, t_a, x))
+}
+
+#[pyfunction]
+/// Received intensity attenuated by the atmosphere.
+///
+/// .. math::
+///
+///    \dot{q}''_{r} = \tau \cdot \dot{q}''
+///
+/// Args:
+///     tau (float): Atmospheric transmissivity (dimensionless)
+///     q (float): Received intensity without attenuation (kW/m²)
+///
+/// Returns:
+///     float: Attenuated received intensity (kW/m²)
+///
+/// Example:
+///     >>> import ofire
+///     >>> ofire.fire_dynamics_tools.chapter_5.atmospheric_transmissivity.attenuated_intensity(0.75, 12.0)
+///     9.0
+fn attenuated_intensity(tau: f64, q: f64) -> PyResult<f64> {
+    Ok(rust_atmospheric_transmissivity::attenuated_intensity(
+        tau, q,
+    ))
+}
+
+#[pyfunction]
+/// Radiant heat flux from a point source fire attenuated by the atmosphere.
+///
+/// .. math::
+///
+///    \dot{q}^{"} = \tau \cdot \frac{\chi_r \cdot \dot{Q}}{4 \cdot \pi \cdot r^2}
+///
+/// Args:
+///     tau (float): Atmospheric transmissivity (dimensionless)
+///     q (float): Heat release rate (kW)
+///     r (float): Radial distance (m)
+///     x_r (float): Radiative fraction (dimensionless)
+///
+/// Returns:
+///     float: Attenuated radiant heat flux (kW/m²)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.fire_dynamics_tools.chapter_5.atmospheric_transmissivity.attenuated_point_source(0.8, 750.0, 2.5, 0.3)
+fn attenuated_point_source(tau: f64, q: f64, r: f64, x_r: f64) -> PyResult<f64> {
+    Ok(rust_atmospheric_transmissivity::attenuated_point_source(
+        tau, q, r, x_r,
+    ))
+}
+
+#[pymodule]
+/// Atmospheric transmissivity of thermal radiation over long path lengths.
+fn atmospheric_transmissivity(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(saturated_vapour_pressure, m)?)?;
+    m.add_function(wrap_pyfunction!(water_vapour_partial_pressure, m)?)?;
+    m.add_function(wrap_pyfunction!(transmissivity, m)?)?;
+    m.add_function(wrap_pyfunction!(transmissivity_api_521, m)?)?;
+    m.add_function(wrap_pyfunction!(transmissivity_from_humidity, m)?)?;
+    m.add_function(wrap_pyfunction!(attenuated_intensity, m)?)?;
+    m.add_function(wrap_pyfunction!(attenuated_point_source, m)?)?;
+    Ok(())
+}
+
 #[pymodule]
 /// Chapter 5 - Estimating Radiant Heat Flux fom Fire to a Target Fuel.
 ///
 /// This module contains fire dynamics calculations from Chapter 5.
 pub fn chapter_5(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pymodule!(equation_5_1))?;
+    m.add_wrapped(wrap_pymodule!(solid_flame))?;
+    m.add_wrapped(wrap_pymodule!(wind_tilted_flame))?;
+    m.add_wrapped(wrap_pymodule!(model_comparison))?;
+    m.add_wrapped(wrap_pymodule!(atmospheric_transmissivity))?;
     Ok(())
 }
@@ -3,7 +3,7 @@ use pyo3::wrap_pymodule;
 
 use openfire::fire_dynamics_tools::chapter_9::{
     equation_9_2 as rust_equation_9_2, equation_9_3 as rust_equation_9_3,
-    equation_9_4 as rust_equation_9_4,
+    equation_9_4 as rust_equation_9_4, plume_mass_flow as rust_plume_mass_flow,
 };
 
 #[pyfunction]
@@ -134,6 +134,229 @@ fn effective_diameter(a_f: f64) -> PyResult<f64> {
     Ok(rust_equation_9_4::effective_diameter(a_f))
 }
 
+type PlumeFlowTuple = (f64, f64, f64);
+
+fn flow_tuple(flow: rust_plume_mass_flow::PlumeFlow) -> PlumeFlowTuple {
+    (flow.mass_flow, flow.temperature, flow.volumetric_flow)
+}
+
+#[pyfunction]
+#[pyo3(signature = (q_c, z, z_0=0.0, t_a=293.15))]
+/// Heskestad axisymmetric plume mass flow.
+///
+/// .. math::
+///
+///    \dot{m} = 0.071 \dot{Q}_c^{1/3} (z - z_0)^{5/3} + 0.0018 \dot{Q}_c
+///
+/// above the limiting flame height :math:`z_l = 0.166 \dot{Q}_c^{2/5}`, and
+/// :math:`\dot{m} = 0.032 \dot{Q}_c^{3/5} z` below it.
+///
+/// Args:
+///     q_c (float): Convective heat release rate (kW)
+///     z (float): Height above the fire (m)
+///     z_0 (float): Virtual origin (m)
+///     t_a (float): Ambient temperature (K)
+///
+/// Returns:
+///     tuple[float, float, float]: Mass flow (kg/s), mean plume temperature (K) and volumetric flow (m³/s)
+///
+/// Example:
+///     >>> import ofire
+///     >>> m, t, v = ofire.fire_dynamics_tools.chapter_9.plume_mass_flow.heskestad(3500.0, 10.0)
+fn heskestad(q_c: f64, z: f64, z_0: f64, t_a: f64) -> PyResult<PlumeFlowTuple> {
+    Ok(flow_tuple(rust_plume_mass_flow::heskestad(
+        q_c, z, z_0, t_a,
+    )))
+}
+
+#[pyfunction]
+#[pyo3(signature = (q_c, z, t_a=293.15))]
+/// Zukoski point source plume mass flow.
+///
+/// .. math::
+///
+///    \dot{m} = 0.071 \dot{Q}_c^{1/3} z^{5/3}
+///
+/// Args:
+///     q_c (float): Convective heat release rate (kW)
+///     z (float): Height above the fire (m)
+///     t_a (float): Ambient temperature (K)
+///
+/// Returns:
+///     tuple[float, float, float]: Mass flow (kg/s), mean plume temperature (K) and volumetric flow (m³/s)
+///
+/// Example:
+///     >>> import ofire
+///     >>> m, t, v = ofire.fire_dynamics_tools.chapter_9.plume_mass_flow.zukoski(3500.0, 10.0)
+fn zukoski(q_c: f64, z: f64, t_a: f64) -> PyResult<PlumeFlowTuple> {
+    Ok(flow_tuple(rust_plume_mass_flow::zukoski(q_c, z, t_a)))
+}
+
+#[pyfunction]
+#[pyo3(signature = (q_c, perimeter, z, t_a=293.15))]
+/// Thomas large fire plume mass flow, for heights up to about the fire diameter.
+///
+/// .. math::
+///
+///    \dot{m} = 0.188 P z^{3/2}
+///
+/// Args:
+///     q_c (float): Convective heat release rate (kW)
+///     perimeter (float): Fire perimeter (m)
+///     z (float): Height above the fire (m)
+///     t_a (float): Ambient temperature (K)
+///
+/// Returns:
+///     tuple[float, float, float]: Mass flow (kg/s), mean plume temperature (K) and volumetric flow (m³/s)
+///
+/// Example:
+///     >>> import ofire
+///     >>> m, t, v = ofire.fire_dynamics_tools.chapter_9.plume_mass_flow.thomas(3500.0, 12.0, 3.0)
+fn thomas(q_c: f64, perimeter: f64, z: f64, t_a: f64) -> PyResult<PlumeFlowTuple> {
+    Ok(flow_tuple(rust_plume_mass_flow::thomas(
+        q_c, perimeter, z, t_a,
+    )))
+}
+
+#[pyfunction]
+#[pyo3(signature = (q_c, w, z_b, h, t_a=293.15))]
+/// Balcony spill plume mass flow (BRE/NFPA 92).
+///
+/// .. math::
+///
+///    \dot{m} = 0.36 (\dot{Q}_c W^2)^{1/3} (z_b + 0.25 H)
+///
+/// Args:
+///     q_c (float): Convective heat release rate (kW)
+///     w (float): Width of the spill edge (m)
+///     z_b (float): Height above the balcony edge (m)
+///     h (float): Height of the balcony above the fire (m)
+///     t_a (float): Ambient temperature (K)
+///
+/// Returns:
+///     tuple[float, float, float]: Mass flow (kg/s), mean plume temperature (K) and volumetric flow (m³/s)
+///
+/// Example:
+///     >>> import ofire
+///     >>> m, t, v = ofire.fire_dynamics_tools.chapter_9.plume_mass_flow.balcony_spill(1000.0, 10.0, 6.0, 4.0)
+fn balcony_spill(q_c: f64, w: f64, z_b: f64, h: f64, t_a: f64) -> PyResult<PlumeFlowTuple> {
+    Ok(flow_tuple(rust_plume_mass_flow::balcony_spill(
+        q_c, w, z_b, h, t_a,
+    )))
+}
+
+#[pyfunction]
+#[pyo3(signature = (q_c, l, z, t_a=293.15))]
+/// Free line plume mass flow, entraining on both faces.
+///
+/// .. math::
+///
+///    \dot{m} = 0.21 \dot{Q}_c^{1/3} L^{2/3} z
+///
+/// Args:
+///     q_c (float): Convective heat release rate (kW)
+///     l (float): Length of the line source (m)
+///     z (float): Height above the source (m)
+///     t_a (float): Ambient temperature (K)
+///
+/// Returns:
+///     tuple[float, float, float]: Mass flow (kg/s), mean plume temperature (K) and volumetric flow (m³/s)
+///
+/// Example:
+///     >>> import ofire
+///     >>> m, t, v = ofire.fire_dynamics_tools.chapter_9.plume_mass_flow.free_line(1000.0, 8.0, 5.0)
+fn free_line(q_c: f64, l: f64, z: f64, t_a: f64) -> PyResult<PlumeFlowTuple> {
+    Ok(flow_tuple(rust_plume_mass_flow::free_line(q_c, l, z, t_a)))
+}
+
+#[pyfunction]
+#[pyo3(signature = (q_c, l, z, t_a=293.15))]
+/// Line plume mass flow for a plume adhered to a wall.
+///
+/// By the method of images the plume entrains half as much as a free line
+/// plume carrying twice the heat.
+///
+/// Args:
+///     q_c (float): Convective heat release rate (kW)
+///     l (float): Length of the line source (m)
+///     z (float): Height above the source (m)
+///     t_a (float): Ambient temperature (K)
+///
+/// Returns:
+///     tuple[float, float, float]: Mass flow (kg/s), mean plume temperature (K) and volumetric flow (m³/s)
+///
+/// Example:
+///     >>> import ofire
+///     >>> m, t, v = ofire.fire_dynamics_tools.chapter_9.plume_mass_flow.adhered_line(1000.0, 8.0, 5.0)
+fn adhered_line(q_c: f64, l: f64, z: f64, t_a: f64) -> PyResult<PlumeFlowTuple> {
+    Ok(flow_tuple(rust_plume_mass_flow::adhered_line(
+        q_c, l, z, t_a,
+    )))
+}
+
+#[pyfunction]
+#[pyo3(signature = (a_w, h_w, z_w, t_a=293.15))]
+/// Window plume mass flow from a ventilation controlled room fire (NFPA 92).
+///
+/// .. math::
+///
+///    \dot{m} = 0.68 (A_w H_w^{1/2})^{1/3} (z_w + a)^{5/3} + 1.59 A_w H_w^{1/2}
+///
+/// with :math:`a = 2.40 A_w^{2/5} H_w^{1/5} - 2.1 H_w` and a convective heat
+/// release of 70 % of :math:`1260 A_w H_w^{1/2}`.
+///
+/// Args:
+///     a_w (float): Window area (m²)
+///     h_w (float): Window height (m)
+///     z_w (float): Height above the top of the window (m)
+///     t_a (float): Ambient temperature (K)
+///
+/// Returns:
+///     tuple[float, float, float]: Mass flow (kg/s), mean plume temperature (K) and volumetric flow (m³/s)
+///
+/// Example:
+///     >>> import ofire
+///     >>> m, t, v = ofire.fire_dynamics_tools.chapter_9.plume_mass_flow.window(4.0, 2.0, 5.0)
+fn window(a_w: f64, h_w: f64, z_w: f64, t_a: f64) -> PyResult<PlumeFlowTuple> {
+    Ok(flow_tuple(rust_plume_mass_flow::window(a_w, h_w, z_w, t_a)))
+}
+
+#[pyfunction]
+#[pyo3(signature = (q, a_f, z, t_a=293.15))]
+/// Axisymmetric plume mass flow with the correlation selected by flame height.
+///
+/// Within the Heskestad mean flame height the Thomas correlation is used for
+/// the perimeter of a circular fire of the same area; above it the Heskestad
+/// correlation is used from the virtual origin of Equation 9-3. The
+/// convective heat release is 70 % of the total.
+///
+/// Args:
+///     q (float): Heat release rate (kW)
+///     a_f (float): Fire area (m²), zero for a point source
+///     z (float): Height above the fire (m)
+///     t_a (float): Ambient temperature (K)
+///
+/// Returns:
+///     tuple[str, float, float, float]: Regime ("flame" or "plume"), mass flow (kg/s),
+///     mean plume temperature (K) and volumetric flow (m³/s)
+///
+/// Example:
+///     >>> import ofire
+///     >>> regime, m, t, v = ofire.fire_dynamics_tools.chapter_9.plume_mass_flow.axisymmetric(5000.0, 9.0, 10.0)
+fn axisymmetric(q: f64, a_f: f64, z: f64, t_a: f64) -> PyResult<(String, f64, f64, f64)> {
+    let (regime, flow) = rust_plume_mass_flow::axisymmetric(q, a_f, z, t_a);
+    let regime = match regime {
+        rust_plume_mass_flow::Regime::Flame => "flame",
+        rust_plume_mass_flow::Regime::Plume => "plume",
+    };
+    Ok((
+        regime.to_string(),
+        flow.mass_flow,
+        flow.temperature,
+        flow.volumetric_flow,
+    ))
+}
+
 #[pymodule]
 /// Equation 9-2 - Maximum centerline temperature rise in fire plumes.
 ///
@@ -167,6 +390,23 @@ fn equation_9_4(m: &Bound<'_, PyModule>) -> PyResult<()> {
     Ok(())
 }
 
+#[pymodule]
+/// Plume mass flow - Axisymmetric, spill, line and window plume entrainment.
+///
+/// This module contains plume mass flow correlations for smoke control
+/// design, each returning the mass and volumetric flow at a given height.
+fn plume_mass_flow(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(heskestad, m)?)?;
+    m.add_function(wrap_pyfunction!(zukoski, m)?)?;
+    m.add_function(wrap_pyfunction!(thomas, m)?)?;
+    m.add_function(wrap_pyfunction!(balcony_spill, m)?)?;
+    m.add_function(wrap_pyfunction!(free_line, m)?)?;
+    m.add_function(wrap_pyfunction!(adhered_line, m)?)?;
+    m.add_function(wrap_pyfunction!(window, m)?)?;
+    m.add_function(wrap_pyfunction!(axisymmetric, m)?)?;
+    Ok(())
+}
+
 #[pymodule]
 /// Chapter 9 - Fire plume temperature calculations.
 ///
@@ -175,5 +415,6 @@ pub fn chapter_9(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pymodule!(equation_9_2))?;
     m.add_wrapped(wrap_pymodule!(equation_9_3))?;
     m.add_wrapped(wrap_pymodule!(equation_9_4))?;
+    m.add_wrapped(wrap_pymodule!(plume_mass_flow))?;
     Ok(())
 }
@@ -0,0 +1,39 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// Values passed from Python at times 0, `delta_t`, 2 `delta_t`, ...,
+/// interpolated linearly and held constant beyond the last value.
+pub(crate) struct History<'a> {
+    values: &'a [f64],
+    delta_t: f64,
+}
+
+impl<'a> History<'a> {
+    /// Raises a `ValueError` naming the argument `name` if `values` is empty
+    /// or `delta_t` is not positive.
+    pub(crate) fn new(name: &str, values: &'a [f64], delta_t: f64) -> PyResult<Self> {
+        if values.is_empty() {
+            return Err(PyValueError::new_err(format!("{} must not be empty", name)));
+        }
+        if delta_t <= 0.0 {
+            return Err(PyValueError::new_err("delta_t must be positive"));
+        }
+        Ok(History { values, delta_t })
+    }
+
+    /// Time of the last value.
+    pub(crate) fn end(&self) -> f64 {
+        (self.values.len() - 1) as f64 * self.delta_t
+    }
+
+    /// Value at time `t`.
+    pub(crate) fn at(&self, t: f64) -> f64 {
+        let last = self.values.len() - 1;
+        if last == 0 {
+            return self.values[0];
+        }
+        let x = (t / self.delta_t).clamp(0.0, last as f64);
+        let i = (x.floor() as usize).min(last - 1);
+        self.values[i] + (x - i as f64) * (self.values[i + 1] - self.values[i])
+    }
+}
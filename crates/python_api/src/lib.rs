@@ -4,7 +4,11 @@ mod br_187;
 mod bs9999;
 mod cibse_guide_e;
 mod eurocode_1_1_2;
+mod eurocode_2_1_2;
+mod eurocode_3_1_2;
+mod eurocode_5_1_2;
 mod fire_dynamics_tools;
+mod history;
 mod introduction_to_fire_dynamics;
 mod pd_7974;
 mod sfpe_handbook;
@@ -27,6 +31,9 @@ use pyo3::wrap_pymodule;
 ///     bs9999: BS 9999 fire safety calculations  
 ///     cibse_guide_e: CIBSE Guide E calculations
 ///     eurocode_1_1_2: Eurocode 1, Part 1-2 calculations
+///     eurocode_2_1_2: Eurocode 2, Part 1-2 calculations
+///     eurocode_3_1_2: Eurocode 3, Part 1-2 calculations
+///     eurocode_5_1_2: Eurocode 5, Part 1-2 calculations
 ///     fire_dynamics_tools: General fire dynamics tools
 ///     introduction_to_fire_dynamics: Introductory fire dynamics
 ///     sfpe_handbook: SFPE Handbook calculations
@@ -42,6 +49,9 @@ fn ofire(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pymodule!(bs9999::bs9999))?;
     m.add_wrapped(wrap_pymodule!(cibse_guide_e::cibse_guide_e))?;
     m.add_wrapped(wrap_pymodule!(eurocode_1_1_2::eurocode_1_1_2))?;
+    m.add_wrapped(wrap_pymodule!(eurocode_2_1_2::eurocode_2_1_2))?;
+    m.add_wrapped(wrap_pymodule!(eurocode_3_1_2::eurocode_3_1_2))?;
+    m.add_wrapped(wrap_pymodule!(eurocode_5_1_2::eurocode_5_1_2))?;
     m.add_wrapped(wrap_pymodule!(fire_dynamics_tools::fire_dynamics_tools))?;
     m.add_wrapped(wrap_pymodule!(
         introduction_to_fire_dynamics::introduction_to_fire_dynamics
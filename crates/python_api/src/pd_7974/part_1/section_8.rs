@@ -1,7 +1,14 @@
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::wrap_pymodule;
 
 // Import all pd_7974 functions
+use openfire::pd_7974::part_1::section_8::design_fire::{
+    Decay as RustDecay, DesignFire as RustDesignFire, GrowthRate as RustGrowthRate,
+};
+use openfire::pd_7974::part_1::section_8::vent_flow::{
+    self as rust_vent_flow, Opening as RustOpening,
+};
 use openfire::pd_7974::part_1::section_8::{
     equation_4 as rust_equation_4, equation_28 as rust_equation_28,
     equation_29 as rust_equation_29, equation_33 as rust_equation_33,
@@ -392,6 +399,419 @@ fn equation_44(m: &Bound<'_, PyModule>) -> PyResult<()> {
     Ok(())
 }
 
+// Design fire module functions
+/// Growth rate given by name or as a fire growth coefficient (kW/s²).
+#[derive(FromPyObject)]
+enum Growth {
+    Named(String),
+    Alpha(f64),
+}
+
+fn parse_growth_rate(growth: Growth) -> PyResult<RustGrowthRate> {
+    match growth {
+        Growth::Named(name) => match name.to_lowercase().as_str() {
+            "slow" => Ok(RustGrowthRate::Slow),
+            "medium" => Ok(RustGrowthRate::Medium),
+            "fast" => Ok(RustGrowthRate::Fast),
+            "ultra_fast" => Ok(RustGrowthRate::UltraFast),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown growth rate '{}', expected 'slow', 'medium', 'fast' or 'ultra_fast'",
+                name
+            ))),
+        },
+        Growth::Alpha(alpha) if alpha > 0.0 => Ok(RustGrowthRate::Custom(alpha)),
+        Growth::Alpha(_) => Err(PyValueError::new_err(
+            "the fire growth coefficient must be positive",
+        )),
+    }
+}
+
+fn parse_decay(decay: &str) -> PyResult<RustDecay> {
+    match decay.to_lowercase().as_str() {
+        "linear" => Ok(RustDecay::Linear),
+        "exponential" => Ok(RustDecay::Exponential),
+        _ => Err(PyValueError::new_err(format!(
+            "Unknown decay '{}', expected 'linear' or 'exponential'",
+            decay
+        ))),
+    }
+}
+
+fn build_design_fire(
+    growth: Growth,
+    q_peak: f64,
+    fuel_load: Option<(f64, f64)>,
+    decay: &str,
+) -> PyResult<RustDesignFire> {
+    let fire = RustDesignFire::new(parse_growth_rate(growth)?, q_peak);
+    let decay = parse_decay(decay)?;
+    Ok(match fuel_load {
+        Some((q_f, a_f)) => fire.with_fuel_load(q_f, a_f, decay),
+        None => fire,
+    })
+}
+
+#[pyfunction]
+/// Fire growth coefficient of a named t-squared growth rate.
+///
+/// .. math::
+///
+///    Q = \alpha t^2
+///
+/// Args:
+///     growth (str or float): Growth rate: "slow", "medium", "fast" or "ultra_fast", or a fire growth coefficient (kW/s²)
+///
+/// Returns:
+///     float: Fire growth coefficient (kW/s²)
+///
+/// Raises:
+///     ValueError: If the growth rate is not recognised or not positive
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.pd_7974.part_1.section_8.design_fire.alpha("fast")
+fn alpha(growth: Growth) -> PyResult<f64> {
+    Ok(parse_growth_rate(growth)?.alpha())
+}
+
+#[pyfunction]
+/// Peak heat release rate capped at the lower of the fuel controlled
+/// (Equation 4) and ventilation controlled (Equation 33) values.
+///
+/// Args:
+///     a_f (float): Fire area (m²)
+///     hrrpua (float): Heat release rate per unit area (kW/m²)
+///     a_v (float): Area of the ventilation opening (m²)
+///     h_v (float): Height of the ventilation opening (m)
+///
+/// Returns:
+///     float: Peak heat release rate (kW)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.pd_7974.part_1.section_8.design_fire.peak_hrr(20.0, 500.0, 2.0, 2.25)
+fn peak_hrr(a_f: f64, hrrpua: f64, a_v: f64, h_v: f64) -> PyResult<f64> {
+    let q_fc = rust_equation_4::q_max_fc(a_f, hrrpua);
+    let q_vc = rust_equation_33::q_max_vc(a_v, h_v);
+    Ok(q_fc.min(q_vc))
+}
+
+#[pyfunction]
+#[pyo3(signature = (growth, q_peak, t, fuel_load=None, decay="linear"))]
+/// Heat release rate of a design fire.
+///
+/// The fire grows as :math:`\alpha t^2` up to ``q_peak``. With a fuel load
+/// it decays once 70 % of the fuel has burnt, releasing the remainder
+/// linearly or exponentially.
+///
+/// Args:
+///     growth (str or float): Growth rate: "slow", "medium", "fast" or "ultra_fast", or a fire growth coefficient (kW/s²)
+///     q_peak (float): Peak heat release rate (kW)
+///     t (float): Time (s)
+///     fuel_load (tuple[float, float], optional): Fuel load density (MJ/m²) and floor area (m²)
+///     decay (str): Decay after the fuel limit: "linear" or "exponential"
+///
+/// Returns:
+///     float: Heat release rate (kW)
+///
+/// Raises:
+///     ValueError: If the growth rate or decay is not recognised, or the growth rate is not positive
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.pd_7974.part_1.section_8.design_fire.hrr("fast", 5000.0, 600.0, (400.0, 20.0))
+fn hrr(
+    growth: Growth,
+    q_peak: f64,
+    t: f64,
+    fuel_load: Option<(f64, f64)>,
+    decay: &str,
+) -> PyResult<f64> {
+    Ok(build_design_fire(growth, q_peak, fuel_load, decay)?.hrr(t))
+}
+
+#[pyfunction]
+#[pyo3(signature = (growth, q_peak, t, fuel_load=None, decay="linear"))]
+/// Energy released by a design fire up to a given time.
+///
+/// Args:
+///     growth (str or float): Growth rate: "slow", "medium", "fast" or "ultra_fast", or a fire growth coefficient (kW/s²)
+///     q_peak (float): Peak heat release rate (kW)
+///     t (float): Time (s)
+///     fuel_load (tuple[float, float], optional): Fuel load density (MJ/m²) and floor area (m²)
+///     decay (str): Decay after the fuel limit: "linear" or "exponential"
+///
+/// Returns:
+///     float: Energy released (kJ)
+///
+/// Raises:
+///     ValueError: If the growth rate or decay is not recognised, or the growth rate is not positive
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.pd_7974.part_1.section_8.design_fire.energy("medium", 2000.0, 900.0)
+fn energy(
+    growth: Growth,
+    q_peak: f64,
+    t: f64,
+    fuel_load: Option<(f64, f64)>,
+    decay: &str,
+) -> PyResult<f64> {
+    Ok(build_design_fire(growth, q_peak, fuel_load, decay)?.energy(t))
+}
+
+#[pyfunction]
+#[pyo3(signature = (growth, q_peak, fuel_load, decay="linear"))]
+/// Time and heat release rate at the start of the decay phase.
+///
+/// Args:
+///     growth (str or float): Growth rate: "slow", "medium", "fast" or "ultra_fast", or a fire growth coefficient (kW/s²)
+///     q_peak (float): Peak heat release rate (kW)
+///     fuel_load (tuple[float, float]): Fuel load density (MJ/m²) and floor area (m²)
+///     decay (str): Decay after the fuel limit: "linear" or "exponential"
+///
+/// Returns:
+///     tuple[float, float]: Time (s) and heat release rate (kW)
+///
+/// Raises:
+///     ValueError: If the growth rate or decay is not recognised, or the growth rate is not positive
+///
+/// Example:
+///     >>> import ofire
+///     >>> t_d, q_d = ofire.pd_7974.part_1.section_8.design_fire.decay_start("fast", 5000.0, (400.0, 20.0))
+fn decay_start(
+    growth: Growth,
+    q_peak: f64,
+    fuel_load: (f64, f64),
+    decay: &str,
+) -> PyResult<(f64, f64)> {
+    let fire = build_design_fire(growth, q_peak, Some(fuel_load), decay)?;
+    Ok(fire.decay_start().unwrap())
+}
+
+#[pyfunction]
+#[pyo3(signature = (growth, q_peak, step, t_end, fuel_load=None, decay="linear"))]
+/// Design fire sampled at a constant time step.
+///
+/// Args:
+///     growth (str or float): Growth rate: "slow", "medium", "fast" or "ultra_fast", or a fire growth coefficient (kW/s²)
+///     q_peak (float): Peak heat release rate (kW)
+///     step (float): Time step (s)
+///     t_end (float): End time (s)
+///     fuel_load (tuple[float, float], optional): Fuel load density (MJ/m²) and floor area (m²)
+///     decay (str): Decay after the fuel limit: "linear" or "exponential"
+///
+/// Returns:
+///     list[tuple[float, float]]: Time (s) and heat release rate (kW) pairs
+///
+/// Raises:
+///     ValueError: If the growth rate or decay is not recognised, the growth rate is not positive or the step is not positive
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.pd_7974.part_1.section_8.design_fire.sample("fast", 5000.0, 10.0, 3600.0)
+fn sample(
+    growth: Growth,
+    q_peak: f64,
+    step: f64,
+    t_end: f64,
+    fuel_load: Option<(f64, f64)>,
+    decay: &str,
+) -> PyResult<Vec<(f64, f64)>> {
+    if step <= 0.0 {
+        return Err(PyValueError::new_err("step must be positive"));
+    }
+    Ok(build_design_fire(growth, q_peak, fuel_load, decay)?.sample(step, t_end))
+}
+
+#[pymodule]
+/// Design fire - t-squared growth, peak capping and fuel limited decay.
+///
+/// Provides heat release rate curves for design fires.
+fn design_fire(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(alpha, m)?)?;
+    m.add_function(wrap_pyfunction!(peak_hrr, m)?)?;
+    m.add_function(wrap_pyfunction!(hrr, m)?)?;
+    m.add_function(wrap_pyfunction!(energy, m)?)?;
+    m.add_function(wrap_pyfunction!(decay_start, m)?)?;
+    m.add_function(wrap_pyfunction!(sample, m)?)?;
+    Ok(())
+}
+
+fn build_opening(opening: (f64, f64), c_d: f64) -> RustOpening {
+    let (width, height) = opening;
+    RustOpening {
+        c_d,
+        ..RustOpening::new(width, height)
+    }
+}
+
+#[pyfunction]
+/// Air inflow of a ventilation controlled fire, after Kawagoe.
+///
+/// .. math::
+///
+///    \dot{m}_a = 0.5 A_v \sqrt{H_v}
+///
+/// At 3000 kJ per kg of air this gives the ventilation controlled heat
+/// release rate of Equation 33.
+///
+/// Args:
+///     a_v (float): Area of the opening (m²)
+///     h_v (float): Height of the opening (m)
+///
+/// Returns:
+///     float: Air inflow (kg/s)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.pd_7974.part_1.section_8.vent_flow.kawagoe_air_flow(3.0, 2.0)
+fn kawagoe_air_flow(a_v: f64, h_v: f64) -> PyResult<f64> {
+    Ok(rust_vent_flow::kawagoe_air_flow(a_v, h_v))
+}
+
+#[pyfunction]
+/// Pressure difference across an opening relative to the neutral plane.
+///
+/// .. math::
+///
+///    \Delta p = (\rho_a - \rho_g) g z
+///
+/// Args:
+///     t_g (float): Hot gas temperature (K)
+///     t_a (float): Ambient temperature (K)
+///     z (float): Height above the neutral plane (m)
+///
+/// Returns:
+///     float: Pressure difference, positive outwards (Pa)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.pd_7974.part_1.section_8.vent_flow.pressure_difference(873.15, 293.15, 1.0)
+fn pressure_difference(t_g: f64, t_a: f64, z: f64) -> PyResult<f64> {
+    Ok(rust_vent_flow::pressure_difference(t_g, t_a, z))
+}
+
+#[pyfunction]
+#[pyo3(signature = (opening, t_g, t_a, h_n, c_d=0.7))]
+/// Hot gas outflow above the neutral plane.
+///
+/// .. math::
+///
+///    \dot{m}_g = \frac{2}{3} C_d W \sqrt{2 g \rho_g (\rho_a - \rho_g)} (H - H_n)^{3/2}
+///
+/// Args:
+///     opening (tuple[float, float]): Width (m) and height (m) of the opening
+///     t_g (float): Hot gas temperature (K)
+///     t_a (float): Ambient temperature (K)
+///     h_n (float): Neutral plane height above the sill (m)
+///     c_d (float): Discharge coefficient
+///
+/// Returns:
+///     float: Hot gas outflow (kg/s)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.pd_7974.part_1.section_8.vent_flow.outflow((1.0, 2.0), 1073.15, 293.15, 0.8)
+fn outflow(opening: (f64, f64), t_g: f64, t_a: f64, h_n: f64, c_d: f64) -> PyResult<f64> {
+    Ok(build_opening(opening, c_d).outflow(t_g, t_a, h_n))
+}
+
+#[pyfunction]
+#[pyo3(signature = (opening, t_g, t_a, h_n, h_d=0.0, c_d=0.7))]
+/// Ambient air inflow below the neutral plane.
+///
+/// .. math::
+///
+///    \dot{m}_a = \frac{2}{3} C_d W \sqrt{2 g \rho_a (\rho_a - \rho_g)} (H_n - H_d)^{1/2} \left(H_n + \frac{H_d}{2}\right)
+///
+/// Args:
+///     opening (tuple[float, float]): Width (m) and height (m) of the opening
+///     t_g (float): Hot gas temperature (K)
+///     t_a (float): Ambient temperature (K)
+///     h_n (float): Neutral plane height above the sill (m)
+///     h_d (float): Layer interface height above the sill (m)
+///     c_d (float): Discharge coefficient
+///
+/// Returns:
+///     float: Air inflow (kg/s)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.pd_7974.part_1.section_8.vent_flow.inflow((1.0, 2.0), 1073.15, 293.15, 0.8)
+fn inflow(opening: (f64, f64), t_g: f64, t_a: f64, h_n: f64, h_d: f64, c_d: f64) -> PyResult<f64> {
+    Ok(build_opening(opening, c_d).inflow(t_g, t_a, h_n, h_d))
+}
+
+#[pyfunction]
+/// Neutral plane height of a fully mixed compartment with no fuel mass added.
+///
+/// .. math::
+///
+///    H_n = \frac{H}{1 + (T_g / T_a)^{1/3}}
+///
+/// Args:
+///     opening (tuple[float, float]): Width (m) and height (m) of the opening
+///     t_g (float): Hot gas temperature (K)
+///     t_a (float): Ambient temperature (K)
+///
+/// Returns:
+///     float: Neutral plane height above the sill (m)
+///
+/// Example:
+///     >>> import ofire
+///     >>> result = ofire.pd_7974.part_1.section_8.vent_flow.neutral_plane_mixed((1.0, 2.0), 1073.15, 293.15)
+fn neutral_plane_mixed(opening: (f64, f64), t_g: f64, t_a: f64) -> PyResult<f64> {
+    Ok(build_opening(opening, 0.7).neutral_plane_mixed(t_g, t_a))
+}
+
+#[pyfunction]
+#[pyo3(signature = (opening, t_g, t_a, h_d=0.0, m_f=0.0, c_d=0.7))]
+/// Flows through an opening with the neutral plane where the hot gas
+/// outflow balances the air inflow and the fuel pyrolysis rate.
+///
+/// Args:
+///     opening (tuple[float, float]): Width (m) and height (m) of the opening
+///     t_g (float): Hot gas temperature (K)
+///     t_a (float): Ambient temperature (K)
+///     h_d (float): Layer interface height above the sill (m), zero for a fully mixed compartment
+///     m_f (float): Fuel pyrolysis rate (kg/s)
+///     c_d (float): Discharge coefficient
+///
+/// Returns:
+///     tuple[float, float, float] or None: Neutral plane height above the sill (m), outflow (kg/s) and inflow (kg/s), or None if the gas is not hotter than ambient, the interface is not below the soffit or the fuel exceeds the largest outflow
+///
+/// Example:
+///     >>> import ofire
+///     >>> h_n, m_out, m_in = ofire.pd_7974.part_1.section_8.vent_flow.flows((1.0, 2.0), 1073.15, 293.15)
+fn flows(
+    opening: (f64, f64),
+    t_g: f64,
+    t_a: f64,
+    h_d: f64,
+    m_f: f64,
+    c_d: f64,
+) -> PyResult<Option<(f64, f64, f64)>> {
+    Ok(build_opening(opening, c_d)
+        .flows(t_g, t_a, h_d, m_f)
+        .map(|flow| (flow.neutral_plane, flow.outflow, flow.inflow)))
+}
+
+#[pymodule]
+/// Vent flow - Buoyancy driven flow through vertical openings.
+///
+/// Provides outflow, inflow and neutral plane height for doors and windows
+/// of a fire compartment.
+fn vent_flow(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(kawagoe_air_flow, m)?)?;
+    m.add_function(wrap_pyfunction!(pressure_difference, m)?)?;
+    m.add_function(wrap_pyfunction!(outflow, m)?)?;
+    m.add_function(wrap_pyfunction!(inflow, m)?)?;
+    m.add_function(wrap_pyfunction!(neutral_plane_mixed, m)?)?;
+    m.add_function(wrap_pyfunction!(flows, m)?)?;
+    Ok(())
+}
+
 #[pymodule]
 /// Section 8 - Fire growth and heat release rate calculations.
 ///
@@ -406,5 +826,7 @@ pub fn section_8(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pymodule!(equation_42))?;
     m.add_wrapped(wrap_pymodule!(equation_43))?;
     m.add_wrapped(wrap_pymodule!(equation_44))?;
+    m.add_wrapped(wrap_pymodule!(design_fire))?;
+    m.add_wrapped(wrap_pymodule!(vent_flow))?;
     Ok(())
 }
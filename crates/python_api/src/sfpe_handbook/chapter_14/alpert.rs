@@ -1,3 +1,5 @@
+pub mod activation;
+pub mod ceiling_jet;
 pub mod heat_release;
 
 use pyo3::prelude::*;
@@ -10,6 +12,8 @@ use pyo3::wrap_pymodule;
 /// flows beneath unconfined ceilings. These correlations relate fire
 /// properties to measurements taken in the ceiling jet.
 pub fn alpert(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_wrapped(wrap_pymodule!(activation::activation))?;
+    m.add_wrapped(wrap_pymodule!(ceiling_jet::ceiling_jet))?;
     m.add_wrapped(wrap_pymodule!(heat_release::heat_release))?;
     Ok(())
 }
@@ -0,0 +1,75 @@
+use pyo3::prelude::*;
+use pyo3::wrap_pyfunction;
+
+use openfire::sfpe_handbook::chapter_14::alpert::activation as rust_activation;
+
+use crate::history::History;
+
+#[pyfunction]
+#[pyo3(signature = (detector, hrr, delta_t, height, radial_position, temp_amb=20.0))]
+/// Activation of a sprinkler or heat detector under a growing fire (DETACT).
+///
+/// The link temperature is integrated through time with the gas temperature
+/// and velocity of the Alpert ceiling jet correlations:
+///
+/// .. math::
+///
+///    \frac{dT_l}{dt} = \frac{\sqrt{u}}{RTI} (T_g - T_l) - \frac{C}{RTI} (T_l - T_{\infty})
+///
+/// where:
+///
+/// - :math:`T_l` is the link temperature (°C)
+/// - :math:`T_g` is the ceiling jet temperature (°C)
+/// - :math:`u` is the ceiling jet velocity (m/s)
+/// - :math:`RTI` is the response time index (m^½ s^½)
+/// - :math:`C` is the conduction factor ((m/s)^½)
+///
+/// Args:
+///     detector (tuple[float, float, float]): RTI (m^½ s^½), C-factor ((m/s)^½) and activation
+///         temperature (°C)
+///     hrr (list[float]): Heat release rates at times 0, delta_t, 2 delta_t, ... (kW)
+///     delta_t (float): Time step of the heat release rates (s)
+///     height (float): Height of ceiling above fire (m)
+///     radial_position (float): Radial distance from fire centerline (m)
+///     temp_amb (float): Ambient temperature (°C)
+///
+/// Returns:
+///     tuple[float, float] | None: Activation time (s) and heat release rate at activation (kW),
+///     or None if the detector does not activate within the heat release rate history
+///
+/// Raises:
+///     ValueError: If there are no heat release rates or delta_t is not positive
+///
+/// Example:
+///     >>> import ofire
+///     >>> hrr = [0.0469 * (10.0 * i) ** 2 for i in range(61)]
+///     >>> result = ofire.sfpe_handbook.chapter_14.alpert.activation.activation_time((50.0, 0.5, 68.0), hrr, 10.0, 3.0, 2.0)
+fn activation_time(
+    detector: (f64, f64, f64),
+    hrr: Vec<f64>,
+    delta_t: f64,
+    height: f64,
+    radial_position: f64,
+    temp_amb: f64,
+) -> PyResult<Option<(f64, f64)>> {
+    let hrr = History::new("hrr", &hrr, delta_t)?;
+
+    let (rti, c, activation_temperature) = detector;
+    let detector = rust_activation::Detector::new(rti, c, activation_temperature);
+
+    let curve = |t: f64| hrr.at(t);
+
+    Ok(detector
+        .activation(curve, height, radial_position, temp_amb, hrr.end())
+        .map(|result| (result.time, result.hrr)))
+}
+
+#[pymodule]
+/// Detector Activation - RTI model for sprinklers and heat detectors.
+///
+/// This module integrates the link temperature of a sprinkler or heat
+/// detector in the Alpert ceiling jet of a time-varying fire.
+pub fn activation(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(activation_time, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use pyo3::prelude::*;
+use pyo3::wrap_pyfunction;
+
+use openfire::sfpe_handbook::chapter_14::alpert::ceiling_jet as rust_ceiling_jet;
+
+#[pyfunction]
+/// Maximum ceiling jet temperature beneath an unconfined ceiling (Alpert).
+///
+/// .. math::
+///    :nowrap:
+///
+///    \begin{align}
+///    \text{For } \frac{r}{H} \leq 0.18: \quad &T = T_{\infty} + \frac{16.9 \dot{Q}^{2/3}}{H^{5/3}} \\
+///    \text{For } \frac{r}{H} > 0.18: \quad &T = T_{\infty} + \frac{5.38 (\dot{Q} / r)^{2/3}}{H}
+///    \end{align}
+///
+/// where:
+///
+/// - :math:`T` is the ceiling jet temperature
+/// - :math:`T_{\infty}` is the ambient temperature
+/// - :math:`\dot{Q}` is the heat release rate (kW)
+/// - :math:`H` is the height of the ceiling above the fire (m)
+/// - :math:`r` is the radial distance from the fire centerline (m)
+///
+/// Args:
+///     q (float): Heat release rate (kW)
+///     height (float): Height of ceiling above fire (m)
+///     radial_position (float): Radial distance from fire centerline (m)
+///     temp_amb (float): Ambient temperature (K or °C)
+///
+/// Returns:
+///     float: Ceiling jet temperature, in the units of ``temp_amb``
+///
+/// Example:
+///     >>> import ofire
+///     >>> t = ofire.sfpe_handbook.chapter_14.alpert.ceiling_jet.temperature(1000.0, 5.0, 2.0, 293.15)
+fn temperature(q: f64, height: f64, radial_position: f64, temp_amb: f64) -> PyResult<f64> {
+    Ok(rust_ceiling_jet::temperature(
+        q,
+        height,
+        radial_position,
+        temp_amb,
+    ))
+}
+
+#[pyfunction]
+/// Maximum ceiling jet velocity beneath an unconfined ceiling (Alpert).
+///
+/// .. math::
+///    :nowrap:
+///
+///    \begin{align}
+///    \text{For } \frac{r}{H} \leq 0.15: \quad &U = 0.96 \left(\frac{\dot{Q}}{H}\right)^{1/3} \\
+///    \text{For } \frac{r}{H} > 0.15: \quad &U = \frac{0.195 \dot{Q}^{1/3} H^{1/2}}{r^{5/6}}
+///    \end{align}
+///
+/// Args:
+///     q (float): Heat release rate (kW)
+///     height (float): Height of ceiling above fire (m)
+///     radial_position (float): Radial distance from fire centerline (m)
+///
+/// Returns:
+///     float: Ceiling jet velocity (m/s)
+///
+/// Example:
+///     >>> import ofire
+///     >>> u = ofire.sfpe_handbook.chapter_14.alpert.ceiling_jet.velocity(1000.0, 5.0, 2.0)
+fn velocity(q: f64, height: f64, radial_position: f64) -> PyResult<f64> {
+    Ok(rust_ceiling_jet::velocity(q, height, radial_position))
+}
+
+#[pymodule]
+/// Ceiling Jet Correlations - Alpert correlations for ceiling jet temperature and velocity.
+///
+/// This module contains the forward Alpert correlations giving the maximum
+/// temperature and velocity of the ceiling jet for a given heat release rate.
+pub fn ceiling_jet(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(temperature, m)?)?;
+    m.add_function(wrap_pyfunction!(velocity, m)?)?;
+    Ok(())
+}
@@ -1 +1,3 @@
+pub mod activation;
+pub mod ceiling_jet;
 pub mod heat_release;
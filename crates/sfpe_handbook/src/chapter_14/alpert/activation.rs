@@ -0,0 +1,163 @@
+//! Activation of sprinklers and heat detectors under a ceiling jet
+//! (DETACT). The link temperature `T_l` follows
+//! `dT_l/dt = √u / RTI (T_g - T_l) - C / RTI (T_l - T_a)`, with the gas
+//! temperature `T_g` and velocity `u` from Alpert's correlations for a
+//! time-varying heat release rate, treated as quasi-steady. Temperatures are
+//! in °C, times in s and heat release rates in kW.
+
+use ode::events::{Direction, Event};
+use ode::integrator::{self, Method, Options, Status};
+
+use super::ceiling_jet;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Detector {
+    /// Response time index (m^½ s^½).
+    pub rti: f64,
+    /// Conduction factor (m/s)^½.
+    pub c: f64,
+    /// Activation temperature (°C).
+    pub activation_temperature: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Activation {
+    /// Activation time (s).
+    pub time: f64,
+    /// Heat release rate at activation (kW).
+    pub hrr: f64,
+    /// Ceiling jet temperature at activation (°C).
+    pub gas_temperature: f64,
+    /// Ceiling jet velocity at activation (m/s).
+    pub velocity: f64,
+}
+
+impl Detector {
+    pub fn new(rti: f64, c: f64, activation_temperature: f64) -> Self {
+        Detector {
+            rti,
+            c,
+            activation_temperature,
+        }
+    }
+
+    /// Link temperature rate of change (°C/s).
+    pub fn heating_rate(
+        &self,
+        gas_temperature: f64,
+        velocity: f64,
+        link: f64,
+        temp_amb: f64,
+    ) -> f64 {
+        (velocity.sqrt() * (gas_temperature - link) - self.c * (link - temp_amb)) / self.rti
+    }
+
+    /// Activation under a fire with heat release rate `hrr(t)`, at
+    /// `height` (m) above the fuel and `radial_position` (m) from the plume
+    /// centreline, starting at `temp_amb`. Returns `None` if the detector
+    /// does not activate by `t_end`.
+    pub fn activation<H: Fn(f64) -> f64>(
+        &self,
+        hrr: H,
+        height: f64,
+        radial_position: f64,
+        temp_amb: f64,
+        t_end: f64,
+    ) -> Option<Activation> {
+        let jet = |t: f64| {
+            let q = hrr(t).max(0.0);
+            (
+                q,
+                ceiling_jet::temperature(q, height, radial_position, temp_amb),
+                ceiling_jet::velocity(q, height, radial_position),
+            )
+        };
+        let events = [Event::threshold(
+            0,
+            self.activation_temperature,
+            Direction::Rising,
+            true,
+        )];
+        // Steps are limited so a sampled heat release rate is followed.
+        let options = Options {
+            h_max: 1.0,
+            ..Options::default()
+        };
+
+        let solution = integrator::solve(
+            Method::DormandPrince,
+            |t, y| {
+                let (_, gas, u) = jet(t);
+                vec![self.heating_rate(gas, u, y[0], temp_amb)]
+            },
+            0.0,
+            &[temp_amb],
+            t_end,
+            t_end,
+            &events,
+            &options,
+        );
+
+        if solution.status != Status::Terminated {
+            return None;
+        }
+        let time = solution.first_event(0)?.t;
+        let (hrr, gas_temperature, velocity) = jet(time);
+        Some(Activation {
+            time,
+            hrr,
+            gas_temperature,
+            velocity,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_steady_fire_matches_analytical() {
+        // For a steady fire the link approaches its equilibrium exponentially.
+        let detector = Detector::new(100.0, 0.0, 68.0);
+        let (q, h, r) = (1000.0, 3.0, 2.0);
+        let gas = ceiling_jet::temperature(q, h, r, 20.0);
+        let u = ceiling_jet::velocity(q, h, r);
+        let expected = -detector.rti / u.sqrt() * (1.0 - (68.0 - 20.0) / (gas - 20.0)).ln();
+
+        let result = detector.activation(|_| q, h, r, 20.0, 600.0).unwrap();
+
+        assert!((result.time - expected).abs() < 1e-3);
+        assert_eq!(result.hrr, q);
+        assert!((result.gas_temperature - gas).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_conduction_delays_activation() {
+        let fire = |t: f64| 0.0469 * t * t;
+        let without = Detector::new(50.0, 0.0, 68.0)
+            .activation(fire, 3.0, 2.0, 20.0, 900.0)
+            .unwrap();
+        let with = Detector::new(50.0, 0.5, 68.0)
+            .activation(fire, 3.0, 2.0, 20.0, 900.0)
+            .unwrap();
+
+        assert!(with.time > without.time);
+        assert!((with.hrr - 0.0469 * with.time.powi(2)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_fast_response_activates_first() {
+        let fire = |t: f64| 0.01172 * t * t;
+        let fast = Detector::new(50.0, 0.0, 68.0).activation(fire, 4.0, 3.0, 20.0, 1200.0);
+        let standard = Detector::new(300.0, 0.0, 68.0).activation(fire, 4.0, 3.0, 20.0, 1200.0);
+
+        assert!(fast.unwrap().time < standard.unwrap().time);
+    }
+
+    #[test]
+    fn test_no_activation() {
+        let result = Detector::new(100.0, 0.0, 141.0).activation(|_| 100.0, 8.0, 5.0, 20.0, 600.0);
+        assert_eq!(result, None);
+    }
+}
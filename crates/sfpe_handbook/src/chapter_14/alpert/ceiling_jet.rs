@@ -0,0 +1,54 @@
+//! Alpert's correlations for the maximum temperature and velocity of the
+//! ceiling jet under an unconfined ceiling, for a heat release rate `q`
+//! (kW), ceiling height above the fuel `height` (m) and radial distance from
+//! the plume centreline `radial_position` (m).
+
+/// Ceiling jet temperature, in the units of `temp_amb`.
+pub fn temperature(q: f64, height: f64, radial_position: f64, temp_amb: f64) -> f64 {
+    if radial_position / height <= 0.18 {
+        temp_amb + 16.9 * q.powf(2.0 / 3.0) / height.powf(5.0 / 3.0)
+    } else {
+        temp_amb + 5.38 * (q / radial_position).powf(2.0 / 3.0) / height
+    }
+}
+
+/// Ceiling jet velocity (m/s).
+pub fn velocity(q: f64, height: f64, radial_position: f64) -> f64 {
+    if radial_position / height <= 0.15 {
+        0.96 * (q / height).powf(1.0 / 3.0)
+    } else {
+        0.195 * q.powf(1.0 / 3.0) * height.sqrt() / radial_position.powf(5.0 / 6.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chapter_14::alpert::heat_release::from_temperature_and_position;
+
+    #[test]
+    fn test_temperature_near_axis() {
+        let result = temperature(1000.0, 5.0, 0.5, 20.0);
+        let expected = 20.0 + 16.9 * 100.0 / 5.0_f64.powf(5.0 / 3.0);
+        assert!((result - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_temperature_inverts_heat_release() {
+        for r in [0.5, 3.0] {
+            let temp = temperature(2500.0, 6.0, r, 20.0);
+            let q = from_temperature_and_position(temp, 20.0, 6.0, r);
+            assert!((q - 2500.0).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_velocity() {
+        let near = velocity(1000.0, 5.0, 0.5);
+        let far = velocity(1000.0, 5.0, 4.0);
+        assert!((near - 0.96 * 200.0_f64.cbrt()).abs() < 1e-9);
+        let expected = 0.195 * 10.0 * 5.0_f64.sqrt() / 4.0_f64.powf(5.0 / 6.0);
+        assert!((far - expected).abs() < 1e-9);
+        assert!(far < near);
+    }
+}
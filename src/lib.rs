@@ -2,8 +2,12 @@ pub use br_187;
 pub use bs9999;
 pub use cibse_guide_e;
 pub use eurocode_1_1_2;
+pub use eurocode_2_1_2;
+pub use eurocode_3_1_2;
+pub use eurocode_5_1_2;
 pub use fire_dynamics_tools;
 pub use introduction_to_fire_dynamics;
+pub use ode;
 pub use pd_7974;
 pub use sfpe_handbook;
 pub use tr17;